@@ -0,0 +1,137 @@
+//! A webview on a hidden window of the benchmark thread, created with the
+//! installed WebView2 runtime.
+
+use std::cell::RefCell;
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+use webview2::{Environment, EnvironmentBuilder, Host, WebView};
+use widestring::WideCString;
+use winapi::shared::windef::HWND;
+use winapi::um::combaseapi::CoInitializeEx;
+use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+use winapi::um::winuser::*;
+
+pub struct Live {
+    pub window: HWND,
+    pub environment: Environment,
+    pub host: Host,
+    pub webview: WebView,
+}
+
+impl Live {
+    /// Create the webview and wait until it has loaded `about:blank`. `None`
+    /// if that fails, e.g. because the runtime is not installed.
+    pub fn new() -> Option<Self> {
+        unsafe {
+            CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+        }
+        let window = create_window();
+        let created: Rc<RefCell<Option<webview2::Result<(Environment, Host)>>>> =
+            Rc::new(RefCell::new(None));
+        let result = {
+            let created = created.clone();
+            EnvironmentBuilder::new().build(move |env| {
+                let env = match env {
+                    Ok(env) => env,
+                    Err(e) => {
+                        *created.borrow_mut() = Some(Err(e));
+                        return Ok(());
+                    }
+                };
+                let result = {
+                    let created = created.clone();
+                    env.clone().create_host(window, move |host| {
+                        *created.borrow_mut() = Some(host.map(|host| (env, host)));
+                        Ok(())
+                    })
+                };
+                if let Err(e) = result {
+                    *created.borrow_mut() = Some(Err(e));
+                }
+                Ok(())
+            })
+        };
+        if let Err(e) = result {
+            eprintln!("skipping live benchmarks: {}", e);
+            return None;
+        }
+        pump_until(|| created.borrow().is_some());
+        let (environment, host) = match created.borrow_mut().take().unwrap() {
+            Ok(created) => created,
+            Err(e) => {
+                eprintln!("skipping live benchmarks: {}", e);
+                return None;
+            }
+        };
+        let webview = host.get_webview().ok()?;
+
+        let loaded = Rc::new(RefCell::new(false));
+        {
+            let loaded = loaded.clone();
+            webview
+                .add_navigation_completed(move |_, _| {
+                    *loaded.borrow_mut() = true;
+                    Ok(())
+                })
+                .ok()?;
+        }
+        webview.navigate("about:blank").ok()?;
+        pump_until(|| *loaded.borrow());
+
+        Some(Live {
+            window,
+            environment,
+            host,
+            webview,
+        })
+    }
+
+    /// Run the message loop of the thread until `done` returns true.
+    pub fn pump_until(&self, done: impl Fn() -> bool) {
+        pump_until(done)
+    }
+}
+
+impl Drop for Live {
+    fn drop(&mut self) {
+        let _ = self.host.close();
+        unsafe {
+            DestroyWindow(self.window);
+        }
+    }
+}
+
+fn pump_until(done: impl Fn() -> bool) {
+    unsafe {
+        let mut msg: MSG = mem::zeroed();
+        while !done() && GetMessageW(&mut msg, ptr::null_mut(), 0, 0) > 0 {
+            TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+}
+
+/// A hidden window of the predefined `STATIC` class, so no window class has
+/// to be registered.
+fn create_window() -> HWND {
+    let class_name = WideCString::from_str("STATIC").unwrap();
+    let window = unsafe {
+        CreateWindowExW(
+            0,
+            class_name.as_ptr(),
+            ptr::null(),
+            WS_OVERLAPPEDWINDOW,
+            0,
+            0,
+            800,
+            600,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
+    };
+    assert!(!window.is_null(), "CreateWindowExW failed");
+    window
+}
@@ -0,0 +1,157 @@
+//! Overhead of the wrappers over calling the COM interfaces directly.
+//!
+//! Runs against the installed runtime, and does nothing if a webview can't
+//! be created. `call` benchmarks time only issuing asynchronous calls, the
+//! completions are waited for outside of the measurement. `round_trip`
+//! includes waiting for them.
+
+mod live;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use live::Live;
+use std::cell::Cell;
+use std::ptr;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use webview2::raw::*;
+use webview2::Stream;
+use widestring::WideCString;
+use winapi::shared::ntdef::LPCWSTR;
+use winapi::shared::winerror::{HRESULT, S_OK};
+
+const SCRIPT: &str = "1";
+const MESSAGE: &str = r#"{"type":"benchmark","value":1}"#;
+const BODY: &[u8] = b"<!doctype html><title>benchmark</title>";
+const HEADERS: &str = "Content-Type: text/html";
+
+/// Time issuing `iters` calls with `call`, which is passed a counter to
+/// increment on completion, then wait for all of them.
+fn time_calls(live: &Live, iters: u64, call: impl Fn(Rc<Cell<u64>>)) -> Duration {
+    let completed = Rc::new(Cell::new(0));
+    let start = Instant::now();
+    for _ in 0..iters {
+        call(completed.clone());
+    }
+    let elapsed = start.elapsed();
+    live.pump_until(|| completed.get() == iters);
+    elapsed
+}
+
+fn execute_script_wrapper(live: &Live, completed: Rc<Cell<u64>>) {
+    live.webview
+        .execute_script(SCRIPT, move |_| {
+            completed.set(completed.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn execute_script_raw(live: &Live, script: &WideCString, completed: Rc<Cell<u64>>) {
+    let handler = webview2::callback!(
+        ICoreWebView2ExecuteScriptCompletedHandler,
+        move |_error_code: HRESULT, _result_object_as_json: LPCWSTR| -> HRESULT {
+            completed.set(completed.get() + 1);
+            S_OK
+        }
+    );
+    let hr = unsafe {
+        live.webview
+            .as_raw()
+            .execute_script(script.as_ptr(), handler.as_raw())
+    };
+    assert_eq!(hr, S_OK);
+}
+
+fn execute_script(c: &mut Criterion, live: &Live) {
+    let script = WideCString::from_str(SCRIPT).unwrap();
+    let mut group = c.benchmark_group("execute_script");
+    group.bench_function("call/wrapper", |b| {
+        b.iter_custom(|iters| time_calls(live, iters, |n| execute_script_wrapper(live, n)))
+    });
+    group.bench_function("call/raw", |b| {
+        b.iter_custom(|iters| time_calls(live, iters, |n| execute_script_raw(live, &script, n)))
+    });
+    group.bench_function("round_trip/wrapper", |b| {
+        b.iter(|| {
+            let completed = Rc::new(Cell::new(0));
+            execute_script_wrapper(live, completed.clone());
+            live.pump_until(|| completed.get() == 1);
+        })
+    });
+    group.bench_function("round_trip/raw", |b| {
+        b.iter(|| {
+            let completed = Rc::new(Cell::new(0));
+            execute_script_raw(live, &script, completed.clone());
+            live.pump_until(|| completed.get() == 1);
+        })
+    });
+    group.finish();
+}
+
+fn post_web_message(c: &mut Criterion, live: &Live) {
+    let mut group = c.benchmark_group("post_web_message_as_json");
+    group.bench_function("wrapper", |b| {
+        b.iter(|| live.webview.post_web_message_as_json(MESSAGE).unwrap())
+    });
+    // The wrapper converts to UTF-16 on every call, so does this.
+    group.bench_function("raw", |b| {
+        b.iter(|| {
+            let message = WideCString::from_str(MESSAGE).unwrap();
+            let hr = unsafe {
+                live.webview
+                    .as_raw()
+                    .post_web_message_as_json(message.as_ptr())
+            };
+            assert_eq!(hr, S_OK);
+        })
+    });
+    group.finish();
+}
+
+/// Creating the response of an intercepted request, as in a
+/// `WebResourceRequested` handler.
+fn web_resource_response(c: &mut Criterion, live: &Live) {
+    let mut group = c.benchmark_group("create_web_resource_response");
+    group.bench_function("wrapper", |b| {
+        b.iter(|| {
+            let content = Stream::from_bytes(BODY);
+            live.environment
+                .create_web_resource_response(Some(&content), 200, "OK", HEADERS)
+                .unwrap()
+        })
+    });
+    group.bench_function("raw", |b| {
+        b.iter(|| {
+            let content = Stream::from_bytes(BODY);
+            let reason_phrase = WideCString::from_str("OK").unwrap();
+            let headers = WideCString::from_str(HEADERS).unwrap();
+            let mut response: *mut *mut ICoreWebView2WebResourceResponseVTable = ptr::null_mut();
+            let hr = unsafe {
+                live.environment.as_raw().create_web_resource_response(
+                    content.as_raw().as_raw(),
+                    200,
+                    reason_phrase.as_ptr(),
+                    headers.as_ptr(),
+                    &mut response,
+                )
+            };
+            assert_eq!(hr, S_OK);
+            unsafe { com::ComRc::<dyn ICoreWebView2WebResourceResponse>::from_raw(response) }
+        })
+    });
+    group.finish();
+}
+
+fn wrapper(c: &mut Criterion) {
+    // One webview for all benchmarks, creating it takes a while.
+    let live = match Live::new() {
+        Some(live) => live,
+        None => return,
+    };
+    execute_script(c, &live);
+    post_web_message(c, &live);
+    web_resource_response(c, &live);
+}
+
+criterion_group!(benches, wrapper);
+criterion_main!(benches);
@@ -0,0 +1,102 @@
+//! Picks the loader for the target architecture: `WebView2Loader.dll` of the
+//! bundled package to embed with the `embed-dll` feature, or
+//! `WebView2LoaderStatic.lib` to link with the `static-loader` feature.
+//!
+//! The bundled 0.9.430 package predates the static loader, so the library is
+//! taken from another `Microsoft.Web.WebView2` NuGet package, either:
+//!
+//! * the extracted package at `WEBVIEW2_PACKAGE_DIR`, or
+//! * the newest version in the NuGet global packages folder, i.e.
+//!   `NUGET_PACKAGES` or `%USERPROFILE%\.nuget\packages`, where
+//!   `nuget install Microsoft.Web.WebView2` or a restore puts it.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=WEBVIEW2_PACKAGE_DIR");
+    println!("cargo:rerun-if-env-changed=NUGET_PACKAGES");
+
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() != Ok("windows") {
+        return;
+    }
+    // The package folder of the architecture.
+    let arch = match env::var("CARGO_CFG_TARGET_ARCH").unwrap().as_str() {
+        "x86_64" => "x64",
+        "x86" => "x86",
+        "aarch64" => "arm64",
+        arch => panic!("WebView2 has no loader for {}", arch),
+    };
+
+    if env::var_os("CARGO_FEATURE_STATIC_LOADER").is_some() {
+        link_static_loader(arch);
+    } else if env::var_os("CARGO_FEATURE_EMBED_DLL").is_some()
+        && env::var_os("CARGO_FEATURE_RUST_LOADER").is_none()
+    {
+        let dll = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap())
+            .join("Microsoft.Web.WebView2.0.9.430")
+            .join("build")
+            .join("native")
+            .join(arch)
+            .join("WebView2Loader.dll");
+        println!("cargo:rerun-if-changed={}", dll.display());
+        println!("cargo:rustc-env=WEBVIEW2_LOADER_DLL={}", dll.display());
+    }
+}
+
+fn link_static_loader(arch: &str) {
+    let package_dir = match env::var_os("WEBVIEW2_PACKAGE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => newest_nuget_package().unwrap_or_else(|| {
+            panic!(
+                "the static-loader feature needs a Microsoft.Web.WebView2 NuGet package, \
+                 install one with `nuget install Microsoft.Web.WebView2` or set \
+                 WEBVIEW2_PACKAGE_DIR to an extracted one"
+            )
+        }),
+    };
+    let lib_dir = package_dir.join("build").join("native").join(arch);
+    if !lib_dir.join("WebView2LoaderStatic.lib").exists() {
+        panic!(
+            "no WebView2LoaderStatic.lib in {}, it is in packages 1.0.622 and later",
+            lib_dir.display()
+        );
+    }
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    println!("cargo:rustc-link-lib=static=WebView2LoaderStatic");
+    // What the loader itself links to.
+    for lib in &[
+        "advapi32", "ole32", "shell32", "shlwapi", "user32", "version",
+    ] {
+        println!("cargo:rustc-link-lib=dylib={}", lib);
+    }
+}
+
+fn newest_nuget_package() -> Option<PathBuf> {
+    let packages = match env::var_os("NUGET_PACKAGES") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(&env::var_os("USERPROFILE")?)
+            .join(".nuget")
+            .join("packages"),
+    };
+    // Package folders there are lowercase, one per version.
+    std::fs::read_dir(packages.join("microsoft.web.webview2"))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .max_by_key(|path| version_key(&path.file_name().unwrap().to_string_lossy()))
+}
+
+/// `1.0.2210.55` to `[1, 0, 2210, 55]`, ignoring prerelease suffixes.
+fn version_key(version: &str) -> Vec<u32> {
+    version
+        .split('-')
+        .next()
+        .unwrap()
+        .split('.')
+        .map(|n| n.parse().unwrap_or(0))
+        .collect()
+}
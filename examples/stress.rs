@@ -0,0 +1,35 @@
+//! Create and close webviews in a loop and fail if resources leak.
+//!
+//! Usage: `stress [ITERATIONS]`.
+
+use webview2::stress::{self, StressOptions};
+use winapi::shared::windef::HWND;
+use winit::dpi::Size;
+use winit::event_loop::EventLoop;
+use winit::platform::windows::WindowExtWindows;
+use winit::window::WindowBuilder;
+
+fn main() {
+    let iterations = std::env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("ITERATIONS must be a number"))
+        .unwrap_or(1000);
+
+    // The event loop is not run, `stress::run` pumps messages itself.
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("WebView2 - Stress")
+        .with_inner_size(Size::Logical((800, 600).into()))
+        .build(&event_loop)
+        .unwrap();
+
+    let options = StressOptions {
+        iterations,
+        ..StressOptions::default()
+    };
+    let report = stress::run(window.hwnd() as HWND, options).expect("stress run");
+    println!("after warm-up: {}", report.baseline);
+    println!("at the end:    {}", report.last);
+    report.assert_stable();
+    println!("no leaks over {} iterations", report.iterations);
+}
@@ -0,0 +1,4504 @@
+//! Generate Rust bindings from the WebView2 IDL, for the `com` crate or as
+//! plain vtables without it (`Document::render_raw_to_string`).
+//!
+//! ```no_run
+//! let idl = std::fs::read_to_string("WebView2.idl").unwrap();
+//! let doc = idl2rs::parse_idl(&idl).unwrap_or_else(|errors| {
+//!     for e in errors {
+//!         eprintln!("{}", e);
+//!     }
+//!     panic!("failed to parse WebView2.idl");
+//! });
+//! std::fs::write("sys.rs", doc.render_to_string()).unwrap();
+//! ```
+
+use pest::error::{Error, ErrorVariant, InputLocation};
+use pest::{iterators::Pair, Parser, Position};
+use pest_derive::Parser;
+use serde::{Serialize, Serializer};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+
+#[derive(Parser)]
+#[grammar = "idl.pest"]
+struct IDLParser;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Modifier {
+    Pointer,
+    Const,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct Type<'a> {
+    /// The Rust type.
+    base_type: Cow<'a, str>,
+    modifiers: Vec<Modifier>,
+    is_interface: bool,
+    /// The type as written in the IDL, which the tables below are keyed by.
+    #[serde(skip)]
+    idl_name: &'a str,
+}
+
+impl<'a> Type<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::_type);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identifier => {
+                    let name = p.as_str();
+                    result.idl_name = name;
+                    result.base_type = if is_interface_name(name) {
+                        result.is_interface = true;
+                        result.modifiers.push(Modifier::Pointer);
+                        format!("{}VTable", name).into()
+                    } else {
+                        match PRIMITIVE_TYPES.iter().find(|(idl, _)| *idl == name) {
+                            Some((_, rust)) => (*rust).into(),
+                            None => name.into(),
+                        }
+                    }
+                }
+                Rule::pointer => result.modifiers.push(Modifier::Pointer),
+                Rule::_const => result.modifiers.push(Modifier::Const),
+                _ => {}
+            }
+        }
+        result.modifiers.reverse();
+        result
+    }
+
+    pub fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        self.render_pointers(w, false)
+    }
+
+    /// Render the type, making the outermost pointer `*const` if `input` is
+    /// true.
+    ///
+    /// Interface pointers are always `*mut`, because COM objects are
+    /// reference counted through them even when passed as input.
+    fn render_pointers(&self, w: &mut impl Write, input: bool) -> io::Result<()> {
+        let mut outermost = input && !self.is_interface;
+        for m in &self.modifiers {
+            if matches!(m, Modifier::Pointer) {
+                if outermost {
+                    write!(w, "*const ")?;
+                    outermost = false;
+                } else {
+                    write!(w, "*mut ")?;
+                }
+            }
+        }
+
+        write!(w, "{}", self.base_type)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct Parameter<'a> {
+    attributes: Vec<&'a str>,
+    r#type: Type<'a>,
+    name: &'a str,
+    /// The parameter with the element count, if this is an array.
+    size_is: Option<&'a str>,
+}
+
+impl<'a> Parameter<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::parameter);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::parameter_attribute => {
+                    result.attributes.push(p.as_str());
+                    let size_is = p.into_inner().find(|p| p.as_rule() == Rule::size_is);
+                    if let Some(size_is) = size_is {
+                        // The last argument is the size of the outermost
+                        // pointer, dereferenced for `[out]` counts.
+                        let argument = size_is.into_inner().next().unwrap().as_str();
+                        let count = argument.rsplit(',').next().unwrap();
+                        result.size_is = Some(count.trim().trim_start_matches('*').trim());
+                    }
+                }
+                Rule::_type => result.r#type = Type::from_pest(p),
+                Rule::identifier => result.name = p.as_str(),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    pub fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        if !self.attributes.is_empty() {
+            write!(w, "/* {} */ ", self.attributes.join(", "))?;
+        };
+        write!(w, "{}: ", self.name)?;
+        self.r#type.render_pointers(w, self.is_input())
+    }
+
+    /// `[in]` but not `[out]`.
+    fn is_input(&self) -> bool {
+        self.attributes.contains(&"in") && !self.attributes.contains(&"out")
+    }
+
+    /// `[out]` but not `[in]`, and not a caller allocated array, which may
+    /// have no element to write. Mocks zero these.
+    fn is_zeroed_by_mock(&self) -> bool {
+        let caller_allocated = self.size_is.is_some() && self.r#type.modifiers.len() == 1;
+        self.attributes.contains(&"out")
+            && !self.attributes.contains(&"in")
+            && !self.r#type.modifiers.is_empty()
+            && !caller_allocated
+    }
+
+    /// The type of the parameter or result of a wrapper method.
+    fn wrapper_type(
+        &self,
+        output: bool,
+        kind: WrapperKind,
+        wrapped: &HashMap<&str, String>,
+    ) -> String {
+        let t = &self.r#type;
+        let interface = || {
+            if let Some(wrapper) = wrapped.get(t.idl_name) {
+                wrapper.clone()
+            } else {
+                format!("ComRc<dyn {}>", t.idl_name)
+            }
+        };
+        match kind {
+            WrapperKind::Bool => "bool".to_owned(),
+            WrapperKind::String if output => "String".to_owned(),
+            WrapperKind::String => "&str".to_owned(),
+            WrapperKind::Value => t.base_type.to_string(),
+            WrapperKind::Interface if output => interface(),
+            WrapperKind::Interface => format!("&{}", interface()),
+        }
+    }
+
+    /// The type a closure implementing a handler gets this argument of
+    /// `Invoke` as, and the expression converting it, which is unsafe if the
+    /// last value is true. Interfaces are only borrowed by handlers, so a
+    /// reference is added.
+    fn handler_argument(&self, wrapped: &HashMap<&str, String>) -> Option<(String, String, bool)> {
+        let name = camel_to_snake(self.name);
+        let t = &self.r#type;
+        Some(match self.wrapper_kind()? {
+            (true, _) => return None,
+            (false, WrapperKind::Bool) => ("bool".to_owned(), format!("{} != 0", name), false),
+            (false, WrapperKind::String) => {
+                ("String".to_owned(), format!("copy_string({})", name), true)
+            }
+            (false, WrapperKind::Value) => (t.base_type.to_string(), name, false),
+            (false, WrapperKind::Interface) if wrapped.contains_key(t.idl_name) => {
+                let wrapper = wrapped[t.idl_name].clone();
+                let argument = format!("{}::new(add_ref({}))", wrapper, name);
+                (wrapper, argument, true)
+            }
+            (false, WrapperKind::Interface) => (
+                format!("ComRc<dyn {}>", t.idl_name),
+                format!("add_ref({})", name),
+                true,
+            ),
+        })
+    }
+
+    /// Whether this is an output, and how a wrapper method takes or returns
+    /// it. `None` for arrays, `[in, out]` and pointers to data.
+    fn wrapper_kind(&self) -> Option<(bool, WrapperKind)> {
+        let output = if self.is_input() {
+            false
+        } else if self.attributes.contains(&"out") && !self.attributes.contains(&"in") {
+            true
+        } else {
+            return None;
+        };
+        if self.size_is.is_some() {
+            return None;
+        }
+        let t = &self.r#type;
+        let pointers = t
+            .modifiers
+            .iter()
+            .filter(|m| matches!(m, Modifier::Pointer))
+            .count()
+            .checked_sub(output as usize)?;
+        let kind = if t.is_interface {
+            if pointers != 2 {
+                return None;
+            }
+            WrapperKind::Interface
+        } else if pointers != 0 {
+            return None;
+        } else if t.idl_name == "BOOL" {
+            WrapperKind::Bool
+        } else if STRING_TYPES.contains(&t.idl_name) {
+            WrapperKind::String
+        } else {
+            WrapperKind::Value
+        };
+        Some((output, kind))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct Method<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    return_type: Type<'a>,
+    name: &'a str,
+    parameters: Vec<Parameter<'a>>,
+    attributes: Vec<&'a str>,
+    /// The note of `#[deprecated]`, from a `deprecated` attribute or note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<String>,
+}
+
+impl<'a> Method<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::method);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::_type => result.return_type = Type::from_pest(p),
+                Rule::method_name => result.name = p.as_str(),
+                Rule::parameter => result.parameters.push(Parameter::from_pest(p)),
+                Rule::other_attribute => result.attributes.push(p.as_str()),
+                _ => {}
+            }
+        }
+        result.deprecated = deprecation(&result.attributes, result.doc_comment);
+        result
+    }
+
+    pub fn render(&self, w: &mut impl Write, interface: &str) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        self.render_array_doc(w)?;
+        self.render_learn_link(w, interface)?;
+        self.render_doc_alias(w)?;
+        render_deprecated(w, "    ", &self.deprecated)?;
+        write!(w, "    ")?;
+        self.render_signature(w, false)?;
+        writeln!(w, ";")
+    }
+
+    /// Render a method of a mock implementation, which records the call and
+    /// zeroes the out parameters, so callers never read uninitialized values.
+    fn render_mock(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "    ")?;
+        self.render_signature(w, true)?;
+        writeln!(w, " {{")?;
+        writeln!(
+            w,
+            "        self.calls.borrow_mut().push(\"{}\");",
+            self.name
+        )?;
+        for p in self.parameters.iter().filter(|p| p.is_zeroed_by_mock()) {
+            writeln!(w, "        if !{}.is_null() {{", p.name)?;
+            writeln!(w, "            *{} = std::mem::zeroed();", p.name)?;
+            writeln!(w, "        }}")?;
+        }
+        if self.return_type.idl_name == "HRESULT" && self.return_type.modifiers.is_empty() {
+            writeln!(w, "        S_OK")?;
+        } else {
+            writeln!(w, "        std::mem::zeroed()")?;
+        }
+        writeln!(w, "    }}")
+    }
+
+    fn render_raw_field(&self, w: &mut impl Write, interface: &str, abi: Abi) -> io::Result<()> {
+        write!(
+            w,
+            "    pub {}: unsafe extern \"{}\" fn(This: *mut *mut {}VTable",
+            self.name,
+            abi.as_str(),
+            interface
+        )?;
+        for p in &self.parameters {
+            write!(w, ", ")?;
+            p.render(w)?;
+        }
+        write!(w, ") -> ")?;
+        self.return_type.render(w)?;
+        writeln!(w, ",")
+    }
+
+    fn render_raw_wrapper(&self, w: &mut impl Write, interface: &str) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        self.render_array_doc(w)?;
+        self.render_learn_link(w, interface)?;
+        self.render_doc_alias(w)?;
+        render_deprecated(w, "    ", &self.deprecated)?;
+        write!(w, "    pub ")?;
+        self.render_signature(w, false)?;
+        writeln!(w, " {{")?;
+        write!(w, "        ((**self.0).{})(self.0", self.name)?;
+        for p in &self.parameters {
+            write!(w, ", {}", p.name)?;
+        }
+        writeln!(w, ")")?;
+        writeln!(w, "    }}")
+    }
+
+    /// Render a method of a safe wrapper, if all of its parameters can be
+    /// converted: `bool` for `BOOL`, `&str` and `String` for strings,
+    /// wrappers for interfaces in `wrapped` and outputs returned in a
+    /// `Result`.
+    fn render_wrapper(
+        &self,
+        w: &mut impl Write,
+        wrapped: &HashMap<&str, String>,
+    ) -> io::Result<()> {
+        if self.return_type.idl_name != "HRESULT" || !self.return_type.modifiers.is_empty() {
+            return Ok(());
+        }
+        let parameters: Option<Vec<_>> = self
+            .parameters
+            .iter()
+            .map(|p| p.wrapper_kind().map(|(output, kind)| (p, output, kind)))
+            .collect();
+        let parameters = match parameters {
+            Some(parameters) => parameters,
+            None => return Ok(()),
+        };
+        writeln!(w)?;
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        self.render_doc_alias(w)?;
+        render_deprecated(w, "    ", &self.deprecated)?;
+        write!(w, "    pub fn {}(&self", self.rust_name())?;
+        let mut outputs = Vec::new();
+        for &(p, output, kind) in &parameters {
+            let r#type = p.wrapper_type(output, kind, wrapped);
+            if output {
+                outputs.push(r#type);
+            } else {
+                write!(w, ", {}: {}", camel_to_snake(p.name), r#type)?;
+            }
+        }
+        match outputs.as_slice() {
+            [output] => writeln!(w, ") -> Result<{}> {{", output)?,
+            _ => writeln!(w, ") -> Result<({})> {{", outputs.join(", "))?,
+        }
+
+        for &(p, output, kind) in &parameters {
+            if !output && matches!(kind, WrapperKind::String) {
+                let name = camel_to_snake(p.name);
+                writeln!(w, "        let {} = to_wide({})?;", name, name)?;
+            }
+        }
+        writeln!(w, "        unsafe {{")?;
+        let mut arguments = Vec::new();
+        let mut results = Vec::new();
+        for &(p, output, kind) in &parameters {
+            let name = camel_to_snake(p.name);
+            if output {
+                writeln!(w, "            let mut {} = MaybeUninit::uninit();", name)?;
+                arguments.push(format!("{}.as_mut_ptr()", name));
+                let value = format!("{}.assume_init()", name);
+                results.push(match kind {
+                    WrapperKind::Bool => format!("{} != 0", value),
+                    WrapperKind::String => format!("take_string({})", value),
+                    WrapperKind::Value => value,
+                    WrapperKind::Interface if wrapped.contains_key(p.r#type.idl_name) => format!(
+                        "{}::new(ComRc::from_raw({}))",
+                        wrapped[p.r#type.idl_name], value
+                    ),
+                    WrapperKind::Interface => format!("ComRc::from_raw({})", value),
+                });
+            } else {
+                arguments.push(match kind {
+                    WrapperKind::Bool => format!("{} as {}", name, p.r#type.base_type),
+                    WrapperKind::String if p.r#type.idl_name == "LPWSTR" => {
+                        format!("{}.as_ptr() as _", name)
+                    }
+                    WrapperKind::String => format!("{}.as_ptr()", name),
+                    WrapperKind::Value => name,
+                    WrapperKind::Interface if wrapped.contains_key(p.r#type.idl_name) => {
+                        format!("{}.inner.as_raw()", name)
+                    }
+                    WrapperKind::Interface => format!("{}.as_raw()", name),
+                });
+            }
+        }
+        writeln!(
+            w,
+            "            check(self.inner.{}({}))?;",
+            self.rust_name(),
+            arguments.join(", ")
+        )?;
+        match results.as_slice() {
+            [result] => writeln!(w, "            Ok({})", result)?,
+            _ => writeln!(w, "            Ok(({}))", results.join(", "))?,
+        }
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")
+    }
+
+    /// The event and handler interface of an `add_X([in] IHandler* handler,
+    /// [out] EventRegistrationToken* token)` method.
+    fn event(&self) -> Option<(&'a str, &'a str)> {
+        let event = self.name.strip_prefix("add_")?;
+        match self.parameters.as_slice() {
+            [handler, token]
+                if handler.wrapper_kind() == Some((false, WrapperKind::Interface))
+                    && token.wrapper_kind() == Some((true, WrapperKind::Value))
+                    && token.r#type.idl_name == "EventRegistrationToken" =>
+            {
+                Some((event, handler.r#type.idl_name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this is the `remove_X([in] EventRegistrationToken token)`
+    /// method of `event`.
+    fn removes(&self, event: &str) -> bool {
+        self.name.strip_prefix("remove_") == Some(event)
+            && matches!(self.parameters.as_slice(), [token]
+                if token.wrapper_kind() == Some((false, WrapperKind::Value))
+                    && token.r#type.idl_name == "EventRegistrationToken")
+    }
+
+    /// The handler interface of a method whose last parameter is an
+    /// `[in] I...CompletedHandler*` and whose other parameters are inputs a
+    /// wrapper method takes.
+    fn completed_handler(&self) -> Option<&'a str> {
+        let (handler, inputs) = self.parameters.split_last()?;
+        let is_input = |p: &Parameter| matches!(p.wrapper_kind(), Some((false, _)));
+        if self.return_type.idl_name == "HRESULT"
+            && self.return_type.modifiers.is_empty()
+            && handler.r#type.idl_name.ends_with("CompletedHandler")
+            && handler.wrapper_kind() == Some((false, WrapperKind::Interface))
+            && inputs.iter().all(is_input)
+        {
+            Some(handler.r#type.idl_name)
+        } else {
+            None
+        }
+    }
+
+    /// Render `x_async` for this method taking a completed handler, which
+    /// returns a `Completion` future of the results passed to the handler.
+    /// A leading `HRESULT` argument of `Invoke` is the error code of the
+    /// operation and makes the future fail.
+    fn render_async_wrapper(
+        &self,
+        w: &mut impl Write,
+        invoke: &Method,
+        handler: &str,
+        wrapped: &HashMap<&str, String>,
+    ) -> io::Result<()> {
+        let mut results = invoke.parameters.as_slice();
+        let error_code = match results.first() {
+            Some(p) if p.r#type.idl_name == "HRESULT" && p.r#type.modifiers.is_empty() => {
+                results = &results[1..];
+                Some(camel_to_snake(p.name))
+            }
+            _ => None,
+        };
+        let mut types = Vec::new();
+        let mut values = Vec::new();
+        let mut needs_unsafe = false;
+        for p in results {
+            let (r#type, value, unsafe_value) = match p.handler_argument(wrapped) {
+                Some(argument) => argument,
+                None => return Ok(()),
+            };
+            needs_unsafe |= unsafe_value;
+            types.push(r#type);
+            values.push(value);
+        }
+        let (output, value) = match (types.as_slice(), values.as_slice()) {
+            ([r#type], [value]) => (r#type.clone(), value.clone()),
+            _ => (
+                format!("({})", types.join(", ")),
+                format!("({})", values.join(", ")),
+            ),
+        };
+        let value = if needs_unsafe {
+            format!("unsafe {{ {} }}", value)
+        } else {
+            value
+        };
+        let result = match &error_code {
+            Some(error_code) if values.is_empty() => format!("check({})", error_code),
+            Some(error_code) => format!("check({}).map(|()| {})", error_code, value),
+            None => format!("Ok({})", value),
+        };
+        let inputs = &self.parameters[..self.parameters.len() - 1];
+        let names: Vec<String> = invoke
+            .parameters
+            .iter()
+            .map(|p| camel_to_snake(p.name))
+            .collect();
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "    /// `{}` as a future, which completes when the handler is called.",
+            self.rust_name()
+        )?;
+        render_deprecated(w, "    ", &self.deprecated)?;
+        write!(w, "    pub fn {}_async(&self", self.rust_name())?;
+        for p in inputs {
+            let kind = p.wrapper_kind().unwrap().1;
+            write!(
+                w,
+                ", {}: {}",
+                camel_to_snake(p.name),
+                p.wrapper_type(false, kind, wrapped)
+            )?;
+        }
+        writeln!(w, ") -> Completion<{}> {{", output)?;
+        writeln!(
+            w,
+            "        let (completion, completer) = Completion::new();"
+        )?;
+        writeln!(
+            w,
+            "        let handler = {}Closure::create(move |{}| {{",
+            wrapper_name(handler),
+            names.join(", ")
+        )?;
+        writeln!(w, "            completer.complete({});", result)?;
+        writeln!(w, "            S_OK")?;
+        writeln!(w, "        }});")?;
+        let mut arguments: Vec<String> = inputs.iter().map(|p| camel_to_snake(p.name)).collect();
+        arguments.push("&handler".to_owned());
+        writeln!(
+            w,
+            "        match self.{}({}) {{",
+            self.rust_name(),
+            arguments.join(", ")
+        )?;
+        writeln!(w, "            Ok(()) => completion,")?;
+        writeln!(w, "            Err(e) => Completion::ready(Err(e)),")?;
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")
+    }
+
+    /// Render `on_x` for this `add_X` method, which takes a closure and
+    /// returns an `EventSubscription` that calls `remove_X` when dropped.
+    /// The closure gets the arguments of the handler's `Invoke` converted
+    /// like the parameters of wrapper methods.
+    fn render_event_wrapper(
+        &self,
+        w: &mut impl Write,
+        event: &str,
+        invoke: &Method,
+        handler: &str,
+        wrapped: &HashMap<&str, String>,
+    ) -> io::Result<()> {
+        let mut types = Vec::new();
+        let mut names = Vec::new();
+        let mut arguments = Vec::new();
+        let mut needs_unsafe = false;
+        for p in &invoke.parameters {
+            let (r#type, argument, unsafe_argument) = match p.handler_argument(wrapped) {
+                Some(argument) => argument,
+                None => return Ok(()),
+            };
+            needs_unsafe |= unsafe_argument;
+            types.push(r#type);
+            names.push(camel_to_snake(p.name));
+            arguments.push(argument);
+        }
+        let call = format!("handler({})", arguments.join(", "));
+        let call = if needs_unsafe {
+            format!("unsafe {{ {} }}", call)
+        } else {
+            call
+        };
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "    /// Add a handler of `{}`, which is removed when the subscription is",
+            event
+        )?;
+        writeln!(w, "    /// dropped.")?;
+        render_deprecated(w, "    ", &self.deprecated)?;
+        writeln!(
+            w,
+            "    pub fn on_{}(&self, handler: impl Fn({}) -> Result<()> + 'static) \
+             -> Result<EventSubscription> {{",
+            camel_to_snake(event),
+            types.join(", ")
+        )?;
+        writeln!(
+            w,
+            "        let handler = {}Closure::create(move |{}| to_hresult({}));",
+            wrapper_name(handler),
+            names.join(", "),
+            call
+        )?;
+        writeln!(
+            w,
+            "        let token = self.{}(&handler)?;",
+            self.rust_name()
+        )?;
+        writeln!(w, "        let inner = self.inner.clone();")?;
+        writeln!(w, "        Ok(EventSubscription::new(move || unsafe {{")?;
+        writeln!(
+            w,
+            "            inner.{}(token);",
+            camel_to_snake(&format!("remove_{}", event))
+        )?;
+        writeln!(w, "        }}))")?;
+        writeln!(w, "    }}")
+    }
+
+    /// Document which parameter has the length of each array parameter.
+    fn render_array_doc(&self, w: &mut impl Write) -> io::Result<()> {
+        let mut first = true;
+        for p in &self.parameters {
+            let count = match p.size_is {
+                Some(count) => count,
+                None => continue,
+            };
+            if first && self.doc_comment.is_some() {
+                writeln!(w, "    ///")?;
+            }
+            first = false;
+            writeln!(
+                w,
+                "    /// `{}` is an array with `{}` elements.",
+                p.name, count
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Link to the section of the method on the page of its interface.
+    fn render_learn_link(&self, w: &mut impl Write, interface: &str) -> io::Result<()> {
+        if let Some(url) = learn_url(interface) {
+            let has_docs =
+                self.doc_comment.is_some() || self.parameters.iter().any(|p| p.size_is.is_some());
+            if has_docs {
+                writeln!(w, "    ///")?;
+            }
+            writeln!(
+                w,
+                "    /// See also: <{}#{}>",
+                url,
+                self.name.to_lowercase()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Make the method searchable in the docs by its name in the IDL and the
+    /// Microsoft documentation. Not allowed on trait implementations, so
+    /// mocks don't have it.
+    fn render_doc_alias(&self, w: &mut impl Write) -> io::Result<()> {
+        if self.rust_name() != self.name {
+            writeln!(w, "    #[doc(alias = \"{}\")]", self.name)?;
+        }
+        Ok(())
+    }
+
+    /// `get_X` and `put_X` become `get_x` and `put_x`, with the property
+    /// name converted on its own so that both accessors agree. Other methods
+    /// are converted as a whole.
+    fn rust_name(&self) -> String {
+        match self.property() {
+            Some((accessor, property)) => {
+                format!("{}_{}", accessor.prefix(), camel_to_snake(property))
+            }
+            None => camel_to_snake(self.name),
+        }
+    }
+
+    /// The accessor kind and property name of `get_X` and `put_X`.
+    fn property(&self) -> Option<(Accessor, &'a str)> {
+        if let Some(property) = self.name.strip_prefix("get_") {
+            Some((Accessor::Get, property))
+        } else {
+            self.name
+                .strip_prefix("put_")
+                .map(|property| (Accessor::Put, property))
+        }
+    }
+
+    /// The type of a getter with a single `[out, retval] T*` of a value type.
+    fn getter_type(&self) -> Option<&str> {
+        match self.parameters.as_slice() {
+            [p] if p.attributes.contains(&"retval")
+                && p.r#type.modifiers.len() == 1
+                && matches!(p.r#type.modifiers[0], Modifier::Pointer)
+                && is_value_type(&p.r#type) =>
+            {
+                Some(&p.r#type.base_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// The type of a setter with a single `[in]` value.
+    fn setter_type(&self) -> Option<&str> {
+        match self.parameters.as_slice() {
+            [p] if p.is_input() && p.r#type.modifiers.is_empty() && is_value_type(&p.r#type) => {
+                Some(&p.r#type.base_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parameters of mock methods have no attribute comments, and are unused
+    /// unless zeroed.
+    fn render_signature(&self, w: &mut impl Write, mock: bool) -> io::Result<()> {
+        write!(w, "unsafe fn {}(&self", self.rust_name())?;
+        for p in &self.parameters {
+            write!(w, ", ")?;
+            if mock {
+                let unused = if p.is_zeroed_by_mock() { "" } else { "_" };
+                write!(w, "{}{}: ", unused, p.name)?;
+                p.r#type.render_pointers(w, p.is_input())?;
+            } else {
+                p.render(w)?;
+            }
+        }
+        write!(w, ") -> ")?;
+        self.return_type.render(w)
+    }
+}
+
+/// A parameter type that safe wrappers convert.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum WrapperKind {
+    Bool,
+    String,
+    Value,
+    Interface,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Accessor {
+    Get,
+    Put,
+}
+
+impl Accessor {
+    fn prefix(self) -> &'static str {
+        match self {
+            Accessor::Get => "get",
+            Accessor::Put => "put",
+        }
+    }
+}
+
+/// A `get_X`/`put_X` pair, or one of them, whose values can be passed
+/// without pointers.
+struct Property<'m, 'a> {
+    /// Snake case, e.g. `is_visible`.
+    name: String,
+    r#type: &'m str,
+    getter: Option<&'m Method<'a>>,
+    setter: Option<&'m Method<'a>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct TypedefEnum<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    variants: Vec<Variant<'a>>,
+    #[serde(skip)]
+    derive_serde: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Variant<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+}
+
+impl<'a> TypedefEnum<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::typedef_enum);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::identifier => result.name = p.as_str(),
+                Rule::variant => {
+                    let variant = {
+                        let mut result = Variant::default();
+                        for p in p.into_inner() {
+                            match p.as_rule() {
+                                Rule::doc_comment => {
+                                    result.doc_comment = Some(p.as_str().trim_end_matches(" \t"))
+                                }
+                                Rule::identifier => result.name = p.as_str(),
+                                _ => {}
+                            }
+                        }
+                        result
+                    };
+                    result.variants.push(variant);
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    pub fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        writeln!(w, "#[repr(u32)]")?;
+        writeln!(w, "#[derive(Debug, Clone, Copy, Eq, PartialEq)]")?;
+        if self.derive_serde {
+            writeln!(w, "{}", SERDE_DERIVE)?;
+        }
+        writeln!(w, "pub enum {} {{", self.name)?;
+        for variant in &self.variants {
+            write!(w, "{}", variant.doc_comment.unwrap_or(""))?;
+            writeln!(w, "    {},", variant.name)?;
+        }
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(w, "impl {} {{", self.name)?;
+        writeln!(w, "    pub const fn as_u32(self) -> u32 {{")?;
+        writeln!(w, "        self as u32")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+
+        // Variants have no explicit values in the IDL, so they count up from
+        // zero.
+        writeln!(w)?;
+        writeln!(w, "/// Fails with the value if it is not a known variant.")?;
+        writeln!(w, "impl std::convert::TryFrom<u32> for {} {{", self.name)?;
+        writeln!(w, "    type Error = u32;")?;
+        writeln!(w)?;
+        writeln!(w, "    fn try_from(value: u32) -> Result<Self, u32> {{")?;
+        writeln!(w, "        match value {{")?;
+        for (i, variant) in self.variants.iter().enumerate() {
+            writeln!(w, "            {} => Ok(Self::{}),", i, variant.name)?;
+        }
+        writeln!(w, "            _ => Err(value),")?;
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Field<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    r#type: Type<'a>,
+}
+
+impl<'a> Field<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::field);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::_type => result.r#type = Type::from_pest(p),
+                Rule::identifier => result.name = p.as_str(),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct TypedefStruct<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    fields: Vec<Field<'a>>,
+    #[serde(skip)]
+    derive_serde: bool,
+    /// `(size, align)` on 32- and 64-bit targets, if all field types are
+    /// known.
+    #[serde(skip)]
+    layout: Option<[(usize, usize); 2]>,
+}
+
+impl<'a> TypedefStruct<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::typedef_struct);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::identifier => result.name = p.as_str(),
+                Rule::field => result.fields.push(Field::from_pest(p)),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        writeln!(w, "#[repr(C)]")?;
+        // Plain C structs, passed and returned by value.
+        writeln!(w, "#[derive(Clone, Copy)]")?;
+        if self.derive_serde {
+            writeln!(w, "{}", SERDE_DERIVE)?;
+        }
+        writeln!(w, "pub struct {} {{", self.name)?;
+        for field in &self.fields {
+            write!(w, "{}", field.doc_comment.unwrap_or(""))?;
+            write!(w, "    pub {}: ", field.name)?;
+            field.r#type.render(w)?;
+            writeln!(w, ",")?;
+        }
+        writeln!(w, "}}")?;
+        self.render_layout_assertions(w)
+    }
+
+    /// Check the size computed from the IDL at compile time, to catch wrong
+    /// type mappings.
+    fn render_layout_assertions(&self, w: &mut impl Write) -> io::Result<()> {
+        let [(size32, _), (size64, _)] = match self.layout {
+            Some(layout) => layout,
+            None => return Ok(()),
+        };
+        let assertion = |size| {
+            format!(
+                "const _: () = assert!(std::mem::size_of::<{}>() == {});",
+                self.name, size
+            )
+        };
+        writeln!(w)?;
+        if size32 == size64 {
+            writeln!(w, "{}", assertion(size32))
+        } else {
+            writeln!(w, "#[cfg(target_pointer_width = \"32\")]")?;
+            writeln!(w, "{}", assertion(size32))?;
+            writeln!(w, "#[cfg(target_pointer_width = \"64\")]")?;
+            writeln!(w, "{}", assertion(size64))
+        }
+    }
+
+    /// `(size, align)` with `pointer` sized pointers, using the C rules:
+    /// fields are aligned to their own alignment and the size is rounded up
+    /// to the largest one.
+    fn compute_layout(
+        &self,
+        pointer: usize,
+        known: &HashMap<&str, [(usize, usize); 2]>,
+    ) -> Option<(usize, usize)> {
+        let index = if pointer == 4 { 0 } else { 1 };
+        let mut size = 0;
+        let mut align = 1;
+        for field in &self.fields {
+            let t = &field.r#type;
+            let (field_size, field_align) =
+                if t.modifiers.iter().any(|m| matches!(m, Modifier::Pointer)) {
+                    (pointer, pointer)
+                } else if let Some(&(_, s)) = SCALAR_SIZES.iter().find(|(n, _)| t.idl_name == *n) {
+                    (s, s)
+                } else if POINTER_SIZED_TYPES.contains(&t.idl_name) {
+                    (pointer, pointer)
+                } else {
+                    known.get(t.idl_name)?[index]
+                };
+            size = round_up(size, field_align) + field_size;
+            align = align.max(field_align);
+        }
+        Some((round_up(size, align), align))
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Constant<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    r#type: Option<Type<'a>>,
+    value: &'a str,
+}
+
+impl<'a> Constant<'a> {
+    /// Parse either a `const` declaration or a `#define` line.
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert!(matches!(pair.as_rule(), Rule::constant | Rule::define));
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::_type => result.r#type = Some(Type::from_pest(p)),
+                Rule::identifier => result.name = p.as_str(),
+                Rule::const_value => result.value = p.as_str(),
+                Rule::define_body => {
+                    for p in p.into_inner() {
+                        match p.as_rule() {
+                            Rule::identifier => result.name = p.as_str(),
+                            Rule::const_value => result.value = p.as_str(),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// The value as written, without C integer suffixes.
+    fn literal(&self) -> &'a str {
+        self.value.trim_end_matches(['u', 'U', 'l', 'L'])
+    }
+
+    fn parse_value(&self) -> i128 {
+        let literal = self.literal();
+        let (negative, digits) = match literal.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, literal),
+        };
+        let value = if digits.starts_with("0x") || digits.starts_with("0X") {
+            i128::from_str_radix(&digits[2..], 16)
+        } else {
+            digits.parse()
+        }
+        .unwrap();
+        if negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// The smallest of `i32`, `u32`, `i64` and `u64` that can hold the value.
+    fn inferred_type(&self) -> &'static str {
+        let value = self.parse_value();
+        if value >= i128::from(i32::MIN) && value <= i128::from(i32::MAX) {
+            "i32"
+        } else if value >= 0 && value <= i128::from(u32::MAX) {
+            "u32"
+        } else if value >= i128::from(i64::MIN) && value <= i128::from(i64::MAX) {
+            "i64"
+        } else {
+            "u64"
+        }
+    }
+
+    pub fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        let inferred = self.inferred_type();
+        match &self.r#type {
+            // Literals that do not fit in an `i32` are written with an
+            // explicit suffix and cast, so that e.g. `HRESULT` constants like
+            // `0x80070057` don't overflow.
+            Some(t) if inferred != "i32" => {
+                write!(w, "pub const {}: ", self.name)?;
+                t.render(w)?;
+                write!(w, " = {}_{} as ", self.literal(), inferred)?;
+                t.render(w)?;
+                writeln!(w, ";")
+            }
+            Some(t) => {
+                write!(w, "pub const {}: ", self.name)?;
+                t.render(w)?;
+                writeln!(w, " = {};", self.literal())
+            }
+            None => writeln!(
+                w,
+                "pub const {}: {} = {};",
+                self.name,
+                inferred,
+                self.literal()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Interface<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    parent: &'a str,
+    uuid: Option<&'a str>,
+    attributes: Vec<&'a str>,
+    enums: Vec<TypedefEnum<'a>>,
+    structs: Vec<TypedefStruct<'a>>,
+    constants: Vec<Constant<'a>>,
+    methods: Vec<Method<'a>>,
+    /// Rendered behind the `experimental` feature.
+    experimental: bool,
+    /// The SDK version that introduced the interface, rendered behind the
+    /// `sdk-VERSION` feature. See `set_sdk_versions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sdk: Option<String>,
+    /// Render `x()`/`set_x()` accessors of properties.
+    #[serde(skip)]
+    accessors: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<String>,
+}
+
+impl<'a> Interface<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::interface);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::uuid => result.uuid = Some(p.as_str()),
+                Rule::other_attribute => result.attributes.push(p.as_str()),
+                Rule::interface_name => result.name = p.as_str(),
+                Rule::parent => result.parent = p.as_str(),
+                Rule::method => result.methods.push(Method::from_pest(p)),
+                Rule::typedef_enum => result.enums.push(TypedefEnum::from_pest(p)),
+                Rule::typedef_struct => result.structs.push(TypedefStruct::from_pest(p)),
+                Rule::constant => result.constants.push(Constant::from_pest(p)),
+                _ => {}
+            }
+        }
+        // `ICoreWebView2Experimental...` and its handlers and event args.
+        result.experimental = result.name.contains("Experimental");
+        result.deprecated = deprecation(&result.attributes, result.doc_comment);
+        result
+    }
+
+    /// The `cfg` attributes of items that use the interface, a line each.
+    fn cfg(&self) -> String {
+        let mut cfg = String::new();
+        if self.experimental {
+            cfg.push_str(EXPERIMENTAL_CFG);
+            cfg.push('\n');
+        }
+        if let Some(sdk) = &self.sdk {
+            cfg.push_str(&sdk_cfg(sdk));
+            cfg.push('\n');
+        }
+        cfg
+    }
+
+    pub fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        self.render_doc(w)?;
+        render_deprecated(w, "", &self.deprecated)?;
+        if let Some(uuid) = self.uuid {
+            writeln!(w, "#[com_interface(\"{}\")]", uuid)?;
+        }
+        writeln!(w, "pub trait {}: {} {{", self.name, self.parent)?;
+        let mut first = true;
+        for m in &self.methods {
+            if first {
+                first = false;
+            } else {
+                writeln!(w)?;
+            }
+            m.render(w, self.name)?;
+        }
+        writeln!(w, "}}")?;
+        if self.accessors {
+            self.render_properties_trait(w)?;
+        }
+        self.render_items(w)
+    }
+
+    pub fn render_raw(&self, w: &mut impl Write, abi: Abi) -> io::Result<()> {
+        self.render_doc(w)?;
+        render_deprecated(w, "", &self.deprecated)?;
+        writeln!(w, "#[repr(transparent)]")?;
+        writeln!(w, "#[derive(Clone, Copy)]")?;
+        writeln!(w, "pub struct {0}(pub *mut *mut {0}VTable);", self.name)?;
+        writeln!(w)?;
+        writeln!(w, "#[repr(C)]")?;
+        writeln!(w, "pub struct {}VTable {{", self.name)?;
+        writeln!(w, "    pub base: {}VTable,", self.parent)?;
+        for m in &self.methods {
+            m.render_raw_field(w, self.name, abi)?;
+        }
+        writeln!(w, "}}")?;
+        if !self.methods.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "impl {} {{", self.name)?;
+            let mut first = true;
+            for m in &self.methods {
+                if first {
+                    first = false;
+                } else {
+                    writeln!(w)?;
+                }
+                m.render_raw_wrapper(w, self.name)?;
+            }
+            writeln!(w, "}}")?;
+        }
+        if self.accessors {
+            self.render_raw_properties(w)?;
+        }
+        // The vtable starts with the parent vtable, so the pointer is also a
+        // valid pointer to the parent interface.
+        writeln!(w)?;
+        writeln!(w, "impl Deref for {} {{", self.name)?;
+        writeln!(w, "    type Target = {};", self.parent)?;
+        writeln!(w, "    fn deref(&self) -> &{} {{", self.parent)?;
+        writeln!(
+            w,
+            "        unsafe {{ &*(self as *const Self as *const {}) }}",
+            self.parent
+        )?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        self.render_items(w)
+    }
+
+    /// Properties with accessors that can be called without pointers, in
+    /// IDL order. Skipped if a name is taken by a method.
+    fn properties(&self) -> Vec<Property<'_, 'a>> {
+        let mut properties: Vec<Property> = Vec::new();
+        for m in &self.methods {
+            let (accessor, name) = match m.property() {
+                Some(property) => property,
+                None => continue,
+            };
+            let r#type = match accessor {
+                Accessor::Get => m.getter_type(),
+                Accessor::Put => m.setter_type(),
+            };
+            let r#type = match r#type {
+                Some(t) => t,
+                None => continue,
+            };
+            let name = camel_to_snake(name);
+            let index = match properties.iter().position(|p| p.name == name) {
+                Some(index) => index,
+                None => {
+                    properties.push(Property {
+                        name,
+                        r#type,
+                        getter: None,
+                        setter: None,
+                    });
+                    properties.len() - 1
+                }
+            };
+            let property = &mut properties[index];
+            // A getter and setter of different types are not a pair.
+            if property.r#type != r#type {
+                continue;
+            }
+            match accessor {
+                Accessor::Get => property.getter = Some(m),
+                Accessor::Put => property.setter = Some(m),
+            }
+        }
+        let taken: HashSet<String> = self.methods.iter().map(|m| m.rust_name()).collect();
+        properties
+            .retain(|p| !taken.contains(&p.name) && !taken.contains(&format!("set_{}", p.name)));
+        properties
+    }
+
+    /// A `{Name}Properties` extension trait, implemented for everything that
+    /// implements the interface.
+    fn render_properties_trait(&self, w: &mut impl Write) -> io::Result<()> {
+        let properties = self.properties();
+        if properties.is_empty() {
+            return Ok(());
+        }
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Properties of `{}` as values, with failures as `Err(HRESULT)`.",
+            self.name
+        )?;
+        writeln!(w, "pub trait {0}Properties: {0} {{", self.name)?;
+        render_accessors(w, &properties, "fn", true)?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "impl<T: {0} + ?Sized> {0}Properties for T {{}}",
+            self.name
+        )
+    }
+
+    /// Accessors on the newtype. They are still `unsafe`, the newtype does
+    /// not guarantee that the pointer is valid.
+    fn render_raw_properties(&self, w: &mut impl Write) -> io::Result<()> {
+        let properties = self.properties();
+        if properties.is_empty() {
+            return Ok(());
+        }
+        writeln!(w)?;
+        writeln!(w, "impl {} {{", self.name)?;
+        render_accessors(w, &properties, "pub unsafe fn", false)?;
+        writeln!(w, "}}")
+    }
+
+    fn render_doc(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        if let Some(url) = learn_url(self.name) {
+            if self.doc_comment.is_some() {
+                writeln!(w, "///")?;
+            }
+            writeln!(w, "/// See also: <{}>", url)?;
+        }
+        Ok(())
+    }
+
+    /// The IID and the types and constants declared in the interface.
+    fn render_items(&self, w: &mut impl Write) -> io::Result<()> {
+        if let Some(uuid) = self.uuid {
+            writeln!(w)?;
+            writeln!(w, "/// IID of `{}`.", self.name)?;
+            write!(w, "pub const IID_{}: GUID = ", self.name)?;
+            render_guid(uuid, w)?;
+            writeln!(w, ";")?;
+        }
+
+        // Enums are top level.
+        for e in &self.enums {
+            writeln!(w)?;
+            e.render(w)?;
+        }
+
+        for s in &self.structs {
+            writeln!(w)?;
+            s.render(w)?;
+        }
+
+        for c in &self.constants {
+            writeln!(w)?;
+            c.render(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A `coclass`, rendered as its CLSID and a unit struct with
+/// `create_instance()`.
+#[derive(Debug, Default, Serialize)]
+struct Coclass<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    name: &'a str,
+    uuid: Option<&'a str>,
+    attributes: Vec<&'a str>,
+    interfaces: Vec<CoclassInterface<'a>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CoclassInterface<'a> {
+    name: &'a str,
+    /// `default`, `source`.
+    attributes: Vec<&'a str>,
+}
+
+impl<'a> Coclass<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::coclass);
+
+        let mut result = Self::default();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                // Indented in a library.
+                Rule::doc_comment => {
+                    result.doc_comment = Some(p.as_str().trim_end_matches(&[' ', '\t'][..]))
+                }
+                Rule::uuid => result.uuid = Some(p.as_str()),
+                Rule::other_attribute => result.attributes.push(p.as_str()),
+                Rule::coclass_name => result.name = p.as_str(),
+                Rule::coclass_member => {
+                    let mut member = CoclassInterface::default();
+                    for p in p.into_inner() {
+                        match p.as_rule() {
+                            Rule::coclass_member_attribute => member.attributes.push(p.as_str()),
+                            Rule::identifier => member.name = p.as_str(),
+                            _ => {}
+                        }
+                    }
+                    result.interfaces.push(member);
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// The interface marked `[default]`, or else the first one, leaving out
+    /// the `[source]` interfaces, which the coclass calls rather than
+    /// implements.
+    fn default_interface(&self) -> Option<&'a str> {
+        let implemented = || {
+            self.interfaces
+                .iter()
+                .filter(|i| !i.attributes.contains(&"source"))
+        };
+        implemented()
+            .find(|i| i.attributes.contains(&"default"))
+            .or_else(|| implemented().next())
+            .map(|i| i.name)
+    }
+
+    /// The CLSID, and `create_instance()` if the default interface is
+    /// defined in the document with an IID. `CoCreateInstance` is called by
+    /// its full path, so the `combaseapi` and `wtypesbase` features of winapi
+    /// have to be enabled.
+    fn render(&self, w: &mut impl Write, default: Option<&Interface>, raw: bool) -> io::Result<()> {
+        let uuid = match self.uuid {
+            Some(uuid) => uuid,
+            None => return Ok(()),
+        };
+        writeln!(w, "/// CLSID of `{}`.", self.name)?;
+        write!(w, "pub const CLSID_{}: GUID = ", self.name)?;
+        render_guid(uuid, w)?;
+        writeln!(w, ";")?;
+        let interface = match default.filter(|i| i.uuid.is_some()) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+        let (output, created) = if raw {
+            (
+                interface.name.to_string(),
+                format!(
+                    "{}(ppv as *mut *mut {}VTable)",
+                    interface.name, interface.name
+                ),
+            )
+        } else {
+            (
+                format!("com::ComRc<dyn {}>", interface.name),
+                format!(
+                    "unsafe {{ com::ComRc::from_raw(ppv as *mut *mut {}VTable) }}",
+                    interface.name
+                ),
+            )
+        };
+        let cfg = interface.cfg();
+        writeln!(w)?;
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "pub struct {};", self.name)?;
+        writeln!(w)?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "impl {} {{", self.name)?;
+        writeln!(
+            w,
+            "    /// Create an instance as `{}` with `CoCreateInstance`. COM has to be",
+            interface.name
+        )?;
+        writeln!(w, "    /// initialized on the calling thread.")?;
+        if raw {
+            writeln!(w, "    ///")?;
+            writeln!(
+                w,
+                "    /// The caller owns the reference and calls `release`."
+            )?;
+        }
+        writeln!(
+            w,
+            "    pub fn create_instance() -> Result<{}, HRESULT> {{",
+            output
+        )?;
+        writeln!(w, "        let mut ppv = std::ptr::null_mut();")?;
+        writeln!(w, "        let hr = unsafe {{")?;
+        writeln!(w, "            winapi::um::combaseapi::CoCreateInstance(")?;
+        writeln!(w, "                &CLSID_{},", self.name)?;
+        writeln!(w, "                std::ptr::null_mut(),")?;
+        writeln!(w, "                winapi::shared::wtypesbase::CLSCTX_ALL,")?;
+        writeln!(w, "                &IID_{},", interface.name)?;
+        writeln!(w, "                &mut ppv,")?;
+        writeln!(w, "            )")?;
+        writeln!(w, "        }};")?;
+        writeln!(w, "        if hr < 0 {{")?;
+        writeln!(w, "            return Err(hr);")?;
+        writeln!(w, "        }}")?;
+        writeln!(w, "        Ok({})", created)?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")
+    }
+}
+
+/// A function exported by the WebView2 loader DLL, rendered as a function
+/// pointer type to use with `GetProcAddress`.
+#[derive(Debug, Default, Serialize)]
+struct DllExport<'a> {
+    #[serde(serialize_with = "serialize_doc_comment")]
+    doc_comment: Option<&'a str>,
+    /// The name of the type, `Fn` and the function name for `STDAPI`
+    /// declarations.
+    name: Cow<'a, str>,
+    return_type: Type<'a>,
+    parameters: Vec<Parameter<'a>>,
+}
+
+impl<'a> DllExport<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        let is_stdapi = pair.as_rule() == Rule::stdapi;
+        assert!(is_stdapi || pair.as_rule() == Rule::function_typedef);
+
+        let mut result = Self::default();
+        if is_stdapi {
+            result.return_type.base_type = "HRESULT".into();
+            result.return_type.idl_name = "HRESULT";
+        }
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::doc_comment => result.doc_comment = Some(p.as_str().trim_end_matches(" \t")),
+                Rule::_type => result.return_type = Type::from_pest(p),
+                Rule::identifier if is_stdapi => result.name = format!("Fn{}", p.as_str()).into(),
+                Rule::identifier => result.name = p.as_str().into(),
+                Rule::parameter => result.parameters.push(Parameter::from_pest(p)),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    fn render(&self, w: &mut impl Write, abi: Abi) -> io::Result<()> {
+        write!(w, "{}", self.doc_comment.unwrap_or(""))?;
+        write!(
+            w,
+            "pub type {} = unsafe extern \"{}\" fn(",
+            self.name,
+            abi.as_str()
+        )?;
+        for (i, p) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                write!(w, ", ")?;
+            }
+            p.render(w)?;
+        }
+        write!(w, ") -> ")?;
+        self.return_type.render(w)?;
+        writeln!(w, ";")
+    }
+}
+
+/// A parsed IDL file.
+#[derive(Debug, Default, Serialize)]
+pub struct Document<'a> {
+    imports: Vec<&'a str>,
+    /// Interfaces declared with `interface IFoo;`.
+    forward_declarations: Vec<&'a str>,
+    exports: Vec<DllExport<'a>>,
+    constants: Vec<Constant<'a>>,
+    interfaces: Vec<Interface<'a>>,
+    coclasses: Vec<Coclass<'a>>,
+    #[serde(skip)]
+    abi: Abi,
+    /// The whole document is experimental, e.g. `WebView2Experimental.idl`.
+    #[serde(skip)]
+    experimental: bool,
+    /// Replaces the built-in prelude of the current mode.
+    #[serde(skip)]
+    prelude: Option<String>,
+    /// Wrappers of event args get a struct of their property values.
+    #[serde(skip)]
+    eager_args: bool,
+    /// Stripped from the names of wrappers, see `set_wrapper_prefix`.
+    #[serde(skip)]
+    wrapper_prefix: Option<String>,
+}
+
+/// Calling convention of the generated function pointers.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Abi {
+    /// `extern "system"`: `stdcall` on 32-bit x86 and the C convention
+    /// everywhere else. Correct for all targets.
+    #[default]
+    System,
+    /// `extern "stdcall"`, for compilers that predate `"system"` on x86. Not
+    /// accepted for other targets by recent compilers.
+    Stdcall,
+}
+
+impl Abi {
+    fn as_str(self) -> &'static str {
+        match self {
+            Abi::System => "system",
+            Abi::Stdcall => "stdcall",
+        }
+    }
+}
+
+/// IDL types and the Rust types to render them as, e.g. to use `core::ffi`
+/// or `windows-sys` types instead of the winapi names.
+///
+/// Read from lines of `IDL = Rust`, with `#` comments:
+///
+/// ```
+/// let types = idl2rs::TypeMap::parse("UINT32 = u32 # not winapi::UINT32").unwrap();
+/// assert_eq!(types.get("UINT32"), Some("u32"));
+/// ```
+///
+/// The files in `types` are ready-made maps. The prelude still imports the
+/// winapi names, replace it with `Document::set_prelude` as needed.
+#[derive(Debug, Clone, Default)]
+pub struct TypeMap {
+    types: HashMap<String, String>,
+}
+
+impl TypeMap {
+    pub fn parse(config: &str) -> Result<Self, String> {
+        let mut types = HashMap::new();
+        for (n, line) in config.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (idl, rust) = match line.split_once('=') {
+                Some((idl, rust)) if !idl.trim().is_empty() && !rust.trim().is_empty() => {
+                    (idl.trim(), rust.trim())
+                }
+                _ => return Err(format!("line {}: expected `IDL = Rust`", n + 1)),
+            };
+            if types.insert(idl.to_owned(), rust.to_owned()).is_some() {
+                return Err(format!("line {}: `{}` is mapped twice", n + 1, idl));
+            }
+        }
+        Ok(Self { types })
+    }
+
+    pub fn insert(&mut self, idl: &str, rust: &str) {
+        self.types.insert(idl.to_owned(), rust.to_owned());
+    }
+
+    pub fn get(&self, idl: &str) -> Option<&str> {
+        self.types.get(idl).map(String::as_str)
+    }
+}
+
+impl<'a> Document<'a> {
+    fn from_pest(pair: Pair<'a, Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::document);
+
+        let mut result = Self::default();
+        result.add_items(pair);
+        result.compute_layouts();
+        result
+    }
+
+    /// Add the items of the document or of a library.
+    fn add_items(&mut self, pair: Pair<'a, Rule>) {
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::import => self.imports.push(p.into_inner().next().unwrap().as_str()),
+                Rule::interface_forward_declaration => self
+                    .forward_declarations
+                    .push(p.into_inner().next().unwrap().as_str()),
+                Rule::interface => self.interfaces.push(Interface::from_pest(p)),
+                Rule::coclass => self.coclasses.push(Coclass::from_pest(p)),
+                Rule::library => self.add_items(p),
+                Rule::constant | Rule::define => self.constants.push(Constant::from_pest(p)),
+                Rule::function_typedef | Rule::stdapi => self.exports.push(DllExport::from_pest(p)),
+                _ => {}
+            }
+        }
+    }
+
+    fn compute_layouts(&mut self) {
+        // Enums are `u32`.
+        let mut known: HashMap<&str, [(usize, usize); 2]> = HashMap::new();
+        for i in &self.interfaces {
+            for e in &i.enums {
+                known.insert(e.name, [(4, 4); 2]);
+            }
+        }
+        // Structs can contain structs declared before them.
+        for i in &mut self.interfaces {
+            for s in &mut i.structs {
+                s.layout = match (s.compute_layout(4, &known), s.compute_layout(8, &known)) {
+                    (Some(l32), Some(l64)) => Some([l32, l64]),
+                    _ => None,
+                };
+                if let Some(layout) = s.layout {
+                    known.insert(s.name, layout);
+                }
+            }
+        }
+    }
+
+    /// The interface registry: `InterfaceInfo`, the table of the stable
+    /// interfaces, and lookups that include the tables of the SDK versions
+    /// and the experimental table when their features are enabled.
+    fn render_registry(
+        &self,
+        w: &mut impl Write,
+        has_experimental: bool,
+        sdk_versions: &[&str],
+    ) -> io::Result<()> {
+        write!(w, "{}", REGISTRY)?;
+        writeln!(w)?;
+        writeln!(w, "/// Interfaces with an IID, in IDL order.")?;
+        let stable: Vec<&Interface> = self
+            .interfaces
+            .iter()
+            .filter(|i| !i.experimental && i.sdk.is_none())
+            .collect();
+        render_interface_table(w, "INTERFACES", &stable)?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {{"
+        )?;
+        if has_experimental || !sdk_versions.is_empty() {
+            writeln!(w, "    let interfaces = INTERFACES.iter();")?;
+            for version in sdk_versions {
+                writeln!(w, "    {}", sdk_cfg(version))?;
+                writeln!(
+                    w,
+                    "    let interfaces = interfaces.chain({}_INTERFACES.iter());",
+                    sdk_module(version).to_uppercase()
+                )?;
+            }
+            if has_experimental {
+                writeln!(w, "    {}", EXPERIMENTAL_CFG)?;
+                writeln!(
+                    w,
+                    "    let interfaces = interfaces.chain(EXPERIMENTAL_INTERFACES.iter());"
+                )?;
+            }
+            writeln!(w, "    interfaces")?;
+        } else {
+            writeln!(w, "    INTERFACES.iter()")?;
+        }
+        writeln!(w, "}}")
+    }
+
+    /// Render the bindings, including the prelude.
+    ///
+    /// The output is not formatted, run it through rustfmt to check it in.
+    pub fn render_to_string(&self) -> String {
+        let prelude = [HEADER, COM_IMPORTS, IMPORTS, COM_STREAMS];
+        let mut output = self.with_abi(&self.prelude_or(&prelude)).into_bytes();
+        self.render(&mut output).unwrap();
+        // Doc comments are copied verbatim, so normalize line endings to get
+        // the same output from a CRLF checkout.
+        String::from_utf8(output).unwrap().replace("\r\n", "\n")
+    }
+
+    /// Render the bindings without the `com` crate: a `#[repr(C)]` vtable
+    /// struct of `extern "system"` function pointers for each interface, and
+    /// a `Copy` newtype around the interface pointer with a method per vtable
+    /// entry. The newtypes don't manage reference counts, call `add_ref` and
+    /// `release` as in C.
+    ///
+    /// Interface pointers have the same types as in the default mode, so
+    /// signatures don't change between the two.
+    pub fn render_raw_to_string(&self) -> String {
+        let prelude = [HEADER, RAW_IMPORTS, IMPORTS, RAW_STREAMS];
+        let mut output = self.with_abi(&self.prelude_or(&prelude)).into_bytes();
+        self.render_raw(&mut output).unwrap();
+        String::from_utf8(output).unwrap().replace("\r\n", "\n")
+    }
+
+    /// Methods of an interface whose Rust names are the same, which wouldn't
+    /// compile. Reported at the second method.
+    fn check_method_names(&self, input: &str) -> Vec<Error<Rule>> {
+        let mut errors = Vec::new();
+        for i in &self.interfaces {
+            let mut seen: Vec<(String, &str)> = Vec::new();
+            for m in &i.methods {
+                let snake = m.rust_name();
+                if let Some((_, first)) = seen.iter().find(|(s, _)| *s == snake) {
+                    // Names are slices of the input.
+                    let offset = m.name.as_ptr() as usize - input.as_ptr() as usize;
+                    errors.push(Error::new_from_pos(
+                        ErrorVariant::CustomError {
+                            message: format!(
+                                "`{}` and `{}` of `{}` would both be named `{}`",
+                                first, m.name, i.name, snake
+                            ),
+                        },
+                        Position::new(input, offset).unwrap(),
+                    ));
+                } else {
+                    seen.push((snake, m.name));
+                }
+            }
+        }
+        errors
+    }
+
+    /// `size_is` must name another parameter of the method.
+    fn check_size_is(&self, input: &str) -> Vec<Error<Rule>> {
+        let mut errors = Vec::new();
+        for i in &self.interfaces {
+            for m in &i.methods {
+                for p in &m.parameters {
+                    let count = match p.size_is {
+                        Some(count) => count,
+                        None => continue,
+                    };
+                    if m.parameters
+                        .iter()
+                        .any(|c| c.name == count && c.name != p.name)
+                    {
+                        continue;
+                    }
+                    let offset = count.as_ptr() as usize - input.as_ptr() as usize;
+                    errors.push(Error::new_from_pos(
+                        ErrorVariant::CustomError {
+                            message: format!(
+                                "`size_is` of `{}` in `{}` does not name another parameter",
+                                p.name, m.name
+                            ),
+                        },
+                        Position::new(input, offset).unwrap(),
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    /// A coclass needs a CLSID to be created with.
+    fn check_coclasses(&self, input: &str) -> Vec<Error<Rule>> {
+        self.coclasses
+            .iter()
+            .filter(|c| c.uuid.is_none())
+            .map(|c| {
+                let offset = c.name.as_ptr() as usize - input.as_ptr() as usize;
+                Error::new_from_pos(
+                    ErrorVariant::CustomError {
+                        message: format!("coclass `{}` has no `uuid`", c.name),
+                    },
+                    Position::new(input, offset).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    /// Forward declared interfaces must be defined in the document or come
+    /// from an import. In a document with forward declarations, so must every
+    /// interface that is referenced. Snippets without any aren't checked.
+    fn check_interfaces(&self, input: &str) -> Vec<Error<Rule>> {
+        let mut available: HashSet<&str> = self.interfaces.iter().map(|i| i.name).collect();
+        for import in &self.imports {
+            let imported = IMPORTED_INTERFACES
+                .iter()
+                .find(|(file, _)| file.eq_ignore_ascii_case(import));
+            if let Some((_, names)) = imported {
+                available.extend(names.iter().copied());
+            }
+        }
+
+        let mut errors = Vec::new();
+        let mut report = |name: &str, message: String| {
+            let offset = name.as_ptr() as usize - input.as_ptr() as usize;
+            errors.push(Error::new_from_pos(
+                ErrorVariant::CustomError { message },
+                Position::new(input, offset).unwrap(),
+            ));
+        };
+        for &name in &self.forward_declarations {
+            if !available.contains(name) {
+                report(
+                    name,
+                    format!("`{}` is declared but neither defined nor imported", name),
+                );
+            }
+        }
+        if self.forward_declarations.is_empty() {
+            return errors;
+        }
+
+        // The first reference to each undeclared interface.
+        let mut reported = HashSet::new();
+        let methods = self.interfaces.iter().flat_map(|i| {
+            i.methods
+                .iter()
+                .map(move |m| (i.name, m.name, &m.return_type, &m.parameters))
+        });
+        let exports = self
+            .exports
+            .iter()
+            .map(|e| ("", &*e.name, &e.return_type, &e.parameters));
+        for i in &self.interfaces {
+            if !available.contains(i.parent) && reported.insert(i.parent) {
+                report(
+                    i.parent,
+                    format!(
+                        "`{}`, the parent of `{}`, is neither defined nor imported",
+                        i.parent, i.name
+                    ),
+                );
+            }
+        }
+        for (interface, method, return_type, parameters) in methods.chain(exports) {
+            let types = std::iter::once(return_type).chain(parameters.iter().map(|p| &p.r#type));
+            for t in types.filter(|t| t.is_interface) {
+                let name = t.idl_name;
+                if available.contains(name)
+                    || self.forward_declarations.contains(&name)
+                    || !reported.insert(name)
+                {
+                    continue;
+                }
+                let user = if interface.is_empty() {
+                    format!("`{}`", method)
+                } else {
+                    format!("`{}` of `{}`", method, interface)
+                };
+                report(
+                    name,
+                    format!(
+                        "`{}`, used by {}, is neither defined nor imported",
+                        name, user
+                    ),
+                );
+            }
+        }
+        errors
+    }
+
+    /// Put everything in the document, including constants, behind the
+    /// `experimental` feature. Interfaces with `Experimental` in their name
+    /// always are.
+    pub fn set_experimental(&mut self) {
+        self.experimental = true;
+        for i in &mut self.interfaces {
+            i.experimental = true;
+        }
+    }
+
+    /// Put interfaces behind `sdk-VERSION` features, from lines like
+    /// `ICoreWebView2_2 = 1.0.774` giving the SDK version that introduced
+    /// them, so that code written for an older runtime can't use them. Each
+    /// version's interfaces are rendered into a module of their own.
+    ///
+    /// Interfaces only ever use interfaces of the same or an earlier SDK, so
+    /// the crate's features must enable the earlier versions, e.g.
+    /// `sdk-1.0.1185 = ["sdk-1.0.774"]`.
+    pub fn set_sdk_versions(&mut self, config: &str) -> Result<(), String> {
+        for (n, line) in config.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (name, version) = match line.split_once('=') {
+                Some((name, version))
+                    if !name.trim().is_empty()
+                        && !version.trim().is_empty()
+                        && version.trim().split('.').all(|p| p.parse::<u32>().is_ok()) =>
+                {
+                    (name.trim(), version.trim())
+                }
+                _ => return Err(format!("line {}: expected `Interface = version`", n + 1)),
+            };
+            let interface = match self.interfaces.iter_mut().find(|i| i.name == name) {
+                Some(interface) => interface,
+                None => return Err(format!("line {}: unknown interface `{}`", n + 1, name)),
+            };
+            if interface.sdk.is_some() {
+                return Err(format!("line {}: `{}` is listed twice", n + 1, name));
+            }
+            interface.sdk = Some(version.to_owned());
+        }
+        Ok(())
+    }
+
+    /// The SDK versions of `set_sdk_versions` that have interfaces outside
+    /// of the experimental module, oldest first.
+    fn sdk_versions(&self) -> Vec<&str> {
+        let mut versions: Vec<&str> = self
+            .interfaces
+            .iter()
+            .filter(|i| !i.experimental)
+            .filter_map(|i| i.sdk.as_deref())
+            .collect();
+        versions.sort_by_key(|v| {
+            v.split('.')
+                .map(|p| p.parse::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        });
+        versions.dedup();
+        versions
+    }
+
+    /// Derive `Serialize` and `Deserialize` on all enums, and on structs
+    /// whose fields are numbers, `BOOL`s, enums or such structs. Structs with
+    /// pointers or handles can't be serialized.
+    ///
+    /// The derives are behind a `serde` feature of the crate the bindings
+    /// are in, which enables an optional `serde` dependency with `derive`.
+    pub fn set_serde(&mut self) {
+        let mut plain: HashSet<&str> = PLAIN_TYPES.iter().copied().collect();
+        for i in &mut self.interfaces {
+            for e in &mut i.enums {
+                e.derive_serde = true;
+                plain.insert(e.name);
+            }
+        }
+        // Structs can contain structs declared before them.
+        for i in &mut self.interfaces {
+            for s in &mut i.structs {
+                s.derive_serde = s
+                    .fields
+                    .iter()
+                    .all(|f| f.r#type.modifiers.is_empty() && plain.contains(f.r#type.idl_name));
+                if s.derive_serde {
+                    plain.insert(s.name);
+                }
+            }
+        }
+    }
+
+    /// Also render `x()`/`set_x()` accessors for `get_X`/`put_X` properties
+    /// whose values are not pointers: an extension trait in the default mode,
+    /// `unsafe` methods of the newtype in the raw mode.
+    pub fn set_accessors(&mut self) {
+        for i in &mut self.interfaces {
+            i.accessors = true;
+        }
+    }
+
+    /// Name the wrappers of interfaces that start with `prefix` after the
+    /// rest of the name, e.g. `CookieManager` for `ICoreWebView2CookieManager`
+    /// with `ICoreWebView2`. A version suffix is dropped too, from the latest
+    /// version only: with `ICoreWebView2Settings` and `ICoreWebView2Settings2`,
+    /// `Settings` wraps the latter and the former keeps its usual name,
+    /// `CoreWebView2Settings`. So do interfaces that are just the prefix.
+    ///
+    /// Fails if two interfaces would get the same name.
+    pub fn set_wrapper_prefix(&mut self, prefix: &str) -> Result<(), String> {
+        self.wrapper_prefix = Some(prefix.to_owned());
+        let names = self.wrapper_names();
+        let mut taken: HashMap<&str, &str> = HashMap::new();
+        let collision = self.wrapped_interfaces().find_map(|i| {
+            let other = taken.insert(&names[i.name], i.name)?;
+            Some(format!(
+                "`{}` and `{}` would both be wrapped as `{}`",
+                other, i.name, names[i.name]
+            ))
+        });
+        match collision {
+            Some(error) => {
+                self.wrapper_prefix = None;
+                Err(error)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Add a `FooEventArgsValues` struct to the wrappers of each
+    /// `IFooEventArgs`, with the values of all its properties read at once,
+    /// for handlers that only look at a few fields or pass them on.
+    pub fn set_eager_args(&mut self) {
+        self.eager_args = true;
+    }
+
+    /// Render the types in `types` as the Rust types they are mapped to, in
+    /// both modes. Interfaces can't be mapped.
+    pub fn set_types(&mut self, types: &TypeMap) {
+        self.for_each_type(|t| {
+            if let Some(rust) = types.get(t.idl_name).filter(|_| !t.is_interface) {
+                t.base_type = rust.to_owned().into();
+            }
+        });
+    }
+
+    fn for_each_type(&mut self, mut f: impl FnMut(&mut Type<'a>)) {
+        for c in &mut self.constants {
+            c.r#type.iter_mut().for_each(&mut f);
+        }
+        for e in &mut self.exports {
+            f(&mut e.return_type);
+            e.parameters.iter_mut().for_each(|p| f(&mut p.r#type));
+        }
+        for i in &mut self.interfaces {
+            for s in &mut i.structs {
+                s.fields.iter_mut().for_each(|field| f(&mut field.r#type));
+            }
+            for m in &mut i.methods {
+                f(&mut m.return_type);
+                m.parameters.iter_mut().for_each(|p| f(&mut p.r#type));
+            }
+        }
+    }
+
+    /// Use `abi` for vtable entries of the raw mode and for DLL exports. In
+    /// the default mode vtables are generated by the `com` crate, which
+    /// always uses `stdcall`.
+    pub fn set_abi(&mut self, abi: Abi) {
+        self.abi = abi;
+    }
+
+    /// Use `prelude` instead of the built-in one, which is made of the files
+    /// in `templates`. It has to provide everything the generated code uses
+    /// that isn't in the IDL.
+    pub fn set_prelude(&mut self, prelude: String) {
+        self.prelude = Some(prelude);
+    }
+
+    fn prelude_or(&self, default: &[&str]) -> String {
+        match &self.prelude {
+            Some(prelude) => prelude.clone(),
+            None => default.concat(),
+        }
+    }
+
+    /// The prelude is written with `extern "system"`.
+    fn with_abi(&self, prelude: &str) -> String {
+        prelude.replace(
+            "extern \"system\"",
+            &format!("extern \"{}\"", self.abi.as_str()),
+        )
+    }
+
+    /// Copy the methods of parent interfaces defined in the document into
+    /// each interface, in vtable order, and derive it from the first parent
+    /// that is not defined in the document (usually `IUnknown`) instead.
+    ///
+    /// For COM frameworks that want the whole method list of an interface
+    /// rather than trait inheritance.
+    pub fn flatten_parents(&mut self) {
+        let flattened: Vec<(&'a str, Vec<Method<'a>>)> = self
+            .interfaces
+            .iter()
+            .map(|i| {
+                let mut methods = i.methods.clone();
+                let mut parent = i.parent;
+                let mut depth = 0;
+                while let Some(p) = self.interfaces.iter().find(|p| p.name == parent) {
+                    depth += 1;
+                    assert!(
+                        depth <= self.interfaces.len(),
+                        "cyclic inheritance of {}",
+                        i.name
+                    );
+                    methods.splice(0..0, p.methods.iter().cloned());
+                    parent = p.parent;
+                }
+                (parent, methods)
+            })
+            .collect();
+        for (i, (parent, methods)) in self.interfaces.iter_mut().zip(flattened) {
+            i.parent = parent;
+            i.methods = methods;
+        }
+    }
+
+    /// Render a `mocks` module with a `co_class` for every interface that
+    /// records the names of the methods called on it. Methods return `S_OK`
+    /// and zero their out parameters, except for caller allocated arrays.
+    ///
+    /// Append it to the bindings to unit test code that consumes the
+    /// interfaces without a browser.
+    pub fn render_mocks(&self) -> String {
+        let mut w = Vec::new();
+        self.render_mocks_to(&mut w).unwrap();
+        String::from_utf8(w).unwrap()
+    }
+
+    fn render_mocks_to(&self, w: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            w,
+            "\n/// Recording implementations of the interfaces, for tests."
+        )?;
+        writeln!(w, "pub mod mocks {{")?;
+        writeln!(w, "use super::*;")?;
+        writeln!(w, "use com::co_class;")?;
+        writeln!(w, "use std::cell::RefCell;")?;
+        writeln!(w, "use winapi::shared::winerror::S_OK;")?;
+        for i in self.interfaces.iter().filter(|i| i.uuid.is_some()) {
+            let name = mock_name(i.name);
+            let cfg = i.cfg();
+            writeln!(w)?;
+            writeln!(w, "/// Mock `{}`.", i.name)?;
+            write!(w, "{}", cfg)?;
+            writeln!(w, "#[co_class(implements({}))]", i.name)?;
+            writeln!(w, "pub struct {} {{", name)?;
+            writeln!(w, "    calls: RefCell<Vec<&'static str>>,")?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+            write!(w, "{}", cfg)?;
+            writeln!(w, "impl {} {{", name)?;
+            writeln!(w, "    pub fn new() -> Box<Self> {{")?;
+            writeln!(w, "        Self::allocate(RefCell::new(Vec::new()))")?;
+            writeln!(w, "    }}")?;
+            writeln!(w)?;
+            writeln!(w, "    /// Names of the methods called so far, in order.")?;
+            writeln!(w, "    pub fn calls(&self) -> Vec<&'static str> {{")?;
+            writeln!(w, "        self.calls.borrow().clone()")?;
+            writeln!(w, "    }}")?;
+            writeln!(w, "}}")?;
+
+            // `co_class` needs the parent traits implemented as well.
+            let mut interface = Some(i);
+            while let Some(current) = interface {
+                writeln!(w)?;
+                write!(w, "{}", cfg)?;
+                writeln!(w, "impl {} for {} {{", current.name, name)?;
+                for m in &current.methods {
+                    m.render_mock(w)?;
+                }
+                writeln!(w, "}}")?;
+                interface = self.interfaces.iter().find(|p| p.name == current.parent);
+            }
+        }
+        writeln!(w, "}}")
+    }
+
+    /// Render safe wrappers of the interfaces that are called rather than
+    /// implemented, i.e. not handlers, in a `wrappers` module to be appended
+    /// to the bindings of the default mode. Each is a `Clone` struct around a
+    /// `ComRc` with a method for each method of the interface and its
+    /// parents whose parameters can be converted. Methods with arrays,
+    /// `[in, out]` or other pointer parameters are left to the bindings.
+    ///
+    /// Methods taking a completed handler also get an `x_async` variant
+    /// returning a `Completion` future of the results, instead of taking the
+    /// handler.
+    ///
+    /// Property bags, with nothing but getters and setters, get a builder
+    /// struct. Event args with a `GetDeferral` method get `take_deferral`,
+    /// returning a guard that completes the deferral when dropped.
+    ///
+    /// Events, i.e. `add_X` and `remove_X` pairs, also get an `on_x` method
+    /// taking a closure, with the handler implemented by a `co_class` and
+    /// removed when the returned `EventSubscription` is dropped, so tokens
+    /// needn't be kept by hand.
+    pub fn render_wrappers(&self) -> String {
+        let mut w = Vec::new();
+        self.render_wrappers_to(&mut w).unwrap();
+        String::from_utf8(w).unwrap().replace("\r\n", "\n")
+    }
+
+    /// Interfaces with an IID, except handlers, which closures implement.
+    fn wrapped_interfaces(&self) -> impl Iterator<Item = &Interface<'a>> {
+        self.interfaces
+            .iter()
+            .filter(|i| i.uuid.is_some() && !i.name.ends_with("Handler"))
+    }
+
+    /// The wrapper of each wrapped interface: the name without the leading
+    /// `I`, or as described in `set_wrapper_prefix`.
+    fn wrapper_names(&self) -> HashMap<&'a str, String> {
+        let mut names: HashMap<&'a str, String> = self
+            .wrapped_interfaces()
+            .map(|i| (i.name, wrapper_name(i.name)))
+            .collect();
+        let prefix = match &self.wrapper_prefix {
+            Some(prefix) => prefix,
+            None => return names,
+        };
+        // The interfaces named after each base name, with their versions.
+        let mut versions: Vec<(&str, Vec<(u32, &'a str)>)> = Vec::new();
+        for i in self.wrapped_interfaces() {
+            let rest = match i.name.strip_prefix(prefix.as_str()) {
+                Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest,
+                _ => continue,
+            };
+            let (base, version) = split_version(rest).unwrap_or((rest, 1));
+            match versions.iter_mut().find(|(b, _)| *b == base) {
+                Some((_, interfaces)) => interfaces.push((version, i.name)),
+                None => versions.push((base, vec![(version, i.name)])),
+            }
+        }
+        for (base, interfaces) in versions {
+            let latest = interfaces.iter().max().unwrap().1;
+            names.insert(latest, base.to_owned());
+        }
+        names
+    }
+
+    /// The wrapped interfaces that `i` extends, directly or not, nearest
+    /// first.
+    fn wrapped_ancestors(&self, i: &Interface) -> Vec<&Interface<'a>> {
+        let mut ancestors = Vec::new();
+        let mut parent = self.interfaces.iter().find(|p| p.name == i.parent);
+        while let Some(p) = parent {
+            if self.wrapped_interfaces().any(|w| w.name == p.name) {
+                ancestors.push(p);
+            }
+            parent = self.interfaces.iter().find(|q| q.name == p.parent);
+        }
+        ancestors
+    }
+
+    /// Interfaces with later versions, e.g. `IFoo`, `IFoo2` and `IFoo3`, or
+    /// `IFoo` and `IFoo_2`, by the name of the first version. The versions
+    /// are in order, starting with 1 for the name without a suffix, which
+    /// doesn't have to be in the document. Only wrapped interfaces count.
+    fn families(&self) -> Vec<(&'a str, Vec<(u32, &'a str)>)> {
+        let names: HashSet<&str> = self.wrapped_interfaces().map(|i| i.name).collect();
+        let mut families: Vec<(&'a str, Vec<(u32, &'a str)>)> = Vec::new();
+        for i in self.wrapped_interfaces() {
+            // `IFoo2` is the first version if there is an `IFoo2_2`.
+            let is_first = names
+                .iter()
+                .any(|n| split_version(n).map(|v| v.0) == Some(i.name));
+            let (base, version) = match split_version(i.name) {
+                _ if is_first => (i.name, 1),
+                Some(version) => version,
+                None => continue,
+            };
+            match families.iter_mut().find(|(b, _)| *b == base) {
+                Some((_, versions)) => versions.push((version, i.name)),
+                None => families.push((base, vec![(version, i.name)])),
+            }
+        }
+        families.retain(|(_, versions)| versions.len() > 1);
+        for (_, versions) in &mut families {
+            versions.sort();
+        }
+        families
+    }
+
+    /// `FooAny`, with a variant for each version of the family `base`, and
+    /// `latest_supported` to get the newest version an object implements.
+    fn render_family(
+        &self,
+        w: &mut impl Write,
+        base: &str,
+        versions: &[(u32, &str)],
+        wrapped: &HashMap<&str, String>,
+    ) -> io::Result<()> {
+        let latest = &wrapped[versions.last().unwrap().1];
+        let name = format!("{}Any", split_version(latest).map_or(&latest[..], |v| v.0));
+        let cfg = |i: &str, indent: &str| match self.interfaces.iter().find(|j| j.name == i) {
+            Some(j) => j
+                .cfg()
+                .lines()
+                .map(|l| format!("{}{}\n", indent, l))
+                .collect(),
+            None => String::new(),
+        };
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// The latest version of `{}` that an object implements.",
+            base
+        )?;
+        writeln!(w, "pub enum {} {{", name)?;
+        for &(version, i) in versions {
+            write!(w, "{}", cfg(i, "    "))?;
+            writeln!(w, "    V{}({}),", version, wrapped[i])?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(w, "impl {} {{", name)?;
+        writeln!(
+            w,
+            "    /// Query the versions from the newest to the oldest. `None` if the"
+        )?;
+        writeln!(w, "    /// object implements none of them.")?;
+        writeln!(
+            w,
+            "    pub fn latest_supported<T: ComInterface + ?Sized>(object: &ComRc<T>) -> Option<Self> {{"
+        )?;
+        for &(version, i) in versions.iter().rev() {
+            write!(w, "{}", cfg(i, "        "))?;
+            writeln!(
+                w,
+                "        if let Ok(inner) = query_interface::<T, dyn {}>(object) {{",
+                i
+            )?;
+            writeln!(
+                w,
+                "            return Some(Self::V{}({}::new(inner)));",
+                version, wrapped[i]
+            )?;
+            writeln!(w, "        }}")?;
+        }
+        writeln!(w, "        None")?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+        writeln!(w, "    pub fn version(&self) -> u32 {{")?;
+        writeln!(w, "        match self {{")?;
+        for &(version, i) in versions {
+            write!(w, "{}", cfg(i, "            "))?;
+            writeln!(w, "            Self::V{0}(_) => {0},", version)?;
+        }
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")
+    }
+
+    fn render_wrappers_to(&self, w: &mut impl Write) -> io::Result<()> {
+        let interfaces: Vec<&Interface> = self.wrapped_interfaces().collect();
+        let wrapped = self.wrapper_names();
+        let mut handlers: Vec<(&Interface, &Method)> = Vec::new();
+        let mut deferrals: Vec<&str> = Vec::new();
+        let ancestors: HashMap<&str, Vec<&Interface>> = interfaces
+            .iter()
+            .map(|i| (i.name, self.wrapped_ancestors(i)))
+            .collect();
+        let extended: HashSet<&str> = ancestors.values().flatten().map(|a| a.name).collect();
+
+        writeln!(w, "\n/// Safe wrappers of the interfaces.")?;
+        writeln!(w, "pub mod wrappers {{")?;
+        writeln!(w, "use super::*;")?;
+        write!(w, "{}", WRAPPERS)?;
+        for i in interfaces {
+            let name = &wrapped[i.name];
+            let cfg = i.cfg();
+            writeln!(w)?;
+            writeln!(w, "/// `{}`.", i.name)?;
+            render_deprecated(w, "", &i.deprecated)?;
+            write!(w, "{}", cfg)?;
+            writeln!(w, "#[derive(Clone)]")?;
+            writeln!(w, "pub struct {} {{", name)?;
+            writeln!(w, "    inner: ComRc<dyn {}>,", i.name)?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+            write!(w, "{}", cfg)?;
+            writeln!(w, "impl {} {{", name)?;
+            writeln!(w, "    pub fn new(inner: ComRc<dyn {}>) -> Self {{", i.name)?;
+            writeln!(w, "        Self {{ inner }}")?;
+            writeln!(w, "    }}")?;
+            writeln!(w)?;
+            writeln!(w, "    pub fn as_raw(&self) -> &ComRc<dyn {}> {{", i.name)?;
+            writeln!(w, "        &self.inner")?;
+            writeln!(w, "    }}")?;
+            if extended.contains(i.name) {
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "    /// Query a later version of the interface, e.g. with a newer browser"
+                )?;
+                writeln!(
+                    w,
+                    "    /// than the SDK requires. Fails with `E_NOINTERFACE` if it isn't"
+                )?;
+                writeln!(w, "    /// implemented.")?;
+                writeln!(
+                    w,
+                    "    pub fn try_upgrade<W: Wrapper + Extends<Self>>(&self) -> Result<W> {{"
+                )?;
+                writeln!(w, "        query_interface(&self.inner).map(W::from_inner)")?;
+                writeln!(w, "    }}")?;
+            }
+            let mut interface = Some(i);
+            while let Some(current) = interface {
+                for m in &current.methods {
+                    m.render_wrapper(w, &wrapped)?;
+                }
+                for m in &current.methods {
+                    let deferral = match self.deferral(m) {
+                        Some(deferral) if wrapped.contains_key(deferral) => deferral,
+                        _ => continue,
+                    };
+                    let guard = format!("{}Guard", wrapped[deferral]);
+                    writeln!(w)?;
+                    writeln!(
+                        w,
+                        "    /// `{}`, with the deferral completed when the guard is dropped.",
+                        m.rust_name()
+                    )?;
+                    writeln!(w, "    pub fn take_deferral(&self) -> Result<{}> {{", guard)?;
+                    writeln!(w, "        Ok({} {{", guard)?;
+                    writeln!(w, "            deferral: Some(self.{}()?),", m.rust_name())?;
+                    writeln!(w, "        }})")?;
+                    writeln!(w, "    }}")?;
+                    if !deferrals.contains(&deferral) {
+                        deferrals.push(deferral);
+                    }
+                }
+                for m in &current.methods {
+                    let handler = match m.completed_handler().and_then(|h| self.handler(h)) {
+                        Some(handler) => handler,
+                        None => continue,
+                    };
+                    m.render_async_wrapper(w, handler.1, handler.0.name, &wrapped)?;
+                    if !handlers.iter().any(|h| h.0.name == handler.0.name) {
+                        handlers.push(handler);
+                    }
+                }
+                for m in &current.methods {
+                    let (event, handler) = match m.event() {
+                        Some(event) => event,
+                        None => continue,
+                    };
+                    if !current.methods.iter().any(|r| r.removes(event)) {
+                        continue;
+                    }
+                    let handler = match self.handler(handler) {
+                        Some(handler) => handler,
+                        None => continue,
+                    };
+                    m.render_event_wrapper(w, event, handler.1, handler.0.name, &wrapped)?;
+                    if !handlers.iter().any(|h| h.0.name == handler.0.name) {
+                        handlers.push(handler);
+                    }
+                }
+                interface = self.interfaces.iter().find(|p| p.name == current.parent);
+            }
+            writeln!(w, "}}")?;
+            if !ancestors[i.name].is_empty() {
+                writeln!(w)?;
+                write!(w, "{}", cfg)?;
+                writeln!(w, "impl Wrapper for {} {{", name)?;
+                writeln!(w, "    type Interface = dyn {};", i.name)?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "    fn from_inner(inner: ComRc<dyn {}>) -> Self {{",
+                    i.name
+                )?;
+                writeln!(w, "        Self::new(inner)")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            for a in &ancestors[i.name] {
+                // The wrapper of an experimental interface only exists with
+                // the feature.
+                let mut cfg = cfg.clone();
+                for line in a.cfg().lines() {
+                    if !cfg.lines().any(|l| l == line) {
+                        cfg.push_str(line);
+                        cfg.push('\n');
+                    }
+                }
+                writeln!(w)?;
+                write!(w, "{}", cfg)?;
+                writeln!(w, "impl Extends<{}> for {} {{}}", wrapped[a.name], name)?;
+            }
+            self.render_builder(w, i, name, &cfg)?;
+            if self.eager_args && i.name.ends_with("EventArgs") {
+                self.render_args_values(w, i, name, &cfg)?;
+            }
+        }
+        for (base, versions) in self.families() {
+            self.render_family(w, base, &versions, &wrapped)?;
+        }
+        for (handler, invoke) in handlers {
+            render_handler_closure(w, handler, invoke)?;
+        }
+        for deferral in deferrals {
+            let cfg = match self.interfaces.iter().find(|i| i.name == deferral) {
+                Some(i) => i.cfg(),
+                None => String::new(),
+            };
+            render_deferral_guard(w, &wrapped[deferral], &cfg)?;
+        }
+        writeln!(w, "}}")
+    }
+
+    /// The deferral interface of a `GetDeferral([out, retval] IDeferral**)`
+    /// method, if the interface has a `Complete()` method.
+    fn deferral(&self, m: &Method) -> Option<&'a str> {
+        let deferral = match m.parameters.as_slice() {
+            [p] if m.name == "GetDeferral"
+                && p.wrapper_kind() == Some((true, WrapperKind::Interface)) =>
+            {
+                p.r#type.idl_name
+            }
+            _ => return None,
+        };
+        let i = self.interfaces.iter().find(|i| i.name == deferral)?;
+        if i.methods
+            .iter()
+            .any(|c| c.name == "Complete" && c.parameters.is_empty())
+        {
+            Some(i.name)
+        } else {
+            None
+        }
+    }
+
+    /// Render `FooBuilder` for a property bag, i.e. an interface whose
+    /// methods, including those of its parents, are all `get_X` and `put_X`.
+    /// It has a field for each property with a `bool`, string or value
+    /// setter, set with `with_x`, and `apply_to` calls the setters of the
+    /// fields that are set.
+    ///
+    /// Event args are skipped, their setters are for replying to the event
+    /// rather than configuration.
+    fn render_builder(
+        &self,
+        w: &mut impl Write,
+        i: &Interface,
+        wrapper: &str,
+        cfg: &str,
+    ) -> io::Result<()> {
+        if i.name.ends_with("EventArgs") {
+            return Ok(());
+        }
+        let mut setters = Vec::new();
+        let mut interface = Some(i);
+        while let Some(current) = interface {
+            for m in &current.methods {
+                match m.property() {
+                    Some((Accessor::Get, _)) => {}
+                    Some((Accessor::Put, property)) => {
+                        let kind = match m.parameters.as_slice() {
+                            [p] => p.wrapper_kind(),
+                            _ => None,
+                        };
+                        match kind {
+                            Some((false, kind))
+                                if kind != WrapperKind::Interface
+                                    && m.return_type.idl_name == "HRESULT"
+                                    && m.return_type.modifiers.is_empty() =>
+                            {
+                                setters.push((m, &m.parameters[0], kind, property))
+                            }
+                            _ => {}
+                        }
+                    }
+                    None => return Ok(()),
+                }
+            }
+            interface = self.interfaces.iter().find(|p| p.name == current.parent);
+        }
+        if setters.is_empty() {
+            return Ok(());
+        }
+
+        let name = format!("{}Builder", wrapper);
+        let field_type = |p: &Parameter, kind| match kind {
+            WrapperKind::String => "String".to_owned(),
+            _ => p.wrapper_type(false, kind, &HashMap::new()),
+        };
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Properties to set on a `{}` with `apply_to`.",
+            wrapper
+        )?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "#[derive(Clone, Default)]")?;
+        writeln!(w, "pub struct {} {{", name)?;
+        for &(_, p, kind, property) in &setters {
+            writeln!(
+                w,
+                "    {}: Option<{}>,",
+                camel_to_snake(property),
+                field_type(p, kind)
+            )?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "impl {} {{", name)?;
+        writeln!(w, "    pub fn new() -> Self {{")?;
+        writeln!(w, "        Self::default()")?;
+        writeln!(w, "    }}")?;
+        for &(_, p, kind, property) in &setters {
+            let field = camel_to_snake(property);
+            let value = match kind {
+                WrapperKind::String => "value.to_owned()",
+                _ => "value",
+            };
+            writeln!(w)?;
+            writeln!(
+                w,
+                "    pub fn with_{}(self, value: {}) -> Self {{",
+                field,
+                p.wrapper_type(false, kind, &HashMap::new())
+            )?;
+            writeln!(w, "        Self {{")?;
+            writeln!(w, "            {}: Some({}),", field, value)?;
+            writeln!(w, "            ..self")?;
+            writeln!(w, "        }}")?;
+            writeln!(w, "    }}")?;
+        }
+        writeln!(w)?;
+        writeln!(
+            w,
+            "    /// Set the properties that were given, leaving the others as they are."
+        )?;
+        writeln!(
+            w,
+            "    pub fn apply_to(&self, target: &{}) -> Result<()> {{",
+            wrapper
+        )?;
+        for &(m, _, kind, property) in &setters {
+            let value = match kind {
+                WrapperKind::String => "value",
+                _ => "*value",
+            };
+            writeln!(
+                w,
+                "        if let Some(value) = &self.{} {{",
+                camel_to_snake(property)
+            )?;
+            writeln!(w, "            target.{}({})?;", m.rust_name(), value)?;
+            writeln!(w, "        }}")?;
+        }
+        writeln!(w, "        Ok(())")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")
+    }
+
+    /// Render `FooEventArgsValues` with a field for each `bool`, string or
+    /// value property of the event args and their parents, and the args
+    /// themselves for setters and deferrals.
+    fn render_args_values(
+        &self,
+        w: &mut impl Write,
+        i: &Interface,
+        wrapper: &str,
+        cfg: &str,
+    ) -> io::Result<()> {
+        let mut getters = Vec::new();
+        let mut interface = Some(i);
+        while let Some(current) = interface {
+            for m in &current.methods {
+                let property = match m.property() {
+                    Some((Accessor::Get, property)) => property,
+                    _ => continue,
+                };
+                let kind = match m.parameters.as_slice() {
+                    [p] => p.wrapper_kind(),
+                    _ => None,
+                };
+                match kind {
+                    Some((true, kind))
+                        if kind != WrapperKind::Interface
+                            && m.return_type.idl_name == "HRESULT"
+                            && m.return_type.modifiers.is_empty() =>
+                    {
+                        getters.push((m, &m.parameters[0], kind, property))
+                    }
+                    _ => {}
+                }
+            }
+            interface = self.interfaces.iter().find(|p| p.name == current.parent);
+        }
+
+        let name = format!("{}Values", wrapper);
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// The properties of a `{}`, read at once, e.g. to pass them on.",
+            wrapper
+        )?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "#[derive(Clone)]")?;
+        writeln!(w, "pub struct {} {{", name)?;
+        for &(_, p, kind, property) in &getters {
+            writeln!(
+                w,
+                "    pub {}: {},",
+                camel_to_snake(property),
+                p.wrapper_type(true, kind, &HashMap::new())
+            )?;
+        }
+        writeln!(w, "    /// For the setters and the deferral.")?;
+        writeln!(w, "    pub args: {},", wrapper)?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        write!(w, "{}", cfg)?;
+        writeln!(w, "impl {} {{", name)?;
+        writeln!(w, "    pub fn read(args: {}) -> Result<Self> {{", wrapper)?;
+        writeln!(w, "        Ok(Self {{")?;
+        for &(m, _, _, property) in &getters {
+            writeln!(
+                w,
+                "            {}: args.{}()?,",
+                camel_to_snake(property),
+                m.rust_name()
+            )?;
+        }
+        writeln!(w, "            args,")?;
+        writeln!(w, "        }})")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")
+    }
+
+    /// The interface named `name` and its `Invoke` method, if it is a
+    /// handler with nothing but that method.
+    fn handler(&self, name: &str) -> Option<(&Interface<'a>, &Method<'a>)> {
+        let handler = self.interfaces.iter().find(|i| i.name == name)?;
+        match handler.methods.as_slice() {
+            [invoke]
+                if handler.uuid.is_some()
+                    && handler.parent == "IUnknown"
+                    && invoke.name == "Invoke"
+                    && invoke.return_type.idl_name == "HRESULT"
+                    && invoke.return_type.modifiers.is_empty() =>
+            {
+                Some((handler, invoke))
+            }
+            _ => None,
+        }
+    }
+
+    /// Serialize the parsed model as JSON, for tools that want to consume the
+    /// IDL without parsing it.
+    pub fn render_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    fn render(&self, w: &mut impl Write) -> io::Result<()> {
+        self.render_with(w, false, Interface::render)
+    }
+
+    fn render_raw(&self, w: &mut impl Write) -> io::Result<()> {
+        self.render_with(w, true, |i, w| i.render_raw(w, self.abi))
+    }
+
+    fn render_with(
+        &self,
+        w: &mut impl Write,
+        raw: bool,
+        render_interface: impl Fn(&Interface<'a>, &mut Vec<u8>) -> io::Result<()> + Sync,
+    ) -> io::Result<()> {
+        // Interfaces are most of the output and independent of each other.
+        let rendered = render_parallel(&self.interfaces, &render_interface)?;
+        let interfaces = || self.interfaces.iter().zip(&rendered);
+
+        for e in &self.exports {
+            writeln!(w)?;
+            e.render(w, self.abi)?;
+        }
+        // Constants are grouped before all interfaces.
+        if !self.experimental {
+            for c in &self.constants {
+                c.render(w)?;
+                writeln!(w)?;
+            }
+        }
+        let mut first = true;
+        for (_, text) in interfaces().filter(|(i, _)| !i.experimental && i.sdk.is_none()) {
+            if !first {
+                writeln!(w)?;
+            } else {
+                first = false;
+            }
+            w.write_all(text)?;
+        }
+        for c in &self.coclasses {
+            if !first {
+                writeln!(w)?;
+            } else {
+                first = false;
+            }
+            let default = c
+                .default_interface()
+                .and_then(|name| self.interfaces.iter().find(|i| i.name == name));
+            c.render(w, default, raw)?;
+        }
+
+        // Experimental items and those of later SDKs go into modules, so that
+        // they only need one `cfg` attribute.
+        let experimental: Vec<&Interface> =
+            self.interfaces.iter().filter(|i| i.experimental).collect();
+        let sdk_versions = self.sdk_versions();
+        if !self.interfaces.is_empty() {
+            if !first {
+                writeln!(w)?;
+            }
+            self.render_registry(w, !experimental.is_empty(), &sdk_versions)?;
+        }
+        for version in &sdk_versions {
+            let module = sdk_module(version);
+            let sdk: Vec<&Interface> = self
+                .interfaces
+                .iter()
+                .filter(|i| !i.experimental && i.sdk.as_deref() == Some(version))
+                .collect();
+            writeln!(w)?;
+            writeln!(w, "{}", sdk_cfg(version))?;
+            writeln!(w, "mod {} {{", module)?;
+            writeln!(w, "use super::*;")?;
+            writeln!(w)?;
+            for (n, (_, text)) in interfaces()
+                .filter(|(i, _)| !i.experimental && i.sdk.as_deref() == Some(version))
+                .enumerate()
+            {
+                if n > 0 {
+                    writeln!(w)?;
+                }
+                w.write_all(text)?;
+            }
+            writeln!(w)?;
+            let table = format!("{}_INTERFACES", module.to_uppercase());
+            render_interface_table(w, &table, &sdk)?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+            writeln!(w, "{}", sdk_cfg(version))?;
+            writeln!(w, "pub use self::{}::*;", module)?;
+        }
+        if !self.experimental && experimental.is_empty() {
+            return Ok(());
+        }
+        writeln!(w)?;
+        writeln!(w, "{}", EXPERIMENTAL_CFG)?;
+        writeln!(w, "mod experimental {{")?;
+        writeln!(w, "use super::*;")?;
+        writeln!(w)?;
+        if self.experimental {
+            for c in &self.constants {
+                c.render(w)?;
+                writeln!(w)?;
+            }
+        }
+        for (n, (_, text)) in interfaces().filter(|(i, _)| i.experimental).enumerate() {
+            if n > 0 {
+                writeln!(w)?;
+            }
+            w.write_all(text)?;
+        }
+        writeln!(w)?;
+        render_interface_table(w, "EXPERIMENTAL_INTERFACES", &experimental)?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(w, "{}", EXPERIMENTAL_CFG)?;
+        writeln!(w, "pub use self::experimental::*;")
+    }
+}
+
+/// Render each interface into a buffer of its own, in the order of
+/// `interfaces`, on up to as many threads as there are cores.
+fn render_parallel<'a>(
+    interfaces: &[Interface<'a>],
+    render: &(impl Fn(&Interface<'a>, &mut Vec<u8>) -> io::Result<()> + Sync),
+) -> io::Result<Vec<Vec<u8>>> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_len = interfaces.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let chunks: Vec<_> = interfaces
+            .chunks(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|i| {
+                            let mut text = Vec::new();
+                            render(i, &mut text)?;
+                            Ok(text)
+                        })
+                        .collect::<io::Result<Vec<_>>>()
+                })
+            })
+            .collect();
+        let mut rendered = Vec::with_capacity(interfaces.len());
+        for chunk in chunks {
+            rendered.extend(chunk.join().unwrap()?);
+        }
+        Ok(rendered)
+    })
+}
+
+/// `x()` and `set_x()` for each property, calling `get_x` and `put_x`.
+fn render_accessors(
+    w: &mut impl Write,
+    properties: &[Property],
+    fn_keyword: &str,
+    unsafe_blocks: bool,
+) -> io::Result<()> {
+    let call = |call: String| {
+        if unsafe_blocks {
+            format!("unsafe {{ {} }}", call)
+        } else {
+            call
+        }
+    };
+    let mut first = true;
+    for p in properties {
+        for (accessor, method) in [(Accessor::Get, p.getter), (Accessor::Put, p.setter)] {
+            let method = match method {
+                Some(m) => m,
+                None => continue,
+            };
+            if !first {
+                writeln!(w)?;
+            }
+            first = false;
+            write!(w, "{}", method.doc_comment.unwrap_or(""))?;
+            render_deprecated(w, "    ", &method.deprecated)?;
+            match accessor {
+                Accessor::Get => {
+                    writeln!(
+                        w,
+                        "    {} {}(&self) -> Result<{}, HRESULT> {{",
+                        fn_keyword, p.name, p.r#type
+                    )?;
+                    writeln!(
+                        w,
+                        "        let mut value = std::mem::MaybeUninit::uninit();"
+                    )?;
+                    writeln!(
+                        w,
+                        "        let hr = {};",
+                        call(format!("self.{}(value.as_mut_ptr())", method.rust_name()))
+                    )?;
+                    writeln!(w, "        if hr < 0 {{")?;
+                    writeln!(w, "            return Err(hr);")?;
+                    writeln!(w, "        }}")?;
+                    writeln!(w, "        Ok({})", call("value.assume_init()".into()))?;
+                }
+                Accessor::Put => {
+                    writeln!(
+                        w,
+                        "    {} set_{}(&self, value: {}) -> Result<(), HRESULT> {{",
+                        fn_keyword, p.name, p.r#type
+                    )?;
+                    writeln!(
+                        w,
+                        "        let hr = {};",
+                        call(format!("self.{}(value)", method.rust_name()))
+                    )?;
+                    writeln!(w, "        if hr < 0 {{ Err(hr) }} else {{ Ok(()) }}")?;
+                }
+            }
+            writeln!(w, "    }}")?;
+        }
+    }
+    Ok(())
+}
+
+/// `pub const name: &[InterfaceInfo]` with the interfaces that have an IID.
+fn render_interface_table(
+    w: &mut impl Write,
+    name: &str,
+    interfaces: &[&Interface],
+) -> io::Result<()> {
+    writeln!(w, "pub const {}: &[InterfaceInfo] = &[", name)?;
+    for i in interfaces.iter().filter(|i| i.uuid.is_some()) {
+        writeln!(
+            w,
+            "    InterfaceInfo {{ name: \"{0}\", parent: \"{1}\", iid: IID_{0}, methods: {2} }},",
+            i.name,
+            i.parent,
+            i.methods.len()
+        )?;
+    }
+    writeln!(w, "];")
+}
+
+/// Serialize doc comments as plain text, without the `///` markers.
+fn serialize_doc_comment<S: Serializer>(
+    doc_comment: &Option<&str>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let text = doc_comment.map(|d| {
+        d.lines()
+            .map(|line| {
+                let line = line.trim().trim_start_matches("///");
+                line.strip_prefix(' ').unwrap_or(line).trim_end()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .to_string()
+    });
+    text.serialize(serializer)
+}
+
+/// The note of a `deprecated` or `deprecated("note", ...)` attribute, or
+/// else the rest of the first doc comment line that starts with
+/// `Deprecated`, e.g. `Use IFoo2 instead.` for `/// Deprecated: Use IFoo2
+/// instead.` The note is empty if there is nothing after the word.
+fn deprecation(attributes: &[&str], doc_comment: Option<&str>) -> Option<String> {
+    for a in attributes {
+        let arguments = match a.strip_prefix("deprecated") {
+            Some(arguments) if arguments.is_empty() || arguments.starts_with('(') => arguments,
+            _ => continue,
+        };
+        let note = arguments
+            .split('"')
+            .nth(1)
+            .filter(|_| arguments.matches('"').count() >= 2);
+        return Some(note.unwrap_or("").to_string());
+    }
+    doc_comment?.lines().find_map(|line| {
+        let text = line.trim().trim_start_matches('/').trim_start();
+        let rest = text.strip_prefix("Deprecated")?;
+        if rest.starts_with(|c: char| c.is_alphanumeric()) {
+            return None;
+        }
+        Some(
+            rest.trim_start_matches(|c: char| {
+                c == ':' || c == '.' || c == '-' || c.is_whitespace()
+            })
+            .trim_end()
+            .to_string(),
+        )
+    })
+}
+
+fn render_deprecated(w: &mut impl Write, indent: &str, note: &Option<String>) -> io::Result<()> {
+    match note.as_deref() {
+        None => Ok(()),
+        Some("") => writeln!(w, "{}#[deprecated]", indent),
+        Some(note) => writeln!(w, "{}#[deprecated(note = {:?})]", indent, note),
+    }
+}
+
+/// The name without the version suffix and the version, e.g. `Foo` and 2 for
+/// `Foo2` and `Foo_2`. `None` without a suffix.
+fn split_version(name: &str) -> Option<(&str, u32)> {
+    let base = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let version = name[base.len()..].parse().ok()?;
+    let base = base.strip_suffix('_').unwrap_or(base);
+    if base.is_empty() {
+        return None;
+    }
+    Some((base, version))
+}
+
+/// Render a `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` uuid as a `GUID` literal.
+fn render_guid(uuid: &str, w: &mut impl Write) -> io::Result<()> {
+    let hex: String = uuid.chars().filter(|c| *c != '-').collect();
+    assert_eq!(hex.len(), 32, "invalid uuid {}", uuid);
+    let data4: Vec<String> = (0..8)
+        .map(|i| format!("0x{}", &hex[16 + i * 2..18 + i * 2]))
+        .collect();
+    write!(
+        w,
+        "GUID {{ Data1: 0x{}, Data2: 0x{}, Data3: 0x{}, Data4: [{}] }}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        data4.join(", ")
+    )
+}
+
+/// Reference page of a WebView2 interface on Microsoft Learn. Pages are
+/// under the current names of interfaces.
+fn learn_url(interface: &str) -> Option<String> {
+    if !interface.starts_with("ICoreWebView2") {
+        return None;
+    }
+    let name = RENAMED_INTERFACES
+        .iter()
+        .find(|(old, _)| *old == interface)
+        .map_or(interface, |(_, new)| new);
+    Some(format!(
+        "https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/{}",
+        name.to_lowercase()
+    ))
+}
+
+/// `ICoreWebView2Foo` -> `MockCoreWebView2Foo`.
+fn mock_name(interface: &str) -> String {
+    let mut chars = interface.chars();
+    match (chars.next(), chars.next()) {
+        (Some('I'), Some(c)) if c.is_uppercase() => format!("Mock{}", &interface[1..]),
+        _ => format!("Mock{}", interface),
+    }
+}
+
+/// Render a `co_class` implementing the handler interface with a closure
+/// that gets the raw arguments of `Invoke`, for `on_` methods of wrappers.
+fn render_handler_closure(
+    w: &mut impl Write,
+    handler: &Interface,
+    invoke: &Method,
+) -> io::Result<()> {
+    let name = format!("{}Closure", wrapper_name(handler.name));
+    let cfg = handler.cfg();
+    let mut types = Vec::new();
+    for p in &invoke.parameters {
+        let mut t = Vec::new();
+        p.r#type.render_pointers(&mut t, p.is_input())?;
+        types.push(String::from_utf8(t).unwrap());
+    }
+    let callback = format!("Fn({}) -> HRESULT", types.join(", "));
+
+    writeln!(w)?;
+    writeln!(w, "/// `{}` implemented by a closure.", handler.name)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "#[com::co_class(implements({}))]", handler.name)?;
+    writeln!(w, "struct {} {{", name)?;
+    writeln!(w, "    callback: Box<dyn {}>,", callback)?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "impl {} {{", name)?;
+    writeln!(
+        w,
+        "    // Required by `co_class`, `create` is used instead."
+    )?;
+    writeln!(w, "    fn new() -> Box<Self> {{")?;
+    writeln!(w, "        unreachable!()")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    fn create(callback: impl {} + 'static) -> ComRc<dyn {}> {{",
+        callback, handler.name
+    )?;
+    writeln!(
+        w,
+        "        let handler = Self::allocate(Box::new(callback));"
+    )?;
+    writeln!(w, "        unsafe {{")?;
+    writeln!(w, "            handler.add_ref();")?;
+    writeln!(
+        w,
+        "            ComPtr::<dyn {}>::new(Box::into_raw(handler) as _).upgrade()",
+        handler.name
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "impl {} for {} {{", handler.name, name)?;
+    write!(w, "    ")?;
+    invoke.render_signature(w, true)?;
+    writeln!(w, " {{")?;
+    let arguments: Vec<String> = invoke
+        .parameters
+        .iter()
+        .map(|p| format!("_{}", p.name))
+        .collect();
+    writeln!(w, "        (self.callback)({})", arguments.join(", "))?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")
+}
+
+/// Render the guard returned by `take_deferral` of event args, which
+/// completes the deferral when dropped, so that an early return or a panic
+/// doesn't leave the event waiting forever.
+fn render_deferral_guard(w: &mut impl Write, wrapper: &str, cfg: &str) -> io::Result<()> {
+    let guard = format!("{}Guard", wrapper);
+    writeln!(w)?;
+    writeln!(w, "/// A `{}` completed when dropped.", wrapper)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "pub struct {} {{", guard)?;
+    writeln!(w, "    deferral: Option<{}>,", wrapper)?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "impl {} {{", guard)?;
+    writeln!(w, "    /// Complete now, to see whether it failed.")?;
+    writeln!(w, "    pub fn complete(mut self) -> Result<()> {{")?;
+    writeln!(w, "        self.deferral.take().unwrap().complete()")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write!(w, "{}", cfg)?;
+    writeln!(w, "impl Drop for {} {{", guard)?;
+    writeln!(w, "    fn drop(&mut self) {{")?;
+    writeln!(w, "        if let Some(deferral) = self.deferral.take() {{")?;
+    writeln!(w, "            let _ = deferral.complete();")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")
+}
+
+/// `ICoreWebView2Settings` -> `CoreWebView2Settings`.
+fn wrapper_name(interface: &str) -> String {
+    mock_name(interface)["Mock".len()..].to_owned()
+}
+
+/// `NavigateToString` -> `navigate_to_string`, `get_HTTPStatusCode` ->
+/// `get_http_status_code`.
+///
+/// A run of capitals is one word, except for its last capital when a
+/// lowercase letter follows, which starts the next word. A single capital
+/// before a word stays with it, as in `KeyEventLParam`. Digits stay with the
+/// word before them.
+fn camel_to_snake(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let is_upper = |i: usize| chars[i].is_uppercase();
+    let mut new = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            let acronym_ends = prev.is_uppercase() && i >= 2 && is_upper(i - 2);
+            if prev.is_lowercase() || prev.is_ascii_digit() || (acronym_ends && next_is_lowercase) {
+                new.push('_');
+            }
+        }
+        new.extend(c.to_lowercase());
+    }
+
+    new
+}
+
+/// Parse an IDL file.
+///
+/// On failure, parsing continues past malformed items and all errors are
+/// returned.
+pub fn parse_idl(input: &str) -> Result<Document<'_>, Vec<Error<Rule>>> {
+    match IDLParser::parse(Rule::document, input) {
+        Ok(mut p) => {
+            let doc = Document::from_pest(p.next().unwrap());
+            let mut errors = doc.check_method_names(input);
+            errors.extend(doc.check_size_is(input));
+            errors.extend(doc.check_interfaces(input));
+            errors.extend(doc.check_coclasses(input));
+            if errors.is_empty() {
+                Ok(doc)
+            } else {
+                Err(errors)
+            }
+        }
+        Err(e) => {
+            let mut errors = diagnose(input);
+            if errors.is_empty() {
+                // Recovery parses differently, make sure to report something.
+                errors.push(e);
+            }
+            Err(errors)
+        }
+    }
+}
+
+/// Parse `input` item by item and collect every error, skipping to the next
+/// top level item after each one.
+///
+/// Only used to report diagnostics once parsing the whole document failed.
+fn diagnose(input: &str) -> Vec<Error<Rule>> {
+    let mut errors = Vec::new();
+    let mut offset = match IDLParser::parse(Rule::document_head, input) {
+        Ok(mut p) => p.next().unwrap().as_span().end(),
+        Err(_) => 0,
+    };
+    loop {
+        let rest = &input[offset..];
+        if IDLParser::parse(Rule::document_end, rest).is_ok() {
+            break;
+        }
+        match IDLParser::parse(Rule::document_item, rest) {
+            Ok(mut p) => offset += p.next().unwrap().as_span().end(),
+            Err(e) => {
+                let error_offset = offset
+                    + match e.location {
+                        InputLocation::Pos(p) => p,
+                        InputLocation::Span((start, _)) => start,
+                    };
+                errors.push(Error::new_from_pos(
+                    e.variant,
+                    Position::new(input, error_offset).unwrap(),
+                ));
+                match next_item_start(input, error_offset) {
+                    Some(next) => offset = next,
+                    None => break,
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Offset of the first line after the one containing `offset` that looks like
+/// the start of a top level item.
+///
+/// Top level items start at column 0 while interface members are indented,
+/// which is true of every SDK drop so far.
+fn next_item_start(input: &str, offset: usize) -> Option<usize> {
+    let mut line_start = offset + input[offset..].find('\n')? + 1;
+    loop {
+        let line = &input[line_start..];
+        if [
+            "[",
+            "///",
+            "interface",
+            "coclass",
+            "library",
+            "typedef",
+            "cpp_quote",
+            "const",
+            "#define",
+        ]
+        .iter()
+        .any(|start| line.starts_with(start))
+        {
+            return Some(line_start);
+        }
+        line_start += line.find('\n')? + 1;
+    }
+}
+
+/// Interfaces that imported files define and the prelude provides.
+const IMPORTED_INTERFACES: &[(&str, &[&str])] = &[
+    ("unknwn.idl", &["IUnknown"]),
+    ("objidl.idl", &["IUnknown", "ISequentialStream", "IStream"]),
+];
+
+/// C types that have no name in winapi, and their Rust types. `INT` has one,
+/// but was always rendered as `i32` too.
+const PRIMITIVE_TYPES: &[(&str, &str)] = &[("int", "i32"), ("INT", "i32"), ("double", "f64")];
+
+/// Sizes of fixed size types, which are also their alignment. 64-bit values
+/// are 8-byte aligned on 32-bit Windows too.
+const SCALAR_SIZES: &[(&str, usize)] = &[
+    ("BOOL", 4),
+    ("BYTE", 1),
+    ("int", 4),
+    ("double", 8),
+    ("DWORD", 4),
+    ("HRESULT", 4),
+    ("INT", 4),
+    ("INT16", 2),
+    ("INT32", 4),
+    ("INT64", 8),
+    ("LONG", 4),
+    ("UINT", 4),
+    ("UINT16", 2),
+    ("UINT32", 4),
+    ("UINT64", 8),
+    ("ULONG", 4),
+    ("USHORT", 2),
+    ("EventRegistrationToken", 8),
+];
+
+/// Handles and string typedefs.
+const POINTER_SIZED_TYPES: &[&str] = &["HANDLE", "HCURSOR", "HICON", "HWND", "LPCWSTR", "LPWSTR"];
+
+/// `IUnknown` or `ICoreWebView2`, but not `INT32`.
+/// Not a pointer once dereferenced: no interfaces and no strings.
+fn is_value_type(r#type: &Type) -> bool {
+    !r#type.is_interface && !STRING_TYPES.contains(&r#type.idl_name)
+}
+
+fn is_interface_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('I')
+        && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && name.chars().any(|c| c.is_ascii_lowercase())
+}
+
+fn round_up(n: usize, align: usize) -> usize {
+    n.div_ceil(align) * align
+}
+
+const STRING_TYPES: &[&str] = &["LPCWSTR", "LPWSTR", "PCWSTR"];
+
+const SERDE_DERIVE: &str =
+    "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]";
+
+/// Field types that serde can handle.
+const PLAIN_TYPES: &[&str] = &[
+    "BOOL", "BYTE", "int", "double", "INT", "INT16", "INT32", "INT64", "LONG", "UINT", "UINT16",
+    "UINT32", "UINT64", "ULONG", "USHORT",
+];
+
+/// Interfaces renamed after this SDK, or declared under another name next to
+/// it, for `learn_url`.
+const RENAMED_INTERFACES: &[(&str, &str)] = &[
+    ("ICoreWebView2Host", "ICoreWebView2Controller"),
+    ("ICoreWebView2_1", "ICoreWebView2"),
+    (
+        "ICoreWebView2NewWindowRequestedEventArgs_1",
+        "ICoreWebView2NewWindowRequestedEventArgs",
+    ),
+    (
+        "ICoreWebView2CreateCoreWebView2HostCompletedHandler",
+        "ICoreWebView2CreateCoreWebView2ControllerCompletedHandler",
+    ),
+];
+
+const EXPERIMENTAL_CFG: &str = "#[cfg(feature = \"experimental\")]";
+
+fn sdk_cfg(version: &str) -> String {
+    format!("#[cfg(feature = \"sdk-{}\")]", version)
+}
+
+/// The module of the interfaces of an SDK version, e.g. `sdk_1_0_774`.
+fn sdk_module(version: &str) -> String {
+    format!("sdk_{}", version.replace('.', "_"))
+}
+
+/// Shared start of the output of both modes.
+const HEADER: &str = include_str!("../templates/header.rs");
+
+const COM_IMPORTS: &str = include_str!("../templates/com_imports.rs");
+
+const RAW_IMPORTS: &str = include_str!("../templates/raw_imports.rs");
+
+const IMPORTS: &str = include_str!("../templates/imports.rs");
+
+/// Interfaces from other headers used by WebView2.
+const COM_STREAMS: &str = include_str!("../templates/com_streams.rs");
+
+/// `IUnknown` is in the `com` crate, the raw mode defines its own.
+const RAW_STREAMS: &str = include_str!("../templates/raw_streams.rs");
+
+/// `InterfaceInfo` and the lookups, followed by the generated tables.
+const REGISTRY: &str = include_str!("../templates/registry.rs");
+const WRAPPERS: &str = include_str!("../templates/wrappers.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_document(input: &str) -> String {
+        let mut p = IDLParser::parse(Rule::document, input).unwrap();
+        let doc = Document::from_pest(p.next().unwrap());
+        let mut output = Vec::new();
+        doc.render(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_in_out_pointers() {
+        let output = render_document(
+            "interface IFoo : IUnknown {\n\
+             HRESULT Bar([in] VARIANT* a, [in, out] VARIANT* b, [out, retval] BOOL* c,\n\
+             [in] IBaz* d, [out] IBaz** e);\n\
+             }\n",
+        );
+        assert!(output.contains(
+            "unsafe fn bar(&self, /* in */ a: *const VARIANT, /* in, out */ b: *mut VARIANT, \
+             /* out, retval */ c: *mut BOOL, /* in */ d: *mut *mut IBazVTable, \
+             /* out */ e: *mut *mut *mut IBazVTable) -> HRESULT;"
+        ));
+    }
+
+    #[test]
+    fn test_iid() {
+        let output = render_document(
+            "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+             interface IFoo : IUnknown {}\n",
+        );
+        // Followed by the registry.
+        assert!(output.contains(
+            "pub const IID_IFoo: GUID = GUID { Data1: 0x5cc5293d, Data2: 0xaf6f, Data3: 0x41d4, \
+             Data4: [0x96, 0x19, 0x44, 0xbd, 0x31, 0xba, 0x4c, 0x93] };\n"
+        ));
+    }
+
+    #[test]
+    fn test_constants() {
+        let output = render_document(
+            "const UINT32 MAX_SIZE = 1024;\n\
+             const HRESULT E_SOMETHING = 0x80070057;\n\
+             #define DEFAULT_WIDTH 640\n\
+             #define NEGATIVE (-5)\n",
+        );
+        assert_eq!(
+            output,
+            "pub const MAX_SIZE: UINT32 = 1024;\n\n\
+             pub const E_SOMETHING: HRESULT = 0x80070057_u32 as HRESULT;\n\n\
+             pub const DEFAULT_WIDTH: i32 = 640;\n\n\
+             pub const NEGATIVE: i32 = -5;\n\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let doc = parse_idl(
+            "/// An interface.\n\
+             /// Second line.\n\
+             [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+             interface IFoo : IUnknown {\n\
+             HRESULT Bar([in] LPCWSTR* a);\n\
+             }\n",
+        )
+        .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&doc.render_json()).unwrap();
+        let interface = &json["interfaces"][0];
+        assert_eq!(interface["name"], "IFoo");
+        assert_eq!(interface["uuid"], "5cc5293d-af6f-41d4-9619-44bd31ba4c93");
+        assert_eq!(interface["doc_comment"], "An interface.\nSecond line.");
+        let parameter = &interface["methods"][0]["parameters"][0];
+        assert_eq!(parameter["attributes"], serde_json::json!(["in"]));
+        assert_eq!(parameter["type"]["base_type"], "LPCWSTR");
+        assert_eq!(
+            parameter["type"]["modifiers"],
+            serde_json::json!(["pointer"])
+        );
+    }
+
+    #[test]
+    fn test_flatten_parents() {
+        let mut doc = parse_idl(
+            "interface IA : IUnknown {\n\
+             HRESULT A();\n\
+             }\n\
+             interface IB : IA {\n\
+             HRESULT B();\n\
+             }\n\
+             interface IC : IB {\n\
+             HRESULT C();\n\
+             }\n",
+        )
+        .unwrap();
+        doc.flatten_parents();
+        let ic = &doc.interfaces[2];
+        assert_eq!(ic.parent, "IUnknown");
+        let names: Vec<&str> = ic.methods.iter().map(|m| m.name).collect();
+        assert_eq!(names, ["A", "B", "C"]);
+        assert_eq!(doc.interfaces[1].methods.len(), 2);
+    }
+
+    #[test]
+    fn test_mocks() {
+        let doc = parse_idl(
+            "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+             interface IFoo : IUnknown {\n\
+             HRESULT get_Bar([out, retval] BOOL* bar);\n\
+             HRESULT Fill([in] UINT32 n, [out, size_is(n)] INT32* values);\n\
+             }\n",
+        )
+        .unwrap();
+        let mocks = doc.render_mocks();
+        // Caller allocated arrays may be empty.
+        assert!(mocks.contains("unsafe fn fill(&self, _n: UINT32, _values: *mut INT32)"));
+        assert!(mocks.contains(
+            "#[co_class(implements(IFoo))]\n\
+             pub struct MockFoo {\n"
+        ));
+        assert!(mocks.contains(
+            "impl IFoo for MockFoo {\n    \
+             unsafe fn get_bar(&self, bar: *mut BOOL) -> HRESULT {\n        \
+             self.calls.borrow_mut().push(\"get_Bar\");\n        \
+             if !bar.is_null() {\n            \
+             *bar = std::mem::zeroed();\n        \
+             }\n        \
+             S_OK\n    \
+             }\n"
+        ));
+    }
+
+    #[test]
+    fn test_doc_alias() {
+        let doc = parse_idl(
+            "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+             interface IFoo : IUnknown {\n\
+             HRESULT add_BarChanged();\n\
+             HRESULT reload();\n\
+             }\n",
+        )
+        .unwrap();
+        let rust = doc.render_to_string();
+        assert!(rust.contains(
+            "    #[doc(alias = \"add_BarChanged\")]\n    \
+             unsafe fn add_bar_changed(&self) -> HRESULT;"
+        ));
+        assert!(!rust.contains("alias = \"reload\""));
+        assert!(doc
+            .render_raw_to_string()
+            .contains("    #[doc(alias = \"add_BarChanged\")]\n    pub unsafe fn add_bar_changed"));
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut doc = parse_idl(
+            "interface IFoo : IUnknown {\n\
+             [v1_enum] typedef enum KIND { KIND_A, } KIND;\n\
+             typedef struct POINT { int x; KIND kind; } POINT;\n\
+             typedef struct LINE { POINT from; POINT to; } LINE;\n\
+             typedef struct NAMED { LPWSTR name; } NAMED;\n\
+             }\n",
+        )
+        .unwrap();
+        doc.set_serde();
+        let rust = doc.render_to_string();
+        for name in &["enum KIND", "struct POINT", "struct LINE"] {
+            assert!(rust.contains(&format!("{}\npub {} {{", SERDE_DERIVE, name)));
+        }
+        assert!(rust.contains("#[repr(C)]\n#[derive(Clone, Copy)]\npub struct NAMED {"));
+    }
+
+    #[test]
+    fn test_layout_assertions() {
+        let doc = parse_idl(
+            "interface IFoo : IUnknown {\n\
+             typedef struct SMALL { BYTE a; UINT32 b; BYTE c; } SMALL;\n\
+             typedef struct NAMED { BYTE a; LPWSTR name; } NAMED;\n\
+             typedef struct OUTER { SMALL small; double d; } OUTER;\n\
+             typedef struct OPAQUE { VARIANT v; } OPAQUE;\n\
+             }\n",
+        )
+        .unwrap();
+        let rust = doc.render_to_string();
+        assert!(rust.contains("const _: () = assert!(std::mem::size_of::<SMALL>() == 12);"));
+        assert!(rust.contains(
+            "#[cfg(target_pointer_width = \"32\")]\n\
+             const _: () = assert!(std::mem::size_of::<NAMED>() == 8);\n\
+             #[cfg(target_pointer_width = \"64\")]\n\
+             const _: () = assert!(std::mem::size_of::<NAMED>() == 16);"
+        ));
+        assert!(rust.contains("const _: () = assert!(std::mem::size_of::<OUTER>() == 24);"));
+        assert!(!rust.contains("size_of::<OPAQUE>"));
+    }
+
+    #[test]
+    fn test_size_is() {
+        let idl = "interface IFoo : IUnknown {\n\
+                   HRESULT Set([in] UINT32 count, [in, size_is(count)] INT32* values);\n\
+                   HRESULT Get([out] UINT32* n, [out, size_is(, *n)] INT32** values);\n\
+                   }\n";
+        let rust = parse_idl(idl).unwrap().render_to_string();
+        assert!(rust.contains(
+            "    /// `values` is an array with `count` elements.\n    \
+             #[doc(alias = \"Set\")]\n    \
+             unsafe fn set(&self, /* in */ count: UINT32, \
+             /* in, size_is(count) */ values: *const INT32) -> HRESULT;"
+        ));
+        assert!(rust.contains("    /// `values` is an array with `n` elements.\n"));
+
+        let errors = parse_idl(
+            "interface IFoo : IUnknown {\n\
+             HRESULT Set([in, size_is(cnt)] INT32* values);\n\
+             }\n",
+        )
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        let message = errors[0].to_string();
+        assert!(message.contains("2:26"), "{}", message);
+        assert!(message.contains("`size_is` of `values` in `Set` does not name another parameter"));
+    }
+
+    #[test]
+    fn test_dll_exports() {
+        let rust = render_document(
+            "/// Create.\n\
+             typedef HRESULT (STDMETHODCALLTYPE *FnCreate)([in] LPCWSTR name, [out] IFoo** foo);\n\
+             cpp_quote(\"STDAPI Shutdown(\n    BOOL force);\")\n",
+        );
+        assert_eq!(
+            rust,
+            "\n/// Create.\n\
+             pub type FnCreate = unsafe extern \"system\" fn(\
+             /* in */ name: LPCWSTR, /* out */ foo: *mut *mut *mut IFooVTable) -> HRESULT;\n\
+             \n\
+             pub type FnShutdown = unsafe extern \"system\" fn(force: BOOL) -> HRESULT;\n"
+        );
+    }
+
+    #[test]
+    fn test_learn_links() {
+        let rust = render_document(
+            "/// The webview.\n\
+             [uuid(00000000-0000-0000-0000-000000000001), object]\n\
+             interface ICoreWebView2Foo : IUnknown {\n\
+             /// Navigate.\n\
+             HRESULT Navigate([in] LPCWSTR uri);\n\
+             HRESULT get_Bar([out, retval] BOOL* bar);\n\
+             }\n",
+        );
+        let url = "https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/\
+                   icorewebview2foo";
+        assert!(rust.starts_with(&format!(
+            "/// The webview.\n///\n/// See also: <{}>\n#[com_interface",
+            url
+        )));
+        assert!(rust.contains(&format!(
+            "/// Navigate.\n    ///\n    /// See also: <{}#navigate>\n    \
+             #[doc(alias = \"Navigate\")]\n    unsafe fn navigate",
+            url
+        )));
+        assert!(rust.contains(&format!(
+            "\n    /// See also: <{}#get_bar>\n    #[doc(alias = \"get_Bar\")]",
+            url
+        )));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let input = "interface IFoo : IUnknown {\n\
+                     HRESULT get_Count([out, retval] UINT32* count);\n\
+                     HRESULT put_Count([in] UINT32 count);\n\
+                     HRESULT get_Name([out, retval] LPWSTR* name);\n\
+                     HRESULT get_Kind([out, retval] INT32* kind);\n\
+                     HRESULT Kind();\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        doc.set_accessors();
+        let mut output = Vec::new();
+        doc.render(&mut output).unwrap();
+        let rust = String::from_utf8(output).unwrap();
+        assert!(rust.contains(
+            "pub trait IFooProperties: IFoo {\n    \
+             fn count(&self) -> Result<UINT32, HRESULT> {\n        \
+             let mut value = std::mem::MaybeUninit::uninit();\n        \
+             let hr = unsafe { self.get_count(value.as_mut_ptr()) };\n"
+        ));
+        assert!(rust.contains("    fn set_count(&self, value: UINT32) -> Result<(), HRESULT> {\n"));
+        assert!(rust.contains("impl<T: IFoo + ?Sized> IFooProperties for T {}\n"));
+        // Strings need freeing, and `kind` is taken by `Kind`.
+        assert!(!rust.contains("fn name(&self) -> Result"));
+        assert!(!rust.contains("fn kind(&self) -> Result"));
+    }
+
+    #[test]
+    fn test_types() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     typedef struct Point { int x; UINT32 y; } Point;\n\
+                     HRESULT Bar([in] LPCWSTR* a, [in] int b, [out] IBaz** c);\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        let mut types = TypeMap::parse(include_str!("../types/core-ffi.txt")).unwrap();
+        types.insert("IBaz", "u8");
+        doc.set_types(&types);
+        let raw = doc.render_raw_to_string();
+        assert!(raw.contains("    pub x: core::ffi::c_int,\n    pub y: u32,\n"));
+        // Layouts and the HRESULT check of mocks go by the IDL names.
+        assert!(raw.contains("const _: () = assert!(std::mem::size_of::<Point>() == 8);"));
+        assert!(raw.contains(
+            "pub unsafe fn bar(&self, /* in */ a: *const *const u16, \
+             /* in */ b: core::ffi::c_int, /* out */ c: *mut *mut *mut IBazVTable) -> i32 {"
+        ));
+        assert!(doc.render_mocks().contains("        S_OK\n"));
+
+        TypeMap::parse(include_str!("../types/windows-sys.txt")).unwrap();
+        assert_eq!(
+            TypeMap::parse("BOOL = i32\nnothing").unwrap_err(),
+            "line 2: expected `IDL = Rust`"
+        );
+        assert_eq!(
+            TypeMap::parse("BOOL = i32\n\n BOOL = u32").unwrap_err(),
+            "line 3: `BOOL` is mapped twice"
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        let rust = render_document(
+            "[uuid(00000000-0000-0000-0000-000000000001), object]\n\
+             interface IFoo : IUnknown {\n\
+               HRESULT Bar();\n\
+             }\n\
+             interface IFooCallback : IUnknown {\n\
+             }\n\
+             [uuid(00000000-0000-0000-0000-000000000002), object]\n\
+             interface IFooExperimental : IUnknown {\n\
+             }\n",
+        );
+        assert!(rust.contains(
+            "pub const INTERFACES: &[InterfaceInfo] = &[\n    \
+             InterfaceInfo { name: \"IFoo\", parent: \"IUnknown\", iid: IID_IFoo, methods: 1 },\n\
+             ];\n"
+        ));
+        assert!(rust.contains(
+            "    #[cfg(feature = \"experimental\")]\n    \
+             let interfaces = interfaces.chain(EXPERIMENTAL_INTERFACES.iter());\n"
+        ));
+        assert!(rust.contains(
+            "pub const EXPERIMENTAL_INTERFACES: &[InterfaceInfo] = &[\n    \
+             InterfaceInfo { name: \"IFooExperimental\", parent: \"IUnknown\", \
+             iid: IID_IFooExperimental, methods: 0 },\n\
+             ];\n}\n"
+        ));
+    }
+
+    #[test]
+    fn test_abi() {
+        let mut doc = parse_idl(
+            "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+             interface IFoo : IUnknown {\n\
+             HRESULT Bar();\n\
+             }\n",
+        )
+        .unwrap();
+        assert!(doc
+            .render_raw_to_string()
+            .contains("pub Bar: unsafe extern \"system\" fn("));
+        doc.set_abi(Abi::Stdcall);
+        let raw = doc.render_raw_to_string();
+        assert!(raw.contains("pub Bar: unsafe extern \"stdcall\" fn("));
+        assert!(!raw.contains("extern \"system\""));
+        assert!(!doc.render_to_string().contains("extern \"system\""));
+    }
+
+    #[test]
+    fn test_camel_to_snake() {
+        assert_eq!(camel_to_snake("NavigateToString"), "navigate_to_string");
+        assert_eq!(camel_to_snake("get_HTTPStatusCode"), "get_http_status_code");
+        assert_eq!(camel_to_snake("get_URI"), "get_uri");
+        assert_eq!(
+            camel_to_snake("AddHostObjectToScript"),
+            "add_host_object_to_script"
+        );
+        assert_eq!(camel_to_snake("get_WebView2Id"), "get_web_view2_id");
+        assert_eq!(camel_to_snake("get_KeyEventLParam"), "get_key_event_lparam");
+    }
+
+    #[test]
+    fn test_method_name_collision() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT GetURI();\n\
+                     HRESULT GetUri();\n\
+                     }\n";
+        let errors = parse_idl(input).err().unwrap();
+        assert_eq!(errors.len(), 1);
+        let message = errors[0].to_string();
+        assert!(message.contains("4:9"), "{}", message);
+        assert!(message.contains("`GetURI` and `GetUri` of `IFoo` would both be named `get_uri`"));
+    }
+
+    #[test]
+    fn test_wrappers() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT get_IsVisible([out, retval] BOOL* isVisible);\n\
+                     HRESULT Navigate([in] LPCWSTR uri, [in] BOOL reload);\n\
+                     HRESULT GetBar([out] IBar** bar, [out] LPWSTR* name);\n\
+                     HRESULT add_Done([in] IDoneHandler* handler, \
+                     [out] EventRegistrationToken* token);\n\
+                     HRESULT Set([in] UINT32 count, [in, size_is(count)] INT32* values);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface IBar : IUnknown {\n\
+                     HRESULT Baz([in] IFoo* foo);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface IFoo2 : IFoo {\n\
+                     HRESULT Close();\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface IDoneHandler : IUnknown {\n\
+                     HRESULT Invoke([in] HRESULT result);\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        assert!(rust.contains(
+            "#[derive(Clone)]\n\
+             pub struct Foo {\n    \
+             inner: ComRc<dyn IFoo>,\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn get_is_visible(&self) -> Result<bool> {\n        \
+             unsafe {\n            \
+             let mut is_visible = MaybeUninit::uninit();\n            \
+             check(self.inner.get_is_visible(is_visible.as_mut_ptr()))?;\n            \
+             Ok(is_visible.assume_init() != 0)\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn navigate(&self, uri: &str, reload: bool) -> Result<()> {\n        \
+             let uri = to_wide(uri)?;\n        \
+             unsafe {\n            \
+             check(self.inner.navigate(uri.as_ptr(), reload as BOOL))?;\n            \
+             Ok(())\n"
+        ));
+        assert!(rust.contains("    pub fn get_bar(&self) -> Result<(Bar, String)> {\n"));
+        assert!(rust.contains(
+            "            Ok((Bar::new(ComRc::from_raw(bar.assume_init())), \
+             take_string(name.assume_init())))\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn add_done(&self, handler: &ComRc<dyn IDoneHandler>) \
+             -> Result<EventRegistrationToken> {\n"
+        ));
+        assert!(rust.contains("    pub fn baz(&self, foo: &Foo) -> Result<()> {\n"));
+        assert!(rust.contains("check(self.inner.baz(foo.inner.as_raw()))?;"));
+        // Arrays are left to the bindings, handlers are implemented rather
+        // than called, and parent methods are included.
+        assert!(!rust.contains("pub fn set("));
+        assert!(!rust.contains("struct DoneHandler"));
+        let foo2 = &rust[rust.find("impl Foo2 {").unwrap()..];
+        assert!(foo2.contains("pub fn close(&self)"));
+        assert!(foo2.contains("pub fn navigate(&self"));
+    }
+
+    #[test]
+    fn test_event_subscriptions() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT add_Done([in] IDoneHandler* handler, \
+                     [out] EventRegistrationToken* token);\n\
+                     HRESULT remove_Done([in] EventRegistrationToken token);\n\
+                     HRESULT add_Orphan([in] IDoneHandler* handler, \
+                     [out] EventRegistrationToken* token);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface IDoneHandler : IUnknown {\n\
+                     HRESULT Invoke([in] IFoo* sender, [in] LPCWSTR message, \
+                     [in] BOOL isError);\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        assert!(rust.contains(
+            "    pub fn on_done(&self, handler: impl Fn(Foo, String, bool) -> Result<()> + 'static) \
+             -> Result<EventSubscription> {\n        \
+             let handler = DoneHandlerClosure::create(move |sender, message, is_error| \
+             to_hresult(unsafe { handler(Foo::new(add_ref(sender)), copy_string(message), \
+             is_error != 0) }));\n        \
+             let token = self.add_done(&handler)?;\n        \
+             let inner = self.inner.clone();\n        \
+             Ok(EventSubscription::new(move || unsafe {\n            \
+             inner.remove_done(token);\n"
+        ));
+        assert!(rust.contains(
+            "#[com::co_class(implements(IDoneHandler))]\n\
+             struct DoneHandlerClosure {\n    \
+             callback: Box<dyn Fn(*mut *mut IFooVTable, LPCWSTR, BOOL) -> HRESULT>,\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "    unsafe fn invoke(&self, _sender: *mut *mut IFooVTable, _message: LPCWSTR, \
+             _isError: BOOL) -> HRESULT {\n        \
+             (self.callback)(_sender, _message, _isError)\n"
+        ));
+        // Without `remove_Orphan` there is nothing for the guard to call.
+        assert!(rust.contains("pub fn add_orphan(&self"));
+        assert!(!rust.contains("pub fn on_orphan("));
+        assert_eq!(rust.matches("struct DoneHandlerClosure").count(), 1);
+    }
+
+    #[test]
+    fn test_async_wrappers() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT Run([in] LPCWSTR script, [in] IRunCompletedHandler* handler);\n\
+                     HRESULT Save([in] ISaveCompletedHandler* handler);\n\
+                     HRESULT Count([out] UINT32* count, [in] ISaveCompletedHandler* handler);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface IRunCompletedHandler : IUnknown {\n\
+                     HRESULT Invoke([in] HRESULT errorCode, [in] LPCWSTR result, \
+                     [in] IFoo* foo);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c97), object]\n\
+                     interface ISaveCompletedHandler : IUnknown {\n\
+                     HRESULT Invoke([in] HRESULT errorCode);\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        assert!(rust.contains(
+            "    pub fn run_async(&self, script: &str) -> Completion<(String, Foo)> {\n        \
+             let (completion, completer) = Completion::new();\n        \
+             let handler = RunCompletedHandlerClosure::create(move |error_code, result, foo| {\n            \
+             completer.complete(check(error_code).map(|()| unsafe { \
+             (copy_string(result), Foo::new(add_ref(foo))) }));\n            \
+             S_OK\n        \
+             });\n        \
+             match self.run(script, &handler) {\n            \
+             Ok(()) => completion,\n            \
+             Err(e) => Completion::ready(Err(e)),\n"
+        ));
+        assert!(rust.contains("    pub fn save_async(&self) -> Completion<()> {\n"));
+        assert!(rust.contains("            completer.complete(check(error_code));\n"));
+        // Outputs other than through the handler aren't supported.
+        assert!(!rust.contains("pub fn count_async("));
+        assert_eq!(
+            rust.matches("struct SaveCompletedHandlerClosure").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_builders() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IOptions : IUnknown {\n\
+                     HRESULT get_Language([out, retval] LPWSTR* value);\n\
+                     HRESULT put_Language([in] LPCWSTR value);\n\
+                     HRESULT get_IsEnabled([out, retval] BOOL* value);\n\
+                     HRESULT put_IsEnabled([in] BOOL value);\n\
+                     HRESULT get_Version([out, retval] UINT32* value);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface IOptions2 : IOptions {\n\
+                     HRESULT put_Count([in] UINT32 value);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT put_Name([in] LPCWSTR value);\n\
+                     HRESULT Close();\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface IFooEventArgs : IUnknown {\n\
+                     HRESULT put_Cancel([in] BOOL value);\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        assert!(rust.contains(
+            "#[derive(Clone, Default)]\n\
+             pub struct Options2Builder {\n    \
+             count: Option<UINT32>,\n    \
+             language: Option<String>,\n    \
+             is_enabled: Option<bool>,\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn with_language(self, value: &str) -> Self {\n        \
+             Self {\n            \
+             language: Some(value.to_owned()),\n            \
+             ..self\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn apply_to(&self, target: &Options) -> Result<()> {\n        \
+             if let Some(value) = &self.language {\n            \
+             target.put_language(value)?;\n        \
+             }\n        \
+             if let Some(value) = &self.is_enabled {\n            \
+             target.put_is_enabled(*value)?;\n        \
+             }\n        \
+             Ok(())\n"
+        ));
+        // Interfaces with other methods, and event args, aren't property
+        // bags.
+        assert!(!rust.contains("FooBuilder"));
+        assert!(!rust.contains("FooEventArgsBuilder"));
+    }
+
+    #[test]
+    fn test_deferral_guards() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFooEventArgs : IUnknown {\n\
+                     HRESULT GetDeferral([out, retval] IDeferral** deferral);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface IBarEventArgs : IUnknown {\n\
+                     HRESULT GetDeferral([out, retval] IDeferral** deferral);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface IDeferral : IUnknown {\n\
+                     HRESULT Complete();\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        let foo = &rust[rust.find("impl FooEventArgs {").unwrap()..];
+        assert!(foo.contains(
+            "    pub fn take_deferral(&self) -> Result<DeferralGuard> {\n        \
+             Ok(DeferralGuard {\n            \
+             deferral: Some(self.get_deferral()?),\n"
+        ));
+        assert!(rust.contains("impl BarEventArgs {"));
+        assert_eq!(rust.matches("pub fn take_deferral(").count(), 2);
+        assert_eq!(rust.matches("pub struct DeferralGuard {").count(), 1);
+        assert!(rust.contains(
+            "impl Drop for DeferralGuard {\n    \
+             fn drop(&mut self) {\n        \
+             if let Some(deferral) = self.deferral.take() {\n            \
+             let _ = deferral.complete();\n"
+        ));
+    }
+
+    #[test]
+    fn test_eager_args() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFooEventArgs : IUnknown {\n\
+                     HRESULT get_Uri([out, retval] LPWSTR* uri);\n\
+                     HRESULT get_IsUserInitiated([out, retval] BOOL* value);\n\
+                     HRESULT get_Id([out, retval] UINT64* id);\n\
+                     HRESULT get_Request([out, retval] IFoo** request);\n\
+                     HRESULT put_Cancel([in] BOOL cancel);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT get_Name([out, retval] LPWSTR* name);\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        assert!(!doc.render_wrappers().contains("Values"));
+        doc.set_eager_args();
+        let rust = doc.render_wrappers();
+        assert!(rust.contains(
+            "#[derive(Clone)]\n\
+             pub struct FooEventArgsValues {\n    \
+             pub uri: String,\n    \
+             pub is_user_initiated: bool,\n    \
+             pub id: UINT64,\n    \
+             /// For the setters and the deferral.\n    \
+             pub args: FooEventArgs,\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn read(args: FooEventArgs) -> Result<Self> {\n        \
+             Ok(Self {\n            \
+             uri: args.get_uri()?,\n            \
+             is_user_initiated: args.get_is_user_initiated()?,\n            \
+             id: args.get_id()?,\n            \
+             args,\n"
+        ));
+        assert!(!rust.contains("struct FooValues"));
+    }
+
+    #[test]
+    fn test_coclasses() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT Bar();\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94)]\n\
+                     coclass Foo {\n\
+                     [default, source] interface IFooEvents;\n\
+                     interface IFoo;\n\
+                     };\n";
+        let doc = parse_idl(input).unwrap();
+        assert_eq!(doc.coclasses[0].default_interface(), Some("IFoo"));
+        let rust = doc.render_to_string();
+        assert!(rust.contains("pub const CLSID_Foo: GUID = GUID { Data1: 0x5cc5293d"));
+        assert!(rust.contains("pub fn create_instance() -> Result<com::ComRc<dyn IFoo>, HRESULT>"));
+        assert!(rust.contains("                &IID_IFoo,\n"));
+        assert!(doc.render_json().contains("\"coclasses\": ["));
+
+        let errors = parse_idl("coclass Foo { interface IFoo; }\n").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .to_string()
+            .contains("coclass `Foo` has no `uuid`"));
+    }
+
+    #[test]
+    fn test_deprecated() {
+        let input = "/// Old.\n\
+                     /// Deprecated: Use `IFoo2` instead.\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     [deprecated(\"Use Baz.\", deprecate, 1)] HRESULT Bar();\n\
+                     /// Deprecated.\n\
+                     HRESULT get_Size([out, retval] UINT32* size);\n\
+                     /// Deprecatedness isn't a note.\n\
+                     HRESULT Qux();\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        doc.set_accessors();
+        let rust = doc.render_to_string();
+        assert!(rust.contains(
+            "#[deprecated(note = \"Use `IFoo2` instead.\")]\n\
+             #[com_interface(\"5cc5293d-af6f-41d4-9619-44bd31ba4c93\")]\n"
+        ));
+        assert!(rust.contains("    #[deprecated(note = \"Use Baz.\")]\n    unsafe fn bar("));
+        assert!(rust.contains("    #[deprecated]\n    unsafe fn get_size("));
+        assert!(rust.contains("    #[deprecated]\n    fn size(&self)"));
+        assert_eq!(rust.matches("#[deprecated").count(), 4);
+        let raw = doc.render_raw_to_string();
+        assert!(
+            raw.contains("#[deprecated(note = \"Use `IFoo2` instead.\")]\n#[repr(transparent)]")
+        );
+        let wrappers = doc.render_wrappers();
+        assert!(
+            wrappers.contains("#[deprecated(note = \"Use `IFoo2` instead.\")]\n#[derive(Clone)]")
+        );
+        assert!(wrappers.contains("    #[deprecated]\n    pub fn get_size(&self)"));
+    }
+
+    #[test]
+    fn test_wrapper_prefix() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface ICoreWebView2 : IUnknown {\n\
+                     HRESULT get_Settings([out, retval] ICoreWebView2Settings2** settings);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface ICoreWebView2Settings : IUnknown {\n\
+                     HRESULT get_IsZoomControlEnabled([out, retval] BOOL* enabled);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface ICoreWebView2Settings2 : ICoreWebView2Settings {\n\
+                     HRESULT get_UserAgent([out, retval] LPWSTR* userAgent);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface ICoreWebView2CookieManager : IUnknown {\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        doc.set_wrapper_prefix("ICoreWebView2").unwrap();
+        let rust = doc.render_wrappers();
+        assert!(rust.contains("pub struct CoreWebView2 {"));
+        assert!(rust.contains("pub struct CoreWebView2Settings {"));
+        assert!(rust.contains("pub struct Settings {"));
+        assert!(rust.contains("pub struct CookieManager {"));
+        assert!(rust.contains("pub fn get_settings(&self) -> Result<Settings> {"));
+
+        let input = format!(
+            "{}[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c97), object]\n\
+             interface ICookieManager : IUnknown {{\n\
+             }}\n",
+            input
+        );
+        let mut doc = parse_idl(&input).unwrap();
+        assert_eq!(
+            doc.set_wrapper_prefix("ICoreWebView2").unwrap_err(),
+            "`ICoreWebView2CookieManager` and `ICookieManager` would both be wrapped as \
+             `CookieManager`"
+        );
+        assert!(doc
+            .render_wrappers()
+            .contains("pub struct CoreWebView2CookieManager {"));
+    }
+
+    #[test]
+    fn test_families() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface ICoreWebView2 : IUnknown {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface ICoreWebView2_2 : ICoreWebView2 {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface ICoreWebView2Settings : IUnknown {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface ICoreWebView2Settings3 : ICoreWebView2Settings {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c97), object]\n\
+                     interface ICoreWebView2Settings2 : ICoreWebView2Settings {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c98), object]\n\
+                     interface ICoreWebView2ExperimentalSettings4 : ICoreWebView2Settings3 {\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        assert_eq!(
+            doc.families(),
+            [
+                (
+                    "ICoreWebView2",
+                    vec![(1, "ICoreWebView2"), (2, "ICoreWebView2_2")]
+                ),
+                (
+                    "ICoreWebView2Settings",
+                    vec![
+                        (1, "ICoreWebView2Settings"),
+                        (2, "ICoreWebView2Settings2"),
+                        (3, "ICoreWebView2Settings3")
+                    ]
+                ),
+            ]
+        );
+        doc.set_wrapper_prefix("ICoreWebView2").unwrap();
+        let rust = doc.render_wrappers();
+        assert!(rust.contains(
+            "pub enum CoreWebView2Any {\n    \
+             V1(CoreWebView2),\n    \
+             V2(CoreWebView2_2),\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "pub enum SettingsAny {\n    \
+             V1(CoreWebView2Settings),\n    \
+             V2(CoreWebView2Settings2),\n    \
+             V3(Settings),\n\
+             }\n"
+        ));
+        assert!(rust.contains(
+            "    pub fn latest_supported<T: ComInterface + ?Sized>(object: &ComRc<T>) -> \
+             Option<Self> {\n        \
+             if let Ok(inner) = query_interface::<T, dyn ICoreWebView2Settings3>(object) {\n            \
+             return Some(Self::V3(Settings::new(inner)));\n"
+        ));
+        assert!(rust.contains("            Self::V2(_) => 2,\n"));
+    }
+
+    #[test]
+    fn test_try_upgrade() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface IFoo : IUnknown {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface IFoo_2 : IFoo {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface IFoo_3 : IFoo_2 {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface IBar : IUnknown {\n\
+                     }\n";
+        let rust = parse_idl(input).unwrap().render_wrappers();
+        assert!(rust.contains(
+            "    pub fn try_upgrade<W: Wrapper + Extends<Self>>(&self) -> Result<W> {\n        \
+             query_interface(&self.inner).map(W::from_inner)\n"
+        ));
+        // On `Foo` and `Foo_2`.
+        assert_eq!(rust.matches("pub fn try_upgrade<").count(), 2);
+        assert!(rust.contains(
+            "impl Wrapper for Foo_3 {\n    \
+             type Interface = dyn IFoo_3;\n"
+        ));
+        assert!(!rust.contains("impl Wrapper for Foo {"));
+        assert!(rust.contains("impl Extends<Foo> for Foo_2 {}\n"));
+        assert!(rust.contains("impl Extends<Foo_2> for Foo_3 {}\n"));
+        assert!(rust.contains("impl Extends<Foo> for Foo_3 {}\n"));
+        assert!(!rust.contains("for Bar"));
+    }
+
+    #[test]
+    fn test_forward_declarations() {
+        let input = "import \"objidl.idl\";\n\
+                     interface IFoo;\n\
+                     interface IMissing;\n\
+                     interface IFoo : IUnknown {\n\
+                     HRESULT Bar([in] IStream* a, [out] IBaz** b, [out] IMissing** c);\n\
+                     }\n\
+                     interface IBar;\n\
+                     interface IBar : IFoo {\n\
+                     HRESULT Baz([in] IBaz* b);\n\
+                     }\n";
+        let errors = parse_idl(input).err().unwrap();
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages[0].contains("3:11"), "{}", messages[0]);
+        assert!(messages[0].contains("`IMissing` is declared but neither defined nor imported"));
+        assert!(messages[1].contains("5:36"), "{}", messages[1]);
+        assert!(messages[1]
+            .contains("`IBaz`, used by `Bar` of `IFoo`, is neither defined nor imported"));
+
+        // `IUnknown` comes from `objidl.idl`.
+        let errors = parse_idl(&input.replace("import \"objidl.idl\";\n", ""))
+            .err()
+            .unwrap();
+        assert!(errors[1]
+            .to_string()
+            .contains("`IUnknown`, the parent of `IFoo`, is neither defined nor imported"));
+
+        // Not checked without forward declarations.
+        parse_idl("interface IFoo : IUnknown {\nHRESULT Bar([out] IBaz** b);\n}\n").unwrap();
+    }
+
+    #[test]
+    fn test_sdk_versions() {
+        let input = "[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object]\n\
+                     interface ICoreWebView2 : IUnknown {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object]\n\
+                     interface ICoreWebView2_2 : ICoreWebView2 {\n\
+                     HRESULT get_CookieManager([out, retval] ICoreWebView2CookieManager** cookieManager);\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object]\n\
+                     interface ICoreWebView2CookieManager : IUnknown {\n\
+                     }\n\
+                     [uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c96), object]\n\
+                     interface ICoreWebView2_3 : ICoreWebView2_2 {\n\
+                     }\n";
+        let mut doc = parse_idl(input).unwrap();
+        doc.set_sdk_versions(
+            "# Introduced in\n\
+             ICoreWebView2_3 = 1.0.1185\n\
+             ICoreWebView2_2 = 1.0.774\n\
+             ICoreWebView2CookieManager = 1.0.774\n",
+        )
+        .unwrap();
+        assert_eq!(doc.sdk_versions(), ["1.0.774", "1.0.1185"]);
+
+        let rust = doc.render_to_string();
+        assert!(rust.contains(
+            "pub const INTERFACES: &[InterfaceInfo] = &[\n    \
+             InterfaceInfo { name: \"ICoreWebView2\", parent: \"IUnknown\", \
+             iid: IID_ICoreWebView2, methods: 0 },\n\
+             ];\n"
+        ));
+        assert!(rust.contains(
+            "    let interfaces = INTERFACES.iter();\n    \
+             #[cfg(feature = \"sdk-1.0.774\")]\n    \
+             let interfaces = interfaces.chain(SDK_1_0_774_INTERFACES.iter());\n    \
+             #[cfg(feature = \"sdk-1.0.1185\")]\n    \
+             let interfaces = interfaces.chain(SDK_1_0_1185_INTERFACES.iter());\n    \
+             interfaces\n"
+        ));
+        assert!(
+            rust.contains("#[cfg(feature = \"sdk-1.0.774\")]\nmod sdk_1_0_774 {\nuse super::*;\n")
+        );
+        assert!(
+            rust.contains("#[cfg(feature = \"sdk-1.0.1185\")]\npub use self::sdk_1_0_1185::*;\n")
+        );
+
+        let rust = doc.render_wrappers();
+        assert!(rust.contains(
+            "#[cfg(feature = \"sdk-1.0.774\")]\n#[derive(Clone)]\npub struct CoreWebView2_2 {"
+        ));
+        assert!(rust.contains(
+            "#[cfg(feature = \"sdk-1.0.1185\")]\n\
+             #[cfg(feature = \"sdk-1.0.774\")]\n\
+             impl Extends<CoreWebView2_2> for CoreWebView2_3 {}"
+        ));
+
+        let mut doc = parse_idl(input).unwrap();
+        assert_eq!(
+            doc.set_sdk_versions("ICoreWebView2_4 = 1.0.1245\n"),
+            Err("line 1: unknown interface `ICoreWebView2_4`".to_string())
+        );
+        assert_eq!(
+            doc.set_sdk_versions("ICoreWebView2_2 = latest\n"),
+            Err("line 1: expected `Interface = version`".to_string())
+        );
+    }
+}
@@ -0,0 +1 @@
+use com::{com_interface, interfaces::{IUnknown, iunknown::IUnknownVTable}};
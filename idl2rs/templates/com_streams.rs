@@ -0,0 +1,49 @@
+#[com_interface("0c733a30-2a1c-11ce-ade5-00aa0044773d")]
+pub trait ISequentialStream: IUnknown {
+    unsafe fn read(
+        &self,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT;
+    unsafe fn write(
+        &self,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT;
+}
+
+#[com_interface("0000000c-0000-0000-C000-000000000046")]
+pub trait IStream: ISequentialStream {
+    unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT;
+    unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT;
+    unsafe fn revert(&self) -> HRESULT;
+    unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT;
+    unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT;
+}
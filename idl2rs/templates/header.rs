@@ -0,0 +1,12 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
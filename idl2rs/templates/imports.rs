@@ -0,0 +1,28 @@
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
@@ -0,0 +1 @@
+use std::ops::Deref;
@@ -0,0 +1,167 @@
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IUnknown(pub *mut *mut IUnknownVTable);
+
+#[repr(C)]
+pub struct IUnknownVTable {
+    pub QueryInterface: unsafe extern "system" fn(
+        This: *mut *mut IUnknownVTable,
+        riid: *const GUID,
+        ppvObject: *mut *mut c_void
+    ) -> HRESULT,
+    pub AddRef: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+    pub Release: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+}
+
+impl IUnknown {
+    pub unsafe fn query_interface(&self, riid: *const GUID, ppvObject: *mut *mut c_void) -> HRESULT {
+        ((**self.0).QueryInterface)(self.0, riid, ppvObject)
+    }
+    pub unsafe fn add_ref(&self) -> ULONG {
+        ((**self.0).AddRef)(self.0)
+    }
+    pub unsafe fn release(&self) -> ULONG {
+        ((**self.0).Release)(self.0)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ISequentialStream(pub *mut *mut ISequentialStreamVTable);
+
+#[repr(C)]
+pub struct ISequentialStreamVTable {
+    pub base: IUnknownVTable,
+    pub Read: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT,
+    pub Write: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT,
+}
+
+impl ISequentialStream {
+    pub unsafe fn read(&self, pv: *mut c_void, cb: ULONG, pcbRead: *mut ULONG) -> HRESULT {
+        ((**self.0).Read)(self.0, pv, cb, pcbRead)
+    }
+    pub unsafe fn write(&self, pv: *const c_void, cb: ULONG, pcbWritten: *mut ULONG) -> HRESULT {
+        ((**self.0).Write)(self.0, pv, cb, pcbWritten)
+    }
+}
+
+impl Deref for ISequentialStream {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IStream(pub *mut *mut IStreamVTable);
+
+#[repr(C)]
+pub struct IStreamVTable {
+    pub base: ISequentialStreamVTable,
+    pub Seek: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub SetSize: unsafe extern "system" fn(This: *mut *mut IStreamVTable, libNewSize: ULARGE_INTEGER) -> HRESULT,
+    pub CopyTo: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub Commit: unsafe extern "system" fn(This: *mut *mut IStreamVTable, grfCommitFlags: DWORD) -> HRESULT,
+    pub Revert: unsafe extern "system" fn(This: *mut *mut IStreamVTable) -> HRESULT,
+    pub LockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub UnlockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub Stat: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstatstg: *mut STATSTG,
+        grfStatFlag: DWORD
+    ) -> HRESULT,
+    pub Clone: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        ppstm: *mut *mut *mut IStreamVTable
+    ) -> HRESULT,
+}
+
+impl IStream {
+    pub unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).Seek)(self.0, dlibMove, dwOrigin, plibNewPosition)
+    }
+    pub unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT {
+        ((**self.0).SetSize)(self.0, libNewSize)
+    }
+    pub unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).CopyTo)(self.0, pstm, cb, pcbRead, pcbWritten)
+    }
+    pub unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT {
+        ((**self.0).Commit)(self.0, grfCommitFlags)
+    }
+    pub unsafe fn revert(&self) -> HRESULT {
+        ((**self.0).Revert)(self.0)
+    }
+    pub unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).LockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).UnlockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT {
+        ((**self.0).Stat)(self.0, pstatstg, grfStatFlag)
+    }
+    pub unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT {
+        ((**self.0).Clone)(self.0, ppstm)
+    }
+}
+
+impl Deref for IStream {
+    type Target = ISequentialStream;
+    fn deref(&self) -> &ISequentialStream {
+        unsafe { &*(self as *const Self as *const ISequentialStream) }
+    }
+}
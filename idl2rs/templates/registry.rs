@@ -0,0 +1,34 @@
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
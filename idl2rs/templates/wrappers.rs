@@ -0,0 +1,207 @@
+use com::{interfaces::IUnknown, ComInterface, ComPtr, ComRc};
+use std::cell::RefCell;
+use std::future::Future;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use winapi::shared::winerror::{E_ABORT, E_INVALIDARG, SUCCEEDED, S_OK};
+use winapi::um::combaseapi::CoTaskMemFree;
+
+/// A failed `HRESULT`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Error {
+    pub hresult: HRESULT,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "HRESULT 0x{:08x}", self.hresult as u32)
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+fn check(hresult: HRESULT) -> Result<()> {
+    if SUCCEEDED(hresult) {
+        Ok(())
+    } else {
+        Err(Error { hresult })
+    }
+}
+
+/// `QueryInterface`, taking over the reference it adds.
+fn query_interface<T, I>(object: &ComRc<T>) -> Result<ComRc<I>>
+where
+    T: ComInterface + ?Sized,
+    I: ComInterface + ?Sized,
+{
+    let mut ppv = std::ptr::null_mut();
+    check(unsafe { object.query_interface(&I::IID, &mut ppv) })?;
+    Ok(unsafe { ComRc::from_raw(ppv as *mut *mut I::VTable) })
+}
+
+/// NUL terminated UTF-16. Strings with NULs would be cut short, so they are
+/// rejected with `E_INVALIDARG`.
+fn to_wide(s: &str) -> Result<Vec<u16>> {
+    if s.contains('\0') {
+        return Err(Error {
+            hresult: E_INVALIDARG,
+        });
+    }
+    Ok(s.encode_utf16().chain(Some(0)).collect())
+}
+
+/// Convert a string passed to a handler.
+unsafe fn copy_string(s: *const u16) -> String {
+    if s.is_null() {
+        return String::new();
+    }
+    let len = (0..).take_while(|&i| *s.offset(i) != 0).count();
+    String::from_utf16_lossy(std::slice::from_raw_parts(s, len))
+}
+
+/// Convert and free a string returned through an out parameter.
+unsafe fn take_string(s: *mut u16) -> String {
+    let result = copy_string(s);
+    CoTaskMemFree(s as _);
+    result
+}
+
+/// Take a reference to an interface passed to a handler, which only borrows
+/// it.
+unsafe fn add_ref<T: ComInterface + ?Sized>(ptr: *mut *mut T::VTable) -> ComRc<T> {
+    let ptr = ComPtr::new(ptr);
+    ptr.add_ref();
+    ptr.upgrade()
+}
+
+fn to_hresult(result: Result<()>) -> HRESULT {
+    match result {
+        Ok(()) => S_OK,
+        Err(e) => e.hresult,
+    }
+}
+
+/// A wrapper of a later version of an interface, for `try_upgrade`.
+pub trait Wrapper {
+    type Interface: ComInterface + ?Sized;
+
+    fn from_inner(inner: ComRc<Self::Interface>) -> Self;
+}
+
+/// Implemented by the wrappers of interfaces that extend the interface of
+/// `T`, directly or not, so that `try_upgrade` only queries later versions.
+pub trait Extends<T> {}
+
+/// An event handler added by an `on_` method, removed when this is dropped.
+#[must_use = "the handler is removed when the subscription is dropped"]
+pub struct EventSubscription {
+    remove: Option<Box<dyn FnOnce()>>,
+}
+
+impl EventSubscription {
+    fn new(remove: impl FnOnce() + 'static) -> Self {
+        Self {
+            remove: Some(Box::new(remove)),
+        }
+    }
+
+    /// Keep the handler for as long as the object raising the event lives.
+    pub fn forget(mut self) {
+        self.remove = None;
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        if let Some(remove) = self.remove.take() {
+            remove();
+        }
+    }
+}
+
+struct CompletionState<T> {
+    completed: bool,
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// The result of an `_async` method, available once WebView2 calls the
+/// completed handler. Handlers are called on the thread of the webview, so
+/// it has to be polled there too, e.g. by an executor driven by the message
+/// loop.
+///
+/// If the handler is released without being called, e.g. because the
+/// webview was closed, it fails with `E_ABORT`.
+pub struct Completion<T> {
+    state: Rc<RefCell<CompletionState<T>>>,
+}
+
+impl<T> Completion<T> {
+    fn new() -> (Self, Completer<T>) {
+        let state = Rc::new(RefCell::new(CompletionState {
+            completed: false,
+            result: None,
+            waker: None,
+        }));
+        (
+            Self {
+                state: state.clone(),
+            },
+            Completer { state },
+        )
+    }
+
+    fn ready(result: Result<T>) -> Self {
+        let (completion, completer) = Self::new();
+        completer.complete(result);
+        completion
+    }
+}
+
+impl<T> Future for Completion<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T>> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Kept by the handler of a `Completion`.
+struct Completer<T> {
+    state: Rc<RefCell<CompletionState<T>>>,
+}
+
+impl<T> Completer<T> {
+    /// Only the first result is kept.
+    fn complete(&self, result: Result<T>) {
+        let waker = {
+            let mut state = self.state.borrow_mut();
+            if state.completed {
+                return;
+            }
+            state.completed = true;
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Completer<T> {
+    fn drop(&mut self) {
+        self.complete(Err(Error { hresult: E_ABORT }));
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_reports_every_malformed_item() {
+    let input = r#"import "objidl.idl";
+
+interface IFirst;
+
+[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c93), object, pointer_default(unique)]
+interface IFirst : IUnknown {
+  HRESULT Missing([in] int a)
+  HRESULT Fine([in] int a);
+}
+
+/// This one is fine.
+[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c94), object, pointer_default(unique)]
+interface ISecond : IUnknown {
+  HRESULT Fine([in] int a);
+}
+
+[uuid(5cc5293d-af6f-41d4-9619-44bd31ba4c95), object, pointer_default(unique)]
+interface IThird : IUnknown {
+  HRESULT Broken([in] int a,, [in] int b);
+}
+"#;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_idl2rs"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(stderr.contains("<stdin>:7:"), "{}", stderr);
+    assert!(stderr.contains("<stdin>:19:"), "{}", stderr);
+    assert!(stderr.contains("2 parse error(s)"), "{}", stderr);
+}
+
+#[test]
+fn test_rejects_conflicting_flags() {
+    for args in &[
+        &["--emit", "json", "--mocks"][..],
+        &["--emit", "json", "--raw"],
+        &["--raw", "--wrappers"],
+        &["--eager-args"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_idl2rs"))
+            .args(*args)
+            .stdin(Stdio::null())
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr.starts_with("usage: "), "{}", stderr);
+    }
+}
+
+#[test]
+fn test_reports_unreadable_files() {
+    let output = Command::new(env!("CARGO_BIN_EXE_idl2rs"))
+        .args(["--types", "does-not-exist.txt"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.starts_with("does-not-exist.txt: "), "{}", stderr);
+}
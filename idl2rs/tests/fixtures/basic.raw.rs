@@ -0,0 +1,401 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use std::ops::Deref;
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IUnknown(pub *mut *mut IUnknownVTable);
+
+#[repr(C)]
+pub struct IUnknownVTable {
+    pub QueryInterface: unsafe extern "system" fn(
+        This: *mut *mut IUnknownVTable,
+        riid: *const GUID,
+        ppvObject: *mut *mut c_void
+    ) -> HRESULT,
+    pub AddRef: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+    pub Release: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+}
+
+impl IUnknown {
+    pub unsafe fn query_interface(&self, riid: *const GUID, ppvObject: *mut *mut c_void) -> HRESULT {
+        ((**self.0).QueryInterface)(self.0, riid, ppvObject)
+    }
+    pub unsafe fn add_ref(&self) -> ULONG {
+        ((**self.0).AddRef)(self.0)
+    }
+    pub unsafe fn release(&self) -> ULONG {
+        ((**self.0).Release)(self.0)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ISequentialStream(pub *mut *mut ISequentialStreamVTable);
+
+#[repr(C)]
+pub struct ISequentialStreamVTable {
+    pub base: IUnknownVTable,
+    pub Read: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT,
+    pub Write: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT,
+}
+
+impl ISequentialStream {
+    pub unsafe fn read(&self, pv: *mut c_void, cb: ULONG, pcbRead: *mut ULONG) -> HRESULT {
+        ((**self.0).Read)(self.0, pv, cb, pcbRead)
+    }
+    pub unsafe fn write(&self, pv: *const c_void, cb: ULONG, pcbWritten: *mut ULONG) -> HRESULT {
+        ((**self.0).Write)(self.0, pv, cb, pcbWritten)
+    }
+}
+
+impl Deref for ISequentialStream {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IStream(pub *mut *mut IStreamVTable);
+
+#[repr(C)]
+pub struct IStreamVTable {
+    pub base: ISequentialStreamVTable,
+    pub Seek: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub SetSize: unsafe extern "system" fn(This: *mut *mut IStreamVTable, libNewSize: ULARGE_INTEGER) -> HRESULT,
+    pub CopyTo: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub Commit: unsafe extern "system" fn(This: *mut *mut IStreamVTable, grfCommitFlags: DWORD) -> HRESULT,
+    pub Revert: unsafe extern "system" fn(This: *mut *mut IStreamVTable) -> HRESULT,
+    pub LockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub UnlockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub Stat: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstatstg: *mut STATSTG,
+        grfStatFlag: DWORD
+    ) -> HRESULT,
+    pub Clone: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        ppstm: *mut *mut *mut IStreamVTable
+    ) -> HRESULT,
+}
+
+impl IStream {
+    pub unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).Seek)(self.0, dlibMove, dwOrigin, plibNewPosition)
+    }
+    pub unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT {
+        ((**self.0).SetSize)(self.0, libNewSize)
+    }
+    pub unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).CopyTo)(self.0, pstm, cb, pcbRead, pcbWritten)
+    }
+    pub unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT {
+        ((**self.0).Commit)(self.0, grfCommitFlags)
+    }
+    pub unsafe fn revert(&self) -> HRESULT {
+        ((**self.0).Revert)(self.0)
+    }
+    pub unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).LockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).UnlockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT {
+        ((**self.0).Stat)(self.0, pstatstg, grfStatFlag)
+    }
+    pub unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT {
+        ((**self.0).Clone)(self.0, ppstm)
+    }
+}
+
+impl Deref for IStream {
+    type Target = ISequentialStream;
+    fn deref(&self) -> &ISequentialStream {
+        unsafe { &*(self as *const Self as *const ISequentialStream) }
+    }
+}
+
+/// Create an example.
+pub type FnCreateExample = unsafe extern "system" fn(/* in */ size: UINT32, /* out */ example: *mut *mut *mut IExampleVTable) -> HRESULT;
+
+/// Release everything.
+pub type FnShutdownExamples = unsafe extern "system" fn(force: BOOL) -> HRESULT;
+/// Default size of an example.
+pub const EXAMPLE_DEFAULT_SIZE: UINT32 = 16;
+
+pub const EXAMPLE_E_FAILED: u32 = 0x80070057;
+
+/// An example interface.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IExample(pub *mut *mut IExampleVTable);
+
+#[repr(C)]
+pub struct IExampleVTable {
+    pub base: IUnknownVTable,
+    pub get_Name: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* out, retval */ name: *mut LPWSTR) -> HRESULT,
+    pub put_Name: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* in */ name: LPCWSTR) -> HRESULT,
+    pub DoSomething: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* in */ input: *const VARIANT, /* in */ handler: *mut *mut IExampleCompletedHandlerVTable) -> HRESULT,
+    pub get_Scale: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* out, retval */ scale: *mut f64) -> HRESULT,
+    pub SetValues: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* in */ count: UINT32, /* in, size_is(count) */ values: *const INT32) -> HRESULT,
+    pub GetValues: unsafe extern "system" fn(This: *mut *mut IExampleVTable, /* out */ count: *mut UINT32, /* out, size_is(, *count) */ values: *mut *mut INT32) -> HRESULT,
+}
+
+impl IExample {
+/// The name.
+      #[doc(alias = "get_Name")]
+    pub unsafe fn get_name(&self, /* out, retval */ name: *mut LPWSTR) -> HRESULT {
+        ((**self.0).get_Name)(self.0, name)
+    }
+
+/// Set the name.
+      #[doc(alias = "put_Name")]
+    pub unsafe fn put_name(&self, /* in */ name: LPCWSTR) -> HRESULT {
+        ((**self.0).put_Name)(self.0, name)
+    }
+
+/// Do something asynchronously.
+      #[doc(alias = "DoSomething")]
+    pub unsafe fn do_something(&self, /* in */ input: *const VARIANT, /* in */ handler: *mut *mut IExampleCompletedHandlerVTable) -> HRESULT {
+        ((**self.0).DoSomething)(self.0, input, handler)
+    }
+
+/// The scale.
+      #[doc(alias = "get_Scale")]
+    pub unsafe fn get_scale(&self, /* out, retval */ scale: *mut f64) -> HRESULT {
+        ((**self.0).get_Scale)(self.0, scale)
+    }
+
+/// Replace the values.
+      ///
+    /// `values` is an array with `count` elements.
+    #[doc(alias = "SetValues")]
+    pub unsafe fn set_values(&self, /* in */ count: UINT32, /* in, size_is(count) */ values: *const INT32) -> HRESULT {
+        ((**self.0).SetValues)(self.0, count, values)
+    }
+
+/// The values, free with `CoTaskMemFree`.
+      ///
+    /// `values` is an array with `count` elements.
+    #[doc(alias = "GetValues")]
+    pub unsafe fn get_values(&self, /* out */ count: *mut UINT32, /* out, size_is(, *count) */ values: *mut *mut INT32) -> HRESULT {
+        ((**self.0).GetValues)(self.0, count, values)
+    }
+}
+
+impl Deref for IExample {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+/// IID of `IExample`.
+pub const IID_IExample: GUID = GUID { Data1: 0x5cc5293d, Data2: 0xaf6f, Data3: 0x41d4, Data4: [0x96, 0x19, 0x44, 0xbd, 0x31, 0xba, 0x4c, 0x93] };
+
+/// Kind of example.
+  #[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EXAMPLE_KIND {
+/// The first kind.
+        EXAMPLE_KIND_FIRST,
+/// The second kind.
+        EXAMPLE_KIND_SECOND,
+}
+
+impl EXAMPLE_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for EXAMPLE_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::EXAMPLE_KIND_FIRST),
+            1 => Ok(Self::EXAMPLE_KIND_SECOND),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A point.
+  #[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EXAMPLE_POINT {
+/// X coordinate.
+        pub x: i32,
+/// Y coordinate.
+        pub y: i32,
+}
+
+const _: () = assert!(std::mem::size_of::<EXAMPLE_POINT>() == 8);
+
+/// Receives the result of `DoSomething`.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IExampleCompletedHandler(pub *mut *mut IExampleCompletedHandlerVTable);
+
+#[repr(C)]
+pub struct IExampleCompletedHandlerVTable {
+    pub base: IUnknownVTable,
+    pub Invoke: unsafe extern "system" fn(This: *mut *mut IExampleCompletedHandlerVTable, /* in */ errorCode: HRESULT, /* in */ kind: EXAMPLE_KIND) -> HRESULT,
+}
+
+impl IExampleCompletedHandler {
+/// Called when done.
+      #[doc(alias = "Invoke")]
+    pub unsafe fn invoke(&self, /* in */ errorCode: HRESULT, /* in */ kind: EXAMPLE_KIND) -> HRESULT {
+        ((**self.0).Invoke)(self.0, errorCode, kind)
+    }
+}
+
+impl Deref for IExampleCompletedHandler {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+/// IID of `IExampleCompletedHandler`.
+pub const IID_IExampleCompletedHandler: GUID = GUID { Data1: 0xB7627F5F, Data2: 0x8723, Data3: 0x4ED3, Data4: [0xAC, 0x20, 0xF9, 0x31, 0x04, 0xCD, 0xEA, 0x51] };
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "IExample", parent: "IUnknown", iid: IID_IExample, methods: 6 },
+    InterfaceInfo { name: "IExampleCompletedHandler", parent: "IUnknown", iid: IID_IExampleCompletedHandler, methods: 1 },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    INTERFACES.iter()
+}
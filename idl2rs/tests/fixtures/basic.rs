@@ -0,0 +1,232 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use com::{com_interface, interfaces::{IUnknown, iunknown::IUnknownVTable}};
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[com_interface("0c733a30-2a1c-11ce-ade5-00aa0044773d")]
+pub trait ISequentialStream: IUnknown {
+    unsafe fn read(
+        &self,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT;
+    unsafe fn write(
+        &self,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT;
+}
+
+#[com_interface("0000000c-0000-0000-C000-000000000046")]
+pub trait IStream: ISequentialStream {
+    unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT;
+    unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT;
+    unsafe fn revert(&self) -> HRESULT;
+    unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT;
+    unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT;
+}
+
+/// Create an example.
+pub type FnCreateExample = unsafe extern "system" fn(/* in */ size: UINT32, /* out */ example: *mut *mut *mut IExampleVTable) -> HRESULT;
+
+/// Release everything.
+pub type FnShutdownExamples = unsafe extern "system" fn(force: BOOL) -> HRESULT;
+/// Default size of an example.
+pub const EXAMPLE_DEFAULT_SIZE: UINT32 = 16;
+
+pub const EXAMPLE_E_FAILED: u32 = 0x80070057;
+
+/// An example interface.
+#[com_interface("5cc5293d-af6f-41d4-9619-44bd31ba4c93")]
+pub trait IExample: IUnknown {
+/// The name.
+      #[doc(alias = "get_Name")]
+    unsafe fn get_name(&self, /* out, retval */ name: *mut LPWSTR) -> HRESULT;
+
+/// Set the name.
+      #[doc(alias = "put_Name")]
+    unsafe fn put_name(&self, /* in */ name: LPCWSTR) -> HRESULT;
+
+/// Do something asynchronously.
+      #[doc(alias = "DoSomething")]
+    unsafe fn do_something(&self, /* in */ input: *const VARIANT, /* in */ handler: *mut *mut IExampleCompletedHandlerVTable) -> HRESULT;
+
+/// The scale.
+      #[doc(alias = "get_Scale")]
+    unsafe fn get_scale(&self, /* out, retval */ scale: *mut f64) -> HRESULT;
+
+/// Replace the values.
+      ///
+    /// `values` is an array with `count` elements.
+    #[doc(alias = "SetValues")]
+    unsafe fn set_values(&self, /* in */ count: UINT32, /* in, size_is(count) */ values: *const INT32) -> HRESULT;
+
+/// The values, free with `CoTaskMemFree`.
+      ///
+    /// `values` is an array with `count` elements.
+    #[doc(alias = "GetValues")]
+    unsafe fn get_values(&self, /* out */ count: *mut UINT32, /* out, size_is(, *count) */ values: *mut *mut INT32) -> HRESULT;
+}
+
+/// IID of `IExample`.
+pub const IID_IExample: GUID = GUID { Data1: 0x5cc5293d, Data2: 0xaf6f, Data3: 0x41d4, Data4: [0x96, 0x19, 0x44, 0xbd, 0x31, 0xba, 0x4c, 0x93] };
+
+/// Kind of example.
+  #[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EXAMPLE_KIND {
+/// The first kind.
+        EXAMPLE_KIND_FIRST,
+/// The second kind.
+        EXAMPLE_KIND_SECOND,
+}
+
+impl EXAMPLE_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for EXAMPLE_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::EXAMPLE_KIND_FIRST),
+            1 => Ok(Self::EXAMPLE_KIND_SECOND),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A point.
+  #[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EXAMPLE_POINT {
+/// X coordinate.
+        pub x: i32,
+/// Y coordinate.
+        pub y: i32,
+}
+
+const _: () = assert!(std::mem::size_of::<EXAMPLE_POINT>() == 8);
+
+/// Receives the result of `DoSomething`.
+#[com_interface("B7627F5F-8723-4ED3-AC20-F93104CDEA51")]
+pub trait IExampleCompletedHandler: IUnknown {
+/// Called when done.
+      #[doc(alias = "Invoke")]
+    unsafe fn invoke(&self, /* in */ errorCode: HRESULT, /* in */ kind: EXAMPLE_KIND) -> HRESULT;
+}
+
+/// IID of `IExampleCompletedHandler`.
+pub const IID_IExampleCompletedHandler: GUID = GUID { Data1: 0xB7627F5F, Data2: 0x8723, Data3: 0x4ED3, Data4: [0xAC, 0x20, 0xF9, 0x31, 0x04, 0xCD, 0xEA, 0x51] };
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "IExample", parent: "IUnknown", iid: IID_IExample, methods: 6 },
+    InterfaceInfo { name: "IExampleCompletedHandler", parent: "IUnknown", iid: IID_IExampleCompletedHandler, methods: 1 },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    INTERFACES.iter()
+}
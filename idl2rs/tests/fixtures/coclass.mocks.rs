@@ -0,0 +1,252 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use com::{com_interface, interfaces::{IUnknown, iunknown::IUnknownVTable}};
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[com_interface("0c733a30-2a1c-11ce-ade5-00aa0044773d")]
+pub trait ISequentialStream: IUnknown {
+    unsafe fn read(
+        &self,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT;
+    unsafe fn write(
+        &self,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT;
+}
+
+#[com_interface("0000000c-0000-0000-C000-000000000046")]
+pub trait IStream: ISequentialStream {
+    unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT;
+    unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT;
+    unsafe fn revert(&self) -> HRESULT;
+    unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT;
+    unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT;
+}
+/// Counts things.
+#[com_interface("3F1D6A52-9C4B-4C57-8E3A-2B7D4F0A6C11")]
+pub trait ICounter: IUnknown {
+/// The current count.
+      #[doc(alias = "get_Count")]
+    unsafe fn get_count(&self, /* out, retval */ count: *mut UINT32) -> HRESULT;
+
+/// Add one.
+      #[doc(alias = "Increment")]
+    unsafe fn increment(&self) -> HRESULT;
+}
+
+/// IID of `ICounter`.
+pub const IID_ICounter: GUID = GUID { Data1: 0x3F1D6A52, Data2: 0x9C4B, Data3: 0x4C57, Data4: [0x8E, 0x3A, 0x2B, 0x7D, 0x4F, 0x0A, 0x6C, 0x11] };
+
+/// Notified by a counter.
+#[com_interface("3F1D6A52-9C4B-4C57-8E3A-2B7D4F0A6C12")]
+pub trait ICounterEvents: IUnknown {
+    #[doc(alias = "Changed")]
+    unsafe fn changed(&self, /* in */ count: UINT32) -> HRESULT;
+}
+
+/// IID of `ICounterEvents`.
+pub const IID_ICounterEvents: GUID = GUID { Data1: 0x3F1D6A52, Data2: 0x9C4B, Data3: 0x4C57, Data4: [0x8E, 0x3A, 0x2B, 0x7D, 0x4F, 0x0A, 0x6C, 0x12] };
+
+/// CLSID of `Counter`.
+pub const CLSID_Counter: GUID = GUID { Data1: 0x3F1D6A52, Data2: 0x9C4B, Data3: 0x4C57, Data4: [0x8E, 0x3A, 0x2B, 0x7D, 0x4F, 0x0A, 0x6C, 0x21] };
+
+/// A counter in the process.
+pub struct Counter;
+
+impl Counter {
+    /// Create an instance as `ICounter` with `CoCreateInstance`. COM has to be
+    /// initialized on the calling thread.
+    pub fn create_instance() -> Result<com::ComRc<dyn ICounter>, HRESULT> {
+        let mut ppv = std::ptr::null_mut();
+        let hr = unsafe {
+            winapi::um::combaseapi::CoCreateInstance(
+                &CLSID_Counter,
+                std::ptr::null_mut(),
+                winapi::shared::wtypesbase::CLSCTX_ALL,
+                &IID_ICounter,
+                &mut ppv,
+            )
+        };
+        if hr < 0 {
+            return Err(hr);
+        }
+        Ok(unsafe { com::ComRc::from_raw(ppv as *mut *mut ICounterVTable) })
+    }
+}
+
+/// CLSID of `ScriptCounter`.
+pub const CLSID_ScriptCounter: GUID = GUID { Data1: 0x3F1D6A52, Data2: 0x9C4B, Data3: 0x4C57, Data4: [0x8E, 0x3A, 0x2B, 0x7D, 0x4F, 0x0A, 0x6C, 0x22] };
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "ICounter", parent: "IUnknown", iid: IID_ICounter, methods: 2 },
+    InterfaceInfo { name: "ICounterEvents", parent: "IUnknown", iid: IID_ICounterEvents, methods: 1 },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    INTERFACES.iter()
+}
+
+/// Recording implementations of the interfaces, for tests.
+pub mod mocks {
+use super::*;
+use com::co_class;
+use std::cell::RefCell;
+use winapi::shared::winerror::S_OK;
+
+/// Mock `ICounter`.
+#[co_class(implements(ICounter))]
+pub struct MockCounter {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl MockCounter {
+    pub fn new() -> Box<Self> {
+        Self::allocate(RefCell::new(Vec::new()))
+    }
+
+    /// Names of the methods called so far, in order.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.borrow().clone()
+    }
+}
+
+impl ICounter for MockCounter {
+    unsafe fn get_count(&self, count: *mut UINT32) -> HRESULT {
+        self.calls.borrow_mut().push("get_Count");
+        if !count.is_null() {
+            *count = std::mem::zeroed();
+        }
+        S_OK
+    }
+    unsafe fn increment(&self) -> HRESULT {
+        self.calls.borrow_mut().push("Increment");
+        S_OK
+    }
+}
+
+/// Mock `ICounterEvents`.
+#[co_class(implements(ICounterEvents))]
+pub struct MockCounterEvents {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl MockCounterEvents {
+    pub fn new() -> Box<Self> {
+        Self::allocate(RefCell::new(Vec::new()))
+    }
+
+    /// Names of the methods called so far, in order.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.borrow().clone()
+    }
+}
+
+impl ICounterEvents for MockCounterEvents {
+    unsafe fn changed(&self, _count: UINT32) -> HRESULT {
+        self.calls.borrow_mut().push("Changed");
+        S_OK
+    }
+}
+}
@@ -0,0 +1,243 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use com::{com_interface, interfaces::{IUnknown, iunknown::IUnknownVTable}};
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[com_interface("0c733a30-2a1c-11ce-ade5-00aa0044773d")]
+pub trait ISequentialStream: IUnknown {
+    unsafe fn read(
+        &self,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT;
+    unsafe fn write(
+        &self,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT;
+}
+
+#[com_interface("0000000c-0000-0000-C000-000000000046")]
+pub trait IStream: ISequentialStream {
+    unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT;
+    unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT;
+    unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT;
+    unsafe fn revert(&self) -> HRESULT;
+    unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT;
+    unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT;
+    unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT;
+}
+/// A stable interface.
+#[com_interface("0b0d4b5e-3f5c-4d8a-9f54-6a4bfc1f9a10")]
+pub trait ISample: IUnknown {
+/// The count.
+      #[doc(alias = "get_Count")]
+    unsafe fn get_count(&self, /* out, retval */ count: *mut UINT32) -> HRESULT;
+}
+
+/// IID of `ISample`.
+pub const IID_ISample: GUID = GUID { Data1: 0x0b0d4b5e, Data2: 0x3f5c, Data3: 0x4d8a, Data4: [0x9f, 0x54, 0x6a, 0x4b, 0xfc, 0x1f, 0x9a, 0x10] };
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "ISample", parent: "IUnknown", iid: IID_ISample, methods: 1 },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    let interfaces = INTERFACES.iter();
+    #[cfg(feature = "experimental")]
+    let interfaces = interfaces.chain(EXPERIMENTAL_INTERFACES.iter());
+    interfaces
+}
+
+#[cfg(feature = "experimental")]
+mod experimental {
+use super::*;
+
+/// Only available behind the `experimental` feature.
+#[com_interface("7d4c2a61-0e1b-4b39-8c5e-2f0a9b6d3e47")]
+pub trait ISampleExperimental: IUnknown {
+/// Whether the experiment is on.
+      #[doc(alias = "get_IsEnabled")]
+    unsafe fn get_is_enabled(&self, /* out, retval */ isEnabled: *mut BOOL) -> HRESULT;
+
+/// Turn the experiment on or off.
+      #[doc(alias = "put_IsEnabled")]
+    unsafe fn put_is_enabled(&self, /* in */ isEnabled: BOOL) -> HRESULT;
+}
+
+/// IID of `ISampleExperimental`.
+pub const IID_ISampleExperimental: GUID = GUID { Data1: 0x7d4c2a61, Data2: 0x0e1b, Data3: 0x4b39, Data4: [0x8c, 0x5e, 0x2f, 0x0a, 0x9b, 0x6d, 0x3e, 0x47] };
+
+pub const EXPERIMENTAL_INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "ISampleExperimental", parent: "IUnknown", iid: IID_ISampleExperimental, methods: 2 },
+];
+}
+
+#[cfg(feature = "experimental")]
+pub use self::experimental::*;
+
+/// Recording implementations of the interfaces, for tests.
+pub mod mocks {
+use super::*;
+use com::co_class;
+use std::cell::RefCell;
+use winapi::shared::winerror::S_OK;
+
+/// Mock `ISample`.
+#[co_class(implements(ISample))]
+pub struct MockSample {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl MockSample {
+    pub fn new() -> Box<Self> {
+        Self::allocate(RefCell::new(Vec::new()))
+    }
+
+    /// Names of the methods called so far, in order.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.borrow().clone()
+    }
+}
+
+impl ISample for MockSample {
+    unsafe fn get_count(&self, count: *mut UINT32) -> HRESULT {
+        self.calls.borrow_mut().push("get_Count");
+        if !count.is_null() {
+            *count = std::mem::zeroed();
+        }
+        S_OK
+    }
+}
+
+/// Mock `ISampleExperimental`.
+#[cfg(feature = "experimental")]
+#[co_class(implements(ISampleExperimental))]
+pub struct MockSampleExperimental {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+#[cfg(feature = "experimental")]
+impl MockSampleExperimental {
+    pub fn new() -> Box<Self> {
+        Self::allocate(RefCell::new(Vec::new()))
+    }
+
+    /// Names of the methods called so far, in order.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.borrow().clone()
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl ISampleExperimental for MockSampleExperimental {
+    unsafe fn get_is_enabled(&self, isEnabled: *mut BOOL) -> HRESULT {
+        self.calls.borrow_mut().push("get_IsEnabled");
+        if !isEnabled.is_null() {
+            *isEnabled = std::mem::zeroed();
+        }
+        S_OK
+    }
+    unsafe fn put_is_enabled(&self, _isEnabled: BOOL) -> HRESULT {
+        self.calls.borrow_mut().push("put_IsEnabled");
+        S_OK
+    }
+}
+}
@@ -0,0 +1,331 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use std::ops::Deref;
+use winapi::shared::minwindef::{*, ULONG};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+use std::ffi::c_void;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IUnknown(pub *mut *mut IUnknownVTable);
+
+#[repr(C)]
+pub struct IUnknownVTable {
+    pub QueryInterface: unsafe extern "system" fn(
+        This: *mut *mut IUnknownVTable,
+        riid: *const GUID,
+        ppvObject: *mut *mut c_void
+    ) -> HRESULT,
+    pub AddRef: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+    pub Release: unsafe extern "system" fn(This: *mut *mut IUnknownVTable) -> ULONG,
+}
+
+impl IUnknown {
+    pub unsafe fn query_interface(&self, riid: *const GUID, ppvObject: *mut *mut c_void) -> HRESULT {
+        ((**self.0).QueryInterface)(self.0, riid, ppvObject)
+    }
+    pub unsafe fn add_ref(&self) -> ULONG {
+        ((**self.0).AddRef)(self.0)
+    }
+    pub unsafe fn release(&self) -> ULONG {
+        ((**self.0).Release)(self.0)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ISequentialStream(pub *mut *mut ISequentialStreamVTable);
+
+#[repr(C)]
+pub struct ISequentialStreamVTable {
+    pub base: IUnknownVTable,
+    pub Read: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *mut c_void,
+        cb: ULONG,
+        pcbRead: *mut ULONG
+    ) -> HRESULT,
+    pub Write: unsafe extern "system" fn(
+        This: *mut *mut ISequentialStreamVTable,
+        pv: *const c_void,
+        cb: ULONG,
+        pcbWritten: *mut ULONG
+    ) -> HRESULT,
+}
+
+impl ISequentialStream {
+    pub unsafe fn read(&self, pv: *mut c_void, cb: ULONG, pcbRead: *mut ULONG) -> HRESULT {
+        ((**self.0).Read)(self.0, pv, cb, pcbRead)
+    }
+    pub unsafe fn write(&self, pv: *const c_void, cb: ULONG, pcbWritten: *mut ULONG) -> HRESULT {
+        ((**self.0).Write)(self.0, pv, cb, pcbWritten)
+    }
+}
+
+impl Deref for ISequentialStream {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IStream(pub *mut *mut IStreamVTable);
+
+#[repr(C)]
+pub struct IStreamVTable {
+    pub base: ISequentialStreamVTable,
+    pub Seek: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub SetSize: unsafe extern "system" fn(This: *mut *mut IStreamVTable, libNewSize: ULARGE_INTEGER) -> HRESULT,
+    pub CopyTo: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT,
+    pub Commit: unsafe extern "system" fn(This: *mut *mut IStreamVTable, grfCommitFlags: DWORD) -> HRESULT,
+    pub Revert: unsafe extern "system" fn(This: *mut *mut IStreamVTable) -> HRESULT,
+    pub LockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub UnlockRegion: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT,
+    pub Stat: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        pstatstg: *mut STATSTG,
+        grfStatFlag: DWORD
+    ) -> HRESULT,
+    pub Clone: unsafe extern "system" fn(
+        This: *mut *mut IStreamVTable,
+        ppstm: *mut *mut *mut IStreamVTable
+    ) -> HRESULT,
+}
+
+impl IStream {
+    pub unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).Seek)(self.0, dlibMove, dwOrigin, plibNewPosition)
+    }
+    pub unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT {
+        ((**self.0).SetSize)(self.0, libNewSize)
+    }
+    pub unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER
+    ) -> HRESULT {
+        ((**self.0).CopyTo)(self.0, pstm, cb, pcbRead, pcbWritten)
+    }
+    pub unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT {
+        ((**self.0).Commit)(self.0, grfCommitFlags)
+    }
+    pub unsafe fn revert(&self) -> HRESULT {
+        ((**self.0).Revert)(self.0)
+    }
+    pub unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).LockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD
+    ) -> HRESULT {
+        ((**self.0).UnlockRegion)(self.0, libOffset, cb, dwLockType)
+    }
+    pub unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT {
+        ((**self.0).Stat)(self.0, pstatstg, grfStatFlag)
+    }
+    pub unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT {
+        ((**self.0).Clone)(self.0, ppstm)
+    }
+}
+
+impl Deref for IStream {
+    type Target = ISequentialStream;
+    fn deref(&self) -> &ISequentialStream {
+        unsafe { &*(self as *const Self as *const ISequentialStream) }
+    }
+}
+/// A stable interface.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ISample(pub *mut *mut ISampleVTable);
+
+#[repr(C)]
+pub struct ISampleVTable {
+    pub base: IUnknownVTable,
+    pub get_Count: unsafe extern "system" fn(This: *mut *mut ISampleVTable, /* out, retval */ count: *mut UINT32) -> HRESULT,
+}
+
+impl ISample {
+/// The count.
+      #[doc(alias = "get_Count")]
+    pub unsafe fn get_count(&self, /* out, retval */ count: *mut UINT32) -> HRESULT {
+        ((**self.0).get_Count)(self.0, count)
+    }
+}
+
+impl Deref for ISample {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+/// IID of `ISample`.
+pub const IID_ISample: GUID = GUID { Data1: 0x0b0d4b5e, Data2: 0x3f5c, Data3: 0x4d8a, Data4: [0x9f, 0x54, 0x6a, 0x4b, 0xfc, 0x1f, 0x9a, 0x10] };
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "ISample", parent: "IUnknown", iid: IID_ISample, methods: 1 },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    let interfaces = INTERFACES.iter();
+    #[cfg(feature = "experimental")]
+    let interfaces = interfaces.chain(EXPERIMENTAL_INTERFACES.iter());
+    interfaces
+}
+
+#[cfg(feature = "experimental")]
+mod experimental {
+use super::*;
+
+/// Only available behind the `experimental` feature.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ISampleExperimental(pub *mut *mut ISampleExperimentalVTable);
+
+#[repr(C)]
+pub struct ISampleExperimentalVTable {
+    pub base: IUnknownVTable,
+    pub get_IsEnabled: unsafe extern "system" fn(This: *mut *mut ISampleExperimentalVTable, /* out, retval */ isEnabled: *mut BOOL) -> HRESULT,
+    pub put_IsEnabled: unsafe extern "system" fn(This: *mut *mut ISampleExperimentalVTable, /* in */ isEnabled: BOOL) -> HRESULT,
+}
+
+impl ISampleExperimental {
+/// Whether the experiment is on.
+      #[doc(alias = "get_IsEnabled")]
+    pub unsafe fn get_is_enabled(&self, /* out, retval */ isEnabled: *mut BOOL) -> HRESULT {
+        ((**self.0).get_IsEnabled)(self.0, isEnabled)
+    }
+
+/// Turn the experiment on or off.
+      #[doc(alias = "put_IsEnabled")]
+    pub unsafe fn put_is_enabled(&self, /* in */ isEnabled: BOOL) -> HRESULT {
+        ((**self.0).put_IsEnabled)(self.0, isEnabled)
+    }
+}
+
+impl Deref for ISampleExperimental {
+    type Target = IUnknown;
+    fn deref(&self) -> &IUnknown {
+        unsafe { &*(self as *const Self as *const IUnknown) }
+    }
+}
+
+/// IID of `ISampleExperimental`.
+pub const IID_ISampleExperimental: GUID = GUID { Data1: 0x7d4c2a61, Data2: 0x0e1b, Data3: 0x4b39, Data4: [0x8c, 0x5e, 0x2f, 0x0a, 0x9b, 0x6d, 0x3e, 0x47] };
+
+pub const EXPERIMENTAL_INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo { name: "ISampleExperimental", parent: "IUnknown", iid: IID_ISampleExperimental, methods: 2 },
+];
+}
+
+#[cfg(feature = "experimental")]
+pub use self::experimental::*;
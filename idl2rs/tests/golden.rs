@@ -0,0 +1,83 @@
+//! Golden tests: every `fixtures/*.idl` is run through idl2rs and the output
+//! is compared with the `.rs` file next to it, the output of `--raw` with the
+//! `.raw.rs` file and the output of `--mocks` with the `.mocks.rs` file.
+//!
+//! Run with `UPDATE_GOLDEN=1` to rewrite the expected output after an
+//! intentional change, and review the diff.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Output file extensions and the arguments that produce them.
+const MODES: &[(&str, &[&str])] = &[
+    ("rs", &[]),
+    ("raw.rs", &["--raw"]),
+    ("mocks.rs", &["--mocks"]),
+];
+
+fn idl2rs(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_idl2rs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "idl2rs failed");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut idls: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension() == Some("idl".as_ref()))
+        .collect();
+    idls.sort();
+    assert!(!idls.is_empty());
+    idls
+}
+
+#[test]
+fn test_golden() {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    for idl in fixtures() {
+        let input = fs::read_to_string(&idl).unwrap();
+        for (extension, args) in MODES {
+            let actual = idl2rs(args, &input);
+            let expected_path = idl.with_extension(extension);
+            if update {
+                fs::write(&expected_path, &actual).unwrap();
+                continue;
+            }
+            let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+            assert!(
+                actual == expected,
+                "output for {} differs from {}, rerun with UPDATE_GOLDEN=1 to update",
+                idl.display(),
+                expected_path.display(),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deterministic() {
+    for idl in fixtures() {
+        let input = fs::read_to_string(&idl).unwrap();
+        for (_, args) in MODES {
+            let output = idl2rs(args, &input);
+            assert_eq!(output, idl2rs(args, &input));
+            assert_eq!(output, idl2rs(args, &input.replace('\n', "\r\n")));
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Windows 11 backdrop materials (Mica, Acrylic) for the host window.
+//!
+//! DWM fills the window with the material where nothing else is painted. The
+//! frame is extended into the client area for that, so the app has to leave
+//! its background unpainted, e.g. with a null background brush.
+//!
+//! The webview paints an opaque white background of its own, so the material
+//! shows around the webview, e.g. behind a title bar or side bar the app draws
+//! itself. To show it behind the page too, the page needs a transparent
+//! background and the webview `Host::put_default_background_color` with
+//! `Color::TRANSPARENT`, which needs the `sdk-1.0.774` feature.
+//!
+//! ```no_run
+//! # fn f(host: webview2::Host) -> webview2::Result<()> {
+//! use webview2::backdrop::Backdrop;
+//!
+//! host.set_backdrop(Backdrop::Mica)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A visual hosted webview, see `composition`, also needs a DirectComposition
+//! visual tree on the window. `CompositionController::host_over_backdrop`
+//! sets up all of it: the backdrop, the transparent background and a visual
+//! over the client area that the webview renders into.
+
+#[cfg(feature = "sdk-1.0.774")]
+use crate::composition::CompositionController;
+#[cfg(feature = "sdk-1.0.774")]
+use crate::raw::{ICoreWebView2Controller2, COREWEBVIEW2_COLOR};
+use crate::{check_hresult, Host, Result};
+#[cfg(feature = "sdk-1.0.774")]
+use crate::{query_interface, CheckOpen};
+#[cfg(feature = "sdk-1.0.774")]
+use com::ComRc;
+use std::mem;
+#[cfg(feature = "sdk-1.0.774")]
+use std::mem::MaybeUninit;
+#[cfg(feature = "sdk-1.0.774")]
+use std::ptr;
+#[cfg(feature = "sdk-1.0.774")]
+use winapi::shared::minwindef::TRUE;
+use winapi::shared::minwindef::{BOOL, DWORD};
+use winapi::shared::windef::HWND;
+#[cfg(feature = "sdk-1.0.774")]
+use winapi::um::dcomp::{
+    DCompositionCreateDevice2, IDCompositionDevice, IDCompositionTarget, IDCompositionVisual,
+};
+use winapi::um::dwmapi::{DwmExtendFrameIntoClientArea, DwmSetWindowAttribute};
+use winapi::um::uxtheme::MARGINS;
+use winapi::um::winuser::{GetAncestor, GA_ROOT};
+#[cfg(feature = "sdk-1.0.774")]
+use winapi::Interface;
+
+// Newer than the winapi headers.
+/// Windows 10 20H1 and later.
+const DWMWA_USE_IMMERSIVE_DARK_MODE: DWORD = 20;
+/// Windows 11 22H2 and later.
+const DWMWA_SYSTEMBACKDROP_TYPE: DWORD = 38;
+
+/// A `DWM_SYSTEMBACKDROP_TYPE`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Backdrop {
+    /// Let DWM decide, usually no material in the client area.
+    Auto = 0,
+    None = 1,
+    /// For long lived windows. Tinted with the desktop wallpaper.
+    Mica = 2,
+    /// For transient windows like popups and menus. Blurs what is behind.
+    Acrylic = 3,
+    /// Mica with a stronger tint, for windows with tabs in the title bar.
+    Tabbed = 4,
+}
+
+/// A color with alpha, from `0` transparent to `255` opaque.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Color {
+    pub a: u8,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::argb(0, 0, 0, 0);
+    /// The default.
+    pub const WHITE: Color = Color::argb(255, 255, 255, 255);
+    pub const BLACK: Color = Color::argb(255, 0, 0, 0);
+
+    pub const fn argb(a: u8, r: u8, g: u8, b: u8) -> Self {
+        Self { a, r, g, b }
+    }
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::argb(255, r, g, b)
+    }
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl From<COREWEBVIEW2_COLOR> for Color {
+    fn from(c: COREWEBVIEW2_COLOR) -> Self {
+        Self::argb(c.A, c.R, c.G, c.B)
+    }
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl From<Color> for COREWEBVIEW2_COLOR {
+    fn from(c: Color) -> Self {
+        Self {
+            A: c.a,
+            R: c.r,
+            G: c.g,
+            B: c.b,
+        }
+    }
+}
+
+/// Set the backdrop of a top level window. Fails with `E_INVALIDARG` before
+/// Windows 11 22H2.
+pub fn set_backdrop(window: HWND, backdrop: Backdrop) -> Result<()> {
+    let margins = extended_frame(backdrop);
+    let margins = MARGINS {
+        cxLeftWidth: margins,
+        cxRightWidth: margins,
+        cyTopHeight: margins,
+        cyBottomHeight: margins,
+    };
+    let value = backdrop as DWORD;
+    unsafe {
+        check_hresult(DwmSetWindowAttribute(
+            window,
+            DWMWA_SYSTEMBACKDROP_TYPE,
+            &value as *const DWORD as *const _,
+            mem::size_of::<DWORD>() as DWORD,
+        ))?;
+        check_hresult(DwmExtendFrameIntoClientArea(window, &margins))
+    }
+}
+
+/// How far the frame is extended into the client area for `backdrop`, -1 for
+/// the whole client area.
+fn extended_frame(backdrop: Backdrop) -> i32 {
+    match backdrop {
+        Backdrop::Auto | Backdrop::None => 0,
+        _ => -1,
+    }
+}
+
+/// Draw the title bar and the material of a top level window dark, to match
+/// a dark page. Not done by DWM on its own.
+pub fn set_dark_mode(window: HWND, dark: bool) -> Result<()> {
+    let value = dark as BOOL;
+    check_hresult(unsafe {
+        DwmSetWindowAttribute(
+            window,
+            DWMWA_USE_IMMERSIVE_DARK_MODE,
+            &value as *const BOOL as *const _,
+            mem::size_of::<BOOL>() as DWORD,
+        )
+    })
+}
+
+impl Host {
+    /// Set the backdrop of the top level window of the webview, see
+    /// `set_backdrop`.
+    pub fn set_backdrop(&self, backdrop: Backdrop) -> Result<()> {
+        set_backdrop(self.root_window()?, backdrop)
+    }
+
+    /// See `set_dark_mode`.
+    pub fn set_dark_mode(&self, dark: bool) -> Result<()> {
+        set_dark_mode(self.root_window()?, dark)
+    }
+
+    fn root_window(&self) -> Result<HWND> {
+        Ok(unsafe { GetAncestor(self.get_parent_window()?, GA_ROOT) })
+    }
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl Host {
+    /// `ICoreWebView2Controller2::get_DefaultBackgroundColor`.
+    pub fn get_default_background_color(&self) -> Result<Color> {
+        self.check_open()?;
+        let controller2: ComRc<dyn ICoreWebView2Controller2> = query_interface(&self.inner)?;
+        let mut color = MaybeUninit::uninit();
+        check_hresult(unsafe { controller2.get_default_background_color(color.as_mut_ptr()) })?;
+        Ok(unsafe { color.assume_init() }.into())
+    }
+
+    /// The background, which shows until the page paints and where the page
+    /// is transparent. Only opaque and fully transparent colors are supported,
+    /// others fail with `E_INVALIDARG`. Fails with `E_NOINTERFACE` on
+    /// runtimes before `ICoreWebView2Controller2`.
+    pub fn put_default_background_color(&self, color: Color) -> Result<()> {
+        self.check_open()?;
+        let controller2: ComRc<dyn ICoreWebView2Controller2> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller2.put_default_background_color(color.into()) })
+    }
+}
+
+/// The DirectComposition visual tree of
+/// `CompositionController::host_over_backdrop`. The webview keeps rendering
+/// into the visual, but the visual is no longer shown once this is dropped.
+#[cfg(feature = "sdk-1.0.774")]
+pub struct BackdropVisual {
+    device: *mut IDCompositionDevice,
+    target: *mut IDCompositionTarget,
+    visual: *mut IDCompositionVisual,
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl BackdropVisual {
+    /// The device, e.g. to add visuals of the app and `Commit` them.
+    pub fn device(&self) -> *mut IDCompositionDevice {
+        self.device
+    }
+
+    /// The visual the webview renders into, the root of the window's tree.
+    pub fn visual(&self) -> *mut IDCompositionVisual {
+        self.visual
+    }
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl Drop for BackdropVisual {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.visual.is_null() {
+                (*self.visual).Release();
+            }
+            if !self.target.is_null() {
+                (*self.target).Release();
+            }
+            if !self.device.is_null() {
+                (*self.device).Release();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+impl CompositionController {
+    /// Show `backdrop` behind the page: set it on the top level window of
+    /// `parent_window`, make the default background of the webview
+    /// transparent, and render the webview into a new DirectComposition
+    /// visual over the client area of `parent_window`.
+    ///
+    /// The page needs a transparent background too. The bounds of the
+    /// webview are still set with `Host::put_bounds`, and input has to be
+    /// forwarded as for any visual hosted webview.
+    pub fn host_over_backdrop(
+        &self,
+        parent_window: HWND,
+        backdrop: Backdrop,
+    ) -> Result<BackdropVisual> {
+        set_backdrop(unsafe { GetAncestor(parent_window, GA_ROOT) }, backdrop)?;
+        self.get_host()?
+            .put_default_background_color(Color::TRANSPARENT)?;
+
+        let mut tree = BackdropVisual {
+            device: ptr::null_mut(),
+            target: ptr::null_mut(),
+            visual: ptr::null_mut(),
+        };
+        unsafe {
+            // No rendering device, the visual only shows the webview.
+            check_hresult(DCompositionCreateDevice2(
+                ptr::null_mut(),
+                &IDCompositionDevice::uuidof(),
+                &mut tree.device as *mut *mut IDCompositionDevice as *mut _,
+            ))?;
+            // Topmost, over what the window paints with GDI.
+            check_hresult((*tree.device).CreateTargetForHwnd(
+                parent_window,
+                TRUE,
+                &mut tree.target,
+            ))?;
+            check_hresult((*tree.device).CreateVisual(&mut tree.visual))?;
+            check_hresult((*tree.target).SetRoot(tree.visual))?;
+            self.put_root_visual_target_raw(tree.visual as *mut _)?;
+            check_hresult((*tree.device).Commit())?;
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extended_frame() {
+        assert_eq!(extended_frame(Backdrop::Auto), 0);
+        assert_eq!(extended_frame(Backdrop::None), 0);
+        assert_eq!(extended_frame(Backdrop::Mica), -1);
+        assert_eq!(extended_frame(Backdrop::Acrylic), -1);
+        assert_eq!(extended_frame(Backdrop::Tabbed), -1);
+    }
+
+    #[test]
+    fn test_backdrop_values() {
+        // `DWM_SYSTEMBACKDROP_TYPE`.
+        assert_eq!(Backdrop::Auto as DWORD, 0);
+        assert_eq!(Backdrop::Mica as DWORD, 2);
+        assert_eq!(Backdrop::Tabbed as DWORD, 4);
+    }
+
+    #[cfg(feature = "sdk-1.0.774")]
+    #[test]
+    fn test_color() {
+        let color = COREWEBVIEW2_COLOR::from(Color::rgb(1, 2, 3));
+        assert_eq!((color.A, color.R, color.G, color.B), (255, 1, 2, 3));
+        assert_eq!(Color::from(color), Color::argb(255, 1, 2, 3));
+        let color = COREWEBVIEW2_COLOR::from(Color::TRANSPARENT);
+        assert_eq!(color.A, 0);
+    }
+}
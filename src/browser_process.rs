@@ -0,0 +1,82 @@
+//! Getting notified when the browser process exits.
+//!
+//! Webviews of an environment, or of environments with the same user data
+//! folder, share the browser process. Once it has exited the user data folder
+//! can be deleted or used by a new environment.
+//!
+//! Needs the `sdk-1.0.992` feature.
+
+use crate::raw::*;
+use crate::{
+    add_ref_to_rc, callback, check_hresult, query_interface, to_hresult, Environment, Result,
+};
+use com::ComRc;
+use std::mem::{self, MaybeUninit};
+use winapi::shared::ntdef::HRESULT;
+
+#[doc(inline)]
+pub type BrowserProcessExitKind = COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND;
+
+/// `ICoreWebView2BrowserProcessExitedEventArgs`.
+#[derive(Clone)]
+pub struct BrowserProcessExitedEventArgs {
+    inner: ComRc<dyn ICoreWebView2BrowserProcessExitedEventArgs>,
+}
+
+impl BrowserProcessExitedEventArgs {
+    /// Failed if the process crashed or was killed. `ProcessFailed` is raised
+    /// on the webviews too then.
+    pub fn get_browser_process_exit_kind(&self) -> Result<BrowserProcessExitKind> {
+        let mut kind = MaybeUninit::uninit();
+        check_hresult(unsafe { self.inner.get_browser_process_exit_kind(kind.as_mut_ptr()) })?;
+        Ok(unsafe { kind.assume_init() })
+    }
+
+    pub fn get_browser_process_id(&self) -> Result<u32> {
+        let mut id = 0;
+        check_hresult(unsafe { self.inner.get_browser_process_id(&mut id) })?;
+        Ok(id)
+    }
+
+    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2BrowserProcessExitedEventArgs> {
+        &self.inner
+    }
+}
+
+impl Environment {
+    /// Called once the browser process of the environment has exited, after
+    /// all its webviews are closed or when it failed. Fails with
+    /// `E_NOINTERFACE` on runtimes before `ICoreWebView2Environment5`.
+    pub fn add_browser_process_exited(
+        &self,
+        handler: impl Fn(Environment, BrowserProcessExitedEventArgs) -> Result<()> + 'static,
+    ) -> Result<EventRegistrationToken> {
+        let environment5: ComRc<dyn ICoreWebView2Environment5> = query_interface(&self.inner)?;
+        let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
+
+        let handler = callback!(
+            ICoreWebView2BrowserProcessExitedEventHandler,
+            move |sender: *mut *mut ICoreWebView2EnvironmentVTable,
+                  args: *mut *mut ICoreWebView2BrowserProcessExitedEventArgsVTable|
+                  -> HRESULT {
+                let sender = Environment {
+                    inner: unsafe { add_ref_to_rc(sender) },
+                };
+                let args = BrowserProcessExitedEventArgs {
+                    inner: unsafe { add_ref_to_rc(args) },
+                };
+                to_hresult(handler(sender, args))
+            }
+        );
+
+        check_hresult(unsafe {
+            environment5.add_browser_process_exited(handler.as_raw(), &mut token)
+        })?;
+        Ok(token)
+    }
+
+    pub fn remove_browser_process_exited(&self, token: EventRegistrationToken) -> Result<()> {
+        let environment5: ComRc<dyn ICoreWebView2Environment5> = query_interface(&self.inner)?;
+        check_hresult(unsafe { environment5.remove_browser_process_exited(token) })
+    }
+}
@@ -0,0 +1,634 @@
+//! A ready-made browser pane.
+//!
+//! `BrowserWidget` creates a webview filling a window and wires up what a
+//! browser UI needs: the address, title, favicon, loading state and history
+//! are reported to a `BrowserUi`, which can also veto navigations and
+//! decide where popups go. It has methods for the usual toolbar buttons,
+//! find in page and zoom steps. Ctrl+Plus, Ctrl+Minus, Ctrl+0 and Ctrl+wheel
+//! zoom as in Edge.
+//!
+//! ```no_run
+//! # fn f(env: webview2::Environment, parent: winapi::shared::windef::HWND) -> webview2::Result<()> {
+//! use webview2::browser_widget::{BrowserUi, BrowserWidget, BrowserWidgetOptions};
+//!
+//! struct Toolbar;
+//!
+//! impl BrowserUi for Toolbar {
+//!     fn address_changed(&self, url: &str) {
+//!         println!("at {}", url);
+//!     }
+//! }
+//!
+//! let options = BrowserWidgetOptions {
+//!     home: "https://example.com".into(),
+//!     ..Default::default()
+//! };
+//! BrowserWidget::create(&env, parent, options, Toolbar, |widget| {
+//!     // Keep the widget, e.g. in the window state, and call `resize` on
+//!     // `WM_SIZE`.
+//!     let _widget = widget?;
+//!     Ok(())
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This SDK has no download events or download UI settings, so downloads are
+//! followed with the DevTools protocol (`Page.downloadWillBegin` and
+//! `Page.downloadProgress`). Progress is only reported when a downloads
+//! folder is set, otherwise the browser handles downloads with its own UI.
+
+use crate::cdp::js_string;
+use crate::context_menu::{ContextMenuPolicyToken, Keep};
+use crate::error_page::NavigationError;
+use crate::raw::CORE_WEBVIEW2_WEB_ERROR_STATUS::*;
+use crate::{Environment, Error, Host, Result, WebView};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use winapi::shared::windef::{HWND, RECT};
+use winapi::shared::winerror::E_INVALIDARG;
+use winapi::um::winuser::GetClientRect;
+
+/// Zoom levels of `zoom_in` and `zoom_out`, those of Edge.
+const ZOOM_LEVELS: &[f64] = &[
+    0.25, 0.33, 0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0, 4.0, 5.0,
+];
+
+/// The icon of the page: the first `<link rel="icon">`, or `/favicon.ico` of
+/// web pages without one.
+const FAVICON_SCRIPT: &str = r#"(() => {
+    const link = document.querySelector("link[rel~='icon' i]");
+    if (link) return link.href;
+    return location.protocol.startsWith("http") ? location.origin + "/favicon.ico" : null;
+})()"#;
+
+/// Loading state of the top level document.
+#[derive(Debug, Clone)]
+pub enum LoadingState {
+    Loading,
+    Loaded,
+    /// Navigations canceled by another navigation or `stop` are reported as
+    /// `Loaded`.
+    Failed(NavigationError),
+}
+
+/// What to do with a page's request for a new window.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NewWindow {
+    /// Open it in this widget instead. The default.
+    Replace,
+    /// Open it in a browser window of its own, as a webview does by default.
+    Popup,
+    /// Don't open it.
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DownloadState {
+    InProgress,
+    Completed,
+    Canceled,
+}
+
+#[derive(Debug, Clone)]
+pub struct Download {
+    /// Identifies the download across `download_updated` calls.
+    pub id: String,
+    pub url: String,
+    pub suggested_file_name: String,
+    pub received_bytes: u64,
+    /// 0 if unknown.
+    pub total_bytes: u64,
+    pub state: DownloadState,
+}
+
+/// The UI around a `BrowserWidget`, e.g. a toolbar and a tab strip. All
+/// methods have defaults that do nothing or allow everything.
+pub trait BrowserUi: 'static {
+    fn address_changed(&self, _url: &str) {}
+
+    fn title_changed(&self, _title: &str) {}
+
+    /// The URL of the page's icon, `None` for pages without one.
+    fn favicon_changed(&self, _url: Option<&str>) {}
+
+    fn loading_changed(&self, _state: &LoadingState) {}
+
+    /// After navigations, to enable the back and forward buttons.
+    fn history_changed(&self, _can_go_back: bool, _can_go_forward: bool) {}
+
+    fn zoom_changed(&self, _zoom_factor: f64) {}
+
+    /// Whether to allow a navigation of the top level document, including
+    /// redirects.
+    fn allow_navigation(&self, _url: &str, _user_initiated: bool) -> bool {
+        true
+    }
+
+    fn new_window_requested(&self, _url: &str) -> NewWindow {
+        NewWindow::Replace
+    }
+
+    /// The URL to search for text typed into the address bar that isn't an
+    /// address, see `BrowserWidget::navigate_to_input`.
+    fn search_url(&self, _query: &str) -> Option<String> {
+        None
+    }
+
+    /// Called when a download starts and whenever it progresses.
+    fn download_updated(&self, _download: &Download) {}
+}
+
+/// Settings of `BrowserWidget::create`.
+#[derive(Debug, Clone)]
+pub struct BrowserWidgetOptions {
+    /// Loaded first and by `go_home`. Defaults to `about:blank`.
+    pub home: String,
+    /// Defaults to `Keep::All`.
+    pub context_menu: Keep,
+    /// Whether F12 and the context menu open the DevTools. Defaults to false.
+    pub dev_tools: bool,
+    /// Save downloads here without asking and report them to
+    /// `BrowserUi::download_updated`. Defaults to `None`, for the browser's
+    /// own download UI.
+    pub downloads_folder: Option<PathBuf>,
+}
+
+impl Default for BrowserWidgetOptions {
+    fn default() -> Self {
+        Self {
+            home: "about:blank".into(),
+            context_menu: Keep::All,
+            dev_tools: false,
+            downloads_folder: None,
+        }
+    }
+}
+
+struct Inner {
+    host: Host,
+    webview: WebView,
+    home: String,
+    ui: Box<dyn BrowserUi>,
+    downloads: RefCell<HashMap<String, Download>>,
+    /// Kept for the lifetime of the widget.
+    _context_menu: Option<ContextMenuPolicyToken>,
+}
+
+/// A webview filling a window, reporting to a `BrowserUi`. Clones share the
+/// webview.
+#[derive(Clone)]
+pub struct BrowserWidget {
+    inner: Rc<Inner>,
+}
+
+impl BrowserWidget {
+    /// Create the webview in `parent` and load the home page.
+    pub fn create(
+        env: &Environment,
+        parent: HWND,
+        options: BrowserWidgetOptions,
+        ui: impl BrowserUi,
+        completed: impl FnOnce(Result<BrowserWidget>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        env.create_host(parent, move |host| {
+            let widget = host.and_then(|host| Self::new(host, options, Box::new(ui)));
+            completed(widget)
+        })
+    }
+
+    fn new(host: Host, options: BrowserWidgetOptions, ui: Box<dyn BrowserUi>) -> Result<Self> {
+        let webview = host.get_webview()?;
+        let settings = webview.get_settings()?;
+        settings.put_are_dev_tools_enabled(options.dev_tools)?;
+        settings.put_is_zoom_control_enabled(true)?;
+        settings.put_is_status_bar_enabled(true)?;
+        let context_menu = match options.context_menu {
+            Keep::All => None,
+            keep => Some(host.context_menu_policy(keep)?),
+        };
+        let widget = BrowserWidget {
+            inner: Rc::new(Inner {
+                host,
+                webview,
+                home: options.home,
+                ui,
+                downloads: RefCell::new(HashMap::new()),
+                _context_menu: context_menu,
+            }),
+        };
+        widget.resize()?;
+        widget.add_handlers()?;
+        if let Some(folder) = &options.downloads_folder {
+            widget.follow_downloads(folder)?;
+        }
+        widget.go_home()?;
+        Ok(widget)
+    }
+
+    /// Handlers hold weak references, the webview is owned by `inner`.
+    fn add_handlers(&self) -> Result<()> {
+        let w = &self.inner.webview;
+
+        let weak = Rc::downgrade(&self.inner);
+        w.add_navigation_starting(move |_, args| {
+            if let Some(inner) = weak.upgrade() {
+                if !inner
+                    .ui
+                    .allow_navigation(&args.get_uri()?, args.get_is_user_initiated()?)
+                {
+                    return args.put_cancel(true);
+                }
+                inner.ui.loading_changed(&LoadingState::Loading);
+            }
+            Ok(())
+        })?;
+
+        // `NavigationCompleted` has no URI, but the source is the URI that
+        // failed.
+        let weak = Rc::downgrade(&self.inner);
+        w.add_navigation_completed(move |w, args| {
+            let inner = match weak.upgrade() {
+                Some(inner) => inner,
+                None => return Ok(()),
+            };
+            let status = args.get_web_error_status()?;
+            let state = if args.get_is_success()?
+                || status == CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED
+            {
+                LoadingState::Loaded
+            } else {
+                LoadingState::Failed(NavigationError {
+                    uri: w.get_source()?.unwrap_or_default(),
+                    status,
+                })
+            };
+            inner.ui.loading_changed(&state);
+            if let LoadingState::Loaded = state {
+                let weak = weak.clone();
+                w.evaluate(FAVICON_SCRIPT, move |icon| {
+                    if let Some(inner) = weak.upgrade() {
+                        inner.ui.favicon_changed(icon.as_str());
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        w.add_source_changed(move |w, _| {
+            if let Some(inner) = weak.upgrade() {
+                inner
+                    .ui
+                    .address_changed(&w.get_source()?.unwrap_or_default());
+            }
+            Ok(())
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        w.add_document_title_changed(move |w| {
+            if let Some(inner) = weak.upgrade() {
+                inner
+                    .ui
+                    .title_changed(&w.get_document_title()?.unwrap_or_default());
+            }
+            Ok(())
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        w.add_history_changed(move |w| {
+            if let Some(inner) = weak.upgrade() {
+                inner
+                    .ui
+                    .history_changed(w.get_can_go_back()?, w.get_can_go_forward()?);
+            }
+            Ok(())
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        w.add_new_window_requested(move |w, args| {
+            let inner = match weak.upgrade() {
+                Some(inner) => inner,
+                None => return Ok(()),
+            };
+            let uri = args.get_uri()?;
+            match inner.ui.new_window_requested(&uri) {
+                NewWindow::Replace => {
+                    args.put_handled(true)?;
+                    w.navigate(&uri)
+                }
+                NewWindow::Popup => Ok(()),
+                NewWindow::Block => args.put_handled(true),
+            }
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        self.inner.host.add_zoom_factor_changed(move |host| {
+            if let Some(inner) = weak.upgrade() {
+                inner.ui.zoom_changed(host.get_zoom_factor()?);
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    fn follow_downloads(&self, folder: &Path) -> Result<()> {
+        let w = &self.inner.webview;
+        let weak = Rc::downgrade(&self.inner);
+        let receiver = w.get_dev_tools_protocol_event_receiver("Page.downloadWillBegin")?;
+        receiver.add_dev_tools_protocol_event_received(move |_, args| {
+            let event: Value =
+                serde_json::from_str(&args.get_parameter_object_as_json()?).unwrap_or_default();
+            if let (Some(inner), Some(id)) = (weak.upgrade(), event["guid"].as_str()) {
+                let download = Download {
+                    id: id.to_string(),
+                    url: event["url"].as_str().unwrap_or("").to_string(),
+                    suggested_file_name: event["suggestedFilename"]
+                        .as_str()
+                        .unwrap_or("")
+                        .to_string(),
+                    received_bytes: 0,
+                    total_bytes: 0,
+                    state: DownloadState::InProgress,
+                };
+                inner.ui.download_updated(&download);
+                inner
+                    .downloads
+                    .borrow_mut()
+                    .insert(id.to_string(), download);
+            }
+            Ok(())
+        })?;
+
+        let weak = Rc::downgrade(&self.inner);
+        let receiver = w.get_dev_tools_protocol_event_receiver("Page.downloadProgress")?;
+        receiver.add_dev_tools_protocol_event_received(move |_, args| {
+            let event: Value =
+                serde_json::from_str(&args.get_parameter_object_as_json()?).unwrap_or_default();
+            let inner = match weak.upgrade() {
+                Some(inner) => inner,
+                None => return Ok(()),
+            };
+            let id = event["guid"].as_str().unwrap_or("");
+            let state = match event["state"].as_str() {
+                Some("completed") => DownloadState::Completed,
+                Some("canceled") => DownloadState::Canceled,
+                _ => DownloadState::InProgress,
+            };
+            let download = {
+                let mut downloads = inner.downloads.borrow_mut();
+                let download = match downloads.get_mut(id) {
+                    Some(download) => download,
+                    None => return Ok(()),
+                };
+                download.received_bytes = event["receivedBytes"].as_f64().unwrap_or(0.0) as u64;
+                download.total_bytes = event["totalBytes"].as_f64().unwrap_or(0.0) as u64;
+                download.state = state;
+                if state == DownloadState::InProgress {
+                    download.clone()
+                } else {
+                    downloads.remove(id).unwrap()
+                }
+            };
+            inner.ui.download_updated(&download);
+            Ok(())
+        })?;
+
+        w.call_cdp("Page.enable", json!({}), |_| Ok(()))?;
+        w.call_cdp(
+            "Page.setDownloadBehavior",
+            json!({ "behavior": "allow", "downloadPath": folder }),
+            |_| Ok(()),
+        )
+    }
+
+    pub fn host(&self) -> &Host {
+        &self.inner.host
+    }
+
+    pub fn webview(&self) -> &WebView {
+        &self.inner.webview
+    }
+
+    /// Fit the webview to the client area of the parent window, e.g. on
+    /// `WM_SIZE`.
+    pub fn resize(&self) -> Result<()> {
+        let parent = self.inner.host.get_parent_window()?;
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        unsafe {
+            GetClientRect(parent, &mut rect);
+        }
+        self.inner.host.put_bounds(rect)
+    }
+
+    pub fn navigate(&self, url: &str) -> Result<()> {
+        self.inner.webview.navigate(url)
+    }
+
+    /// Navigate to what was typed into an address bar: URLs as they are,
+    /// things that look like host names over HTTPS, and anything else with
+    /// `BrowserUi::search_url`. Without a search URL, a single word is taken
+    /// as a host name, and other input fails with `E_INVALIDARG`.
+    pub fn navigate_to_input(&self, input: &str) -> Result<()> {
+        let url = input_to_url(input, |query| self.inner.ui.search_url(query))?;
+        self.navigate(&url)
+    }
+
+    pub fn go_home(&self) -> Result<()> {
+        self.navigate(&self.inner.home)
+    }
+
+    pub fn go_back(&self) -> Result<()> {
+        self.inner.webview.go_back()
+    }
+
+    pub fn go_forward(&self) -> Result<()> {
+        self.inner.webview.go_forward()
+    }
+
+    pub fn reload(&self) -> Result<()> {
+        self.inner.webview.reload()
+    }
+
+    pub fn stop(&self) -> Result<()> {
+        self.inner.webview.stop()
+    }
+
+    /// Select the next match of `text` after the selection, wrapping around,
+    /// and scroll it into view. `found` tells whether there is any.
+    pub fn find(
+        &self,
+        text: &str,
+        backwards: bool,
+        match_case: bool,
+        found: impl FnOnce(bool) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let script = format!(
+            "window.find({}, {}, {}, true)",
+            js_string(text),
+            match_case,
+            backwards
+        );
+        self.inner
+            .webview
+            .evaluate(&script, move |result| found(result.as_bool() == Some(true)))
+    }
+
+    /// Clear the selection left by `find`.
+    pub fn clear_find(&self) -> Result<()> {
+        self.inner
+            .webview
+            .evaluate("getSelection().removeAllRanges()", |_| Ok(()))
+    }
+
+    pub fn zoom_in(&self) -> Result<()> {
+        let zoom = self.inner.host.get_zoom_factor()?;
+        self.inner.host.put_zoom_factor(next_zoom_level(zoom, true))
+    }
+
+    pub fn zoom_out(&self) -> Result<()> {
+        let zoom = self.inner.host.get_zoom_factor()?;
+        self.inner
+            .host
+            .put_zoom_factor(next_zoom_level(zoom, false))
+    }
+
+    pub fn reset_zoom(&self) -> Result<()> {
+        self.inner.host.put_zoom_factor(1.0)
+    }
+
+    /// Close the webview. Other methods fail afterwards.
+    pub fn close(&self) -> Result<()> {
+        self.inner.host.close()
+    }
+}
+
+/// The zoom level after `zoom` in `ZOOM_LEVELS`, or before it. Stays at the
+/// ends.
+fn next_zoom_level(zoom: f64, up: bool) -> f64 {
+    // Zoom factors set with Ctrl+wheel are between levels.
+    const EPSILON: f64 = 0.001;
+    if up {
+        ZOOM_LEVELS
+            .iter()
+            .copied()
+            .find(|&level| level > zoom + EPSILON)
+            .unwrap_or(ZOOM_LEVELS[ZOOM_LEVELS.len() - 1])
+    } else {
+        ZOOM_LEVELS
+            .iter()
+            .copied()
+            .rev()
+            .find(|&level| level < zoom - EPSILON)
+            .unwrap_or(ZOOM_LEVELS[0])
+    }
+}
+
+/// The URL for address bar input that is one, `None` for search terms.
+/// The URL to navigate to for address bar input, see
+/// `BrowserWidget::navigate_to_input`.
+fn input_to_url(input: &str, search_url: impl FnOnce(&str) -> Option<String>) -> Result<String> {
+    if let Some(url) = address_to_url(input) {
+        return Ok(url);
+    }
+    let input = input.trim();
+    if let Some(url) = search_url(input) {
+        return Ok(url);
+    }
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return Err(Error::new(E_INVALIDARG));
+    }
+    Ok(format!("https://{}", input))
+}
+
+fn address_to_url(input: &str) -> Option<String> {
+    let input = input.trim();
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+    let scheme_end = input.find(':').unwrap_or(0);
+    let has_scheme = scheme_end > 0
+        && input[..scheme_end]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        // `localhost:8080` has a port rather than a scheme.
+        && !input[scheme_end + 1..].starts_with(|c: char| c.is_ascii_digit());
+    if has_scheme {
+        return Some(input.to_string());
+    }
+    let host = input.split(&['/', '?', '#'][..]).next().unwrap();
+    let host = host.split(':').next().unwrap();
+    if host == "localhost" {
+        Some(format!("http://{}", input))
+    } else if host.contains('.') && !host.starts_with('.') && !host.ends_with('.') {
+        Some(format!("https://{}", input))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_to_url() {
+        assert_eq!(
+            address_to_url("https://example.com/a").as_deref(),
+            Some("https://example.com/a")
+        );
+        assert_eq!(
+            address_to_url("about:blank").as_deref(),
+            Some("about:blank")
+        );
+        assert_eq!(
+            address_to_url(" example.com/a?b ").as_deref(),
+            Some("https://example.com/a?b")
+        );
+        assert_eq!(
+            address_to_url("localhost:8080").as_deref(),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(address_to_url("rust lang"), None);
+        assert_eq!(address_to_url("rust"), None);
+    }
+
+    #[test]
+    fn test_input_to_url() {
+        let search = |query: &str| Some(format!("https://search.example/?q={}", query));
+        assert_eq!(
+            input_to_url("example.com", search).unwrap(),
+            "https://example.com"
+        );
+        assert_eq!(
+            input_to_url(" rust lang ", search).unwrap(),
+            "https://search.example/?q=rust lang"
+        );
+
+        let no_search = |_: &str| None;
+        assert_eq!(
+            input_to_url(" intranet ", no_search).unwrap(),
+            "https://intranet"
+        );
+        assert_eq!(
+            input_to_url("rust lang", no_search).unwrap_err(),
+            Error::new(E_INVALIDARG)
+        );
+        assert!(input_to_url("  ", no_search).is_err());
+    }
+
+    #[test]
+    fn test_next_zoom_level() {
+        assert_eq!(next_zoom_level(1.0, true), 1.1);
+        assert_eq!(next_zoom_level(1.0, false), 0.9);
+        assert_eq!(next_zoom_level(1.17, true), 1.25);
+        assert_eq!(next_zoom_level(1.17, false), 1.1);
+        assert_eq!(next_zoom_level(5.0, true), 5.0);
+        assert_eq!(next_zoom_level(0.25, false), 0.25);
+    }
+}
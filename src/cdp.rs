@@ -0,0 +1,224 @@
+//! Helpers on top of `CallDevToolsProtocolMethod`, shared by the higher level
+//! wrappers that are implemented with the DevTools protocol, and of scripts
+//! talking back with web messages.
+
+use crate::{Error, EventRegistrationToken, Result, WebView};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+use winapi::shared::winerror::E_FAIL;
+
+/// A script injected into the current document and every document created
+/// afterwards. Returned by `WebView::add_document_script`.
+pub(crate) struct DocumentScript {
+    script_id: Rc<RefCell<ScriptId>>,
+}
+
+/// A `DocumentScript` with a `WebMessageReceived` handler for its messages.
+/// Returned by `WebView::add_message_script`.
+pub(crate) struct MessageScript {
+    script: DocumentScript,
+    token: EventRegistrationToken,
+}
+
+/// The id of a document created script, which is only known once the script
+/// is added.
+#[derive(Debug, Eq, PartialEq)]
+enum ScriptId {
+    Pending,
+    Added(String),
+    /// Removed before it was added, the script is removed once it is.
+    Removed,
+}
+
+impl ScriptId {
+    /// Record the id of the added script. Returns it if the script must be
+    /// removed right away.
+    fn added(&mut self, id: String) -> Option<String> {
+        match self {
+            ScriptId::Removed => Some(id),
+            _ => {
+                *self = ScriptId::Added(id);
+                None
+            }
+        }
+    }
+
+    /// Mark the script as removed. Returns its id if it was already added.
+    fn remove(&mut self) -> Option<String> {
+        match mem::replace(self, ScriptId::Removed) {
+            ScriptId::Added(id) => Some(id),
+            _ => None,
+        }
+    }
+}
+
+impl WebView {
+    /// Call a DevTools protocol method and parse the returned JSON object.
+    pub(crate) fn call_cdp(
+        &self,
+        method_name: &str,
+        parameters: Value,
+        callback: impl FnOnce(Value) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.call_dev_tools_protocol_method(method_name, &parameters.to_string(), move |result| {
+            let result = serde_json::from_str(&result).map_err(|_| Error::new(E_FAIL))?;
+            callback(result)
+        })
+    }
+
+    /// `call_cdp` in a session of a target attached with `flatten: true`.
+    #[cfg(feature = "sdk-1.0.1185")]
+    pub(crate) fn call_cdp_for_session(
+        &self,
+        session_id: &str,
+        method_name: &str,
+        parameters: Value,
+        callback: impl FnOnce(Value) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = parameters.to_string();
+        self.call_dev_tools_protocol_method_for_session(
+            session_id,
+            method_name,
+            &parameters,
+            move |result| {
+                let result = serde_json::from_str(&result).map_err(|_| Error::new(E_FAIL))?;
+                callback(result)
+            },
+        )
+    }
+
+    /// Evaluate `expression` in the top level document with `Runtime.evaluate`.
+    ///
+    /// Promises are awaited and the result is returned by value. The
+    /// evaluation is treated as if it was initiated by a user gesture. An
+    /// exception thrown by the script fails with `E_FAIL`.
+    pub(crate) fn evaluate(
+        &self,
+        expression: &str,
+        callback: impl FnOnce(Value) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = json!({
+            "expression": expression,
+            "awaitPromise": true,
+            "returnByValue": true,
+            "userGesture": true,
+        });
+        self.call_cdp("Runtime.evaluate", parameters, move |response| {
+            if response.get("exceptionDetails").is_some() {
+                return Err(Error::new(E_FAIL));
+            }
+            callback(response["result"]["value"].clone())
+        })
+    }
+
+    /// Run `script` in the current document and every document created
+    /// afterwards.
+    pub(crate) fn add_document_script(&self, script: &str) -> Result<DocumentScript> {
+        let script_id = Rc::new(RefCell::new(ScriptId::Pending));
+        {
+            let script_id = script_id.clone();
+            let webview = self.clone();
+            self.add_script_to_execute_on_document_created(script, move |id| {
+                // `remove_message_script` may be called before the id is known.
+                let removed = script_id.borrow_mut().added(id);
+                if let Some(id) = removed {
+                    webview.remove_script_to_execute_on_document_created(&id)?;
+                }
+                Ok(())
+            })?;
+        }
+        self.execute_script(script, |_| Ok(()))?;
+        Ok(DocumentScript { script_id })
+    }
+
+    /// Remove the script from documents created afterwards. It stays in the
+    /// current document until the next navigation.
+    pub(crate) fn remove_document_script(&self, script: DocumentScript) -> Result<()> {
+        // Not borrowed while calling into the webview, which may run the id
+        // callback.
+        let id = script.script_id.borrow_mut().remove();
+        if let Some(id) = id {
+            self.remove_script_to_execute_on_document_created(&id)?;
+        }
+        Ok(())
+    }
+
+    /// `add_document_script`, and call `handler` with the web messages the
+    /// script posts, i.e. JSON objects with a `type` of `message_type`. Other
+    /// messages are ignored.
+    pub(crate) fn add_message_script(
+        &self,
+        script: &str,
+        message_type: &'static str,
+        handler: impl Fn(WebView, Value) -> Result<()> + 'static,
+    ) -> Result<MessageScript> {
+        let token = self.add_web_message_received(move |w, args| {
+            match parse_message(&args.get_web_message_as_json()?, message_type) {
+                Some(message) => handler(w, message),
+                None => Ok(()),
+            }
+        })?;
+        let script = match self.add_document_script(script) {
+            Ok(script) => script,
+            Err(e) => {
+                let _ = self.remove_web_message_received(token);
+                return Err(e);
+            }
+        };
+        Ok(MessageScript { script, token })
+    }
+
+    /// Stop handling the messages and `remove_document_script`.
+    pub(crate) fn remove_message_script(&self, script: MessageScript) -> Result<()> {
+        self.remove_web_message_received(script.token)?;
+        self.remove_document_script(script.script)
+    }
+}
+
+/// The object of a JSON web message, if its `type` is `message_type`.
+fn parse_message(json: &str, message_type: &str) -> Option<Value> {
+    let message: Value = serde_json::from_str(json).ok()?;
+    if message["type"] == message_type {
+        Some(message)
+    } else {
+        None
+    }
+}
+
+/// Quote `s` as a JavaScript string literal.
+pub(crate) fn js_string(s: &str) -> String {
+    Value::from(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_message() {
+        assert_eq!(
+            parse_message(r#"{"type":"a","x":1}"#, "a"),
+            Some(json!({"type": "a", "x": 1}))
+        );
+        assert_eq!(parse_message(r#"{"type":"b"}"#, "a"), None);
+        assert_eq!(parse_message(r#""a""#, "a"), None);
+        assert_eq!(parse_message("not json", "a"), None);
+    }
+
+    #[test]
+    fn test_script_id() {
+        let mut id = ScriptId::Pending;
+        assert_eq!(id.added("1".into()), None);
+        assert_eq!(id, ScriptId::Added("1".into()));
+        assert_eq!(id.remove(), Some("1".into()));
+        assert_eq!(id.remove(), None);
+
+        // Removed before the id is known.
+        let mut id = ScriptId::Pending;
+        assert_eq!(id.remove(), None);
+        assert_eq!(id.added("2".into()), Some("2".into()));
+        assert_eq!(id, ScriptId::Removed);
+    }
+}
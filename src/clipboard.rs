@@ -0,0 +1,126 @@
+//! Clipboard access for web content.
+//!
+//! Reading the clipboard from a page (`navigator.clipboard.read`) raises a
+//! `CLIPBOARD_READ` permission request. The helpers here grant it for a set of
+//! trusted origins, and move clipboard contents of arbitrary formats between
+//! the host and the page with the DevTools protocol.
+
+use crate::cdp::js_string;
+use crate::text::origin_of;
+use crate::{EventRegistrationToken, PermissionKind, PermissionState, Result, WebView};
+use serde_json::json;
+
+impl WebView {
+    /// Automatically allow clipboard reads for pages whose origin is one of
+    /// `origins`, e.g. `https://example.com`.
+    ///
+    /// Other permission requests are left alone. Use
+    /// `remove_permission_requested` with the returned token to stop.
+    pub fn allow_clipboard_read(&self, origins: &[&str]) -> Result<EventRegistrationToken> {
+        let origins: Vec<String> = origins
+            .iter()
+            .map(|o| o.trim_end_matches('/').to_ascii_lowercase())
+            .collect();
+        self.add_permission_requested(move |_, args| {
+            if !matches!(
+                args.get_permission_kind()?,
+                PermissionKind::CORE_WEBVIEW2_PERMISSION_KIND_CLIPBOARD_READ
+            ) {
+                return Ok(());
+            }
+            let uri = args.get_uri()?;
+            let origin = origin_of(&uri).to_ascii_lowercase();
+            if origins.iter().any(|o| *o == origin) {
+                args.put_state(PermissionState::CORE_WEBVIEW2_PERMISSION_STATE_ALLOW)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Grant clipboard read and write access to `origin` through the DevTools
+    /// protocol (`Browser.grantPermissions`), so that no permission request is
+    /// raised at all.
+    pub fn grant_clipboard_permissions(
+        &self,
+        origin: &str,
+        callback: impl FnOnce() -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = json!({
+            "permissions": ["clipboardReadWrite", "clipboardSanitizedWrite"],
+            "origin": origin,
+        });
+        self.call_cdp("Browser.grantPermissions", parameters, move |_| callback())
+    }
+
+    /// Put `data` on the clipboard as `format` from within the page.
+    ///
+    /// `text/plain`, `text/html` and `image/svg+xml` are written as is. Any
+    /// other format is written as a web custom format (`web <format>`), which
+    /// pages can read back with `navigator.clipboard.read`.
+    ///
+    /// The document must have focus for the browser to accept the write.
+    pub fn write_clipboard(
+        &self,
+        format: &str,
+        data: &str,
+        callback: impl FnOnce() -> Result<()> + 'static,
+    ) -> Result<()> {
+        let script = format!(
+            "(async () => {{
+                const type = {};
+                await navigator.clipboard.write([
+                    new ClipboardItem({{ [type]: new Blob([{}], {{ type }}) }})
+                ]);
+            }})()",
+            js_string(&clipboard_type(format)),
+            js_string(data),
+        );
+        self.evaluate(&script, move |_| callback())
+    }
+
+    /// Read the clipboard contents of `format` from within the page.
+    ///
+    /// Formats are mapped the same way as `write_clipboard`. The callback
+    /// receives `None` if the clipboard holds no data of that format.
+    pub fn read_clipboard(
+        &self,
+        format: &str,
+        callback: impl FnOnce(Option<String>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let script = format!(
+            "(async () => {{
+                const type = {};
+                for (const item of await navigator.clipboard.read()) {{
+                    if (item.types.includes(type)) {{
+                        return await (await item.getType(type)).text();
+                    }}
+                }}
+                return null;
+            }})()",
+            js_string(&clipboard_type(format)),
+        );
+        self.evaluate(&script, move |value| {
+            callback(value.as_str().map(|s| s.to_string()))
+        })
+    }
+}
+
+/// Map a format to the type name used by the async clipboard API.
+fn clipboard_type(format: &str) -> String {
+    match format {
+        "text/plain" | "text/html" | "image/svg+xml" => format.to_string(),
+        _ if format.starts_with("web ") => format.to_string(),
+        _ => format!("web {}", format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clipboard_type() {
+        assert_eq!(clipboard_type("text/plain"), "text/plain");
+        assert_eq!(clipboard_type("application/x-app"), "web application/x-app");
+    }
+}
@@ -0,0 +1,697 @@
+//! Visual hosting with `ICoreWebView2CompositionController`.
+//!
+//! The webview renders into a DirectComposition or `Windows.UI.Composition`
+//! visual of the app rather than a window of its own, so it can be blended
+//! with other content. The app connects the visual with
+//! `put_root_visual_target`, and has to forward input, the cursor and drag
+//! and drop itself, since no window of the webview receives them.
+//!
+//! The controller also implements `ICoreWebView2Host`, see `get_host`, for
+//! the bounds, visibility and the webview.
+//!
+//! For drag and drop, `register_drop_target` registers an `IDropTarget` for
+//! the parent window that forwards to the webview.
+//!
+//! Needs the `sdk-1.0.774` feature, and `sdk-1.0.1245` for
+//! `create_composition_controller_with_options` and `register_drop_target`.
+
+use crate::raw::*;
+#[cfg(feature = "sdk-1.0.1245")]
+use crate::ControllerOptions;
+use crate::{
+    add_ref_to_rc, callback, check_hresult, query_interface, to_hresult, CheckOpen, Environment,
+    Error, EventRegistrationToken, Host, Result,
+};
+#[cfg(feature = "sdk-1.0.1245")]
+use com::com_interface;
+use com::interfaces::iunknown::IUnknownVTable;
+use com::interfaces::IUnknown;
+use com::ComRc;
+use std::cell::{Cell, RefCell};
+use std::ffi::c_void;
+#[cfg(feature = "sdk-1.0.1245")]
+use std::io;
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+#[cfg(feature = "sdk-1.0.1245")]
+use winapi::shared::minwindef::DWORD;
+use winapi::shared::ntdef::HRESULT;
+#[cfg(feature = "sdk-1.0.1245")]
+use winapi::shared::windef::POINTL;
+use winapi::shared::windef::{HCURSOR, HWND, POINT, RECT};
+use winapi::shared::winerror::E_POINTER;
+#[cfg(feature = "sdk-1.0.1245")]
+use winapi::shared::winerror::FAILED;
+#[cfg(feature = "sdk-1.0.1245")]
+use winapi::um::ole2::{RegisterDragDrop, RevokeDragDrop};
+#[cfg(feature = "sdk-1.0.1245")]
+use winapi::um::oleidl::DROPEFFECT_NONE;
+use winapi::um::winuser::*;
+
+/// `ICoreWebView2CompositionController`.
+#[derive(Clone)]
+pub struct CompositionController {
+    inner: ComRc<dyn ICoreWebView2CompositionController>,
+    /// Shared with the host from `get_host` and its webview.
+    closed: Rc<Cell<bool>>,
+}
+
+/// `ICoreWebView2PointerInfo`, see `PointerInfoBuilder`.
+#[derive(Clone)]
+pub struct PointerInfo {
+    inner: ComRc<dyn ICoreWebView2PointerInfo>,
+}
+
+fn composition_controller_completed(
+    completed: impl FnOnce(Result<CompositionController>) -> Result<()> + 'static,
+) -> com::ComPtr<dyn ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler> {
+    let completed = RefCell::new(Some(completed));
+    callback!(
+        ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler,
+        move |result: HRESULT,
+              created: *mut *mut ICoreWebView2CompositionControllerVTable|
+              -> HRESULT {
+            let result = check_hresult(result).map(|_| CompositionController {
+                inner: unsafe { add_ref_to_rc(created) },
+                closed: Rc::new(Cell::new(false)),
+            });
+            to_hresult(completed.borrow_mut().take().unwrap()(result))
+        }
+    )
+}
+
+impl Environment {
+    /// Like `create_host`, but for visual hosting. `parent_window` still
+    /// receives the window messages the webview needs, e.g. for accessibility.
+    /// Fails with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2Environment3`.
+    #[allow(clippy::useless_transmute)]
+    pub fn create_composition_controller(
+        &self,
+        parent_window: HWND,
+        completed: impl FnOnce(Result<CompositionController>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let environment3: ComRc<dyn ICoreWebView2Environment3> = query_interface(&self.inner)?;
+        let completed = composition_controller_completed(completed);
+        check_hresult(unsafe {
+            environment3.create_core_web_view2_composition_controller(
+                mem::transmute(parent_window),
+                completed.as_raw(),
+            )
+        })
+    }
+
+    /// `create_composition_controller` with a profile, see
+    /// `create_host_with_options`.
+    #[cfg(feature = "sdk-1.0.1245")]
+    #[allow(clippy::useless_transmute)]
+    pub fn create_composition_controller_with_options(
+        &self,
+        parent_window: HWND,
+        options: &ControllerOptions,
+        completed: impl FnOnce(Result<CompositionController>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let environment10: ComRc<dyn ICoreWebView2Environment10> = query_interface(&self.inner)?;
+        let completed = composition_controller_completed(completed);
+        check_hresult(unsafe {
+            environment10.create_core_web_view2_composition_controller_with_options(
+                mem::transmute(parent_window),
+                options.inner.as_raw(),
+                completed.as_raw(),
+            )
+        })
+    }
+
+    /// An empty `PointerInfo`, usually filled by `PointerInfoBuilder::build`.
+    /// Fails with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2Environment3`.
+    pub fn create_pointer_info(&self) -> Result<PointerInfo> {
+        let environment3: ComRc<dyn ICoreWebView2Environment3> = query_interface(&self.inner)?;
+        let mut info: *mut *mut ICoreWebView2PointerInfoVTable = ptr::null_mut();
+        check_hresult(unsafe { environment3.create_core_web_view2_pointer_info(&mut info) })?;
+        Ok(PointerInfo {
+            inner: unsafe { ComRc::from_raw(info) },
+        })
+    }
+}
+
+impl CompositionController {
+    /// The `ICoreWebView2Host` of the controller. Closing it makes the
+    /// methods of the controller fail with `Error::Closed` too.
+    pub fn get_host(&self) -> Result<Host> {
+        self.check_open()?;
+        Ok(Host::with_closed(
+            query_interface(&self.inner)?,
+            self.closed.clone(),
+        ))
+    }
+
+    /// The visual the webview is rendered into, `None` if not set.
+    pub fn get_root_visual_target(&self) -> Result<Option<ComRc<dyn IUnknown>>> {
+        self.check_open()?;
+        let mut target: *mut *mut IUnknownVTable = ptr::null_mut();
+        check_hresult(unsafe { self.inner.get_root_visual_target(&mut target) })?;
+        if target.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(unsafe { ComRc::from_raw(target) }))
+        }
+    }
+
+    /// Render into `target`, an `IDCompositionVisual` or a
+    /// `Windows.UI.Composition.ContainerVisual`, or nothing with `None`.
+    pub fn put_root_visual_target(&self, target: Option<&ComRc<dyn IUnknown>>) -> Result<()> {
+        self.check_open()?;
+        let target = target.map_or(ptr::null_mut(), |t| t.as_raw());
+        check_hresult(unsafe { self.inner.put_root_visual_target(target) })
+    }
+
+    /// `put_root_visual_target` with a pointer from other COM bindings, e.g.
+    /// the `as_raw` of a windows-rs `ContainerVisual`. It is `AddRef`ed by
+    /// the webview.
+    ///
+    /// ```no_run
+    /// # fn f(
+    /// #     controller: &webview2::composition::CompositionController,
+    /// #     visual: *mut std::ffi::c_void,
+    /// # ) -> webview2::Result<()> {
+    /// // With windows-rs: `let visual = Interface::as_raw(&container_visual);`
+    /// unsafe { controller.put_root_visual_target_raw(visual) }
+    /// # }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `target` must be an `IUnknown` pointer of a visual.
+    pub unsafe fn put_root_visual_target_raw(&self, target: *mut c_void) -> Result<()> {
+        self.check_open()?;
+        if target.is_null() {
+            return Err(Error::new(E_POINTER));
+        }
+        check_hresult(self.inner.put_root_visual_target(target as _))
+    }
+
+    /// Forward a mouse message of the parent window. `kind` is the message,
+    /// one of `COREWEBVIEW2_MOUSE_EVENT_KIND_*`, e.g. `WM_MOUSEMOVE`, and
+    /// `virtual_keys` the `MK_*` flags of its `wParam`. `mouse_data` is the
+    /// wheel delta for the wheel messages and the X button for the X button
+    /// messages, 0 otherwise. `point` is relative to the webview, i.e. the
+    /// client coordinates minus the top left of the bounds.
+    ///
+    /// Send `WM_MOUSELEAVE` with zeros when the mouse leaves the webview, and
+    /// capture the mouse while a button is down.
+    #[allow(clippy::useless_transmute)]
+    pub fn send_mouse_input(
+        &self,
+        kind: u32,
+        virtual_keys: u32,
+        mouse_data: u32,
+        point: POINT,
+    ) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe {
+            self.inner
+                .send_mouse_input(kind, virtual_keys, mouse_data, mem::transmute(point))
+        })
+    }
+
+    /// Forward touch or pen input. `kind` is the message, one of
+    /// `COREWEBVIEW2_POINTER_EVENT_KIND_*`, e.g. `WM_POINTERDOWN`.
+    pub fn send_pointer_input(&self, kind: u32, info: &PointerInfo) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.send_pointer_input(kind, info.inner.as_raw()) })
+    }
+
+    /// The cursor for the mouse position of the last `send_mouse_input`.
+    /// Set it in `WM_SETCURSOR` of the parent window. It can be destroyed by
+    /// the webview later, so copy it with `CopyCursor` to keep it.
+    #[allow(clippy::useless_transmute)]
+    pub fn get_cursor(&self) -> Result<HCURSOR> {
+        self.check_open()?;
+        let mut cursor = mem::MaybeUninit::uninit();
+        check_hresult(unsafe { self.inner.get_cursor(cursor.as_mut_ptr()) })?;
+        Ok(unsafe { mem::transmute(cursor.assume_init()) })
+    }
+
+    /// The standard cursor the cursor is, `None` for a custom CSS cursor.
+    pub fn get_system_cursor_id(&self) -> Result<Option<SystemCursorId>> {
+        self.check_open()?;
+        let mut id = 0;
+        check_hresult(unsafe { self.inner.get_system_cursor_id(&mut id) })?;
+        Ok(SystemCursorId::from_id(id))
+    }
+
+    /// Called when the cursor changes, e.g. from the arrow to the I-beam
+    /// over text. Send `WM_MOUSELEAVE` with `send_mouse_input` too, or else
+    /// it is called after the mouse left the webview.
+    pub fn add_cursor_changed(
+        &self,
+        handler: impl Fn(CompositionController) -> Result<()> + 'static,
+    ) -> Result<EventRegistrationToken> {
+        self.check_open()?;
+        let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
+
+        let closed = self.closed.clone();
+        let handler = callback!(
+            ICoreWebView2CursorChangedEventHandler,
+            move |sender: *mut *mut ICoreWebView2CompositionControllerVTable,
+                  _args: *mut *mut IUnknownVTable|
+                  -> HRESULT {
+                let sender = CompositionController {
+                    inner: unsafe { add_ref_to_rc(sender) },
+                    closed: closed.clone(),
+                };
+                to_hresult(handler(sender))
+            }
+        );
+
+        check_hresult(unsafe { self.inner.add_cursor_changed(handler.as_raw(), &mut token) })?;
+        Ok(token)
+    }
+
+    pub fn remove_cursor_changed(&self, token: EventRegistrationToken) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.remove_cursor_changed(token) })
+    }
+
+    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2CompositionController> {
+        &self.inner
+    }
+}
+
+impl CheckOpen for CompositionController {
+    fn check_open(&self) -> Result<()> {
+        if self.closed.get() {
+            Err(Error::Closed)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A standard cursor, by its `IDC_*` resource id.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SystemCursorId {
+    Arrow,
+    IBeam,
+    Wait,
+    Cross,
+    UpArrow,
+    SizeNwse,
+    SizeNesw,
+    SizeWe,
+    SizeNs,
+    SizeAll,
+    No,
+    Hand,
+    AppStarting,
+    Help,
+    /// Another id, e.g. of a cursor added in a later Windows.
+    Other(u32),
+}
+
+impl SystemCursorId {
+    /// `None` for 0, which is not a cursor.
+    pub fn from_id(id: u32) -> Option<Self> {
+        let cursor = match id as usize {
+            0 => return None,
+            c if c == IDC_ARROW as usize => Self::Arrow,
+            c if c == IDC_IBEAM as usize => Self::IBeam,
+            c if c == IDC_WAIT as usize => Self::Wait,
+            c if c == IDC_CROSS as usize => Self::Cross,
+            c if c == IDC_UPARROW as usize => Self::UpArrow,
+            c if c == IDC_SIZENWSE as usize => Self::SizeNwse,
+            c if c == IDC_SIZENESW as usize => Self::SizeNesw,
+            c if c == IDC_SIZEWE as usize => Self::SizeWe,
+            c if c == IDC_SIZENS as usize => Self::SizeNs,
+            c if c == IDC_SIZEALL as usize => Self::SizeAll,
+            c if c == IDC_NO as usize => Self::No,
+            c if c == IDC_HAND as usize => Self::Hand,
+            c if c == IDC_APPSTARTING as usize => Self::AppStarting,
+            c if c == IDC_HELP as usize => Self::Help,
+            _ => Self::Other(id),
+        };
+        Some(cursor)
+    }
+
+    pub fn id(self) -> u32 {
+        let id = match self {
+            Self::Arrow => IDC_ARROW,
+            Self::IBeam => IDC_IBEAM,
+            Self::Wait => IDC_WAIT,
+            Self::Cross => IDC_CROSS,
+            Self::UpArrow => IDC_UPARROW,
+            Self::SizeNwse => IDC_SIZENWSE,
+            Self::SizeNesw => IDC_SIZENESW,
+            Self::SizeWe => IDC_SIZEWE,
+            Self::SizeNs => IDC_SIZENS,
+            Self::SizeAll => IDC_SIZEALL,
+            Self::No => IDC_NO,
+            Self::Hand => IDC_HAND,
+            Self::AppStarting => IDC_APPSTARTING,
+            Self::Help => IDC_HELP,
+            Self::Other(id) => return id,
+        };
+        id as usize as u32
+    }
+
+    /// The shared cursor, which is never destroyed.
+    pub fn load(self) -> Result<HCURSOR> {
+        let cursor = unsafe { LoadCursorW(ptr::null_mut(), self.id() as usize as _) };
+        if cursor.is_null() {
+            Err(std::io::Error::last_os_error().into())
+        } else {
+            Ok(cursor)
+        }
+    }
+}
+
+impl PointerInfo {
+    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2PointerInfo> {
+        &self.inner
+    }
+}
+
+/// Builds a `PointerInfo` from what `GetPointerInfo`, `GetPointerPenInfo` or
+/// `GetPointerTouchInfo` and `GetPointerDeviceRects` return for a
+/// `WM_POINTER*` message.
+///
+/// The locations have to be relative to the webview like for
+/// `send_mouse_input`, see `with_offset`.
+///
+/// ```no_run
+/// # use winapi::um::winuser::*;
+/// # fn f(
+/// #     environment: &webview2::Environment,
+/// #     controller: &webview2::composition::CompositionController,
+/// #     msg: u32,
+/// #     touch: POINTER_TOUCH_INFO,
+/// # ) -> webview2::Result<()> {
+/// use webview2::composition::PointerInfoBuilder;
+///
+/// let bounds = controller.get_host()?.get_bounds()?;
+/// let info = PointerInfoBuilder::new(touch.pointerInfo)
+///     .with_touch_info(touch)
+///     .with_offset(-bounds.left, -bounds.top)
+///     .build(environment)?;
+/// controller.send_pointer_input(msg, &info)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct PointerInfoBuilder {
+    info: POINTER_INFO,
+    device_rect: RECT,
+    display_rect: RECT,
+    pen: Option<POINTER_PEN_INFO>,
+    touch: Option<POINTER_TOUCH_INFO>,
+}
+
+impl PointerInfoBuilder {
+    pub fn new(info: POINTER_INFO) -> Self {
+        let empty = RECT {
+            left: 0,
+            top: 0,
+            right: 0,
+            bottom: 0,
+        };
+        Self {
+            info,
+            device_rect: empty,
+            display_rect: empty,
+            pen: None,
+            touch: None,
+        }
+    }
+
+    /// For `PT_PEN` pointers.
+    pub fn with_pen_info(self, pen: POINTER_PEN_INFO) -> Self {
+        Self {
+            pen: Some(pen),
+            ..self
+        }
+    }
+
+    /// For `PT_TOUCH` pointers.
+    pub fn with_touch_info(self, touch: POINTER_TOUCH_INFO) -> Self {
+        Self {
+            touch: Some(touch),
+            ..self
+        }
+    }
+
+    /// From `GetPointerDeviceRects`, for the himetric locations.
+    pub fn with_device_rects(self, device_rect: RECT, display_rect: RECT) -> Self {
+        Self {
+            device_rect,
+            display_rect,
+            ..self
+        }
+    }
+
+    /// Move the pixel locations and the touch contact, e.g. from the client
+    /// area of the parent window to the webview.
+    pub fn with_offset(mut self, x: i32, y: i32) -> Self {
+        fn offset_point(p: &mut POINT, x: i32, y: i32) {
+            p.x += x;
+            p.y += y;
+        }
+        fn offset_rect(r: &mut RECT, x: i32, y: i32) {
+            r.left += x;
+            r.top += y;
+            r.right += x;
+            r.bottom += y;
+        }
+        offset_point(&mut self.info.ptPixelLocation, x, y);
+        offset_point(&mut self.info.ptPixelLocationRaw, x, y);
+        if let Some(touch) = &mut self.touch {
+            offset_rect(&mut touch.rcContact, x, y);
+            offset_rect(&mut touch.rcContactRaw, x, y);
+        }
+        self
+    }
+
+    #[allow(clippy::useless_transmute)]
+    pub fn build(self, environment: &Environment) -> Result<PointerInfo> {
+        let result = environment.create_pointer_info()?;
+        let p = &result.inner;
+        let info = &self.info;
+        unsafe {
+            check_hresult(p.put_pointer_kind(info.pointerType))?;
+            check_hresult(p.put_pointer_id(info.pointerId))?;
+            check_hresult(p.put_frame_id(info.frameId))?;
+            check_hresult(p.put_pointer_flags(info.pointerFlags))?;
+            check_hresult(p.put_pointer_device_rect(mem::transmute(self.device_rect)))?;
+            check_hresult(p.put_display_rect(mem::transmute(self.display_rect)))?;
+            check_hresult(p.put_pixel_location(mem::transmute(info.ptPixelLocation)))?;
+            check_hresult(p.put_himetric_location(mem::transmute(info.ptHimetricLocation)))?;
+            check_hresult(p.put_pixel_location_raw(mem::transmute(info.ptPixelLocationRaw)))?;
+            check_hresult(p.put_himetric_location_raw(mem::transmute(info.ptHimetricLocationRaw)))?;
+            check_hresult(p.put_time(info.dwTime))?;
+            check_hresult(p.put_history_count(info.historyCount))?;
+            check_hresult(p.put_input_data(info.InputData))?;
+            check_hresult(p.put_key_states(info.dwKeyStates))?;
+            check_hresult(p.put_performance_count(info.PerformanceCount))?;
+            check_hresult(p.put_button_change_kind(info.ButtonChangeType as i32))?;
+            if let Some(pen) = &self.pen {
+                check_hresult(p.put_pen_flags(pen.penFlags))?;
+                check_hresult(p.put_pen_mask(pen.penMask))?;
+                check_hresult(p.put_pen_pressure(pen.pressure))?;
+                check_hresult(p.put_pen_rotation(pen.rotation))?;
+                check_hresult(p.put_pen_tilt_x(pen.tiltX))?;
+                check_hresult(p.put_pen_tilt_y(pen.tiltY))?;
+            }
+            if let Some(touch) = &self.touch {
+                check_hresult(p.put_touch_flags(touch.touchFlags))?;
+                check_hresult(p.put_touch_mask(touch.touchMask))?;
+                check_hresult(p.put_touch_contact(mem::transmute(touch.rcContact)))?;
+                check_hresult(p.put_touch_contact_raw(mem::transmute(touch.rcContactRaw)))?;
+                check_hresult(p.put_touch_orientation(touch.orientation))?;
+                check_hresult(p.put_touch_pressure(touch.pressure))?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// `IDropTarget`, which OLE calls for drags over a window. `data_object` is
+/// an `IDataObject`.
+#[cfg(feature = "sdk-1.0.1245")]
+#[com_interface("00000122-0000-0000-C000-000000000046")]
+pub trait IDropTarget: IUnknown {
+    unsafe fn drag_enter(
+        &self,
+        data_object: *mut *mut IUnknownVTable,
+        key_state: DWORD,
+        point: POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT;
+    unsafe fn drag_over(&self, key_state: DWORD, point: POINTL, effect: *mut DWORD) -> HRESULT;
+    unsafe fn drag_leave(&self) -> HRESULT;
+    unsafe fn drop(
+        &self,
+        data_object: *mut *mut IUnknownVTable,
+        key_state: DWORD,
+        point: POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT;
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+#[com::co_class(implements(IDropTarget))]
+struct DropTarget {
+    controller: ComRc<dyn ICoreWebView2CompositionController3>,
+    host: Host,
+    window: HWND,
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+impl DropTarget {
+    // It is never used.
+    fn new() -> Box<Self> {
+        unreachable!()
+    }
+
+    /// From the screen to the webview, i.e. the client area of the window
+    /// minus the top left of the bounds, like `send_mouse_input`.
+    fn to_webview(&self, point: POINTL) -> Result<POINT> {
+        let mut point = POINT {
+            x: point.x,
+            y: point.y,
+        };
+        if unsafe { ScreenToClient(self.window, &mut point) } == 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        let bounds = self.host.get_bounds()?;
+        Ok(POINT {
+            x: point.x - bounds.left,
+            y: point.y - bounds.top,
+        })
+    }
+}
+
+/// Set `effect` to `DROPEFFECT_NONE` if `hresult` is an error, so that the
+/// drag source doesn't act on whatever was left in it.
+#[cfg(feature = "sdk-1.0.1245")]
+unsafe fn none_on_error(hresult: HRESULT, effect: *mut DWORD) -> HRESULT {
+    if FAILED(hresult) && !effect.is_null() {
+        *effect = DROPEFFECT_NONE;
+    }
+    hresult
+}
+
+// `POINT` in `raw` is the type of windows-sys with the `windows-sys` feature.
+#[cfg(feature = "sdk-1.0.1245")]
+#[allow(clippy::useless_transmute)]
+impl IDropTarget for DropTarget {
+    unsafe fn drag_enter(
+        &self,
+        data_object: *mut *mut IUnknownVTable,
+        key_state: DWORD,
+        point: POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let hresult = match self.to_webview(point) {
+            Ok(point) => {
+                self.controller
+                    .drag_enter(data_object, key_state, mem::transmute(point), effect)
+            }
+            Err(e) => to_hresult::<()>(Err(e)),
+        };
+        none_on_error(hresult, effect)
+    }
+
+    unsafe fn drag_over(&self, key_state: DWORD, point: POINTL, effect: *mut DWORD) -> HRESULT {
+        let hresult = match self.to_webview(point) {
+            Ok(point) => self
+                .controller
+                .drag_over(key_state, mem::transmute(point), effect),
+            Err(e) => to_hresult::<()>(Err(e)),
+        };
+        none_on_error(hresult, effect)
+    }
+
+    unsafe fn drag_leave(&self) -> HRESULT {
+        self.controller.drag_leave()
+    }
+
+    unsafe fn drop(
+        &self,
+        data_object: *mut *mut IUnknownVTable,
+        key_state: DWORD,
+        point: POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let hresult = match self.to_webview(point) {
+            // Not `self.controller.drop`, which would be `Drop::drop` of the
+            // `ComRc`.
+            Ok(point) => ICoreWebView2CompositionController3::drop(
+                &*self.controller,
+                data_object,
+                key_state,
+                mem::transmute(point),
+                effect,
+            ),
+            Err(e) => to_hresult::<()>(Err(e)),
+        };
+        none_on_error(hresult, effect)
+    }
+}
+
+/// Returned by `CompositionController::register_drop_target`. Revokes the
+/// drop target when dropped, which has to happen before the window is
+/// destroyed.
+#[cfg(feature = "sdk-1.0.1245")]
+pub struct DropTargetRegistration {
+    window: HWND,
+    _target: ComRc<dyn IDropTarget>,
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+impl Drop for DropTargetRegistration {
+    fn drop(&mut self) {
+        unsafe { RevokeDragDrop(self.window) };
+    }
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+impl CompositionController {
+    /// Register an `IDropTarget` for `parent_window`, the window of the
+    /// visual, that forwards drags over it to the webview, with
+    /// `RegisterDragDrop`. OLE has to be initialized on the thread with
+    /// `OleInitialize`, and the window must not have a drop target yet.
+    /// Fails with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2CompositionController3`.
+    pub fn register_drop_target(&self, parent_window: HWND) -> Result<DropTargetRegistration> {
+        self.check_open()?;
+        let controller: ComRc<dyn ICoreWebView2CompositionController3> =
+            query_interface(&self.inner)?;
+        let target = DropTarget::allocate(controller, self.get_host()?, parent_window);
+        let target = unsafe {
+            target.add_ref();
+            ComRc::<dyn IDropTarget>::from_raw(Box::into_raw(target) as *mut *mut IDropTargetVTable)
+        };
+        check_hresult(unsafe { RegisterDragDrop(parent_window, target.as_raw() as _) })?;
+        Ok(DropTargetRegistration {
+            window: parent_window,
+            _target: target,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_cursor_id() {
+        assert_eq!(SystemCursorId::from_id(0), None);
+        assert_eq!(SystemCursorId::from_id(32513), Some(SystemCursorId::IBeam));
+        assert_eq!(SystemCursorId::from_id(32649), Some(SystemCursorId::Hand));
+        assert_eq!(SystemCursorId::from_id(1), Some(SystemCursorId::Other(1)));
+        for id in 32512..32700 {
+            assert_eq!(SystemCursorId::from_id(id).unwrap().id(), id);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! Context menus with only some of the built-in items.
+//!
+//! This SDK version can only turn the built-in context menu on or off
+//! (`AreDefaultContextMenusEnabled`). `Host::context_menu_policy` turns it off
+//! and shows a native popup menu with the kept items instead, when the page
+//! doesn't handle the `contextmenu` event itself.
+//!
+//! The native menu is only an approximation of the built-in one: its labels
+//! are in English whatever the locale, and Cut and Copy run
+//! `document.execCommand`, which the page may refuse without a user
+//! activation, e.g. when the right click didn't count as one.
+
+use crate::cdp::{js_string, MessageScript};
+use crate::dispatcher::Dispatcher;
+use crate::{Host, Result, WebView};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ptr;
+use std::rc::Rc;
+use widestring::{WideCStr, WideCString};
+use winapi::shared::windef::{HMENU, HWND, POINT};
+use winapi::um::winbase::{GlobalLock, GlobalUnlock};
+use winapi::um::winuser::*;
+
+/// Message type used by the injected `contextmenu` listener.
+const CONTEXT_MENU_MESSAGE_TYPE: &str = "webview2-context-menu";
+
+/// A built-in context menu item.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ContextMenuItem {
+    Back,
+    Forward,
+    Reload,
+    Cut,
+    Copy,
+    Paste,
+    SelectAll,
+    Print,
+    /// Opens the DevTools window.
+    Inspect,
+}
+
+/// In menu order. Items of different groups are separated.
+const ALL_ITEMS: &[(ContextMenuItem, &str, u8)] = &[
+    (ContextMenuItem::Back, "Back", 0),
+    (ContextMenuItem::Forward, "Forward", 0),
+    (ContextMenuItem::Reload, "Reload", 0),
+    (ContextMenuItem::Cut, "Cut", 1),
+    (ContextMenuItem::Copy, "Copy", 1),
+    (ContextMenuItem::Paste, "Paste", 1),
+    (ContextMenuItem::SelectAll, "Select all", 1),
+    (ContextMenuItem::Print, "Print...", 2),
+    (ContextMenuItem::Inspect, "Inspect", 3),
+];
+
+/// An entry of the native menu.
+#[derive(Debug, Eq, PartialEq)]
+enum MenuEntry {
+    Separator,
+    /// With its index in `ALL_ITEMS`.
+    Item(usize, ContextMenuItem, &'static str),
+}
+
+/// The entries of a menu with `items`, in menu order.
+fn menu_entries(items: &[ContextMenuItem]) -> Vec<MenuEntry> {
+    let mut entries = Vec::new();
+    let mut last_group = None;
+    for (i, &(item, label, group)) in ALL_ITEMS.iter().enumerate() {
+        if !items.contains(&item) {
+            continue;
+        }
+        if last_group.map_or(false, |g| g != group) {
+            entries.push(MenuEntry::Separator);
+        }
+        last_group = Some(group);
+        entries.push(MenuEntry::Item(i, item, label));
+    }
+    entries
+}
+
+/// Which built-in context menu items to show.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Keep {
+    /// The built-in context menu.
+    All,
+    /// No context menu.
+    Nothing,
+    /// A native menu with these items, in the usual order.
+    Only(Vec<ContextMenuItem>),
+}
+
+impl Keep {
+    pub fn only(items: &[ContextMenuItem]) -> Self {
+        Keep::Only(items.to_vec())
+    }
+}
+
+/// Returned by `Host::context_menu_policy`.
+pub struct ContextMenuPolicyToken {
+    script: Option<MessageScript>,
+}
+
+/// What was right clicked, as reported by the page.
+struct Target {
+    editable: bool,
+    selection: bool,
+}
+
+/// A menu to show once the `WebMessageReceived` handler has returned.
+struct PendingMenu {
+    window: HWND,
+    webview: WebView,
+    items: Rc<Vec<ContextMenuItem>>,
+    target: Target,
+}
+
+thread_local! {
+    /// In the order the menus were posted to the dispatcher.
+    static PENDING_MENUS: RefCell<VecDeque<PendingMenu>> = RefCell::new(VecDeque::new());
+}
+
+impl Host {
+    /// Restrict the context menu of the webview to `keep`.
+    ///
+    /// For `Keep::Only` a script is injected into every document, web
+    /// messages must be enabled, and other `WebMessageReceived` handlers see
+    /// `{"type":"webview2-context-menu",...}` messages. The menu is shown at
+    /// the mouse cursor, posted from the message handler to the message loop
+    /// of the current thread, as `TrackPopupMenu` runs a nested one.
+    pub fn context_menu_policy(&self, keep: Keep) -> Result<ContextMenuPolicyToken> {
+        let webview = self.get_webview()?;
+        let settings = webview.get_settings()?;
+        let items = match keep {
+            Keep::All => {
+                settings.put_are_default_context_menus_enabled(true)?;
+                return Ok(ContextMenuPolicyToken { script: None });
+            }
+            Keep::Nothing => Vec::new(),
+            Keep::Only(items) => items,
+        };
+        settings.put_are_default_context_menus_enabled(false)?;
+        if items.is_empty() {
+            return Ok(ContextMenuPolicyToken { script: None });
+        }
+
+        let script = format!(
+            "(() => {{
+                window.addEventListener('contextmenu', e => {{
+                    // The page shows its own menu.
+                    if (e.defaultPrevented) return;
+                    e.preventDefault();
+                    const el = e.target;
+                    const editable = el.isContentEditable ||
+                        ((el instanceof HTMLInputElement || el instanceof HTMLTextAreaElement) &&
+                            !el.readOnly && !el.disabled);
+                    let selection = String(window.getSelection()) !== '';
+                    try {{
+                        selection = selection || (editable && el.selectionStart !== el.selectionEnd);
+                    }} catch (_) {{}}
+                    window.chrome.webview.postMessage({{ type: {}, editable, selection }});
+                }});
+            }})()",
+            js_string(CONTEXT_MENU_MESSAGE_TYPE),
+        );
+
+        let items = Rc::new(items);
+        let dispatcher = Dispatcher::for_current_thread()?;
+        let host = self.clone();
+        let script =
+            webview.add_message_script(&script, CONTEXT_MENU_MESSAGE_TYPE, move |w, message| {
+                let target = Target {
+                    editable: message["editable"] == true,
+                    selection: message["selection"] == true,
+                };
+                let menu = PendingMenu {
+                    window: host.get_parent_window()?,
+                    webview: w,
+                    items: items.clone(),
+                    target,
+                };
+                PENDING_MENUS.with(|m| m.borrow_mut().push_back(menu));
+                dispatcher.post(show_pending_menu)
+            })?;
+        Ok(ContextMenuPolicyToken {
+            script: Some(script),
+        })
+    }
+
+    /// Go back to the built-in context menu. The listener stays in the
+    /// current document until the next navigation, but no menu is shown.
+    pub fn remove_context_menu_policy(&self, token: ContextMenuPolicyToken) -> Result<()> {
+        let webview = self.get_webview()?;
+        if let Some(script) = token.script {
+            webview.remove_message_script(script)?;
+        }
+        webview
+            .get_settings()?
+            .put_are_default_context_menus_enabled(true)
+    }
+}
+
+fn is_enabled(item: ContextMenuItem, webview: &WebView, target: &Target) -> Result<bool> {
+    Ok(match item {
+        ContextMenuItem::Back => webview.get_can_go_back()?,
+        ContextMenuItem::Forward => webview.get_can_go_forward()?,
+        ContextMenuItem::Cut => target.editable && target.selection,
+        ContextMenuItem::Copy => target.selection,
+        ContextMenuItem::Paste => target.editable,
+        ContextMenuItem::Reload
+        | ContextMenuItem::SelectAll
+        | ContextMenuItem::Print
+        | ContextMenuItem::Inspect => true,
+    })
+}
+
+fn show_pending_menu() {
+    if let Some(menu) = PENDING_MENUS.with(|m| m.borrow_mut().pop_front()) {
+        let chosen = show_menu(menu.window, &menu.webview, &menu.items, &menu.target);
+        if let Ok(Some(item)) = chosen {
+            let _ = run_item(menu.window, &menu.webview, item);
+        }
+    }
+}
+
+/// A popup menu that is destroyed on drop.
+struct Menu(HMENU);
+
+impl Drop for Menu {
+    fn drop(&mut self) {
+        unsafe {
+            DestroyMenu(self.0);
+        }
+    }
+}
+
+/// Show the menu at the cursor and return the chosen item.
+fn show_menu(
+    window: HWND,
+    webview: &WebView,
+    items: &[ContextMenuItem],
+    target: &Target,
+) -> Result<Option<ContextMenuItem>> {
+    unsafe {
+        let menu = CreatePopupMenu();
+        if menu.is_null() {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let menu = Menu(menu);
+        for entry in menu_entries(items) {
+            match entry {
+                MenuEntry::Separator => {
+                    AppendMenuW(menu.0, MF_SEPARATOR, 0, ptr::null());
+                }
+                MenuEntry::Item(i, item, label) => {
+                    let mut flags = MF_STRING;
+                    if !is_enabled(item, webview, target)? {
+                        flags |= MF_GRAYED;
+                    }
+                    let label = WideCString::from_str(label)?;
+                    // Ids start at 1, 0 means nothing was chosen.
+                    AppendMenuW(menu.0, flags, i + 1, label.as_ptr());
+                }
+            }
+        }
+
+        let mut cursor = POINT { x: 0, y: 0 };
+        GetCursorPos(&mut cursor);
+        let chosen = TrackPopupMenu(
+            menu.0,
+            TPM_RETURNCMD | TPM_RIGHTBUTTON,
+            cursor.x,
+            cursor.y,
+            0,
+            window,
+            ptr::null(),
+        );
+        Ok(if chosen > 0 {
+            Some(ALL_ITEMS[chosen as usize - 1].0)
+        } else {
+            None
+        })
+    }
+}
+
+fn run_item(window: HWND, webview: &WebView, item: ContextMenuItem) -> Result<()> {
+    let command = |command: &str| {
+        webview.execute_script(
+            &format!("document.execCommand({})", js_string(command)),
+            |_| Ok(()),
+        )
+    };
+    match item {
+        ContextMenuItem::Back => webview.go_back(),
+        ContextMenuItem::Forward => webview.go_forward(),
+        ContextMenuItem::Reload => webview.reload(),
+        ContextMenuItem::Cut => command("cut"),
+        ContextMenuItem::Copy => command("copy"),
+        ContextMenuItem::SelectAll => command("selectAll"),
+        // Pages can't read the clipboard with `execCommand`, insert it as if
+        // it was typed.
+        ContextMenuItem::Paste => match clipboard_text(window) {
+            Some(text) => webview.execute_script(
+                &format!(
+                    "document.execCommand('insertText', false, {})",
+                    js_string(&text)
+                ),
+                |_| Ok(()),
+            ),
+            None => Ok(()),
+        },
+        ContextMenuItem::Print => webview.execute_script("window.print()", |_| Ok(())),
+        ContextMenuItem::Inspect => webview.open_dev_tools_window(),
+    }
+}
+
+fn clipboard_text(window: HWND) -> Option<String> {
+    unsafe {
+        if OpenClipboard(window) == 0 {
+            return None;
+        }
+        let data = GetClipboardData(CF_UNICODETEXT);
+        let text = if data.is_null() {
+            None
+        } else {
+            let p = GlobalLock(data) as *const u16;
+            let text = if p.is_null() {
+                None
+            } else {
+                Some(WideCStr::from_ptr_str(p).to_string_lossy())
+            };
+            GlobalUnlock(data);
+            text
+        };
+        CloseClipboard();
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_entries() {
+        use ContextMenuItem::*;
+        // Menu order whatever the order given, with separators between
+        // groups only.
+        assert_eq!(
+            menu_entries(&[Inspect, Copy, Back, Paste]),
+            vec![
+                MenuEntry::Item(0, Back, "Back"),
+                MenuEntry::Separator,
+                MenuEntry::Item(4, Copy, "Copy"),
+                MenuEntry::Item(5, Paste, "Paste"),
+                MenuEntry::Separator,
+                MenuEntry::Item(8, Inspect, "Inspect"),
+            ]
+        );
+        assert_eq!(
+            menu_entries(&[Reload, Reload]),
+            vec![MenuEntry::Item(2, Reload, "Reload")]
+        );
+        assert_eq!(menu_entries(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_keep_only() {
+        assert_eq!(
+            Keep::only(&[ContextMenuItem::Copy]),
+            Keep::Only(vec![ContextMenuItem::Copy])
+        );
+    }
+}
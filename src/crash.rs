@@ -0,0 +1,177 @@
+//! Closing webviews cleanly when the host panics.
+//!
+//! When the host process dies with live controllers the browser processes
+//! only notice later, and until they exit they keep the user data folder
+//! locked and may leave it half written. `install_panic_hook` closes the
+//! controllers registered with `Host::close_on_panic` first, and can then
+//! show a message box.
+//!
+//! Controllers are tied to the thread that created them, so only those of the
+//! panicking thread are closed.
+//!
+//! The dialog is a plain message box rather than a page in a new webview.
+//! Webviews are created asynchronously and need the message loop of the
+//! panicking thread, which is unwinding, and a new one would touch the user
+//! data folder that was just released. A message box also works when the
+//! panic comes from the webview runtime itself.
+
+use crate::text::fill_template;
+use crate::Host;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic;
+use std::ptr;
+use widestring::WideCString;
+use winapi::um::winuser::*;
+
+thread_local! {
+    static HOSTS: RefCell<Vec<(usize, Host)>> = RefCell::new(Vec::new());
+    static NEXT_ID: Cell<usize> = Cell::new(0);
+}
+
+/// A message box to show after a panic.
+#[derive(Debug, Clone)]
+pub struct CrashDialog {
+    /// Title of the message box.
+    pub title: String,
+    /// Text of the message box. `{message}` and `{location}` are replaced
+    /// with the panic message and source location.
+    pub text: String,
+}
+
+impl Default for CrashDialog {
+    fn default() -> Self {
+        Self {
+            title: "Application error".into(),
+            text: "The application has stopped working and needs to be restarted.\n\n\
+                   {message}\n{location}"
+                .into(),
+        }
+    }
+}
+
+impl CrashDialog {
+    fn render(&self, message: &str, location: &str) -> String {
+        fill_template(&self.text, &[("message", message), ("location", location)])
+    }
+}
+
+/// Returned by `Host::close_on_panic`. The host is no longer closed on panic
+/// when this is dropped.
+pub struct PanicRegistration {
+    id: usize,
+}
+
+impl Drop for PanicRegistration {
+    fn drop(&mut self) {
+        let id = self.id;
+        let _ = HOSTS.try_with(|hosts| {
+            if let Ok(mut hosts) = hosts.try_borrow_mut() {
+                hosts.retain(|(i, _)| *i != id);
+            }
+        });
+    }
+}
+
+impl Host {
+    /// Close this host if the thread panics and `install_panic_hook` is
+    /// installed.
+    pub fn close_on_panic(&self) -> PanicRegistration {
+        let id = NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+        HOSTS.with(|hosts| hosts.borrow_mut().push((id, self.clone())));
+        PanicRegistration { id }
+    }
+}
+
+/// Chain a panic hook that closes the hosts registered on the panicking
+/// thread, and then shows `dialog` if there is one.
+///
+/// The previous hook still runs first, so panics are printed as before. The
+/// dialog is a plain task modal message box: a webview can't be relied on
+/// while the process is failing, with browser processes still shutting down.
+pub fn install_panic_hook(dialog: Option<CrashDialog>) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous(info);
+        close_hosts();
+        if let Some(dialog) = &dialog {
+            let location = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+                .unwrap_or_default();
+            let text = dialog.render(&panic_message(info.payload()), &location);
+            message_box(&dialog.title, &text);
+        }
+    }));
+}
+
+/// The message of `panic!`, which is either a `&str` or a `String`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Unknown error".into()
+    }
+}
+
+fn close_hosts() {
+    // The panic may come from inside `close_on_panic` or a `Drop`, with the
+    // registry borrowed, or during thread shutdown.
+    let hosts = HOSTS
+        .try_with(|hosts| hosts.try_borrow_mut().map(|mut h| h.split_off(0)).ok())
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    for (_, host) in hosts {
+        let _ = host.close();
+    }
+}
+
+fn message_box(title: &str, text: &str) {
+    // Without NULs the conversions can't fail.
+    let (title, text) = match (
+        WideCString::from_str(title.replace('\0', " ")),
+        WideCString::from_str(text.replace('\0', " ")),
+    ) {
+        (Ok(title), Ok(text)) => (title, text),
+        _ => return,
+    };
+    unsafe {
+        MessageBoxW(
+            ptr::null_mut(),
+            text.as_ptr(),
+            title.as_ptr(),
+            MB_OK | MB_ICONERROR | MB_TASKMODAL,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let dialog = CrashDialog {
+            title: String::new(),
+            text: "{message} at {location}".into(),
+        };
+        assert_eq!(
+            dialog.render("index out of bounds: <5>", "src/main.rs:1:1"),
+            "index out of bounds: <5> at src/main.rs:1:1"
+        );
+        assert_eq!(
+            dialog.render("missing {location}", "src/main.rs:1:1"),
+            "missing {location} at src/main.rs:1:1"
+        );
+        assert_eq!(panic_message(&"static"), "static");
+        assert_eq!(panic_message(&String::from("owned")), "owned");
+        assert_eq!(panic_message(&1), "Unknown error");
+    }
+}
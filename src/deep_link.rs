@@ -0,0 +1,224 @@
+//! Routing deep links (`app://settings/profile`, `/settings/profile`) from
+//! native menus or protocol activation into the embedded app.
+//!
+//! Each route either calls a Rust handler or navigates the single page app
+//! with `history.pushState` (or `location.hash` for hash based routers) without
+//! reloading the page. Routes are tried in the order they were added, and the
+//! first match wins, so the same link always ends up in the same place.
+
+use crate::cdp::js_string;
+use crate::{Result, WebView};
+
+/// A deep link matched by a route.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeepLink {
+    /// The path, starting with `/`. For `app://settings/profile` this is
+    /// `/settings/profile`.
+    pub path: String,
+    /// The query string, without the `?`.
+    pub query: Option<String>,
+    /// Values of the `:name` and `*` segments of the route pattern.
+    pub params: Vec<(String, String)>,
+}
+
+impl DeepLink {
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+enum Target {
+    Handler(Box<dyn Fn(&WebView, &DeepLink) -> Result<()>>),
+    /// A SPA location template.
+    Spa(String),
+}
+
+/// Maps deep links to handlers and SPA routes.
+///
+/// Patterns are paths whose segments can be `:name`, matching any single
+/// segment, and a final `*`, matching the rest of the path.
+#[derive(Default)]
+pub struct DeepLinkRouter {
+    routes: Vec<(String, Target)>,
+}
+
+impl DeepLinkRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `handler` for links matching `pattern`.
+    pub fn handler(
+        mut self,
+        pattern: &str,
+        handler: impl Fn(&WebView, &DeepLink) -> Result<()> + 'static,
+    ) -> Self {
+        self.routes
+            .push((pattern.to_string(), Target::Handler(Box::new(handler))));
+        self
+    }
+
+    /// Navigate the SPA to `location` for links matching `pattern`.
+    ///
+    /// `:name` and `*` in `location` are replaced with the matched values,
+    /// and the query string of the link is appended. A location starting with
+    /// `#` is assigned to `location.hash`, anything else is pushed with
+    /// `history.pushState` followed by a `popstate` event, which is what
+    /// client side routers listen to.
+    pub fn spa_route(mut self, pattern: &str, location: &str) -> Self {
+        self.routes
+            .push((pattern.to_string(), Target::Spa(location.to_string())));
+        self
+    }
+
+    /// Route `link` in `webview`. Returns whether a route matched.
+    pub fn navigate(&self, webview: &WebView, link: &str) -> Result<bool> {
+        let (path, query) = split_link(link);
+        for (pattern, target) in &self.routes {
+            let params = match match_pattern(pattern, &path) {
+                Some(params) => params,
+                None => continue,
+            };
+            let link = DeepLink {
+                path,
+                query: query.map(|q| q.to_string()),
+                params,
+            };
+            match target {
+                Target::Handler(handler) => handler(webview, &link)?,
+                Target::Spa(location) => {
+                    let mut location = substitute(location, &link.params);
+                    if let Some(query) = &link.query {
+                        location.push('?');
+                        location.push_str(query);
+                    }
+                    webview.execute_script(&spa_navigation_script(&location), |_| Ok(()))?;
+                }
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+fn spa_navigation_script(location: &str) -> String {
+    if location.starts_with('#') {
+        format!("location.hash = {};", js_string(location))
+    } else {
+        format!(
+            "history.pushState(null, '', {});
+            window.dispatchEvent(new PopStateEvent('popstate', {{ state: null }}));",
+            js_string(location)
+        )
+    }
+}
+
+/// Split a link into its path, including the host of `scheme://` links, and
+/// its query string.
+fn split_link(link: &str) -> (String, Option<&str>) {
+    let rest = match link.find("://") {
+        Some(i) => &link[i + 3..],
+        None => link,
+    };
+    let rest = rest.split('#').next().unwrap();
+    let (path, query) = match rest.find('?') {
+        Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+        None => (rest, None),
+    };
+    (format!("/{}", path.trim_matches('/')), query)
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|s| !s.is_empty())
+}
+
+fn match_pattern(pattern: &str, path: &str) -> Option<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    let mut path_segments = segments(path);
+    for p in segments(pattern) {
+        if p == "*" {
+            let rest: Vec<&str> = path_segments.collect();
+            params.push(("*".to_string(), rest.join("/")));
+            return Some(params);
+        }
+        let s = path_segments.next()?;
+        if p.starts_with(':') {
+            params.push((p[1..].to_string(), s.to_string()));
+        } else if p != s {
+            return None;
+        }
+    }
+    if path_segments.next().is_some() {
+        return None;
+    }
+    Some(params)
+}
+
+fn substitute(location: &str, params: &[(String, String)]) -> String {
+    location
+        .split('/')
+        .map(|segment| {
+            let name = match segment {
+                "*" => "*",
+                _ if segment.starts_with(':') => &segment[1..],
+                _ => return segment,
+            };
+            params
+                .iter()
+                .find(|(n, _)| n == name)
+                .map_or(segment, |(_, v)| v.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_link() {
+        assert_eq!(
+            split_link("app://settings/profile?tab=2"),
+            ("/settings/profile".to_string(), Some("tab=2"))
+        );
+        assert_eq!(split_link("/settings/"), ("/settings".to_string(), None));
+        assert_eq!(split_link("app://"), ("/".to_string(), None));
+    }
+
+    #[test]
+    fn test_match_pattern() {
+        let params = |v: &[(&str, &str)]| {
+            Some(
+                v.iter()
+                    .map(|(n, v)| (n.to_string(), v.to_string()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(match_pattern("/settings", "/settings"), params(&[]));
+        assert_eq!(match_pattern("/settings", "/settings/profile"), None);
+        assert_eq!(
+            match_pattern("/users/:id", "/users/42"),
+            params(&[("id", "42")])
+        );
+        assert_eq!(
+            match_pattern("/docs/*", "/docs/a/b"),
+            params(&[("*", "a/b")])
+        );
+        assert_eq!(match_pattern("/", "/"), params(&[]));
+    }
+
+    #[test]
+    fn test_substitute() {
+        let params = vec![
+            ("id".to_string(), "42".to_string()),
+            ("*".to_string(), "a/b".to_string()),
+        ];
+        assert_eq!(substitute("#/users/:id", &params), "#/users/42");
+        assert_eq!(substitute("/docs/*", &params), "/docs/a/b");
+        assert_eq!(substitute("/:missing", &params), "/:missing");
+    }
+}
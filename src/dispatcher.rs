@@ -0,0 +1,443 @@
+//! Running closures on a UI thread from other threads.
+//!
+//! WebView2 objects can only be used on the thread that created them. A
+//! `Dispatcher` is a `Send` handle to that thread: work posted to it runs
+//! there, in the order it was posted, from the thread's message loop.
+//!
+//! In a UWP or XAML island app the thread has a
+//! `Windows.System.DispatcherQueue`, which `Dispatcher::for_current_thread`
+//! posts to, so that work is interleaved with the framework's own. Other
+//! threads get a hidden message-only window instead.
+//!
+//! WinUI 3 has a queue of its own, `Microsoft.UI.Dispatching.DispatcherQueue`
+//! of the Windows App SDK, with a different handler interface. It is not
+//! supported: a WinUI 3 thread without a `Windows.System.DispatcherQueue`
+//! gets the message-only window, which works there too since the thread
+//! pumps messages, but isn't ordered with the work of the WinUI queue.
+//!
+//! ```no_run
+//! # fn render_report() -> String { String::new() }
+//! # fn show(html: String) {}
+//! # fn f() -> webview2::Result<()> {
+//! use webview2::dispatcher::Dispatcher;
+//!
+//! let dispatcher = Dispatcher::for_current_thread()?;
+//! std::thread::spawn(move || {
+//!     let html = render_report();
+//!     // E.g. `navigate_to_string` on a webview of the UI thread.
+//!     dispatcher.post(move || show(html)).unwrap();
+//! });
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{check_hresult, Result};
+use std::ffi::c_void;
+use std::io;
+use std::ptr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use widestring::WideCString;
+use winapi::shared::guiddef::{IsEqualGUID, GUID, REFIID};
+use winapi::shared::minwindef::*;
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::{E_NOINTERFACE, E_POINTER, S_OK};
+use winapi::um::libloaderapi::GetModuleHandleW;
+use winapi::um::winnt::HRESULT;
+use winapi::um::winuser::*;
+use winapi::winrt::hstring::HSTRING;
+use winapi::winrt::inspectable::IInspectableVtbl;
+use winapi::winrt::roapi::RoGetActivationFactory;
+use winapi::winrt::winstring::{WindowsCreateString, WindowsDeleteString};
+
+const WINDOW_CLASS_NAME: &str = "webview2-dispatcher";
+
+/// `lparam` is a `Box<Work>`.
+const WM_DISPATCH: UINT = WM_APP;
+/// Destroy the window, posted when the last `Dispatcher` is dropped.
+const WM_DISPATCH_CLOSE: UINT = WM_APP + 1;
+
+type Work = Box<dyn FnOnce() + Send>;
+
+/// A handle to a UI thread, for posting work to it. Cheap to clone.
+#[derive(Clone)]
+pub struct Dispatcher {
+    inner: Arc<Inner>,
+}
+
+enum Inner {
+    Window(HWND),
+    Queue(*mut IDispatcherQueue),
+}
+
+// `PostMessageW` can be called from any thread, and `DispatcherQueue` is an
+// agile object.
+unsafe impl Send for Inner {}
+unsafe impl Sync for Inner {}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        unsafe {
+            match *self {
+                // After everything that was posted before.
+                Inner::Window(window) => {
+                    PostMessageW(window, WM_DISPATCH_CLOSE, 0, 0);
+                }
+                Inner::Queue(queue) => {
+                    ((*(*queue).vtable).parent.parent.Release)(queue as _);
+                }
+            }
+        }
+    }
+}
+
+impl Dispatcher {
+    /// A dispatcher for the current thread: its `DispatcherQueue` if it has
+    /// one, a message-only window otherwise.
+    pub fn for_current_thread() -> Result<Self> {
+        match current_dispatcher_queue() {
+            Some(queue) => Ok(Dispatcher {
+                inner: Arc::new(Inner::Queue(queue)),
+            }),
+            None => Self::with_message_window(),
+        }
+    }
+
+    /// A dispatcher for the current thread that always uses a message-only
+    /// window. Work runs as long as the thread pumps messages.
+    pub fn with_message_window() -> Result<Self> {
+        Ok(Dispatcher {
+            inner: Arc::new(Inner::Window(create_message_window()?)),
+        })
+    }
+
+    /// A dispatcher posting to a `Windows.System.DispatcherQueue`, e.g. one
+    /// a XAML island was created with. The pointer is `AddRef`ed.
+    ///
+    /// # Safety
+    ///
+    /// `queue` must be a `Windows.System.IDispatcherQueue` pointer, not a
+    /// `Microsoft.UI.Dispatching.IDispatcherQueue` of WinUI 3.
+    pub unsafe fn from_dispatcher_queue(queue: *mut c_void) -> Result<Self> {
+        if queue.is_null() {
+            return Err(crate::Error::new(E_POINTER));
+        }
+        let queue = queue as *mut IDispatcherQueue;
+        ((*(*queue).vtable).parent.parent.AddRef)(queue as _);
+        Ok(Dispatcher {
+            inner: Arc::new(Inner::Queue(queue)),
+        })
+    }
+
+    /// Whether work goes to a `DispatcherQueue` rather than a window.
+    pub fn uses_dispatcher_queue(&self) -> bool {
+        matches!(*self.inner, Inner::Queue(_))
+    }
+
+    /// Run `work` on the thread of the dispatcher. Fails if the thread is
+    /// gone or its queue is shutting down.
+    pub fn post(&self, work: impl FnOnce() + Send + 'static) -> Result<()> {
+        let work: Work = Box::new(work);
+        match *self.inner {
+            Inner::Window(window) => unsafe {
+                let work = Box::into_raw(Box::new(work));
+                if PostMessageW(window, WM_DISPATCH, 0, work as LPARAM) == 0 {
+                    drop(Box::from_raw(work));
+                    return Err(io::Error::last_os_error().into());
+                }
+                Ok(())
+            },
+            Inner::Queue(queue) => unsafe {
+                let handler = Handler::new(work);
+                let mut enqueued = 0;
+                let hresult = ((*(*queue).vtable).TryEnqueue)(queue, handler, &mut enqueued);
+                // The queue holds its own reference until the handler runs.
+                Handler::release(handler);
+                check_hresult(hresult)?;
+                if enqueued == 0 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::BrokenPipe,
+                        "the DispatcherQueue is shutting down",
+                    )
+                    .into());
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+fn create_message_window() -> Result<HWND> {
+    let class_name = WideCString::from_str(WINDOW_CLASS_NAME)?;
+    unsafe {
+        let instance = GetModuleHandleW(ptr::null());
+        let class = WNDCLASSW {
+            style: 0,
+            lpfnWndProc: Some(window_proc),
+            cbClsExtra: 0,
+            cbWndExtra: 0,
+            hInstance: instance,
+            hIcon: ptr::null_mut(),
+            hCursor: ptr::null_mut(),
+            hbrBackground: ptr::null_mut(),
+            lpszMenuName: ptr::null(),
+            lpszClassName: class_name.as_ptr(),
+        };
+        // Fails if another dispatcher registered it already, which is fine.
+        RegisterClassW(&class);
+        let window = CreateWindowExW(
+            0,
+            class_name.as_ptr(),
+            ptr::null(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            ptr::null_mut(),
+            instance,
+            ptr::null_mut(),
+        );
+        if window.is_null() {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(window)
+    }
+}
+
+unsafe extern "system" fn window_proc(
+    window: HWND,
+    msg: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_DISPATCH => {
+            let work = Box::from_raw(lparam as *mut Work);
+            work();
+            0
+        }
+        WM_DISPATCH_CLOSE => {
+            DestroyWindow(window);
+            0
+        }
+        _ => DefWindowProcW(window, msg, wparam, lparam),
+    }
+}
+
+/// `IDispatcherQueue::GetForCurrentThread`, `None` if the thread has no
+/// queue or WinRT is not available.
+fn current_dispatcher_queue() -> Option<*mut IDispatcherQueue> {
+    let class_name: Vec<u16> = "Windows.System.DispatcherQueue".encode_utf16().collect();
+    unsafe {
+        let mut class: HSTRING = ptr::null_mut();
+        if WindowsCreateString(class_name.as_ptr(), class_name.len() as u32, &mut class) != S_OK {
+            return None;
+        }
+        let mut statics: *mut IDispatcherQueueStatics = ptr::null_mut();
+        let hresult = RoGetActivationFactory(
+            class,
+            &IID_IDISPATCHER_QUEUE_STATICS,
+            &mut statics as *mut _ as *mut _,
+        );
+        WindowsDeleteString(class);
+        if hresult != S_OK || statics.is_null() {
+            return None;
+        }
+        let mut queue: *mut IDispatcherQueue = ptr::null_mut();
+        let hresult = ((*(*statics).vtable).GetForCurrentThread)(statics, &mut queue);
+        ((*(*statics).vtable).parent.parent.Release)(statics as _);
+        if hresult != S_OK || queue.is_null() {
+            return None;
+        }
+        Some(queue)
+    }
+}
+
+// The parts of the WinRT interfaces that are used, there are no bindings in
+// winapi.
+
+/// `Windows.System.IDispatcherQueueStatics`.
+const IID_IDISPATCHER_QUEUE_STATICS: GUID = GUID {
+    Data1: 0xa96d83d7,
+    Data2: 0x9371,
+    Data3: 0x4517,
+    Data4: [0x92, 0x45, 0xd0, 0x82, 0x4a, 0xc1, 0x2c, 0x74],
+};
+
+/// `Windows.System.DispatcherQueueHandler`.
+const IID_DISPATCHER_QUEUE_HANDLER: GUID = GUID {
+    Data1: 0xdfa2dc9c,
+    Data2: 0x1a2d,
+    Data3: 0x4917,
+    Data4: [0x98, 0xf2, 0x93, 0x9a, 0xf1, 0xd6, 0xe0, 0xc8],
+};
+
+const IID_IUNKNOWN: GUID = GUID {
+    Data1: 0x0000_0000,
+    Data2: 0x0000,
+    Data3: 0x0000,
+    Data4: [0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+/// Handlers are agile, so the queue can call them on its thread.
+const IID_IAGILE_OBJECT: GUID = GUID {
+    Data1: 0x94ea2b94,
+    Data2: 0xe9cc,
+    Data3: 0x49e0,
+    Data4: [0xc0, 0xff, 0xee, 0x64, 0xca, 0x8f, 0x5b, 0x90],
+};
+
+#[repr(C)]
+struct IDispatcherQueueStatics {
+    vtable: *const IDispatcherQueueStaticsVtbl,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct IDispatcherQueueStaticsVtbl {
+    parent: IInspectableVtbl,
+    GetForCurrentThread: unsafe extern "system" fn(
+        This: *mut IDispatcherQueueStatics,
+        result: *mut *mut IDispatcherQueue,
+    ) -> HRESULT,
+}
+
+#[repr(C)]
+struct IDispatcherQueue {
+    vtable: *const IDispatcherQueueVtbl,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct IDispatcherQueueVtbl {
+    parent: IInspectableVtbl,
+    CreateTimer: *const c_void,
+    TryEnqueue: unsafe extern "system" fn(
+        This: *mut IDispatcherQueue,
+        callback: *mut Handler,
+        result: *mut u8,
+    ) -> HRESULT,
+}
+
+/// A `DispatcherQueueHandler` running a `Work` once.
+#[repr(C)]
+struct Handler {
+    vtable: *const HandlerVtbl,
+    references: AtomicU32,
+    work: Mutex<Option<Work>>,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct HandlerVtbl {
+    QueryInterface: unsafe extern "system" fn(
+        This: *mut Handler,
+        riid: REFIID,
+        object: *mut *mut c_void,
+    ) -> HRESULT,
+    AddRef: unsafe extern "system" fn(This: *mut Handler) -> ULONG,
+    Release: unsafe extern "system" fn(This: *mut Handler) -> ULONG,
+    Invoke: unsafe extern "system" fn(This: *mut Handler) -> HRESULT,
+}
+
+static HANDLER_VTABLE: HandlerVtbl = HandlerVtbl {
+    QueryInterface: Handler::query_interface,
+    AddRef: Handler::add_ref,
+    Release: Handler::release,
+    Invoke: Handler::invoke,
+};
+
+impl Handler {
+    /// With one reference, for the caller.
+    fn new(work: Work) -> *mut Handler {
+        Box::into_raw(Box::new(Handler {
+            vtable: &HANDLER_VTABLE,
+            references: AtomicU32::new(1),
+            work: Mutex::new(Some(work)),
+        }))
+    }
+
+    unsafe extern "system" fn query_interface(
+        this: *mut Handler,
+        riid: REFIID,
+        object: *mut *mut c_void,
+    ) -> HRESULT {
+        if object.is_null() {
+            return E_POINTER;
+        }
+        let supported = [
+            IID_IUNKNOWN,
+            IID_DISPATCHER_QUEUE_HANDLER,
+            IID_IAGILE_OBJECT,
+        ];
+        if supported.iter().any(|iid| IsEqualGUID(iid, &*riid)) {
+            Handler::add_ref(this);
+            *object = this as *mut c_void;
+            S_OK
+        } else {
+            *object = ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn add_ref(this: *mut Handler) -> ULONG {
+        (*this).references.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    unsafe extern "system" fn release(this: *mut Handler) -> ULONG {
+        let remaining = (*this).references.fetch_sub(1, Ordering::Release) - 1;
+        if remaining == 0 {
+            std::sync::atomic::fence(Ordering::Acquire);
+            drop(Box::from_raw(this));
+        }
+        remaining
+    }
+
+    unsafe extern "system" fn invoke(this: *mut Handler) -> HRESULT {
+        let work = (*this).work.lock().unwrap().take();
+        if let Some(work) = work {
+            work();
+        }
+        S_OK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_handler() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let handler = {
+            let runs = runs.clone();
+            Handler::new(Box::new(move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+            }))
+        };
+        unsafe {
+            let mut object = ptr::null_mut();
+            let hresult =
+                Handler::query_interface(handler, &IID_DISPATCHER_QUEUE_HANDLER, &mut object);
+            assert_eq!(hresult, S_OK);
+            assert_eq!(object, handler as *mut c_void);
+            assert_eq!(
+                Handler::query_interface(handler, &IID_IDISPATCHER_QUEUE_STATICS, &mut object),
+                E_NOINTERFACE
+            );
+            assert!(object.is_null());
+
+            assert_eq!(Handler::invoke(handler), S_OK);
+            assert_eq!(Handler::invoke(handler), S_OK);
+            assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+            assert_eq!(Handler::release(handler), 1);
+            assert_eq!(Handler::release(handler), 0);
+        }
+        // The closure, and its clone of `runs`, are dropped with the handler.
+        assert_eq!(Arc::strong_count(&runs), 1);
+    }
+}
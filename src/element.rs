@@ -0,0 +1,166 @@
+//! Element geometry and screenshots, for positioning native overlays
+//! (tooltips, coach marks, ...) relative to page elements.
+
+use crate::{CapturePreviewImageFormat, Error, Result, WebView};
+use serde_json::{json, Value};
+use winapi::shared::winerror::E_FAIL;
+
+/// The border box of an element, in CSS pixels relative to the top left
+/// corner of the viewport.
+///
+/// Multiply by the zoom factor and the DPI scale of the host window to get
+/// physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElementRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl ElementRect {
+    /// The bounding rectangle of a `DOM.Quad`.
+    fn from_quad(quad: &Value) -> Option<Self> {
+        let points: Vec<f64> = quad.as_array()?.iter().filter_map(Value::as_f64).collect();
+        if points.len() != 8 {
+            return None;
+        }
+        let xs = points.iter().step_by(2);
+        let ys = points.iter().skip(1).step_by(2);
+        let left = xs.clone().cloned().fold(f64::INFINITY, f64::min);
+        let right = xs.cloned().fold(f64::NEG_INFINITY, f64::max);
+        let top = ys.clone().cloned().fold(f64::INFINITY, f64::min);
+        let bottom = ys.cloned().fold(f64::NEG_INFINITY, f64::max);
+        Some(Self {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        })
+    }
+}
+
+impl WebView {
+    /// Get the border box of the first element matching `selector`
+    /// (`DOM.getBoxModel`).
+    ///
+    /// The callback receives `None` if no element matches. If the element is
+    /// not rendered (e.g. `display: none`) this fails and the callback is not
+    /// called.
+    pub fn query_selector_rect(
+        &self,
+        selector: &str,
+        callback: impl FnOnce(Option<ElementRect>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let webview = self.clone();
+        let selector = selector.to_string();
+        self.call_cdp("DOM.getDocument", json!({ "depth": 0 }), move |document| {
+            let root = document["root"]["nodeId"]
+                .as_i64()
+                .ok_or_else(|| Error::new(E_FAIL))?;
+            let w = webview.clone();
+            webview.call_cdp(
+                "DOM.querySelector",
+                json!({ "nodeId": root, "selector": selector }),
+                move |node| {
+                    let node_id = node["nodeId"].as_i64().unwrap_or(0);
+                    if node_id == 0 {
+                        return callback(None);
+                    }
+                    w.call_cdp(
+                        "DOM.getBoxModel",
+                        json!({ "nodeId": node_id }),
+                        move |box_model| {
+                            let rect = ElementRect::from_quad(&box_model["model"]["border"])
+                                .ok_or_else(|| Error::new(E_FAIL))?;
+                            callback(Some(rect))
+                        },
+                    )
+                },
+            )
+        })
+    }
+
+    /// Take a screenshot of the first element matching `selector`
+    /// (`Page.captureScreenshot`).
+    ///
+    /// The callback receives the encoded image, or `None` if no element
+    /// matches. Parts of the element outside of the viewport are captured
+    /// too.
+    pub fn capture_element(
+        &self,
+        selector: &str,
+        format: CapturePreviewImageFormat,
+        callback: impl FnOnce(Option<Vec<u8>>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let format = match format {
+            CapturePreviewImageFormat::CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG => "png",
+            CapturePreviewImageFormat::CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_JPEG => "jpeg",
+        };
+        let webview = self.clone();
+        self.query_selector_rect(selector, move |rect| {
+            let rect = match rect {
+                Some(rect) => rect,
+                None => return callback(None),
+            };
+            let w = webview.clone();
+            // Box model coordinates are relative to the viewport, while the
+            // screenshot clip is relative to the document.
+            webview.call_cdp("Page.getLayoutMetrics", json!({}), move |metrics| {
+                let viewport = &metrics["layoutViewport"];
+                let parameters = json!({
+                    "format": format,
+                    "captureBeyondViewport": true,
+                    "clip": {
+                        "x": rect.x + viewport["pageX"].as_f64().unwrap_or(0.0),
+                        "y": rect.y + viewport["pageY"].as_f64().unwrap_or(0.0),
+                        "width": rect.width,
+                        "height": rect.height,
+                        "scale": 1,
+                    },
+                });
+                w.call_cdp("Page.captureScreenshot", parameters, move |screenshot| {
+                    let data = screenshot["data"]
+                        .as_str()
+                        .ok_or_else(|| Error::new(E_FAIL))?;
+                    let data = base64::decode(data).map_err(|_| Error::new(E_FAIL))?;
+                    callback(Some(data))
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_quad() {
+        let rect = |x, y, width, height| ElementRect {
+            x,
+            y,
+            width,
+            height,
+        };
+        assert_eq!(
+            ElementRect::from_quad(&json!([10, 20, 110, 20, 110, 70, 10, 70])),
+            Some(rect(10.0, 20.0, 100.0, 50.0))
+        );
+        // Rotated by 45 degrees, the bounding box of the corners.
+        assert_eq!(
+            ElementRect::from_quad(&json!([5, 0, 10, 5, 5, 10, 0, 5])),
+            Some(rect(0.0, 0.0, 10.0, 10.0))
+        );
+        assert_eq!(
+            ElementRect::from_quad(&json!([0.5, 1.5, 2.5, 1.5, 2.5, 3.5, 0.5, 3.5])),
+            Some(rect(0.5, 1.5, 2.0, 2.0))
+        );
+        assert_eq!(ElementRect::from_quad(&json!([0, 0, 1, 1])), None);
+        assert_eq!(
+            ElementRect::from_quad(&json!([0, 0, 1, 0, 1, "1", 0, 1])),
+            None
+        );
+        assert_eq!(ElementRect::from_quad(&Value::Null), None);
+    }
+}
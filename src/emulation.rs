@@ -0,0 +1,286 @@
+//! User agent, client hints, language and time zone overrides, for the main
+//! document and per frame.
+//!
+//! Overrides are applied with the DevTools protocol. Out-of-process iframes
+//! (cross-site frames, with site isolation) are separate DevTools targets, so
+//! `WebView::set_frame_emulation` auto-attaches to them with flattened
+//! sessions and applies overrides chosen by frame URL before they run any
+//! script, including frames nested in other out-of-process frames. It needs
+//! the `sdk-1.0.1185` feature. Same-site frames share the target of their
+//! parent and get its overrides.
+
+#[cfg(feature = "sdk-1.0.1185")]
+use crate::{DevToolsProtocolEventReceiver, EventRegistrationToken};
+use crate::{Result, WebView};
+use serde_json::{json, Value};
+#[cfg(feature = "sdk-1.0.1185")]
+use std::cell::RefCell;
+#[cfg(feature = "sdk-1.0.1185")]
+use std::collections::HashSet;
+#[cfg(feature = "sdk-1.0.1185")]
+use std::rc::Rc;
+
+/// User agent client hints (`navigator.userAgentData` and the `Sec-CH-UA`
+/// headers).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ClientHints {
+    /// `(brand, major version)` pairs, e.g. `("Microsoft Edge", "86")`.
+    pub brands: Vec<(String, String)>,
+    pub full_version: String,
+    pub platform: String,
+    pub platform_version: String,
+    pub architecture: String,
+    pub model: String,
+    pub mobile: bool,
+}
+
+impl ClientHints {
+    fn to_json(&self) -> Value {
+        let brands: Vec<Value> = self
+            .brands
+            .iter()
+            .map(|(brand, version)| json!({ "brand": brand, "version": version }))
+            .collect();
+        json!({
+            "brands": brands,
+            "fullVersion": self.full_version,
+            "platform": self.platform,
+            "platformVersion": self.platform_version,
+            "architecture": self.architecture,
+            "model": self.model,
+            "mobile": self.mobile,
+        })
+    }
+}
+
+/// Overrides of a document. Fields that are `None` are reset to the
+/// defaults.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Emulation {
+    /// `navigator.userAgent` and the `User-Agent` header. The other user
+    /// agent fields are only applied together with this.
+    pub user_agent: Option<String>,
+    /// The `Accept-Language` header, e.g. `"de-DE,de;q=0.9"`.
+    pub accept_language: Option<String>,
+    /// `navigator.platform`.
+    pub platform: Option<String>,
+    pub client_hints: Option<ClientHints>,
+    /// ICU locale used by `Intl` and date formatting, e.g. `"de_DE"`.
+    pub locale: Option<String>,
+    /// IANA time zone, e.g. `"Europe/Berlin"`.
+    pub timezone: Option<String>,
+}
+
+impl Emulation {
+    /// DevTools protocol methods and parameters that apply the overrides.
+    fn commands(&self) -> Vec<(&'static str, Value)> {
+        let mut user_agent = json!({ "userAgent": self.user_agent.as_deref().unwrap_or("") });
+        if self.user_agent.is_some() {
+            if let Some(accept_language) = &self.accept_language {
+                user_agent["acceptLanguage"] = accept_language.as_str().into();
+            }
+            if let Some(platform) = &self.platform {
+                user_agent["platform"] = platform.as_str().into();
+            }
+            if let Some(client_hints) = &self.client_hints {
+                user_agent["userAgentMetadata"] = client_hints.to_json();
+            }
+        }
+        let locale = match &self.locale {
+            Some(locale) => json!({ "locale": locale }),
+            None => json!({}),
+        };
+        let timezone = json!({ "timezoneId": self.timezone.as_deref().unwrap_or("") });
+        vec![
+            ("Emulation.setUserAgentOverride", user_agent),
+            ("Emulation.setLocaleOverride", locale),
+            ("Emulation.setTimezoneOverride", timezone),
+        ]
+    }
+}
+
+/// Returned by `WebView::set_frame_emulation`.
+#[cfg(feature = "sdk-1.0.1185")]
+pub struct FrameEmulationToken {
+    receivers: Vec<(DevToolsProtocolEventReceiver, EventRegistrationToken)>,
+    /// The sessions of the attached targets, which auto-attach the frames
+    /// nested in them.
+    sessions: Rc<RefCell<HashSet<String>>>,
+}
+
+impl WebView {
+    /// Apply `emulation` to the main document and the frames that share its
+    /// target. It stays in effect across navigations.
+    pub fn set_emulation(&self, emulation: &Emulation) -> Result<()> {
+        for (method, parameters) in emulation.commands() {
+            self.call_cdp(method, parameters, |_| Ok(()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sdk-1.0.1185")]
+impl WebView {
+    /// Call `select` with the URL of each out-of-process frame when it is
+    /// created, and apply the returned overrides to it. Frames for which it
+    /// returns `None` keep the defaults, not those of the main document.
+    ///
+    /// New frames are paused until the overrides are sent, so `select` should
+    /// return quickly. Fails with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2_11`, which can't send messages to sessions.
+    pub fn set_frame_emulation(
+        &self,
+        select: impl Fn(&str) -> Option<Emulation> + 'static,
+    ) -> Result<FrameEmulationToken> {
+        let sessions = Rc::new(RefCell::new(HashSet::new()));
+        let mut token = FrameEmulationToken {
+            receivers: Vec::new(),
+            sessions: sessions.clone(),
+        };
+        let result = (|| {
+            let receiver = self.get_dev_tools_protocol_event_receiver("Target.attachedToTarget")?;
+            let attached = {
+                let sessions = sessions.clone();
+                receiver.add_dev_tools_protocol_event_received(move |w, args| {
+                    let event = parse_event(&args.get_parameter_object_as_json()?);
+                    let session_id = match event["sessionId"].as_str() {
+                        Some(session_id) => session_id,
+                        None => return Ok(()),
+                    };
+                    sessions.borrow_mut().insert(session_id.to_string());
+                    frame_attached(&w, session_id, &event["targetInfo"], &select)
+                })?
+            };
+            token.receivers.push((receiver, attached));
+
+            let receiver =
+                self.get_dev_tools_protocol_event_receiver("Target.detachedFromTarget")?;
+            let detached = receiver.add_dev_tools_protocol_event_received(move |_, args| {
+                let event = parse_event(&args.get_parameter_object_as_json()?);
+                if let Some(session_id) = event["sessionId"].as_str() {
+                    sessions.borrow_mut().remove(session_id);
+                }
+                Ok(())
+            })?;
+            token.receivers.push((receiver, detached));
+
+            // Flattened, the sessions get their messages from
+            // `CallDevToolsProtocolMethodForSession`. Sent to the top level
+            // session with it, so that older runtimes fail here.
+            let parameters = auto_attach_parameters();
+            self.call_cdp_for_session("", "Target.setAutoAttach", parameters, |_| Ok(()))
+        })();
+        if let Err(e) = result {
+            let _ = self.remove_frame_emulation(token);
+            return Err(e);
+        }
+        Ok(token)
+    }
+
+    /// Stop applying overrides to new frames. Existing frames keep theirs.
+    pub fn remove_frame_emulation(&self, token: FrameEmulationToken) -> Result<()> {
+        for (receiver, t) in token.receivers {
+            receiver.remove_dev_tools_protocol_event_received(t)?;
+        }
+        let parameters = json!({ "autoAttach": false, "waitForDebuggerOnStart": false });
+        // Not borrowed while calling into the webview.
+        let sessions: Vec<String> = token.sessions.borrow_mut().drain().collect();
+        for session_id in sessions {
+            // Fails for targets that are gone already.
+            let _ = self.call_cdp_for_session(
+                &session_id,
+                "Target.setAutoAttach",
+                parameters.clone(),
+                |_| Ok(()),
+            );
+        }
+        self.call_cdp("Target.setAutoAttach", parameters, |_| Ok(()))
+    }
+}
+
+/// The parameters of an event, `Null` if they aren't JSON.
+#[cfg(feature = "sdk-1.0.1185")]
+fn parse_event(json: &str) -> Value {
+    serde_json::from_str(json).unwrap_or_default()
+}
+
+/// Send the overrides `select` chooses for a new target to its session and
+/// resume it.
+#[cfg(feature = "sdk-1.0.1185")]
+fn frame_attached(
+    w: &WebView,
+    session_id: &str,
+    target: &Value,
+    select: &impl Fn(&str) -> Option<Emulation>,
+) -> Result<()> {
+    let mut commands = Vec::new();
+    if target["type"] == "iframe" {
+        if let Some(emulation) = select(target["url"].as_str().unwrap_or("")) {
+            commands = emulation.commands();
+        }
+    }
+    // Out-of-process frames nested in this target attach to its session, and
+    // are reported to `set_frame_emulation` too.
+    commands.push(("Target.setAutoAttach", auto_attach_parameters()));
+    let mut result = Ok(());
+    for (method, parameters) in commands {
+        let sent = w.call_cdp_for_session(session_id, method, parameters, |_| Ok(()));
+        result = result.and(sent);
+    }
+    // Every new target waits, not only frames, so it is resumed even if an
+    // override failed.
+    let resumed = w.call_cdp_for_session(
+        session_id,
+        "Runtime.runIfWaitingForDebugger",
+        json!({}),
+        |_| Ok(()),
+    );
+    result.and(resumed)
+}
+
+/// `Target.setAutoAttach` for `set_frame_emulation`: new targets are paused
+/// until their overrides are sent.
+#[cfg(feature = "sdk-1.0.1185")]
+fn auto_attach_parameters() -> Value {
+    json!({
+        "autoAttach": true,
+        "waitForDebuggerOnStart": true,
+        "flatten": true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands() {
+        let emulation = Emulation {
+            user_agent: Some("Test/1.0".into()),
+            accept_language: Some("de-DE".into()),
+            client_hints: Some(ClientHints {
+                brands: vec![("Test".into(), "1".into())],
+                platform: "Windows".into(),
+                ..ClientHints::default()
+            }),
+            timezone: Some("Europe/Berlin".into()),
+            ..Emulation::default()
+        };
+        let commands = emulation.commands();
+        assert_eq!(commands[0].0, "Emulation.setUserAgentOverride");
+        assert_eq!(commands[0].1["acceptLanguage"], "de-DE");
+        assert_eq!(
+            commands[0].1["userAgentMetadata"]["brands"],
+            json!([{ "brand": "Test", "version": "1" }])
+        );
+        assert_eq!(commands[1].1, json!({}));
+        assert_eq!(commands[2].1, json!({ "timezoneId": "Europe/Berlin" }));
+
+        // Without a user agent the other fields can't be set.
+        let emulation = Emulation {
+            accept_language: Some("de-DE".into()),
+            ..Emulation::default()
+        };
+        assert_eq!(emulation.commands()[0].1, json!({ "userAgent": "" }));
+    }
+}
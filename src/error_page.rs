@@ -0,0 +1,198 @@
+//! Custom pages for failed navigations.
+//!
+//! `WebView::set_error_page` watches `NavigationCompleted` for failures and
+//! replaces the page with HTML from the host, e.g. a branded offline screen
+//! rendered from a template with `NavigationError::render`.
+//!
+//! This SDK version can't turn the built-in error page off
+//! (`IsBuiltInErrorPageEnabled` came later), so it may show briefly before the
+//! custom page is loaded.
+
+use crate::raw::CORE_WEBVIEW2_WEB_ERROR_STATUS;
+use crate::raw::CORE_WEBVIEW2_WEB_ERROR_STATUS::*;
+use crate::text::{escape_html, fill_template};
+use crate::{EventRegistrationToken, Result, WebView};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A navigation that failed.
+#[derive(Debug, Clone)]
+pub struct NavigationError {
+    pub uri: String,
+    pub status: CORE_WEBVIEW2_WEB_ERROR_STATUS,
+}
+
+impl NavigationError {
+    /// The status without the enum prefix, e.g. `HOST_NAME_NOT_RESOLVED`.
+    pub fn status_name(&self) -> &'static str {
+        match self.status {
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_UNKNOWN => "UNKNOWN",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_COMMON_NAME_IS_INCORRECT => {
+                "CERTIFICATE_COMMON_NAME_IS_INCORRECT"
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_EXPIRED => "CERTIFICATE_EXPIRED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CLIENT_CERTIFICATE_CONTAINS_ERRORS => {
+                "CLIENT_CERTIFICATE_CONTAINS_ERRORS"
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_REVOKED => "CERTIFICATE_REVOKED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_IS_INVALID => "CERTIFICATE_IS_INVALID",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_SERVER_UNREACHABLE => "SERVER_UNREACHABLE",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_TIMEOUT => "TIMEOUT",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_ERROR_HTTP_INVALID_SERVER_RESPONSE => {
+                "ERROR_HTTP_INVALID_SERVER_RESPONSE"
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_ABORTED => "CONNECTION_ABORTED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_RESET => "CONNECTION_RESET",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_DISCONNECTED => "DISCONNECTED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CANNOT_CONNECT => "CANNOT_CONNECT",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_HOST_NAME_NOT_RESOLVED => "HOST_NAME_NOT_RESOLVED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED => "OPERATION_CANCELED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_REDIRECT_FAILED => "REDIRECT_FAILED",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_UNEXPECTED_ERROR => "UNEXPECTED_ERROR",
+        }
+    }
+
+    /// A short English description for users.
+    pub fn description(&self) -> &'static str {
+        match self.status {
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_COMMON_NAME_IS_INCORRECT
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_EXPIRED
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CLIENT_CERTIFICATE_CONTAINS_ERRORS
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_REVOKED
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_IS_INVALID => {
+                "The connection to the site is not secure."
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_SERVER_UNREACHABLE
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CANNOT_CONNECT => "The site can't be reached.",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_TIMEOUT => "The site took too long to respond.",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_HOST_NAME_NOT_RESOLVED => {
+                "The address of the site could not be found."
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_DISCONNECTED => "You are offline.",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_ABORTED
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_RESET => "The connection was interrupted.",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_REDIRECT_FAILED => "The site redirected too many times.",
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_ERROR_HTTP_INVALID_SERVER_RESPONSE => {
+                "The site sent an invalid response."
+            }
+            CORE_WEBVIEW2_WEB_ERROR_STATUS_UNKNOWN
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED
+            | CORE_WEBVIEW2_WEB_ERROR_STATUS_UNEXPECTED_ERROR => "The page could not be loaded.",
+        }
+    }
+
+    /// Fill in `{uri}`, `{status}` and `{description}` in an HTML template.
+    /// The values are HTML escaped, and placeholders in them are not
+    /// replaced.
+    pub fn render(&self, template: &str) -> String {
+        fill_template(
+            template,
+            &[
+                ("uri", &escape_html(&self.uri)),
+                ("status", self.status_name()),
+                ("description", &escape_html(self.description())),
+            ],
+        )
+    }
+}
+
+/// Returned by `WebView::set_error_page`.
+pub struct ErrorPageToken {
+    navigation_starting: Option<EventRegistrationToken>,
+    navigation_completed: Option<EventRegistrationToken>,
+}
+
+impl WebView {
+    /// Call `page` when a navigation fails and show the HTML it returns with
+    /// `navigate_to_string`. Return `None` to keep the built-in error page.
+    ///
+    /// Navigations canceled by another navigation or `stop` are not errors.
+    pub fn set_error_page(
+        &self,
+        page: impl Fn(&WebView, &NavigationError) -> Result<Option<String>> + 'static,
+    ) -> Result<ErrorPageToken> {
+        // `NavigationCompleted` has no URI, so it is remembered by navigation
+        // id from `NavigationStarting`.
+        let uris = Rc::new(RefCell::new(HashMap::new()));
+
+        let navigation_starting = {
+            let uris = uris.clone();
+            self.add_navigation_starting(move |_, args| {
+                uris.borrow_mut()
+                    .insert(args.get_navigation_id()?, args.get_uri()?);
+                Ok(())
+            })?
+        };
+
+        let navigation_completed = self.add_navigation_completed(move |w, args| {
+            let uri = uris.borrow_mut().remove(&args.get_navigation_id()?);
+            if args.get_is_success()? {
+                return Ok(());
+            }
+            let status = args.get_web_error_status()?;
+            if status == CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED {
+                return Ok(());
+            }
+            let error = NavigationError {
+                uri: uri.unwrap_or_default(),
+                status,
+            };
+            match page(&w, &error)? {
+                Some(html) => w.navigate_to_string(&html),
+                None => Ok(()),
+            }
+        });
+        let navigation_completed = match navigation_completed {
+            Ok(token) => token,
+            Err(e) => {
+                let _ = self.remove_navigation_starting(navigation_starting);
+                return Err(e);
+            }
+        };
+
+        Ok(ErrorPageToken {
+            navigation_starting: Some(navigation_starting),
+            navigation_completed: Some(navigation_completed),
+        })
+    }
+
+    pub fn remove_error_page(&self, mut token: ErrorPageToken) -> Result<()> {
+        if let Some(t) = token.navigation_starting.take() {
+            self.remove_navigation_starting(t)?;
+        }
+        if let Some(t) = token.navigation_completed.take() {
+            self.remove_navigation_completed(t)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let error = NavigationError {
+            uri: "https://example.com/?a=1&b=<2>".into(),
+            status: CORE_WEBVIEW2_WEB_ERROR_STATUS_DISCONNECTED,
+        };
+        assert_eq!(
+            error.render("<a href=\"{uri}\">{status}</a>: {description}"),
+            "<a href=\"https://example.com/?a=1&amp;b=&lt;2&gt;\">DISCONNECTED</a>: You are offline."
+        );
+    }
+
+    #[test]
+    fn test_render_placeholder_in_uri() {
+        let error = NavigationError {
+            uri: "https://example.com/{description}".into(),
+            status: CORE_WEBVIEW2_WEB_ERROR_STATUS_TIMEOUT,
+        };
+        assert_eq!(
+            error.render("{uri} {status}"),
+            "https://example.com/{description} TIMEOUT"
+        );
+    }
+}
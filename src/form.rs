@@ -0,0 +1,134 @@
+//! Form filling for automation and single sign-on bridging.
+//!
+//! Values are set through the native `value` setter and followed by `input`
+//! and `change` events, so that frameworks which track input state themselves
+//! (React, Vue, ...) register the changes as if they were typed.
+
+use crate::cdp::js_string;
+use crate::{Result, WebView};
+use serde_json::{json, Value};
+
+impl WebView {
+    /// Fill form fields in the top level document.
+    ///
+    /// Each entry is a CSS selector and the value to set. Checkboxes and radio
+    /// buttons are checked if the value is `"true"`, `"on"` or equal to their
+    /// `value` attribute, and unchecked otherwise. The callback receives the
+    /// selectors that did not match any element.
+    pub fn fill_form(
+        &self,
+        fields: &[(&str, &str)],
+        callback: impl FnOnce(Vec<String>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.evaluate(&fill_form_script(fields), move |value| {
+            callback(missing_selectors(&value))
+        })
+    }
+
+    /// Submit a form.
+    ///
+    /// If `selector` matches a form, it is submitted with `requestSubmit`, so
+    /// that validation and `submit` event handlers run. Any other element,
+    /// typically a submit button, is clicked. The callback receives whether an
+    /// element was found.
+    pub fn submit(
+        &self,
+        selector: &str,
+        callback: impl FnOnce(bool) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.evaluate(&submit_script(selector), move |value| {
+            callback(value.as_bool() == Some(true))
+        })
+    }
+}
+
+fn fill_form_script(fields: &[(&str, &str)]) -> String {
+    format!(
+        "(() => {{
+            const missing = [];
+            for (const [selector, value] of {}) {{
+                const el = document.querySelector(selector);
+                if (!el) {{
+                    missing.push(selector);
+                    continue;
+                }}
+                el.focus();
+                if (el.type === 'checkbox' || el.type === 'radio') {{
+                    const checked = value === 'true' || value === 'on' || value === el.value;
+                    if (el.checked !== checked) el.click();
+                }} else {{
+                    const descriptor = Object.getOwnPropertyDescriptor(Object.getPrototypeOf(el), 'value');
+                    if (descriptor && descriptor.set) descriptor.set.call(el, value);
+                    else el.value = value;
+                    el.dispatchEvent(new Event('input', {{ bubbles: true }}));
+                    el.dispatchEvent(new Event('change', {{ bubbles: true }}));
+                }}
+                el.blur();
+            }}
+            return missing;
+        }})()",
+        json!(fields),
+    )
+}
+
+/// The selectors returned by the fill script, ignoring anything that isn't a
+/// string.
+fn missing_selectors(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|s| s.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn submit_script(selector: &str) -> String {
+    format!(
+        "(() => {{
+            const el = document.querySelector({});
+            if (!el) return false;
+            if (el instanceof HTMLFormElement) {{
+                if (el.requestSubmit) el.requestSubmit();
+                else el.submit();
+            }} else {{
+                el.click();
+            }}
+            return true;
+        }})()",
+        js_string(selector),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_form_script() {
+        let script = fill_form_script(&[("#name", "a \"b\""), ("input[name='c']", "</script>")]);
+        assert!(script.contains(r##"[["#name","a \"b\""],["input[name='c']","</script>"]]"##));
+        assert!(fill_form_script(&[]).contains("of []"));
+    }
+
+    #[test]
+    fn test_submit_script() {
+        let script = submit_script("form[action=\"/login\"]");
+        assert!(script.contains(&format!(
+            "document.querySelector({})",
+            js_string("form[action=\"/login\"]")
+        )));
+    }
+
+    #[test]
+    fn test_missing_selectors() {
+        assert_eq!(
+            missing_selectors(&json!(["#a", 1, null, "#b"])),
+            vec!["#a".to_string(), "#b".to_string()]
+        );
+        assert!(missing_selectors(&json!([])).is_empty());
+        assert!(missing_selectors(&Value::Null).is_empty());
+        assert!(missing_selectors(&json!("#a")).is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+//! Keyboard input for webviews in game engines.
+//!
+//! A game usually keeps the keyboard focus on its own window and reads input
+//! in its message loop, with the webview drawn on top as a child window for
+//! the UI. The page then never sees a key, and `document.hasFocus()` is
+//! false. `KeyboardRouter` takes the engine's `WM_KEYDOWN`, `WM_CHAR` etc.
+//! and dispatches them to the page with the DevTools protocol, which works
+//! without the webview having the focus. The engine decides when the UI has
+//! the keyboard with `focus` and `blur`:
+//!
+//! ```no_run
+//! # use winapi::shared::minwindef::*;
+//! # use webview2::keyboard::KeyboardRouter;
+//! # fn f(router: &KeyboardRouter, msg: UINT, wparam: WPARAM, lparam: LPARAM) -> webview2::Result<()> {
+//! // In the window procedure of the engine.
+//! if !router.route(msg, wparam, lparam)? {
+//!     // The game handles it.
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Keys are dispatched like Chromium does on Windows: a raw key down, then a
+//! char event for the text from `TranslateMessage`, and a key up. So
+//! `TranslateMessage` has to be called for the messages as usual.
+
+use crate::{Result, WebView};
+use serde_json::json;
+use std::cell::Cell;
+use winapi::shared::minwindef::*;
+use winapi::um::winuser::*;
+
+/// `modifiers` of `Input.dispatchKeyEvent`.
+const MODIFIER_ALT: u32 = 1;
+const MODIFIER_CONTROL: u32 = 2;
+const MODIFIER_SHIFT: u32 = 8;
+
+/// Keys that don't change the `key` of other keys, unless both are down.
+const CONTROL_AND_ALT: &[i32] = &[
+    VK_CONTROL,
+    VK_LCONTROL,
+    VK_RCONTROL,
+    VK_MENU,
+    VK_LMENU,
+    VK_RMENU,
+];
+
+/// Dispatches keyboard messages of another window to a webview while the UI
+/// has the keyboard focus.
+pub struct KeyboardRouter {
+    webview: WebView,
+    focused: Cell<bool>,
+    /// The first half of a surrogate pair, which comes in its own `WM_CHAR`.
+    high_surrogate: Cell<Option<u16>>,
+}
+
+impl KeyboardRouter {
+    /// Starts unfocused.
+    pub fn new(webview: WebView) -> Self {
+        Self {
+            webview,
+            focused: Cell::new(false),
+            high_surrogate: Cell::new(None),
+        }
+    }
+
+    /// Send keys to the page from now on, and let it behave as if its window
+    /// had the focus: `document.hasFocus()` is true, the caret blinks and
+    /// `focus` and `blur` events fire as in a focused window.
+    pub fn focus(&self) -> Result<()> {
+        self.set_focus_emulation(true)?;
+        self.focused.set(true);
+        Ok(())
+    }
+
+    /// Stop sending keys, `route` returns false for everything.
+    pub fn blur(&self) -> Result<()> {
+        self.focused.set(false);
+        self.high_surrogate.set(None);
+        self.set_focus_emulation(false)
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    /// Dispatch a keyboard message to the page if it has the focus. Returns
+    /// whether it did, i.e. whether the engine should ignore the message.
+    ///
+    /// Modifiers and the keyboard state are read with `GetKeyState` and
+    /// `GetKeyboardState`, so this has to be called while the message is
+    /// processed.
+    pub fn route(&self, msg: UINT, wparam: WPARAM, lparam: LPARAM) -> Result<bool> {
+        if !self.focused.get() {
+            return Ok(false);
+        }
+        let parameters = match msg {
+            WM_KEYDOWN | WM_SYSKEYDOWN | WM_KEYUP | WM_SYSKEYUP => {
+                let down = msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN;
+                let vk = wparam as i32;
+                let mut parameters = json!({
+                    "type": if down { "rawKeyDown" } else { "keyUp" },
+                    "modifiers": modifiers(),
+                    "windowsVirtualKeyCode": vk,
+                    "nativeVirtualKeyCode": vk,
+                    // Bit 30 is set if the key was already down.
+                    "autoRepeat": down && lparam & (1 << 30) != 0,
+                    "isSystemKey": msg == WM_SYSKEYDOWN || msg == WM_SYSKEYUP,
+                });
+                let mut state = [0; 256];
+                unsafe { GetKeyboardState(state.as_mut_ptr()) };
+                let scan_code = ((lparam >> 16) & 0xff) as UINT;
+                let layout = unsafe { GetKeyboardLayout(0) };
+                if let Some(key) = key(vk, scan_code, &state, layout) {
+                    parameters["key"] = key.into();
+                }
+                parameters
+            }
+            WM_CHAR | WM_SYSCHAR => {
+                let unit = wparam as u16;
+                if (0xd800..0xdc00).contains(&unit) {
+                    self.high_surrogate.set(Some(unit));
+                    return Ok(true);
+                }
+                // Other control characters, e.g. for backspace and escape,
+                // are handled by Chromium on key down.
+                if unit < 0x20 && unit != '\r' as u16 {
+                    return Ok(true);
+                }
+                let units = match self.high_surrogate.take() {
+                    Some(high) => vec![high, unit],
+                    None => vec![unit],
+                };
+                let text = String::from_utf16_lossy(&units);
+                json!({
+                    "type": "char",
+                    "modifiers": modifiers(),
+                    "text": text,
+                    "unmodifiedText": text,
+                    "isSystemKey": msg == WM_SYSCHAR,
+                })
+            }
+            _ => return Ok(false),
+        };
+        self.webview
+            .call_cdp("Input.dispatchKeyEvent", parameters, |_| Ok(()))?;
+        Ok(true)
+    }
+
+    fn set_focus_emulation(&self, enabled: bool) -> Result<()> {
+        self.webview.call_cdp(
+            "Emulation.setFocusEmulationEnabled",
+            json!({ "enabled": enabled }),
+            |_| Ok(()),
+        )
+    }
+}
+
+fn modifiers() -> u32 {
+    let down = |vk| unsafe { GetKeyState(vk) } < 0;
+    let mut modifiers = 0;
+    if down(VK_MENU) {
+        modifiers |= MODIFIER_ALT;
+    }
+    if down(VK_CONTROL) {
+        modifiers |= MODIFIER_CONTROL;
+    }
+    if down(VK_SHIFT) {
+        modifiers |= MODIFIER_SHIFT;
+    }
+    modifiers
+}
+
+/// The DOM `key` of a virtual key: the name of keys without text, or else
+/// the text it types in `layout` with the keyboard state `state`, so that
+/// Shift+A is `A`. Chromium doesn't derive it from the key code.
+fn key(vk: i32, scan_code: UINT, state: &[BYTE; 256], layout: HKL) -> Option<String> {
+    let name = match vk {
+        VK_BACK => "Backspace",
+        VK_TAB => "Tab",
+        VK_RETURN => "Enter",
+        VK_SHIFT => "Shift",
+        VK_CONTROL => "Control",
+        VK_MENU => "Alt",
+        VK_PAUSE => "Pause",
+        VK_CAPITAL => "CapsLock",
+        VK_ESCAPE => "Escape",
+        VK_PRIOR => "PageUp",
+        VK_NEXT => "PageDown",
+        VK_END => "End",
+        VK_HOME => "Home",
+        VK_LEFT => "ArrowLeft",
+        VK_UP => "ArrowUp",
+        VK_RIGHT => "ArrowRight",
+        VK_DOWN => "ArrowDown",
+        VK_INSERT => "Insert",
+        VK_DELETE => "Delete",
+        VK_LWIN | VK_RWIN => "Meta",
+        VK_APPS => "ContextMenu",
+        VK_F1..=VK_F24 => return Some(format!("F{}", vk - VK_F1 + 1)),
+        _ => return typed_key(vk, scan_code, state, layout),
+    };
+    Some(name.to_string())
+}
+
+/// The text of a key, ignoring Control and Alt as Chromium does, e.g. `a`
+/// for Ctrl+A, unless both are down for AltGr. `Dead` for dead keys.
+fn typed_key(vk: i32, scan_code: UINT, state: &[BYTE; 256], layout: HKL) -> Option<String> {
+    let mut state = *state;
+    let down = |vk: i32| state[vk as usize] & 0x80 != 0;
+    if !(down(VK_CONTROL) && down(VK_MENU)) {
+        for &vk in CONTROL_AND_ALT {
+            state[vk as usize] = 0;
+        }
+    }
+    let mut text = [0; 8];
+    // Flag 4 keeps the keyboard state as it is, a dead key is still pending
+    // for the `WM_CHAR` of the next key.
+    let len = unsafe {
+        ToUnicodeEx(
+            vk as UINT,
+            scan_code,
+            state.as_ptr(),
+            text.as_mut_ptr(),
+            text.len() as i32,
+            4,
+            layout,
+        )
+    };
+    if len < 0 {
+        return Some("Dead".to_string());
+    }
+    let text = String::from_utf16_lossy(&text[..len as usize]);
+    if text.is_empty() || text.chars().any(char::is_control) {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use widestring::WideCString;
+
+    /// US English, so that the tests don't depend on the layout of the user.
+    fn us_layout() -> HKL {
+        let id = WideCString::from_str("00000409").unwrap();
+        unsafe { LoadKeyboardLayoutW(id.as_ptr(), KLF_NOTELLSHELL) }
+    }
+
+    #[test]
+    fn test_key() {
+        let layout = us_layout();
+        let state = [0; 256];
+        let key = |vk, scan_code, state: &[BYTE; 256]| key(vk, scan_code, state, layout);
+        assert_eq!(key(VK_RETURN, 0x1c, &state).as_deref(), Some("Enter"));
+        assert_eq!(key(VK_LEFT, 0x4b, &state).as_deref(), Some("ArrowLeft"));
+        assert_eq!(key(VK_F1, 0x3b, &state).as_deref(), Some("F1"));
+        assert_eq!(key(VK_F12, 0x58, &state).as_deref(), Some("F12"));
+        assert_eq!(key(VK_RWIN, 0x5c, &state).as_deref(), Some("Meta"));
+
+        let (a, one) = ('A' as i32, '1' as i32);
+        assert_eq!(key(a, 0x1e, &state).as_deref(), Some("a"));
+        assert_eq!(key(one, 0x02, &state).as_deref(), Some("1"));
+        let mut shift = state;
+        shift[VK_SHIFT as usize] = 0x80;
+        assert_eq!(key(a, 0x1e, &shift).as_deref(), Some("A"));
+        assert_eq!(key(one, 0x02, &shift).as_deref(), Some("!"));
+        let mut caps_lock = state;
+        caps_lock[VK_CAPITAL as usize] = 1;
+        assert_eq!(key(a, 0x1e, &caps_lock).as_deref(), Some("A"));
+        let mut control = state;
+        control[VK_CONTROL as usize] = 0x80;
+        assert_eq!(key(a, 0x1e, &control).as_deref(), Some("a"));
+    }
+}
@@ -5,53 +5,115 @@
 //! channels if the stable channel does not work).
 //!
 //! By default, this crate ships a copy of the `WebView2Loader.dll` file for the
-//! target platform (the `embed-dll` feature). This file is then extracted
-//! alongside the executable file and dynamically loaded at runtime. License of
-//! the DLL file (part of the WebView2 SDK) is included in the
-//! `Microsoft.Web.WebView2.0.9.430` folder.
+//! target platform, x86, x64 or arm64 (the `embed-dll` feature). This file is
+//! then extracted alongside the executable file and dynamically loaded at
+//! runtime. License of the DLL file (part of the WebView2 SDK) is included in
+//! the `Microsoft.Web.WebView2.0.9.430` folder.
+//!
+//! With the `static-loader` feature, `WebView2LoaderStatic.lib` is linked into
+//! the executable instead, and there is no DLL to ship. The bundled package
+//! predates the static loader, so the build script takes it from a later
+//! `Microsoft.Web.WebView2` NuGet package, see `build.rs`. With the
+//! `rust-loader` feature, the loader is not used at all, see `loader`.
+//!
+//! Interfaces of SDKs after 0.9.430, and the wrappers using them, need the
+//! `sdk-VERSION` feature of the SDK they came with, e.g. `sdk-1.0.774` for
+//! the `composition` module. Each version enables the earlier ones. The
+//! methods still fail with `E_NOINTERFACE` on older runtimes.
 //!
 //! There are some high level, idiomatic Rust wrappers, but they are very
-//! incomplete. The low level bindings in `sys` though, is automatically
-//! generated and complete. You can use the `as_raw` methods to convert to raw
-//! COM objects and call all those methods. The `callback` macro can be helpful
-//! for implementing callbacks as COM objects.
+//! incomplete. The low level bindings in `raw` though, from the `webview2-sys`
+//! crate, are automatically generated and complete. You can use the `as_raw`
+//! methods to convert to raw COM objects and call all those methods, and
+//! `query` to get other interfaces of the objects, e.g. ones from a newer SDK.
+//! `into_raw` and `from_raw` pass the interface pointers to and from other COM
+//! code, e.g. C++ components.
+//! The `callback` macro can be helpful for implementing callbacks as COM
+//! objects.
 
 #![cfg(windows)]
 // Caused by the `com_interface` macro.
 #![allow(clippy::cmp_null)]
 #![allow(clippy::type_complexity)]
 
-pub mod sys;
+pub mod backdrop;
+#[cfg(feature = "sdk-1.0.992")]
+pub mod browser_process;
+pub mod browser_widget;
+#[cfg(feature = "sdk-1.0.774")]
+pub mod composition;
+pub mod context_menu;
+pub mod crash;
+pub mod deep_link;
+pub mod dispatcher;
+pub mod element;
+pub mod emulation;
+pub mod error_page;
+pub mod keyboard;
+#[cfg(feature = "rust-loader")]
+pub mod loader;
+#[cfg(feature = "loopback-server")]
+pub mod loopback;
+pub mod print;
+pub mod process;
+pub mod rendering;
+pub mod scheme;
+pub mod scope;
+pub mod scroll;
+#[cfg(feature = "sdk-1.0.992")]
+pub mod stress;
+pub mod taskbar;
+pub mod update;
+pub mod visibility;
+pub mod watchdog;
+pub mod webauthn;
+pub mod websocket;
+pub mod window_features;
+pub mod workspace;
+
+mod cdp;
+mod clipboard;
+mod form;
+mod net;
+mod options;
+mod text;
+mod theme;
+#[cfg(feature = "raw-window-handle")]
+mod window_handle;
+
+pub use webview2_sys as raw;
+/// The name of `raw` before it moved to its own crate.
+pub use webview2_sys as sys;
 
 use com::{interfaces::IUnknown, ComInterface, ComPtr, ComRc};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::io;
 use std::mem::{self, MaybeUninit};
 use std::path::Path;
 use std::ptr;
+use std::rc::Rc;
 use widestring::{NulError, WideCStr, WideCString};
 use winapi::shared::minwindef::*;
 use winapi::shared::ntdef::*;
 use winapi::shared::windef::*;
 use winapi::shared::winerror::{
-    E_FAIL, E_INVALIDARG, FACILITY_WIN32, HRESULT_CODE, HRESULT_FROM_WIN32, MAKE_HRESULT,
-    SEVERITY_ERROR, SUCCEEDED, S_OK,
+    E_FAIL, E_INVALIDARG, E_NOTIMPL, FACILITY_WIN32, HRESULT_CODE, HRESULT_FROM_WIN32,
+    MAKE_HRESULT, RO_E_CLOSED, SEVERITY_ERROR, SUCCEEDED, S_OK,
 };
 use winapi::um::combaseapi::CoTaskMemFree;
+#[cfg(not(any(feature = "static-loader", feature = "rust-loader")))]
 use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW};
+use winapi::um::winuser::{WM_DPICHANGED, WM_MOVE, WM_MOVING};
 
-use sys::*;
+use raw::*;
 
-#[cfg(all(feature = "embed-dll", target_arch = "x86_64"))]
-const DLL: &[u8] =
-    include_bytes!("..\\Microsoft.Web.WebView2.0.9.430\\build\\x64\\WebView2Loader.dll");
-#[cfg(all(feature = "embed-dll", target_arch = "x86"))]
-const DLL: &[u8] =
-    include_bytes!("..\\Microsoft.Web.WebView2.0.9.430\\build\\x86\\WebView2Loader.dll");
-#[cfg(all(feature = "embed-dll", target_arch = "aarch64"))]
-const DLL: &[u8] =
-    include_bytes!("..\\Microsoft.Web.WebView2.0.9.430\\build\\arm64\\WebView2Loader.dll");
+/// The loader of the target architecture, see build.rs.
+#[cfg(all(
+    feature = "embed-dll",
+    not(any(feature = "static-loader", feature = "rust-loader"))
+))]
+const DLL: &[u8] = include_bytes!(env!("WEBVIEW2_LOADER_DLL"));
 
 /// Returns a pointer that implements the COM callback interface with the specified closure.
 /// Inspired by C++ Microsoft::WRT::Callback.
@@ -90,7 +152,34 @@ macro_rules! callback {
     }}
 }
 
+// The completion handler of `CallDevToolsProtocolMethod` and
+// `CallDevToolsProtocolMethodForSession`.
+fn dev_tools_protocol_method_completed(
+    callback: impl FnOnce(String) -> Result<()> + 'static,
+) -> com::ComPtr<dyn ICoreWebView2CallDevToolsProtocolMethodCompletedHandler> {
+    let callback = RefCell::new(Some(callback));
+    callback!(
+        ICoreWebView2CallDevToolsProtocolMethodCompletedHandler,
+        move |error_code: HRESULT, return_object_as_json: LPCWSTR| -> HRESULT {
+            to_hresult(check_hresult(error_code).and_then(|_| {
+                let return_object_as_json_string =
+                    unsafe { string_from_ptr(return_object_as_json) }?.unwrap_or_default();
+                if let Some(callback) = callback.borrow_mut().take() {
+                    callback(return_object_as_json_string)
+                } else {
+                    Ok(())
+                }
+            }))
+        }
+    )
+}
+
 // Call `AddRef` and convert to `ComRc`.
+//
+// For pointers that are only borrowed, i.e. arguments of callbacks. The
+// wrappers then own a reference, so event args and deferrals can be kept
+// after the handler returns. Out parameters already carry a reference for the
+// caller and are taken over with `ComRc::from_raw` instead.
 unsafe fn add_ref_to_rc<T: ComInterface + ?Sized>(
     ptr: *mut *mut <T as ComInterface>::VTable,
 ) -> ComRc<T> {
@@ -99,6 +188,17 @@ unsafe fn add_ref_to_rc<T: ComInterface + ?Sized>(
     ptr.upgrade()
 }
 
+fn query_interface<T, I>(inner: &ComRc<T>) -> Result<ComRc<I>>
+where
+    T: ComInterface + ?Sized,
+    I: ComInterface + ?Sized,
+{
+    let mut ppv: *mut std::ffi::c_void = ptr::null_mut();
+    check_hresult(unsafe { inner.query_interface(&I::IID, &mut ppv) })?;
+    // The reference added by `QueryInterface` is taken over.
+    Ok(unsafe { ComRc::from_raw(ppv as *mut *mut I::VTable) })
+}
+
 /// `ICoreWebView2Environment`.
 #[derive(Clone)]
 pub struct Environment {
@@ -109,6 +209,24 @@ pub struct Environment {
 #[derive(Clone)]
 pub struct Host {
     inner: ComRc<dyn ICoreWebView2Host>,
+    /// Set by `close`, shared with the clones and the webview.
+    closed: Rc<Cell<bool>>,
+}
+
+/// `ICoreWebView2ControllerOptions`, from
+/// `Environment::create_controller_options`.
+#[cfg(feature = "sdk-1.0.1245")]
+#[derive(Clone)]
+pub struct ControllerOptions {
+    inner: ComRc<dyn ICoreWebView2ControllerOptions>,
+}
+
+/// `ICoreWebView2ObjectCollection`, from
+/// `Environment::create_object_collection`.
+#[cfg(feature = "sdk-1.0.2739")]
+#[derive(Clone)]
+pub struct ObjectCollection {
+    inner: ComRc<dyn ICoreWebView2ObjectCollection>,
 }
 
 /// `ICoreWebView2`.
@@ -116,6 +234,8 @@ pub struct Host {
 #[repr(C)]
 pub struct WebView {
     inner: ComRc<dyn ICoreWebView2>,
+    /// That of the host, see `Host::get_webview`.
+    closed: Rc<Cell<bool>>,
 }
 
 /// `ICoreWebView2Settings`.
@@ -220,6 +340,32 @@ pub struct NewWindowRequestedEventArgs {
     inner: ComRc<dyn ICoreWebView2NewWindowRequestedEventArgs>,
 }
 
+/// `ICoreWebView2DevToolsProtocolEventReceiver`.
+#[derive(Clone)]
+pub struct DevToolsProtocolEventReceiver {
+    inner: ComRc<dyn ICoreWebView2DevToolsProtocolEventReceiver>,
+    /// That of the webview, passed on to the senders of its events.
+    closed: Rc<Cell<bool>>,
+}
+
+/// `ICoreWebView2DevToolsProtocolEventReceivedEventArgs`.
+#[derive(Clone)]
+pub struct DevToolsProtocolEventReceivedEventArgs {
+    inner: ComRc<dyn ICoreWebView2DevToolsProtocolEventReceivedEventArgs>,
+}
+
+/// `ICoreWebView2NewBrowserVersionAvailableEventArgs`.
+#[derive(Clone)]
+pub struct NewBrowserVersionAvailableEventArgs {
+    inner: ComRc<dyn ICoreWebView2NewBrowserVersionAvailableEventArgs>,
+}
+
+/// `ICoreWebView2MoveFocusRequestedEventArgs`.
+#[derive(Clone)]
+pub struct MoveFocusRequestedEventArgs {
+    inner: ComRc<dyn ICoreWebView2MoveFocusRequestedEventArgs>,
+}
+
 /// `IStream`.
 ///
 /// # `Clone`
@@ -230,14 +376,50 @@ pub struct Stream {
     inner: ComRc<dyn IStream>,
 }
 
-/// A builder for calling the `CreateCoreWebView2EnvironmentWithDetails`
-/// function.
+#[cfg(all(feature = "static-loader", feature = "rust-loader"))]
+compile_error!("the static-loader and rust-loader features are exclusive");
+
+// From `WebView2LoaderStatic.lib`, linked by the build script.
+#[cfg(feature = "static-loader")]
+extern "system" {
+    fn CreateCoreWebView2EnvironmentWithOptions(
+        browser_executable_folder: PCWSTR,
+        user_data_folder: PCWSTR,
+        environment_options: *mut *mut ICoreWebView2EnvironmentOptionsVTable,
+        environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable,
+    ) -> HRESULT;
+}
+
+/// The function of the loader creating environments.
+#[cfg(not(feature = "rust-loader"))]
+enum CreateFn {
+    WithOptions(FnCreateCoreWebView2EnvironmentWithOptions),
+    /// From loaders before `CreateCoreWebView2EnvironmentWithOptions`, like
+    /// the embedded one.
+    WithDetails(FnCreateCoreWebView2EnvironmentWithDetails),
+}
+
+/// A builder for calling the `CreateCoreWebView2EnvironmentWithOptions`
+/// function, or `CreateCoreWebView2EnvironmentWithDetails` with loaders that
+/// don't have it.
 #[derive(Default)]
 pub struct EnvironmentBuilder<'a> {
     dll_file_path: Option<&'a Path>,
     browser_executable_folder: Option<&'a Path>,
     user_data_folder: Option<&'a Path>,
     additional_browser_arguments: Option<&'a str>,
+    language: Option<&'a str>,
+    target_compatible_browser_version: Option<&'a str>,
+    allow_single_sign_on_using_os_primary_account: Option<bool>,
+    exclusive_user_data_folder_access: Option<bool>,
+    custom_crash_reporting_enabled: Option<bool>,
+    custom_scheme_registrations: Option<&'a [ComRc<dyn ICoreWebView2CustomSchemeRegistration>]>,
+    scheme_registrations: Vec<scheme::SchemeRegistration>,
+    tracking_prevention_enabled: Option<bool>,
+    browser_extensions_enabled: Option<bool>,
+    channel_search_kind: Option<ChannelSearchKind>,
+    release_channels: Option<ReleaseChannels>,
+    scroll_bar_style: Option<COREWEBVIEW2_SCROLLBAR_STYLE>,
 }
 
 impl<'a> EnvironmentBuilder<'a> {
@@ -266,6 +448,112 @@ impl<'a> EnvironmentBuilder<'a> {
         }
     }
 
+    /// The UI language and `Accept-Language`, e.g. `de-DE`, rather than the
+    /// language of the OS.
+    pub fn with_language(self, language: &'a str) -> Self {
+        Self {
+            language: Some(language),
+            ..self
+        }
+    }
+
+    /// The oldest runtime version the app works with, e.g. `86.0.616.0`. It
+    /// defaults to the version of the SDK.
+    pub fn with_target_compatible_browser_version(self, version: &'a str) -> Self {
+        Self {
+            target_compatible_browser_version: Some(version),
+            ..self
+        }
+    }
+
+    /// Sign in to sites with the Azure AD or Microsoft account of the OS.
+    pub fn with_allow_single_sign_on_using_os_primary_account(self, allow: bool) -> Self {
+        Self {
+            allow_single_sign_on_using_os_primary_account: Some(allow),
+            ..self
+        }
+    }
+
+    /// Fail to create webviews in the environment if another process uses
+    /// the user data folder, rather than share its browser process.
+    pub fn with_exclusive_user_data_folder_access(self, exclusive: bool) -> Self {
+        Self {
+            exclusive_user_data_folder_access: Some(exclusive),
+            ..self
+        }
+    }
+
+    /// Don't send crash reports to Microsoft, for apps that collect the
+    /// dumps in the user data folder themselves.
+    pub fn with_custom_crash_reporting_enabled(self, enabled: bool) -> Self {
+        Self {
+            custom_crash_reporting_enabled: Some(enabled),
+            ..self
+        }
+    }
+
+    /// Schemes to navigate to and serve with `WebResourceRequested`, as
+    /// `ICoreWebView2CustomSchemeRegistration` objects. All environments
+    /// sharing the browser process need the same registrations.
+    pub fn with_custom_scheme_registrations(
+        self,
+        registrations: &'a [ComRc<dyn ICoreWebView2CustomSchemeRegistration>],
+    ) -> Self {
+        Self {
+            custom_scheme_registrations: Some(registrations),
+            ..self
+        }
+    }
+
+    /// Register a custom scheme, to serve with `WebView::register_custom_scheme_handler`.
+    pub fn register_custom_scheme(mut self, registration: scheme::SchemeRegistration) -> Self {
+        self.scheme_registrations.push(registration);
+        self
+    }
+
+    /// Enabled by default. Disabling it saves the cost of it for apps that
+    /// only show content they know.
+    pub fn with_tracking_prevention_enabled(self, enabled: bool) -> Self {
+        Self {
+            tracking_prevention_enabled: Some(enabled),
+            ..self
+        }
+    }
+
+    pub fn with_browser_extensions_enabled(self, enabled: bool) -> Self {
+        Self {
+            browser_extensions_enabled: Some(enabled),
+            ..self
+        }
+    }
+
+    /// Search the release channels from the least stable, Canary, rather
+    /// than from the WebView2 runtime. This is what
+    /// `WEBVIEW2_RELEASE_CHANNEL_PREFERENCE=1` does, which still wins.
+    pub fn with_channel_search_kind(self, kind: ChannelSearchKind) -> Self {
+        Self {
+            channel_search_kind: Some(kind),
+            ..self
+        }
+    }
+
+    /// Only search these channels. E.g. for Canary, or else Dev, with the
+    /// `LEAST_STABLE` search kind: `ReleaseChannels::CANARY | ReleaseChannels::DEV`.
+    pub fn with_release_channels(self, channels: ReleaseChannels) -> Self {
+        Self {
+            release_channels: Some(channels),
+            ..self
+        }
+    }
+
+    /// Fluent overlay scroll bars rather than the Chromium ones.
+    pub fn with_scroll_bar_style(self, style: COREWEBVIEW2_SCROLLBAR_STYLE) -> Self {
+        Self {
+            scroll_bar_style: Some(style),
+            ..self
+        }
+    }
+
     /// Set path to the `WebView2Loader.dll` file.
     ///
     /// * When the `embed-dll` feature is enabled:
@@ -279,6 +567,8 @@ impl<'a> EnvironmentBuilder<'a> {
     ///   It will be simply passed to `LoadLibraryW`.
     ///
     /// Default value: `WebView2Loader.dll`.
+    ///
+    /// Ignored with the `static-loader` and `rust-loader` features.
     pub fn with_dll_file_path(self, dll_file_path: &'a Path) -> Self {
         Self {
             dll_file_path: Some(dll_file_path),
@@ -286,18 +576,154 @@ impl<'a> EnvironmentBuilder<'a> {
         }
     }
 
+    /// Options other than the folders and additional browser arguments need
+    /// a loader with `CreateCoreWebView2EnvironmentWithOptions`: the embedded
+    /// one predates it, so with it this fails with `E_NOTIMPL` when they are
+    /// set. The `static-loader` and `rust-loader` features have it, and so
+    /// does the `WebView2Loader.dll` of later SDKs.
     pub fn build(
         self,
         completed: impl FnOnce(Result<Environment>) -> Result<()> + 'static,
     ) -> Result<()> {
+        let completed = RefCell::new(Some(completed));
+        let completed = callback!(
+            ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler,
+            move |result: HRESULT,
+                  created_environment: *mut *mut ICoreWebView2EnvironmentVTable|
+                  -> HRESULT {
+                let result = check_hresult(result).map(move |_| Environment {
+                    inner: unsafe { add_ref_to_rc(created_environment) },
+                });
+                to_hresult(completed.borrow_mut().take().unwrap()(result))
+            }
+        );
+
+        self.create(completed.as_raw())
+    }
+
+    /// The options object, with the options that are set.
+    fn options(&self) -> Result<ComRc<dyn ICoreWebView2EnvironmentOptions>> {
+        let options = options::EnvironmentOptions::create();
+        let options2: ComRc<dyn ICoreWebView2EnvironmentOptions2> = query_interface(&options)?;
+        let options3: ComRc<dyn ICoreWebView2EnvironmentOptions3> = query_interface(&options)?;
+        let options4: ComRc<dyn ICoreWebView2EnvironmentOptions4> = query_interface(&options)?;
+        let options5: ComRc<dyn ICoreWebView2EnvironmentOptions5> = query_interface(&options)?;
+        let options6: ComRc<dyn ICoreWebView2EnvironmentOptions6> = query_interface(&options)?;
+        let options7: ComRc<dyn ICoreWebView2EnvironmentOptions7> = query_interface(&options)?;
+        let options8: ComRc<dyn ICoreWebView2EnvironmentOptions8> = query_interface(&options)?;
+        unsafe {
+            if let Some(arguments) = self.additional_browser_arguments {
+                let arguments = WideCString::from_str(arguments)?;
+                check_hresult(options.put_additional_browser_arguments(arguments.as_ptr()))?;
+            }
+            if let Some(language) = self.language {
+                let language = WideCString::from_str(language)?;
+                check_hresult(options.put_language(language.as_ptr()))?;
+            }
+            if let Some(version) = self.target_compatible_browser_version {
+                let version = WideCString::from_str(version)?;
+                check_hresult(options.put_target_compatible_browser_version(version.as_ptr()))?;
+            }
+            if let Some(allow) = self.allow_single_sign_on_using_os_primary_account {
+                check_hresult(
+                    options.put_allow_single_sign_on_using_os_primary_account(allow as BOOL),
+                )?;
+            }
+            if let Some(exclusive) = self.exclusive_user_data_folder_access {
+                check_hresult(options2.put_exclusive_user_data_folder_access(exclusive as BOOL))?;
+            }
+            if let Some(enabled) = self.custom_crash_reporting_enabled {
+                check_hresult(options3.put_is_custom_crash_reporting_enabled(enabled as BOOL))?;
+            }
+            if self.custom_scheme_registrations.is_some() || !self.scheme_registrations.is_empty() {
+                let created = self
+                    .scheme_registrations
+                    .iter()
+                    .map(options::CustomSchemeRegistration::create)
+                    .collect::<Result<Vec<_>>>()?;
+                let mut registrations: Vec<_> = self
+                    .custom_scheme_registrations
+                    .unwrap_or_default()
+                    .iter()
+                    .chain(&created)
+                    .map(|r| r.as_raw())
+                    .collect();
+                check_hresult(options4.set_custom_scheme_registrations(
+                    registrations.len() as u32,
+                    registrations.as_mut_ptr(),
+                ))?;
+            }
+            if let Some(enabled) = self.tracking_prevention_enabled {
+                check_hresult(options5.put_enable_tracking_prevention(enabled as BOOL))?;
+            }
+            if let Some(enabled) = self.browser_extensions_enabled {
+                check_hresult(options6.put_are_browser_extensions_enabled(enabled as BOOL))?;
+            }
+            if let Some(kind) = self.channel_search_kind {
+                check_hresult(options7.put_channel_search_kind(kind))?;
+            }
+            if let Some(channels) = self.release_channels {
+                check_hresult(options7.put_release_channels(channels.bits()))?;
+            }
+            if let Some(style) = self.scroll_bar_style {
+                check_hresult(options8.put_scroll_bar_style(style))?;
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether there is more to pass than `CreateCoreWebView2EnvironmentWithDetails` takes.
+    #[cfg(not(feature = "rust-loader"))]
+    fn needs_options(&self) -> bool {
+        self.language.is_some()
+            || self.target_compatible_browser_version.is_some()
+            || self.allow_single_sign_on_using_os_primary_account.is_some()
+            || self.exclusive_user_data_folder_access.is_some()
+            || self.custom_crash_reporting_enabled.is_some()
+            || self.custom_scheme_registrations.is_some()
+            || !self.scheme_registrations.is_empty()
+            || self.tracking_prevention_enabled.is_some()
+            || self.browser_extensions_enabled.is_some()
+            || self.channel_search_kind.is_some()
+            || self.release_channels.is_some()
+            || self.scroll_bar_style.is_some()
+    }
+
+    #[cfg(feature = "rust-loader")]
+    fn create(
+        self,
+        environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable,
+    ) -> Result<()> {
+        loader::create_environment(
+            self.browser_executable_folder,
+            self.user_data_folder,
+            &self.options()?,
+            environment_created_handler,
+        )
+    }
+
+    #[cfg(not(feature = "rust-loader"))]
+    fn create(
+        self,
+        environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable,
+    ) -> Result<()> {
+        let options = self.options()?;
+        let needs_options = self.needs_options();
         let Self {
             dll_file_path,
             browser_executable_folder,
             user_data_folder,
             additional_browser_arguments,
+            ..
         } = self;
 
-        #[cfg(feature = "embed-dll")]
+        #[cfg(feature = "static-loader")]
+        let create_fn = {
+            let _ = dll_file_path;
+            CreateFn::WithOptions(CreateCoreWebView2EnvironmentWithOptions)
+        };
+
+        #[cfg(all(feature = "embed-dll", not(feature = "static-loader")))]
         let dll_file_path = {
             let dll_file_path = dll_file_path.unwrap_or_else(|| Path::new("WebView2Loader.dll"));
             let exe_path = std::env::current_exe()?;
@@ -308,23 +734,31 @@ impl<'a> EnvironmentBuilder<'a> {
             }
             dll_file_path
         };
-        #[cfg(not(feature = "embed-dll"))]
+        #[cfg(not(any(feature = "embed-dll", feature = "static-loader")))]
         let dll_file_path = dll_file_path.unwrap_or_else(|| Path::new("WebView2Loader.dll"));
 
-        let create_fn: FnCreateCoreWebView2EnvironmentWithDetails = unsafe {
+        #[cfg(not(feature = "static-loader"))]
+        let create_fn = unsafe {
             let dll_file_path = WideCString::from_os_str(dll_file_path)?;
             let dll = LoadLibraryW(dll_file_path.as_ptr());
             if dll.is_null() {
                 return Err(io::Error::last_os_error().into());
             }
-            let create_fn = GetProcAddress(
+            let with_options = GetProcAddress(
+                dll,
+                "CreateCoreWebView2EnvironmentWithOptions\0".as_ptr() as *const i8,
+            );
+            let with_details = GetProcAddress(
                 dll,
                 "CreateCoreWebView2EnvironmentWithDetails\0".as_ptr() as *const i8,
             );
-            if create_fn.is_null() {
+            if !with_options.is_null() {
+                CreateFn::WithOptions(mem::transmute(with_options))
+            } else if !with_details.is_null() {
+                CreateFn::WithDetails(mem::transmute(with_details))
+            } else {
                 return Err(io::Error::last_os_error().into());
             }
-            mem::transmute(create_fn)
         };
 
         let browser_executable_folder = if let Some(p) = browser_executable_folder {
@@ -342,36 +776,37 @@ impl<'a> EnvironmentBuilder<'a> {
         } else {
             None
         };
-
-        let completed = RefCell::new(Some(completed));
-        let completed = callback!(
-            ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler,
-            move |result: HRESULT,
-                  created_environment: *mut *mut ICoreWebView2EnvironmentVTable|
-                  -> HRESULT {
-                let result = check_hresult(result).map(move |_| Environment {
-                    inner: unsafe { add_ref_to_rc(created_environment) },
-                });
-                to_hresult(completed.borrow_mut().take().unwrap()(result))
-            }
-        );
+        if needs_options && matches!(create_fn, CreateFn::WithDetails(_)) {
+            return Err(Error::new(E_NOTIMPL));
+        }
+        let browser_executable_folder = browser_executable_folder
+            .as_ref()
+            .map(|p| p.as_ptr())
+            .unwrap_or(ptr::null());
+        let user_data_folder = user_data_folder
+            .as_ref()
+            .map(|p| p.as_ptr())
+            .unwrap_or(ptr::null());
+        let additional_browser_arguments = additional_browser_arguments
+            .as_ref()
+            .map(|p| p.as_ptr())
+            .unwrap_or(ptr::null());
 
         check_hresult(unsafe {
-            create_fn(
-                browser_executable_folder
-                    .as_ref()
-                    .map(|p| p.as_ptr())
-                    .unwrap_or(ptr::null()),
-                user_data_folder
-                    .as_ref()
-                    .map(|p| p.as_ptr())
-                    .unwrap_or(ptr::null()),
-                additional_browser_arguments
-                    .as_ref()
-                    .map(|p| p.as_ptr())
-                    .unwrap_or(ptr::null()),
-                completed.as_raw(),
-            )
+            match create_fn {
+                CreateFn::WithOptions(create_fn) => create_fn(
+                    browser_executable_folder,
+                    user_data_folder,
+                    options.as_raw(),
+                    environment_created_handler,
+                ),
+                CreateFn::WithDetails(create_fn) => create_fn(
+                    browser_executable_folder,
+                    user_data_folder,
+                    additional_browser_arguments,
+                    environment_created_handler,
+                ),
+            }
         })
     }
 }
@@ -379,6 +814,7 @@ impl<'a> EnvironmentBuilder<'a> {
 macro_rules! get {
     ($get_method:ident, $T: ident) => {
         pub fn $get_method(&self) -> Result<$T> {
+            self.check_open()?;
             let mut value: MaybeUninit<$T> = MaybeUninit::uninit();
             check_hresult(unsafe { self.inner.$get_method(value.as_mut_ptr()) })?;
             Ok(unsafe { value.assume_init() })
@@ -389,6 +825,7 @@ macro_rules! get {
 macro_rules! put {
     ($put_method:ident, $arg_name:ident : $T:ident) => {
         pub fn $put_method(&self, $arg_name: $T) -> Result<()> {
+            self.check_open()?;
             check_hresult(unsafe { self.inner.$put_method($arg_name) })
         }
     };
@@ -397,10 +834,11 @@ macro_rules! put {
 macro_rules! get_interface {
     ($get_method:ident, $T: ident, $VT: ident) => {
         pub fn $get_method(&self) -> Result<$T> {
+            self.check_open()?;
             let mut ppv: MaybeUninit<*mut *mut $VT> = MaybeUninit::uninit();
             check_hresult(unsafe { self.inner.$get_method(ppv.as_mut_ptr()) })?;
             Ok(unsafe { $T {
-                inner: add_ref_to_rc(ppv.assume_init()),
+                inner: ComRc::from_raw(ppv.assume_init()),
             } })
         }
     };
@@ -409,6 +847,7 @@ macro_rules! get_interface {
 macro_rules! put_interface {
     ($put_method:ident, $T: ident) => {
         pub fn $put_method(&self, i: $T) -> Result<()> {
+            self.check_open()?;
             check_hresult(unsafe {
                 // Convert to `ComPtr` so that it is not automatically released.
                 self.inner.$put_method(ComPtr::from(i.inner).as_raw())
@@ -420,6 +859,7 @@ macro_rules! put_interface {
 macro_rules! get_bool {
     ($get_method:ident) => {
         pub fn $get_method(&self) -> Result<bool> {
+            self.check_open()?;
             let mut enabled: BOOL = 0;
             check_hresult(unsafe { self.inner.$get_method(&mut enabled) })?;
             Ok(enabled != 0)
@@ -430,30 +870,42 @@ macro_rules! get_bool {
 macro_rules! put_bool {
     ($put_method:ident) => {
         pub fn $put_method(&self, enabled: bool) -> Result<()> {
+            self.check_open()?;
             let enabled = if enabled { 1 } else { 0 };
             check_hresult(unsafe { self.inner.$put_method(enabled) })
         }
     };
 }
 
+// Null strings are returned as empty strings.
 macro_rules! get_string {
     ($get_string_method:ident) => {
         pub fn $get_string_method(&self) -> Result<String> {
+            self.check_open()?;
             let mut result: LPWSTR = ptr::null_mut();
             check_hresult(unsafe { self.inner.$get_string_method(&mut result) })?;
-            let result1 = unsafe { WideCStr::from_ptr_str(result) };
-            let result1 = result1.to_string().map_err(|_| Error { hresult: E_FAIL });
-            unsafe {
-                CoTaskMemFree(result as _);
-            }
-            result1
+            unsafe { take_string(result) }.map(Option::unwrap_or_default)
         }
     }
 }
 
+// For strings that are commonly unset, `None` if the string is null or empty.
+macro_rules! get_string_opt {
+    ($get_string_method:ident) => {
+        pub fn $get_string_method(&self) -> Result<Option<String>> {
+            self.check_open()?;
+            let mut result: LPWSTR = ptr::null_mut();
+            check_hresult(unsafe { self.inner.$get_string_method(&mut result) })?;
+            let result = unsafe { take_string(result) }?;
+            Ok(result.filter(|s| !s.is_empty()))
+        }
+    };
+}
+
 macro_rules! put_string {
     ($put_string_method:ident) => {
         pub fn $put_string_method(&self, message_string: &str) -> Result<()> {
+            self.check_open()?;
             let message = WideCString::from_str(message_string)?;
             check_hresult(unsafe { self.inner.$put_string_method(message.as_ptr()) })
         }
@@ -463,6 +915,7 @@ macro_rules! put_string {
 macro_rules! call {
     ($method:ident) => {
         pub fn $method(&self) -> Result<()> {
+            self.check_open()?;
             check_hresult(unsafe { self.inner.$method() })
         }
     };
@@ -474,16 +927,17 @@ macro_rules! add_event_handler_host {
             &self,
             event_handler: impl Fn(Host) -> Result<()> + 'static,
         ) -> Result<EventRegistrationToken> {
+            self.check_open()?;
             let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
 
+            let closed = self.closed.clone();
             let event_handler = callback!(
                 $arg_type,
                 move |sender: *mut *mut ICoreWebView2HostVTable,
                     _args: *mut *mut com::interfaces::iunknown::IUnknownVTable|
                     -> HRESULT {
-                    let sender = Host {
-                        inner: unsafe { add_ref_to_rc(sender) },
-                    };
+                    let sender = unsafe { add_ref_to_rc(sender) };
+                    let sender = Host::with_closed(sender, closed.clone());
                     to_hresult(event_handler(sender))
                 }
             );
@@ -502,16 +956,17 @@ macro_rules! add_event_handler_view {
             &self,
             event_handler: impl Fn(WebView) -> Result<()> + 'static,
         ) -> Result<EventRegistrationToken> {
+            self.check_open()?;
             let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
 
+            let closed = self.closed.clone();
             let event_handler = callback!(
                 $arg_type,
                 move |sender: *mut *mut ICoreWebView2VTable,
                     _args: *mut *mut com::interfaces::iunknown::IUnknownVTable|
                     -> HRESULT {
-                    let sender = WebView {
-                        inner: unsafe { add_ref_to_rc(sender) },
-                    };
+                    let sender = unsafe { add_ref_to_rc(sender) };
+                    let sender = WebView::with_closed(sender, closed.clone());
                     to_hresult(event_handler(sender))
                 }
             );
@@ -530,16 +985,17 @@ macro_rules! add_event_handler {
             &self,
             handler: impl Fn(WebView, $arg_args) -> Result<()> + 'static,
         ) -> Result<EventRegistrationToken> {
+            self.check_open()?;
             let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
 
+            let closed = self.closed.clone();
             let handler = callback!(
                 $arg_type,
                 move |sender: *mut *mut ICoreWebView2VTable,
                     args: *mut *mut $arg_args_type|
                     -> HRESULT {
-                    let sender = WebView {
-                        inner: unsafe { add_ref_to_rc(sender) },
-                    };
+                    let sender = unsafe { add_ref_to_rc(sender) };
+                    let sender = WebView::with_closed(sender, closed.clone());
                     let args = $arg_args {
                         inner: unsafe { add_ref_to_rc(args) },
                     };
@@ -558,12 +1014,63 @@ macro_rules! add_event_handler {
 macro_rules! remove_event_handler {
     ($method:ident) => {
         pub fn $method(&self, token: EventRegistrationToken) -> Result<()> {
+            self.check_open()?;
             check_hresult(unsafe { self.inner.$method(token) })
         }
     };
 }
 
+macro_rules! query {
+    () => {
+        /// `QueryInterface` the object for another interface, e.g. one added
+        /// in a later SDK that has no wrapper yet.
+        pub fn query<I: ComInterface + ?Sized>(&self) -> Result<ComRc<I>> {
+            query_interface(&self.inner)
+        }
+    };
+}
+
+// `$new` makes the wrapper from the `ComRc`, for wrappers with more fields
+// than `inner`.
+macro_rules! raw {
+    (@new, $inner:expr) => {
+        Self { inner: $inner }
+    };
+    (@new $new:path, $inner:expr) => {
+        $new($inner)
+    };
+    ($interface:ident, $vtable:ident $(, $new:path)?) => {
+        pub fn as_raw(&self) -> &ComRc<dyn $interface> {
+            &self.inner
+        }
+
+        pub fn into_inner(self) -> ComRc<dyn $interface> {
+            self.inner
+        }
+
+        /// The interface pointer, with the reference the wrapper held, for
+        /// other COM code. Release it or convert it back with `from_raw`.
+        pub fn into_raw(self) -> *mut *mut $vtable {
+            let ptr = self.inner.as_raw();
+            mem::forget(self.inner);
+            ptr
+        }
+
+        /// Wrap an interface pointer, taking over one reference, e.g. from an
+        /// out parameter or `into_raw`.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be a valid pointer to the interface. See
+        /// `ComRc::from_raw`.
+        pub unsafe fn from_raw(ptr: *mut *mut $vtable) -> Self {
+            raw!(@new $($new)?, ComRc::from_raw(ptr))
+        }
+    };
+}
+
 impl Environment {
+    #[allow(clippy::useless_transmute)]
     pub fn create_host(
         &self,
         parent_window: HWND,
@@ -573,28 +1080,173 @@ impl Environment {
         let completed = callback!(
             ICoreWebView2CreateCoreWebView2HostCompletedHandler,
             move |result: HRESULT, created_host: *mut *mut ICoreWebView2HostVTable| -> HRESULT {
-                let result = check_hresult(result).map(|_| Host {
-                    inner: unsafe { add_ref_to_rc(created_host) },
-                });
+                let result = check_hresult(result)
+                    .map(|_| Host::new(unsafe { add_ref_to_rc(created_host) }));
                 to_hresult(completed.borrow_mut().take().unwrap()(result))
             }
         );
+        // `HWND` and `RECT` in `raw` are the types of windows-sys with the
+        // `windows-sys` feature, which have the same layout.
         check_hresult(unsafe {
             self.inner
-                .create_core_web_view2_host(parent_window, completed.as_raw())
+                .create_core_web_view2_host(mem::transmute(parent_window), completed.as_raw())
+        })
+    }
+
+    /// Options for `create_host_with_options`. Fails with `E_NOINTERFACE` on
+    /// runtimes before `ICoreWebView2Environment10`.
+    #[cfg(feature = "sdk-1.0.1245")]
+    pub fn create_controller_options(&self) -> Result<ControllerOptions> {
+        let environment10: ComRc<dyn ICoreWebView2Environment10> = query_interface(&self.inner)?;
+        let mut options: *mut *mut ICoreWebView2ControllerOptionsVTable = ptr::null_mut();
+        check_hresult(unsafe {
+            environment10.create_core_web_view2_controller_options(&mut options)
+        })?;
+        Ok(ControllerOptions {
+            inner: unsafe { ComRc::from_raw(options) },
+        })
+    }
+
+    /// `create_host` with a profile, e.g. one per account, which has its own
+    /// cookies, cache and storage in the user data folder.
+    #[cfg(feature = "sdk-1.0.1245")]
+    #[allow(clippy::useless_transmute)]
+    pub fn create_host_with_options(
+        &self,
+        parent_window: HWND,
+        options: &ControllerOptions,
+        completed: impl FnOnce(Result<Host>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let environment10: ComRc<dyn ICoreWebView2Environment10> = query_interface(&self.inner)?;
+        let completed = RefCell::new(Some(completed));
+        let completed = callback!(
+            ICoreWebView2CreateCoreWebView2HostCompletedHandler,
+            move |result: HRESULT, created_host: *mut *mut ICoreWebView2HostVTable| -> HRESULT {
+                let result = check_hresult(result)
+                    .map(|_| Host::new(unsafe { add_ref_to_rc(created_host) }));
+                to_hresult(completed.borrow_mut().take().unwrap()(result))
+            }
+        );
+        check_hresult(unsafe {
+            environment10.create_core_web_view2_controller_with_options(
+                mem::transmute(parent_window),
+                options.inner.as_raw(),
+                completed.as_raw(),
+            )
+        })
+    }
+
+    /// A collection of COM objects, for methods that take a list. Fails with
+    /// `E_NOINTERFACE` on runtimes before `ICoreWebView2Environment14`.
+    #[cfg(feature = "sdk-1.0.2739")]
+    pub fn create_object_collection(
+        &self,
+        items: &[ComRc<dyn IUnknown>],
+    ) -> Result<ObjectCollection> {
+        let environment14: ComRc<dyn ICoreWebView2Environment14> = query_interface(&self.inner)?;
+        // Only borrowed by the call, the collection adds its own references.
+        let mut items: Vec<_> = items.iter().map(|i| i.as_raw()).collect();
+        let mut collection: *mut *mut ICoreWebView2ObjectCollectionVTable = ptr::null_mut();
+        check_hresult(unsafe {
+            environment14.create_object_collection(
+                items.len() as u32,
+                items.as_mut_ptr(),
+                &mut collection,
+            )
+        })?;
+        Ok(ObjectCollection {
+            inner: unsafe { ComRc::from_raw(collection) },
+        })
+    }
+
+    /// `headers` is the raw response header string, with headers separated
+    /// by newlines.
+    pub fn create_web_resource_response(
+        &self,
+        content: Option<&Stream>,
+        status_code: i32,
+        reason_phrase: &str,
+        headers: &str,
+    ) -> Result<WebResourceResponse> {
+        let content = content.map_or(ptr::null_mut(), |c| c.inner.as_raw());
+        let reason_phrase = WideCString::from_str(reason_phrase)?;
+        let headers = WideCString::from_str(headers)?;
+        let mut response: *mut *mut ICoreWebView2WebResourceResponseVTable = ptr::null_mut();
+        check_hresult(unsafe {
+            self.inner.create_web_resource_response(
+                content,
+                status_code,
+                reason_phrase.as_ptr(),
+                headers.as_ptr(),
+                &mut response,
+            )
+        })?;
+        Ok(WebResourceResponse {
+            inner: unsafe { ComRc::from_raw(response) },
         })
     }
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2Environment> {
-        &self.inner
+    get_string!(get_browser_version_info);
+
+    /// The channel of the runtime that was selected, from the version info.
+    pub fn get_release_channel(&self) -> Result<ReleaseChannels> {
+        Ok(ReleaseChannels::from_version_info(
+            &self.get_browser_version_info()?,
+        ))
+    }
+
+    /// Called when a newer version of the browser is installed. The webviews
+    /// have to be recreated in a new environment to use it, see the `update`
+    /// module.
+    pub fn add_new_browser_version_available(
+        &self,
+        handler: impl Fn(Environment, NewBrowserVersionAvailableEventArgs) -> Result<()> + 'static,
+    ) -> Result<EventRegistrationToken> {
+        let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
+
+        let handler = callback!(
+            ICoreWebView2NewBrowserVersionAvailableEventHandler,
+            move |sender: *mut *mut ICoreWebView2EnvironmentVTable,
+                  args: *mut *mut ICoreWebView2NewBrowserVersionAvailableEventArgsVTable|
+                  -> HRESULT {
+                let sender = Environment {
+                    inner: unsafe { add_ref_to_rc(sender) },
+                };
+                let args = NewBrowserVersionAvailableEventArgs {
+                    inner: unsafe { add_ref_to_rc(args) },
+                };
+                to_hresult(handler(sender, args))
+            }
+        );
+
+        check_hresult(unsafe {
+            self.inner
+                .add_new_browser_version_available(handler.as_raw(), &mut token)
+        })?;
+        Ok(token)
     }
+    remove_event_handler!(remove_new_browser_version_available);
+
+    query!();
+
+    raw!(ICoreWebView2Environment, ICoreWebView2EnvironmentVTable);
 }
 
 impl Host {
     get_bool!(get_is_visible);
     put_bool!(put_is_visible);
-    get!(get_bounds, RECT);
-    put!(put_bounds, bounds: RECT);
+    #[allow(clippy::useless_transmute)]
+    pub fn get_bounds(&self) -> Result<RECT> {
+        self.check_open()?;
+        let mut bounds = MaybeUninit::uninit();
+        check_hresult(unsafe { self.inner.get_bounds(bounds.as_mut_ptr()) })?;
+        Ok(unsafe { mem::transmute(bounds.assume_init()) })
+    }
+    #[allow(clippy::useless_transmute)]
+    pub fn put_bounds(&self, bounds: RECT) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.put_bounds(mem::transmute(bounds)) })
+    }
     get!(get_zoom_factor, f64);
     put!(put_zoom_factor, zoom_factor: f64);
     add_event_handler_host!(
@@ -602,11 +1254,50 @@ impl Host {
         ICoreWebView2ZoomFactorChangedEventHandler
     );
     remove_event_handler!(remove_zoom_factor_changed);
-    // TODO: set_bounds_and_zoom_factor
+    /// Both at once, so the page is laid out once, e.g. when the window is
+    /// resized to keep the content the same size on screen.
+    #[allow(clippy::useless_transmute)]
+    pub fn set_bounds_and_zoom_factor(&self, bounds: RECT, zoom_factor: f64) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe {
+            self.inner
+                .set_bounds_and_zoom_factor(mem::transmute(bounds), zoom_factor)
+        })
+    }
     pub fn move_focus(&self, reason: MoveFocusReason) -> Result<()> {
+        self.check_open()?;
         check_hresult(unsafe { self.inner.move_focus(reason) })
     }
-    // TODO: add_move_focus_requested //eventHandler
+    /// Called when Tab or Shift+Tab moves the focus out of the webview. Move
+    /// it to the next or previous native control and set `handled`, or else
+    /// the focus goes back into the webview.
+    pub fn add_move_focus_requested(
+        &self,
+        handler: impl Fn(Host, MoveFocusRequestedEventArgs) -> Result<()> + 'static,
+    ) -> Result<EventRegistrationToken> {
+        self.check_open()?;
+        let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
+
+        let closed = self.closed.clone();
+        let handler = callback!(
+            ICoreWebView2MoveFocusRequestedEventHandler,
+            move |sender: *mut *mut ICoreWebView2HostVTable,
+                  args: *mut *mut ICoreWebView2MoveFocusRequestedEventArgsVTable|
+                  -> HRESULT {
+                let sender = Host::with_closed(unsafe { add_ref_to_rc(sender) }, closed.clone());
+                let args = MoveFocusRequestedEventArgs {
+                    inner: unsafe { add_ref_to_rc(args) },
+                };
+                to_hresult(handler(sender, args))
+            }
+        );
+
+        check_hresult(unsafe {
+            self.inner
+                .add_move_focus_requested(handler.as_raw(), &mut token)
+        })?;
+        Ok(token)
+    }
     remove_event_handler!(remove_move_focus_requested);
     add_event_handler_host!(add_got_focus, ICoreWebView2FocusChangedEventHandler);
     remove_event_handler!(remove_got_focus);
@@ -614,32 +1305,240 @@ impl Host {
     remove_event_handler!(remove_lost_focus);
     // TODO: add_accelerator_key_pressed //eventHandler
     remove_event_handler!(remove_accelerator_key_pressed);
-    get!(get_parent_window, HWND);
-    put!(put_parent_window, top_level_window: HWND);
+    #[allow(clippy::useless_transmute)]
+    pub fn get_parent_window(&self) -> Result<HWND> {
+        self.check_open()?;
+        let mut window = MaybeUninit::uninit();
+        check_hresult(unsafe { self.inner.get_parent_window(window.as_mut_ptr()) })?;
+        Ok(unsafe { mem::transmute(window.assume_init()) })
+    }
+    #[allow(clippy::useless_transmute)]
+    pub fn put_parent_window(&self, top_level_window: HWND) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.put_parent_window(mem::transmute(top_level_window)) })
+    }
     call!(notify_parent_window_position_changed);
-    call!(close);
+
+    /// Move the webview to another parent window, e.g. a panel torn off into
+    /// a window of its own. Set the bounds for the new parent after this.
+    pub fn reparent(&self, parent_window: HWND) -> Result<()> {
+        self.put_parent_window(parent_window)?;
+        self.notify_parent_window_position_changed()
+    }
+
+    /// Call with the messages of the top level window, so that dialogs,
+    /// menus and other popups of the webview show up next to it after it is
+    /// moved. Calls `notify_parent_window_position_changed` for `WM_MOVE`,
+    /// `WM_MOVING` and `WM_DPICHANGED`, and does nothing for other messages.
+    ///
+    /// ```no_run
+    /// # use winapi::shared::minwindef::*;
+    /// # fn f(host: &webview2::Host, msg: UINT, wparam: WPARAM, lparam: LPARAM) {
+    /// // In the window procedure.
+    /// let _ = host.handle_window_message(msg);
+    /// # }
+    /// ```
+    pub fn handle_window_message(&self, msg: UINT) -> Result<()> {
+        match msg {
+            WM_MOVE | WM_MOVING | WM_DPICHANGED => self.notify_parent_window_position_changed(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Close the webview and its browser process, if no other webview uses
+    /// it. Closing again does nothing. Other methods of the host, its clones,
+    /// its webviews from `get_webview` and the senders passed to event
+    /// handlers registered on them fail with `Error::Closed` after this.
+    pub fn close(&self) -> Result<()> {
+        if self.closed.get() {
+            return Ok(());
+        }
+        check_hresult(unsafe { self.inner.close() })?;
+        self.closed.set(true);
+        Ok(())
+    }
     pub fn get_webview(&self) -> Result<WebView> {
+        self.check_open()?;
         let mut ppv: *mut *mut ICoreWebView2VTable = ptr::null_mut();
         check_hresult(unsafe { self.inner.get_core_web_view2(&mut ppv) })?;
         Ok(WebView {
-            inner: unsafe { add_ref_to_rc(ppv) },
+            inner: unsafe { ComRc::from_raw(ppv) },
+            closed: self.closed.clone(),
         })
     }
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2Host> {
-        &self.inner
+    fn new(inner: ComRc<dyn ICoreWebView2Host>) -> Self {
+        Self::with_closed(inner, Rc::new(Cell::new(false)))
+    }
+
+    /// A host sharing `closed` with the host, or webview, it comes from.
+    pub(crate) fn with_closed(inner: ComRc<dyn ICoreWebView2Host>, closed: Rc<Cell<bool>>) -> Self {
+        Self { inner, closed }
     }
+
+    query!();
+
+    raw!(ICoreWebView2Host, ICoreWebView2HostVTable, Host::new);
 }
 
-impl WebView {
+// `ICoreWebView2Controller3`, these fail with `E_NOINTERFACE` on older
+// runtimes.
+#[cfg(feature = "sdk-1.0.774")]
+impl Host {
+    /// The DPI scale of the webview, e.g. `1.5` for 144 DPI. Follows the
+    /// monitor unless `put_should_detect_monitor_scale_changes(false)`.
+    pub fn get_rasterization_scale(&self) -> Result<f64> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        let mut scale = 0.0;
+        check_hresult(unsafe { controller3.get_rasterization_scale(&mut scale) })?;
+        Ok(scale)
+    }
+    pub fn put_rasterization_scale(&self, scale: f64) -> Result<()> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller3.put_rasterization_scale(scale) })
+    }
+    pub fn get_should_detect_monitor_scale_changes(&self) -> Result<bool> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        let mut value: BOOL = 0;
+        check_hresult(unsafe { controller3.get_should_detect_monitor_scale_changes(&mut value) })?;
+        Ok(value != 0)
+    }
+    /// Turn off for apps that are per monitor DPI aware v2 and set the scale
+    /// themselves, e.g. from `WM_DPICHANGED`.
+    pub fn put_should_detect_monitor_scale_changes(&self, value: bool) -> Result<()> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller3.put_should_detect_monitor_scale_changes(value as BOOL) })
+    }
+    /// Called when the rasterization scale changes, from the monitor or
+    /// `put_rasterization_scale`.
+    pub fn add_rasterization_scale_changed(
+        &self,
+        event_handler: impl Fn(Host) -> Result<()> + 'static,
+    ) -> Result<EventRegistrationToken> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        let mut token: EventRegistrationToken = unsafe { mem::zeroed() };
+
+        let closed = self.closed.clone();
+        let event_handler = callback!(
+            ICoreWebView2RasterizationScaleChangedEventHandler,
+            move |sender: *mut *mut ICoreWebView2HostVTable,
+                  _args: *mut *mut com::interfaces::iunknown::IUnknownVTable|
+                  -> HRESULT {
+                let sender = Host::with_closed(unsafe { add_ref_to_rc(sender) }, closed.clone());
+                to_hresult(event_handler(sender))
+            }
+        );
+
+        check_hresult(unsafe {
+            controller3.add_rasterization_scale_changed(event_handler.as_raw(), &mut token)
+        })?;
+        Ok(token)
+    }
+    pub fn remove_rasterization_scale_changed(&self, token: EventRegistrationToken) -> Result<()> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller3.remove_rasterization_scale_changed(token) })
+    }
+    pub fn get_bounds_mode(&self) -> Result<BoundsMode> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        let mut mode = MaybeUninit::uninit();
+        check_hresult(unsafe { controller3.get_bounds_mode(mode.as_mut_ptr()) })?;
+        Ok(unsafe { mode.assume_init() })
+    }
+    /// With `BoundsMode::COREWEBVIEW2_BOUNDS_MODE_USE_RASTERIZATION_SCALE`
+    /// the bounds are in logical pixels, multiplied by the rasterization
+    /// scale.
+    pub fn put_bounds_mode(&self, mode: BoundsMode) -> Result<()> {
+        self.check_open()?;
+        let controller3: ComRc<dyn ICoreWebView2Controller3> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller3.put_bounds_mode(mode) })
+    }
+}
+
+#[cfg(feature = "sdk-1.0.1518")]
+impl Host {
+    /// `ICoreWebView2Controller4::get_AllowExternalDrop`.
+    pub fn get_allow_external_drop(&self) -> Result<bool> {
+        self.check_open()?;
+        let controller4: ComRc<dyn ICoreWebView2Controller4> = query_interface(&self.inner)?;
+        let mut value: BOOL = 0;
+        check_hresult(unsafe { controller4.get_allow_external_drop(&mut value) })?;
+        Ok(value != 0)
+    }
+    /// Whether content dragged in from other apps, e.g. files from Explorer,
+    /// can be dropped. On by default. Drags within the page always work.
+    /// Fails with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2Controller4`.
+    pub fn put_allow_external_drop(&self, value: bool) -> Result<()> {
+        self.check_open()?;
+        let controller4: ComRc<dyn ICoreWebView2Controller4> = query_interface(&self.inner)?;
+        check_hresult(unsafe { controller4.put_allow_external_drop(value as BOOL) })
+    }
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+impl ControllerOptions {
+    get_string!(get_profile_name);
+    // Letters, digits and `#@$()+-_~.`, at most 64 characters. Webviews with
+    // the same profile name share the profile. Empty is the default profile.
+    put_string!(put_profile_name);
+    get_bool!(get_is_in_private_mode_enabled);
+    // Keep nothing on disk, like an InPrivate window.
+    put_bool!(put_is_in_private_mode_enabled);
+
+    raw!(
+        ICoreWebView2ControllerOptions,
+        ICoreWebView2ControllerOptionsVTable
+    );
+}
+
+#[cfg(feature = "sdk-1.0.2739")]
+impl ObjectCollection {
+    get!(get_count, u32);
+    pub fn get_value_at_index(&self, index: u32) -> Result<ComRc<dyn IUnknown>> {
+        self.check_open()?;
+        let mut value: *mut *mut com::interfaces::iunknown::IUnknownVTable = ptr::null_mut();
+        check_hresult(unsafe { self.inner.get_value_at_index(index, &mut value) })?;
+        Ok(unsafe { ComRc::from_raw(value) })
+    }
+    pub fn remove_value_at_index(&self, index: u32) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.remove_value_at_index(index) })
+    }
+    pub fn insert_value_at_index(&self, index: u32, value: &ComRc<dyn IUnknown>) -> Result<()> {
+        self.check_open()?;
+        check_hresult(unsafe { self.inner.insert_value_at_index(index, value.as_raw()) })
+    }
+
+    /// All the objects.
+    pub fn to_vec(&self) -> Result<Vec<ComRc<dyn IUnknown>>> {
+        (0..self.get_count()?)
+            .map(|i| self.get_value_at_index(i))
+            .collect()
+    }
+
+    raw!(
+        ICoreWebView2ObjectCollection,
+        ICoreWebView2ObjectCollectionVTable
+    );
+}
+
+impl WebView {
     pub fn get_settings(&self) -> Result<Settings> {
+        self.check_open()?;
         let mut ppv: *mut *mut ICoreWebView2SettingsVTable = ptr::null_mut();
         check_hresult(unsafe { self.inner.get_settings(&mut ppv) })?;
         Ok(Settings {
-            inner: unsafe { add_ref_to_rc(ppv) },
+            inner: unsafe { ComRc::from_raw(ppv) },
         })
     }
-    get_string!(get_source);
+    get_string_opt!(get_source);
     put_string!(navigate);
     put_string!(navigate_to_string);
     add_event_handler!(
@@ -707,15 +1606,14 @@ impl WebView {
         script: &str,
         callback: impl FnOnce(String) -> Result<()> + 'static,
     ) -> Result<()> {
+        self.check_open()?;
         let script = WideCString::from_str(script)?;
         let callback = RefCell::new(Some(callback));
         let callback = callback!(
             ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler,
             move |error_code: HRESULT, id: LPCWSTR| -> HRESULT {
                 to_hresult(check_hresult(error_code).and_then(|_| {
-                    let id = unsafe { WideCStr::from_ptr_str(id) }
-                        .to_string()
-                        .map_err(|_| Error::new(E_FAIL))?;
+                    let id = unsafe { string_from_ptr(id) }?.unwrap_or_default();
                     if let Some(callback) = callback.borrow_mut().take() {
                         callback(id)
                     } else {
@@ -730,6 +1628,7 @@ impl WebView {
         })
     }
     pub fn remove_script_to_execute_on_document_created(&self, id: &str) -> Result<()> {
+        self.check_open()?;
         let id = WideCString::from_str(id)?;
         check_hresult(unsafe {
             self.inner
@@ -741,6 +1640,7 @@ impl WebView {
         script: &str,
         callback: impl FnOnce(String) -> Result<()> + 'static,
     ) -> Result<()> {
+        self.check_open()?;
         let script = WideCString::from_str(script)?;
         let callback = RefCell::new(Some(callback));
         let callback = callback!(
@@ -748,9 +1648,7 @@ impl WebView {
             move |error_code: HRESULT, result_object_as_json: LPCWSTR| -> HRESULT {
                 to_hresult(check_hresult(error_code).and_then(|_| {
                     let result_object_as_json_string =
-                        unsafe { WideCStr::from_ptr_str(result_object_as_json) }
-                            .to_string()
-                            .map_err(|_| Error::new(E_FAIL))?;
+                        unsafe { string_from_ptr(result_object_as_json) }?.unwrap_or_default();
                     if let Some(callback) = callback.borrow_mut().take() {
                         callback(result_object_as_json_string)
                     } else {
@@ -780,13 +1678,74 @@ impl WebView {
         ICoreWebView2WebMessageReceivedEventArgsVTable
     );
     remove_event_handler!(remove_web_message_received);
-    // TODO: call_dev_tools_protocol_method
+    pub fn call_dev_tools_protocol_method(
+        &self,
+        method_name: &str,
+        parameters_as_json: &str,
+        callback: impl FnOnce(String) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.check_open()?;
+        let method_name = WideCString::from_str(method_name)?;
+        let parameters_as_json = WideCString::from_str(parameters_as_json)?;
+        let callback = dev_tools_protocol_method_completed(callback);
+        check_hresult(unsafe {
+            self.inner.call_dev_tools_protocol_method(
+                method_name.as_ptr(),
+                parameters_as_json.as_ptr(),
+                callback.as_raw(),
+            )
+        })
+    }
+    /// Call a method in a session of a target attached with `flatten: true`,
+    /// e.g. from the `sessionId` of `Target.attachedToTarget`. An empty
+    /// session id is the top level page, like `call_dev_tools_protocol_method`.
+    ///
+    /// Fails with `E_NOINTERFACE` on runtimes before `ICoreWebView2_11`.
+    #[cfg(feature = "sdk-1.0.1185")]
+    pub fn call_dev_tools_protocol_method_for_session(
+        &self,
+        session_id: &str,
+        method_name: &str,
+        parameters_as_json: &str,
+        callback: impl FnOnce(String) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.check_open()?;
+        let webview11: ComRc<dyn ICoreWebView2_11> = query_interface(&self.inner)?;
+        let session_id = WideCString::from_str(session_id)?;
+        let method_name = WideCString::from_str(method_name)?;
+        let parameters_as_json = WideCString::from_str(parameters_as_json)?;
+        let callback = dev_tools_protocol_method_completed(callback);
+        check_hresult(unsafe {
+            webview11.call_dev_tools_protocol_method_for_session(
+                session_id.as_ptr(),
+                method_name.as_ptr(),
+                parameters_as_json.as_ptr(),
+                callback.as_raw(),
+            )
+        })
+    }
     get!(get_browser_process_id, u32);
     get_bool!(get_can_go_back);
     get_bool!(get_can_go_forward);
     call!(go_back);
     call!(go_forward);
-    // TODO: get_dev_tools_protocol_event_receiver
+    pub fn get_dev_tools_protocol_event_receiver(
+        &self,
+        event_name: &str,
+    ) -> Result<DevToolsProtocolEventReceiver> {
+        self.check_open()?;
+        let event_name = WideCString::from_str(event_name)?;
+        let mut ppv: MaybeUninit<*mut *mut ICoreWebView2DevToolsProtocolEventReceiverVTable> =
+            MaybeUninit::uninit();
+        check_hresult(unsafe {
+            self.inner
+                .get_dev_tools_protocol_event_receiver(event_name.as_ptr(), ppv.as_mut_ptr())
+        })?;
+        Ok(DevToolsProtocolEventReceiver {
+            inner: unsafe { ComRc::from_raw(ppv.assume_init()) },
+            closed: self.closed.clone(),
+        })
+    }
     call!(stop);
     add_event_handler!(
         add_new_window_requested,
@@ -795,7 +1754,7 @@ impl WebView {
         ICoreWebView2NewWindowRequestedEventArgsVTable
     );
     remove_event_handler!(remove_new_window_requested);
-    get_string!(get_document_title);
+    get_string_opt!(get_document_title);
     // TODO: add_remote_object ??
     // TODO: remove_remote_object ??
     call!(open_dev_tools_window);
@@ -812,17 +1771,48 @@ impl WebView {
         ICoreWebView2WebResourceRequestedEventArgsVTable
     );
     remove_event_handler!(remove_web_resource_requested);
-    // TODO: add_web_resource_requested_filter
-    // TODO: remove_web_resource_requested_filter
+    pub fn add_web_resource_requested_filter(
+        &self,
+        uri: &str,
+        resource_context: WebResourceContext,
+    ) -> Result<()> {
+        self.check_open()?;
+        let uri = WideCString::from_str(uri)?;
+        check_hresult(unsafe {
+            self.inner
+                .add_web_resource_requested_filter(uri.as_ptr(), resource_context)
+        })
+    }
+    pub fn remove_web_resource_requested_filter(
+        &self,
+        uri: &str,
+        resource_context: WebResourceContext,
+    ) -> Result<()> {
+        self.check_open()?;
+        let uri = WideCString::from_str(uri)?;
+        check_hresult(unsafe {
+            self.inner
+                .remove_web_resource_requested_filter(uri.as_ptr(), resource_context)
+        })
+    }
     add_event_handler_view!(
         add_window_close_requested,
         ICoreWebView2WindowCloseRequestedEventHandler
     );
     remove_event_handler!(remove_window_close_requested);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2> {
-        &self.inner
+    query!();
+
+    fn new(inner: ComRc<dyn ICoreWebView2>) -> Self {
+        Self::with_closed(inner, Rc::new(Cell::new(false)))
     }
+
+    /// A webview sharing `closed` with the host or webview it comes from.
+    fn with_closed(inner: ComRc<dyn ICoreWebView2>, closed: Rc<Cell<bool>>) -> Self {
+        Self { inner, closed }
+    }
+
+    raw!(ICoreWebView2, ICoreWebView2VTable, WebView::new);
 }
 
 impl Settings {
@@ -850,18 +1840,18 @@ impl Settings {
     get_bool!(get_is_zoom_control_enabled);
     put_bool!(put_is_zoom_control_enabled);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2Settings> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2Settings, ICoreWebView2SettingsVTable);
 }
 
 impl ContentLoadingEventArgs {
     get_bool!(get_is_error_page);
     get!(get_navigation_id, u64);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2ContentLoadingEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2ContentLoadingEventArgs, ICoreWebView2ContentLoadingEventArgsVTable);
 }
 
 impl WebMessageReceivedEventArgs {
@@ -869,9 +1859,9 @@ impl WebMessageReceivedEventArgs {
     get_string!(try_get_web_message_as_string);
     get_string!(get_web_message_as_json);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2WebMessageReceivedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2WebMessageReceivedEventArgs, ICoreWebView2WebMessageReceivedEventArgsVTable);
 }
 
 impl HttpHeadersCollectionIterator {
@@ -883,27 +1873,20 @@ impl HttpHeadersCollectionIterator {
                 self.inner
                     .get_current_header(name.as_mut_ptr(), value.as_mut_ptr()),
             )?;
-            let name = name.assume_init();
-            let value = value.assume_init();
-            let name1 = WideCStr::from_ptr_str(name)
-                .to_string()
-                .map_err(|_| Error::new(E_FAIL));
-            let value1 = WideCStr::from_ptr_str(value)
-                .to_string()
-                .map_err(|_| Error::new(E_FAIL));
-
-            CoTaskMemFree(name as _);
-            CoTaskMemFree(value as _);
-
-            Ok((name1?, value1?))
+            let name = take_string(name.assume_init());
+            let value = take_string(value.assume_init());
+            Ok((name?.unwrap_or_default(), value?.unwrap_or_default()))
         }
     }
     get_bool!(get_has_current_header);
     get_bool!(move_next);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2HttpHeadersCollectionIterator> {
-        &self.inner
-    }
+    query!();
+
+    raw!(
+        ICoreWebView2HttpHeadersCollectionIterator,
+        ICoreWebView2HttpHeadersCollectionIteratorVTable
+    );
 }
 
 impl Iterator for HttpHeadersCollectionIterator {
@@ -922,12 +1905,13 @@ impl Iterator for HttpHeadersCollectionIterator {
 impl HttpRequestHeaders {
     // TODO: get_header //LPCWSTR LPWSTR
     pub fn get_headers(&self, name: &str) -> Result<HttpHeadersCollectionIterator> {
+        self.check_open()?;
         let name = WideCString::from_str(name)?;
         let mut iterator: *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable =
             ptr::null_mut();
         check_hresult(unsafe { self.inner.get_headers(name.as_ptr(), &mut iterator) })?;
         Ok(HttpHeadersCollectionIterator {
-            inner: unsafe { add_ref_to_rc(iterator) },
+            inner: unsafe { ComRc::from_raw(iterator) },
         })
     }
     // TODO: contains //LPCWSTR BOOL
@@ -939,9 +1923,9 @@ impl HttpRequestHeaders {
         ICoreWebView2HttpHeadersCollectionIteratorVTable
     );
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2HttpRequestHeaders> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2HttpRequestHeaders, ICoreWebView2HttpRequestHeadersVTable);
 }
 
 impl HttpResponseHeaders {
@@ -949,12 +1933,13 @@ impl HttpResponseHeaders {
     // TODO: contains //LPCWSTR BOOL
     // TODO: get_header //LPCWSTR LPWSTR
     pub fn get_headers(&self, name: &str) -> Result<HttpHeadersCollectionIterator> {
+        self.check_open()?;
         let name = WideCString::from_str(name)?;
         let mut iterator: *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable =
             ptr::null_mut();
         check_hresult(unsafe { self.inner.get_headers(name.as_ptr(), &mut iterator) })?;
         Ok(HttpHeadersCollectionIterator {
-            inner: unsafe { add_ref_to_rc(iterator) },
+            inner: unsafe { ComRc::from_raw(iterator) },
         })
     }
     get_interface!(
@@ -963,17 +1948,17 @@ impl HttpResponseHeaders {
         ICoreWebView2HttpHeadersCollectionIteratorVTable
     );
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2HttpResponseHeaders> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2HttpResponseHeaders, ICoreWebView2HttpResponseHeadersVTable);
 }
 
 impl Deferral {
     call!(complete);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2Deferral> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2Deferral, ICoreWebView2DeferralVTable);
 }
 
 impl WebResourceRequest {
@@ -989,9 +1974,9 @@ impl WebResourceRequest {
         ICoreWebView2HttpRequestHeadersVTable
     );
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2WebResourceRequest> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2WebResourceRequest, ICoreWebView2WebResourceRequestVTable);
 }
 
 impl WebResourceResponse {
@@ -1007,9 +1992,9 @@ impl WebResourceResponse {
     get_string!(get_reason_phrase);
     put_string!(put_reason_phrase);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2WebResourceResponse> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2WebResourceResponse, ICoreWebView2WebResourceResponseVTable);
 }
 
 impl WebResourceRequestedEventArgs {
@@ -1025,11 +2010,14 @@ impl WebResourceRequestedEventArgs {
     );
     put_interface!(put_response, WebResourceResponse);
     get_interface!(get_deferral, Deferral, ICoreWebView2DeferralVTable);
-    get!(get_resource_context, CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT);
+    get!(get_resource_context, WebResourceContext);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2WebResourceRequestedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(
+        ICoreWebView2WebResourceRequestedEventArgs,
+        ICoreWebView2WebResourceRequestedEventArgsVTable
+    );
 }
 
 impl NavigationCompletedEventArgs {
@@ -1037,9 +2025,12 @@ impl NavigationCompletedEventArgs {
     get!(get_web_error_status, CORE_WEBVIEW2_WEB_ERROR_STATUS);
     get!(get_navigation_id, u64);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2NavigationCompletedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(
+        ICoreWebView2NavigationCompletedEventArgs,
+        ICoreWebView2NavigationCompletedEventArgsVTable
+    );
 }
 
 impl NavigationStartingEventArgs {
@@ -1055,17 +2046,17 @@ impl NavigationStartingEventArgs {
     put_bool!(put_cancel);
     get!(get_navigation_id, u64);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2NavigationStartingEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2NavigationStartingEventArgs, ICoreWebView2NavigationStartingEventArgsVTable);
 }
 
 impl SourceChangedEventArgs {
     get_bool!(get_is_new_document);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2SourceChangedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2SourceChangedEventArgs, ICoreWebView2SourceChangedEventArgsVTable);
 }
 
 impl ScriptDialogOpeningEventArgs {
@@ -1078,9 +2069,12 @@ impl ScriptDialogOpeningEventArgs {
     put_string!(put_result_text);
     get_interface!(get_deferral, Deferral, ICoreWebView2DeferralVTable);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2ScriptDialogOpeningEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(
+        ICoreWebView2ScriptDialogOpeningEventArgs,
+        ICoreWebView2ScriptDialogOpeningEventArgsVTable
+    );
 }
 
 impl PermissionRequestedEventArgs {
@@ -1091,37 +2085,117 @@ impl PermissionRequestedEventArgs {
     put!(put_state, state: CORE_WEBVIEW2_PERMISSION_STATE);
     get_interface!(get_deferral, Deferral, ICoreWebView2DeferralVTable);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2PermissionRequestedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(
+        ICoreWebView2PermissionRequestedEventArgs,
+        ICoreWebView2PermissionRequestedEventArgsVTable
+    );
 }
 
 impl ProcessFailedEventArgs {
     get!(get_process_failed_kind, CORE_WEBVIEW2_PROCESS_FAILED_KIND);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2ProcessFailedEventArgs> {
-        &self.inner
-    }
+    query!();
+
+    raw!(ICoreWebView2ProcessFailedEventArgs, ICoreWebView2ProcessFailedEventArgsVTable);
 }
 
 impl NewWindowRequestedEventArgs {
     get_string!(get_uri);
     put_interface!(put_new_window, WebView);
-    get_interface!(get_new_window, WebView, ICoreWebView2VTable);
+    pub fn get_new_window(&self) -> Result<WebView> {
+        let mut ppv: *mut *mut ICoreWebView2VTable = ptr::null_mut();
+        check_hresult(unsafe { self.inner.get_new_window(&mut ppv) })?;
+        Ok(WebView::new(unsafe { ComRc::from_raw(ppv) }))
+    }
     put_bool!(put_handled);
     get_bool!(get_handled);
     get_bool!(get_is_user_initiated);
     get_interface!(get_deferral, Deferral, ICoreWebView2DeferralVTable);
 
-    pub fn as_raw(&self) -> &ComRc<dyn ICoreWebView2NewWindowRequestedEventArgs> {
-        &self.inner
+    query!();
+
+    raw!(ICoreWebView2NewWindowRequestedEventArgs, ICoreWebView2NewWindowRequestedEventArgsVTable);
+}
+
+impl DevToolsProtocolEventReceiver {
+    add_event_handler!(
+        add_dev_tools_protocol_event_received,
+        ICoreWebView2DevToolsProtocolEventReceivedEventHandler,
+        DevToolsProtocolEventReceivedEventArgs,
+        ICoreWebView2DevToolsProtocolEventReceivedEventArgsVTable
+    );
+    remove_event_handler!(remove_dev_tools_protocol_event_received);
+
+    query!();
+
+    fn new(inner: ComRc<dyn ICoreWebView2DevToolsProtocolEventReceiver>) -> Self {
+        Self {
+            inner,
+            closed: Rc::new(Cell::new(false)),
+        }
     }
+
+    raw!(
+        ICoreWebView2DevToolsProtocolEventReceiver,
+        ICoreWebView2DevToolsProtocolEventReceiverVTable,
+        DevToolsProtocolEventReceiver::new
+    );
+}
+
+impl DevToolsProtocolEventReceivedEventArgs {
+    get_string!(get_parameter_object_as_json);
+
+    /// The session the event comes from, empty for the top level page. Fails
+    /// with `E_NOINTERFACE` on runtimes before
+    /// `ICoreWebView2DevToolsProtocolEventReceivedEventArgs2`.
+    #[cfg(feature = "sdk-1.0.1185")]
+    pub fn get_session_id(&self) -> Result<String> {
+        self.check_open()?;
+        let args2: ComRc<dyn ICoreWebView2DevToolsProtocolEventReceivedEventArgs2> =
+            query_interface(&self.inner)?;
+        let mut result: LPWSTR = ptr::null_mut();
+        check_hresult(unsafe { args2.get_session_id(&mut result) })?;
+        unsafe { take_string(result) }.map(Option::unwrap_or_default)
+    }
+
+    query!();
+
+    raw!(
+        ICoreWebView2DevToolsProtocolEventReceivedEventArgs,
+        ICoreWebView2DevToolsProtocolEventReceivedEventArgsVTable
+    );
+}
+
+impl NewBrowserVersionAvailableEventArgs {
+    get_string!(get_new_version);
+
+    query!();
+
+    raw!(
+        ICoreWebView2NewBrowserVersionAvailableEventArgs,
+        ICoreWebView2NewBrowserVersionAvailableEventArgsVTable
+    );
+}
+
+impl MoveFocusRequestedEventArgs {
+    get!(get_reason, MoveFocusReason);
+    get_bool!(get_handled);
+    put_bool!(put_handled);
+
+    query!();
+
+    raw!(
+        ICoreWebView2MoveFocusRequestedEventArgs,
+        ICoreWebView2MoveFocusRequestedEventArgsVTable
+    );
 }
 
 // This function is not available from winapi yet.
 // FIXME: linking with GNU toolchain.
 #[link(name = "shlwapi")]
-extern "stdcall" {
+extern "system" {
     fn SHCreateMemStream(p_init: *const u8, cb_init: UINT) -> *mut *mut IStreamVTable;
 }
 
@@ -1136,20 +2210,9 @@ impl Stream {
         }
     }
 
-    /// Create a `Stream` from an owning raw pointer to an `IStream`.
-    ///
-    /// # Safety
-    ///
-    /// See `ComRc::from_raw`.
-    pub unsafe fn from_raw(ppv: *mut *mut IStreamVTable) -> Self {
-        Self {
-            inner: ComRc::from_raw(ppv),
-        }
-    }
+    query!();
 
-    pub fn as_raw(&self) -> &ComRc<dyn IStream> {
-        &self.inner
-    }
+    raw!(IStream, IStreamVTable);
 }
 
 impl io::Read for Stream {
@@ -1187,22 +2250,100 @@ impl io::Write for Stream {
 }
 
 #[doc(inline)]
-pub type MoveFocusReason = sys::CORE_WEBVIEW2_MOVE_FOCUS_REASON;
+pub type MoveFocusReason = raw::CORE_WEBVIEW2_MOVE_FOCUS_REASON;
+
+#[doc(inline)]
+pub type CapturePreviewImageFormat = raw::CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT;
+
+#[doc(inline)]
+pub type PermissionKind = raw::CORE_WEBVIEW2_PERMISSION_KIND;
+
+#[doc(inline)]
+pub type PermissionState = raw::CORE_WEBVIEW2_PERMISSION_STATE;
 
 #[doc(inline)]
-pub use sys::EventRegistrationToken;
+pub type WebResourceContext = raw::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT;
+
+#[doc(inline)]
+pub type ChannelSearchKind = raw::COREWEBVIEW2_CHANNEL_SEARCH_KIND;
+
+#[cfg(feature = "sdk-1.0.774")]
+#[doc(inline)]
+pub type BoundsMode = raw::COREWEBVIEW2_BOUNDS_MODE;
+
+#[doc(inline)]
+pub use raw::EventRegistrationToken;
+
+/// Release channels of the runtime, combined with `|`.
+/// (`COREWEBVIEW2_RELEASE_CHANNELS`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ReleaseChannels(u32);
+
+impl ReleaseChannels {
+    pub const NONE: Self = Self(COREWEBVIEW2_RELEASE_CHANNELS_NONE);
+    /// The Evergreen WebView2 runtime.
+    pub const STABLE: Self = Self(COREWEBVIEW2_RELEASE_CHANNELS_STABLE);
+    pub const BETA: Self = Self(COREWEBVIEW2_RELEASE_CHANNELS_BETA);
+    pub const DEV: Self = Self(COREWEBVIEW2_RELEASE_CHANNELS_DEV);
+    pub const CANARY: Self = Self(COREWEBVIEW2_RELEASE_CHANNELS_CANARY);
+    pub const ALL: Self = Self(
+        COREWEBVIEW2_RELEASE_CHANNELS_STABLE
+            | COREWEBVIEW2_RELEASE_CHANNELS_BETA
+            | COREWEBVIEW2_RELEASE_CHANNELS_DEV
+            | COREWEBVIEW2_RELEASE_CHANNELS_CANARY,
+    );
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Preview channels append their name to the version, e.g.
+    /// `86.0.616.0 canary`.
+    fn from_version_info(info: &str) -> Self {
+        match info.rsplit(' ').next() {
+            Some("beta") => Self::BETA,
+            Some("dev") => Self::DEV,
+            Some("canary") => Self::CANARY,
+            _ => Self::STABLE,
+        }
+    }
+}
+
+impl std::ops::BitOr for ReleaseChannels {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ReleaseChannels {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
 
 /// A webview2 error. Actually, an `HRESULT`.
 #[derive(Debug, Eq, PartialEq)]
-pub struct Error {
-    hresult: HRESULT,
+pub enum Error {
+    /// `RO_E_CLOSED`, from a host or webview after `Host::close`.
+    Closed,
+    /// Any other failed `HRESULT`.
+    HResult(HRESULT),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "webview2 error, HRESULT {:#X}", self.hresult as u32)
+        match self {
+            Error::Closed => write!(f, "webview2 error, the webview was closed"),
+            Error::HResult(hresult) => write!(f, "webview2 error, HRESULT {:#X}", *hresult as u32),
+        }
     }
 }
 
@@ -1210,9 +2351,7 @@ impl std::error::Error for Error {}
 
 impl From<NulError<u16>> for Error {
     fn from(_: NulError<u16>) -> Error {
-        Error {
-            hresult: E_INVALIDARG,
-        }
+        Error::new(E_INVALIDARG)
     }
 }
 
@@ -1227,45 +2366,145 @@ impl From<io::Error> for Error {
 
 impl Error {
     pub fn new(hresult: HRESULT) -> Self {
-        Self { hresult }
+        if hresult == RO_E_CLOSED {
+            Error::Closed
+        } else {
+            Error::HResult(hresult)
+        }
     }
 
     fn into_io_error(self) -> io::Error {
-        if (self.hresult & (0xffff_0000_u32 as i32))
-            == MAKE_HRESULT(SEVERITY_ERROR, FACILITY_WIN32, 0)
-        {
-            io::Error::from_raw_os_error(HRESULT_CODE(self.hresult))
+        let hresult = self.hresult();
+        if (hresult & (0xffff_0000_u32 as i32)) == MAKE_HRESULT(SEVERITY_ERROR, FACILITY_WIN32, 0) {
+            io::Error::from_raw_os_error(HRESULT_CODE(hresult))
         } else {
             io::Error::new(io::ErrorKind::Other, self)
         }
     }
 
     pub fn hresult(&self) -> HRESULT {
-        self.hresult
+        match self {
+            Error::Closed => RO_E_CLOSED,
+            Error::HResult(hresult) => *hresult,
+        }
+    }
+
+    /// Whether it is `Error::Closed`.
+    pub fn is_closed(&self) -> bool {
+        *self == Error::Closed
+    }
+}
+
+/// Called by the wrappers before calling into the object. Hosts and webviews
+/// fail with `Error::Closed` after `Host::close`, the other objects can't be
+/// closed.
+trait CheckOpen {
+    fn check_open(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl CheckOpen for Host {
+    fn check_open(&self) -> Result<()> {
+        if self.closed.get() {
+            Err(Error::Closed)
+        } else {
+            Ok(())
+        }
     }
 }
 
+impl CheckOpen for WebView {
+    fn check_open(&self) -> Result<()> {
+        if self.closed.get() {
+            Err(Error::Closed)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl CheckOpen for Environment {}
+#[cfg(feature = "sdk-1.0.1245")]
+impl CheckOpen for ControllerOptions {}
+#[cfg(feature = "sdk-1.0.2739")]
+impl CheckOpen for ObjectCollection {}
+impl CheckOpen for Settings {}
+impl CheckOpen for ContentLoadingEventArgs {}
+impl CheckOpen for WebMessageReceivedEventArgs {}
+impl CheckOpen for HttpHeadersCollectionIterator {}
+impl CheckOpen for HttpRequestHeaders {}
+impl CheckOpen for HttpResponseHeaders {}
+impl CheckOpen for Deferral {}
+impl CheckOpen for WebResourceRequest {}
+impl CheckOpen for WebResourceResponse {}
+impl CheckOpen for WebResourceRequestedEventArgs {}
+impl CheckOpen for NavigationCompletedEventArgs {}
+impl CheckOpen for NavigationStartingEventArgs {}
+impl CheckOpen for SourceChangedEventArgs {}
+impl CheckOpen for ScriptDialogOpeningEventArgs {}
+impl CheckOpen for PermissionRequestedEventArgs {}
+impl CheckOpen for ProcessFailedEventArgs {}
+impl CheckOpen for NewWindowRequestedEventArgs {}
+impl CheckOpen for DevToolsProtocolEventReceiver {
+    fn check_open(&self) -> Result<()> {
+        if self.closed.get() {
+            Err(Error::Closed)
+        } else {
+            Ok(())
+        }
+    }
+}
+impl CheckOpen for DevToolsProtocolEventReceivedEventArgs {}
+impl CheckOpen for NewBrowserVersionAvailableEventArgs {}
+impl CheckOpen for MoveFocusRequestedEventArgs {}
+impl CheckOpen for Stream {}
+
 /// Check a `HRESULT`, if it is `SUCCEEDED`, return `Ok(())`. Otherwide return
 /// an error containing the `HRESULT`.
 pub fn check_hresult(hresult: HRESULT) -> Result<()> {
     if SUCCEEDED(hresult) {
         Ok(())
     } else {
-        Err(Error { hresult })
+        Err(Error::new(hresult))
+    }
+}
+
+/// Convert a string passed to a callback or returned by a getter. Null is
+/// `None`.
+///
+/// All strings from WebView2 should be converted with this or `take_string`.
+unsafe fn string_from_ptr(s: LPCWSTR) -> Result<Option<String>> {
+    if s.is_null() {
+        return Ok(None);
     }
+    WideCStr::from_ptr_str(s)
+        .to_string()
+        .map(Some)
+        .map_err(|_| Error::new(E_FAIL))
+}
+
+/// `string_from_ptr` for strings the caller owns, which are freed.
+unsafe fn take_string(s: LPWSTR) -> Result<Option<String>> {
+    let result = string_from_ptr(s);
+    CoTaskMemFree(s as _);
+    result
 }
 
 fn to_hresult<T>(r: Result<T>) -> HRESULT {
     match r {
         Ok(_) => S_OK,
-        Err(Error { hresult }) => hresult,
+        Err(e) => e.hresult(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use com::interfaces::IUnknown;
+    use std::cell::Cell;
     use std::io::Read;
+    use std::rc::Rc;
 
     #[test]
     fn test_stream() {
@@ -1274,4 +2513,280 @@ mod tests {
         stream.read_to_end(&mut buf).unwrap();
         assert_eq!(buf, &[4u8; 1024][..]);
     }
+
+    /// Counts the drops of the object it is in, i.e. its last `Release`.
+    struct DropCounter(Rc<Cell<u32>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[com::co_class(implements(ICoreWebView2Deferral))]
+    struct FakeDeferral {
+        completed: Rc<Cell<u32>>,
+        dropped: DropCounter,
+    }
+
+    impl FakeDeferral {
+        fn new() -> Box<Self> {
+            unreachable!()
+        }
+    }
+
+    impl ICoreWebView2Deferral for FakeDeferral {
+        unsafe fn complete(&self) -> HRESULT {
+            self.completed.set(self.completed.get() + 1);
+            S_OK
+        }
+    }
+
+    #[test]
+    fn test_deferral_outlives_handler() {
+        let completed = Rc::new(Cell::new(0));
+        let dropped = Rc::new(Cell::new(0));
+        let fake = FakeDeferral::allocate(completed.clone(), DropCounter(dropped.clone()));
+        // The reference of the event source.
+        let ptr = unsafe {
+            fake.add_ref();
+            Box::into_raw(fake) as *mut *mut ICoreWebView2DeferralVTable
+        };
+
+        // What a handler gets and stores in app state.
+        let stored = RefCell::new(None);
+        *stored.borrow_mut() = Some(Deferral {
+            inner: unsafe { add_ref_to_rc(ptr) },
+        });
+        // The handler returned and the event source let go of the args.
+        drop(unsafe { ComRc::<dyn ICoreWebView2Deferral>::from_raw(ptr) });
+
+        let deferral = stored.borrow_mut().take().unwrap();
+        deferral.complete().unwrap();
+        assert_eq!(completed.get(), 1);
+        assert_eq!(dropped.get(), 0);
+        drop(deferral);
+        assert_eq!(dropped.get(), 1);
+    }
+
+    // Only `get_deferral` does something: it hands out a new deferral, with
+    // a reference for the caller.
+    #[com::co_class(implements(ICoreWebView2WebResourceRequestedEventArgs))]
+    struct FakeWebResourceRequestedEventArgs {
+        deferrals_dropped: Rc<Cell<u32>>,
+    }
+
+    impl FakeWebResourceRequestedEventArgs {
+        fn new() -> Box<Self> {
+            unreachable!()
+        }
+    }
+
+    impl ICoreWebView2WebResourceRequestedEventArgs for FakeWebResourceRequestedEventArgs {
+        unsafe fn get_request(
+            &self,
+            _: *mut *mut *mut ICoreWebView2WebResourceRequestVTable,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn get_response(
+            &self,
+            _: *mut *mut *mut ICoreWebView2WebResourceResponseVTable,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn put_response(
+            &self,
+            _: *mut *mut ICoreWebView2WebResourceResponseVTable,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn get_deferral(
+            &self,
+            deferral: *mut *mut *mut ICoreWebView2DeferralVTable,
+        ) -> HRESULT {
+            let fake = FakeDeferral::allocate(
+                Rc::new(Cell::new(0)),
+                DropCounter(self.deferrals_dropped.clone()),
+            );
+            fake.add_ref();
+            *deferral = Box::into_raw(fake) as *mut *mut ICoreWebView2DeferralVTable;
+            S_OK
+        }
+        unsafe fn get_resource_context(
+            &self,
+            _: *mut CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+    }
+
+    #[test]
+    fn test_out_parameters_are_taken_over() {
+        let dropped = Rc::new(Cell::new(0));
+        let fake = FakeWebResourceRequestedEventArgs::allocate(dropped.clone());
+        let args = unsafe {
+            fake.add_ref();
+            WebResourceRequestedEventArgs {
+                inner: ComRc::from_raw(Box::into_raw(fake)
+                    as *mut *mut ICoreWebView2WebResourceRequestedEventArgsVTable),
+            }
+        };
+
+        let deferral = args.get_deferral().unwrap();
+        let clone = deferral.clone();
+        drop(deferral);
+        assert_eq!(dropped.get(), 0);
+        // Released as often as it was added to, no reference is leaked.
+        drop(clone);
+        assert_eq!(dropped.get(), 1);
+    }
+
+    // Only `close` does something.
+    #[com::co_class(implements(ICoreWebView2Host))]
+    struct FakeHost {
+        closed: Rc<Cell<u32>>,
+    }
+
+    impl FakeHost {
+        fn new() -> Box<Self> {
+            unreachable!()
+        }
+    }
+
+    // `RECT` and `HWND` are those of winapi without the `windows-sys` feature.
+    #[cfg(not(feature = "windows-sys"))]
+    impl ICoreWebView2Host for FakeHost {
+        unsafe fn get_is_visible(&self, _: *mut BOOL) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn put_is_visible(&self, _: BOOL) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn get_bounds(&self, _: *mut RECT) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn put_bounds(&self, _: RECT) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn get_zoom_factor(&self, _: *mut f64) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn put_zoom_factor(&self, _: f64) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn add_zoom_factor_changed(
+            &self,
+            _: *mut *mut ICoreWebView2ZoomFactorChangedEventHandlerVTable,
+            _: *mut EventRegistrationToken,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn remove_zoom_factor_changed(&self, _: EventRegistrationToken) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn set_bounds_and_zoom_factor(&self, _: RECT, _: f64) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn move_focus(&self, _: CORE_WEBVIEW2_MOVE_FOCUS_REASON) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn add_move_focus_requested(
+            &self,
+            _: *mut *mut ICoreWebView2MoveFocusRequestedEventHandlerVTable,
+            _: *mut EventRegistrationToken,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn remove_move_focus_requested(&self, _: EventRegistrationToken) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn add_got_focus(
+            &self,
+            _: *mut *mut ICoreWebView2FocusChangedEventHandlerVTable,
+            _: *mut EventRegistrationToken,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn remove_got_focus(&self, _: EventRegistrationToken) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn add_lost_focus(
+            &self,
+            _: *mut *mut ICoreWebView2FocusChangedEventHandlerVTable,
+            _: *mut EventRegistrationToken,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn remove_lost_focus(&self, _: EventRegistrationToken) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn add_accelerator_key_pressed(
+            &self,
+            _: *mut *mut ICoreWebView2AcceleratorKeyPressedEventHandlerVTable,
+            _: *mut EventRegistrationToken,
+        ) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn remove_accelerator_key_pressed(&self, _: EventRegistrationToken) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn get_parent_window(&self, _: *mut HWND) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn put_parent_window(&self, _: HWND) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn notify_parent_window_position_changed(&self) -> HRESULT {
+            E_NOTIMPL
+        }
+        unsafe fn close(&self) -> HRESULT {
+            self.closed.set(self.closed.get() + 1);
+            S_OK
+        }
+        unsafe fn get_core_web_view2(&self, _: *mut *mut *mut ICoreWebView2VTable) -> HRESULT {
+            E_NOTIMPL
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "windows-sys"))]
+    fn test_close() {
+        let closed = Rc::new(Cell::new(0));
+        let fake = FakeHost::allocate(closed.clone());
+        let host = unsafe {
+            fake.add_ref();
+            Host::from_raw(Box::into_raw(fake) as *mut *mut ICoreWebView2HostVTable)
+        };
+        let clone = host.clone();
+        assert_eq!(host.get_zoom_factor(), Err(Error::new(E_NOTIMPL)));
+
+        host.close().unwrap();
+        assert_eq!(closed.get(), 1);
+        assert_eq!(clone.get_zoom_factor(), Err(Error::Closed));
+        assert!(clone.get_webview().unwrap_err().is_closed());
+        assert_eq!(
+            clone.put_is_visible(true).unwrap_err().hresult(),
+            RO_E_CLOSED
+        );
+        // Closing again does nothing.
+        clone.close().unwrap();
+        assert_eq!(closed.get(), 1);
+    }
+
+    #[test]
+    fn test_release_channels() {
+        let channels = ReleaseChannels::CANARY | ReleaseChannels::DEV;
+        assert!(channels.contains(ReleaseChannels::DEV));
+        assert!(!channels.contains(ReleaseChannels::STABLE));
+        assert!(ReleaseChannels::ALL.contains(channels));
+        assert_eq!(
+            ReleaseChannels::from_version_info("86.0.616.0 canary"),
+            ReleaseChannels::CANARY
+        );
+        assert_eq!(
+            ReleaseChannels::from_version_info("86.0.622.38"),
+            ReleaseChannels::STABLE
+        );
+    }
 }
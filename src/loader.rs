@@ -0,0 +1,289 @@
+//! Creating environments without `WebView2Loader.dll`.
+//!
+//! All the loader does is find the `EmbeddedBrowserWebView.dll` of a runtime
+//! and call it, which this module does in Rust with the `rust-loader`
+//! feature. `EnvironmentBuilder::build` then uses it, and there is neither a
+//! DLL to ship nor a library to link.
+//!
+//! The settings are resolved like the loader does, the first of:
+//!
+//! 1. The registry, under
+//!    `HKLM` or `HKCU\Software\Policies\Microsoft\Edge\WebView2\<setting>`,
+//!    then `...\Software\Microsoft\EmbeddedBrowserWebView\LoaderOverride\<setting>`,
+//!    with the executable's file name or `*` as the value name.
+//! 2. The `WEBVIEW2_BROWSER_EXECUTABLE_FOLDER`, `WEBVIEW2_USER_DATA_FOLDER`,
+//!    `WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS` and
+//!    `WEBVIEW2_RELEASE_CHANNEL_PREFERENCE` environment variables.
+//! 3. What is given to `EnvironmentBuilder`.
+//!
+//! A browser executable folder is a fixed version runtime. Without one, the
+//! Evergreen runtime is used if it is installed, or else the first Edge
+//! preview channel installed, from beta to canary. With a release channel
+//! preference of `1`, or `COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE`, the
+//! channels are searched the other way round, from canary to the Evergreen
+//! runtime. Only the channels in the release channels option are searched.
+
+use crate::{check_hresult, query_interface, Result};
+use com::ComRc;
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::os::windows::ffi::OsStringExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use widestring::WideCString;
+use winapi::shared::minwindef::*;
+use winapi::shared::ntdef::*;
+use winapi::shared::winerror::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS};
+use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryExW, LOAD_WITH_ALTERED_SEARCH_PATH};
+use winapi::um::winreg::*;
+
+use crate::raw::*;
+
+/// Where the runtime DLL is, under the folder of a runtime.
+#[cfg(target_arch = "x86_64")]
+const DLL_PATH: &str = "EBWebView\\x64\\EmbeddedBrowserWebView.dll";
+#[cfg(target_arch = "x86")]
+const DLL_PATH: &str = "EBWebView\\x86\\EmbeddedBrowserWebView.dll";
+#[cfg(target_arch = "aarch64")]
+const DLL_PATH: &str = "EBWebView\\arm64\\EmbeddedBrowserWebView.dll";
+
+/// `runtimeType` of `CreateWebViewEnvironmentWithOptionsInternal`.
+const RUNTIME_INSTALLED: i32 = 0;
+const RUNTIME_FIXED_VERSION: i32 = 1;
+
+type FnCreateWebViewEnvironmentWithOptionsInternal = unsafe extern "system" fn(
+    check_running_instance: bool,
+    runtime_type: i32,
+    user_data_folder: PCWSTR,
+    environment_options: *mut *mut ICoreWebView2EnvironmentOptionsVTable,
+    environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable,
+) -> HRESULT;
+
+/// An installed WebView2 runtime or Edge channel.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Channel {
+    /// The Evergreen WebView2 runtime.
+    Stable,
+    Beta,
+    Dev,
+    Canary,
+}
+
+impl Channel {
+    /// In the order the loader searches them by default.
+    pub const ALL: [Channel; 4] = [
+        Channel::Stable,
+        Channel::Beta,
+        Channel::Dev,
+        Channel::Canary,
+    ];
+
+    /// The EdgeUpdate app ID.
+    fn app_id(self) -> &'static str {
+        match self {
+            Channel::Stable => "{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            Channel::Beta => "{2CD8A007-E189-409D-A2C8-9AF4EF3C72AA}",
+            Channel::Dev => "{0D50BFEC-CD6A-4F9A-964C-C7416E3ACB10}",
+            Channel::Canary => "{65C35B14-6C1D-4122-AC46-7148CC9D6497}",
+        }
+    }
+
+    /// Its `COREWEBVIEW2_RELEASE_CHANNELS_*` flag.
+    fn release_channel(self) -> u32 {
+        match self {
+            Channel::Stable => COREWEBVIEW2_RELEASE_CHANNELS_STABLE,
+            Channel::Beta => COREWEBVIEW2_RELEASE_CHANNELS_BETA,
+            Channel::Dev => COREWEBVIEW2_RELEASE_CHANNELS_DEV,
+            Channel::Canary => COREWEBVIEW2_RELEASE_CHANNELS_CANARY,
+        }
+    }
+
+    /// The folder of the installed runtime, machine wide or for the user.
+    pub fn installed_folder(self) -> Option<PathBuf> {
+        let key = format!(
+            "Software\\Microsoft\\EdgeUpdate\\ClientState\\{}",
+            self.app_id()
+        );
+        read_string(HKEY_LOCAL_MACHINE, &key, "EBWebView", RRF_SUBKEY_WOW6432KEY)
+            .or_else(|| read_string(HKEY_CURRENT_USER, &key, "EBWebView", 0))
+            .map(PathBuf::from)
+            .filter(|folder| folder.join(DLL_PATH).is_file())
+    }
+}
+
+/// The runtime DLL to use, from the settings of the loader.
+pub fn find_runtime(browser_executable_folder: Option<&Path>) -> Result<PathBuf> {
+    let folder = setting(
+        "BrowserExecutableFolder",
+        browser_executable_folder.map(Path::as_os_str),
+    );
+    runtime_dll(folder.as_deref().map(Path::new), None).map(|(dll, _)| dll)
+}
+
+/// Create an environment with the runtime DLL directly.
+pub(crate) fn create_environment(
+    browser_executable_folder: Option<&Path>,
+    user_data_folder: Option<&Path>,
+    options: &ComRc<dyn ICoreWebView2EnvironmentOptions>,
+    environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable,
+) -> Result<()> {
+    let browser_executable_folder = setting(
+        "BrowserExecutableFolder",
+        browser_executable_folder.map(Path::as_os_str),
+    );
+    let user_data_folder = setting("UserDataFolder", user_data_folder.map(Path::as_os_str));
+    if let Some(arguments) = setting("AdditionalBrowserArguments", None) {
+        let arguments = WideCString::from_os_str(arguments)?;
+        check_hresult(unsafe { options.put_additional_browser_arguments(arguments.as_ptr()) })?;
+    }
+
+    let (dll, runtime_type) = runtime_dll(
+        browser_executable_folder.as_deref().map(Path::new),
+        Some(options),
+    )?;
+    let create_fn: FnCreateWebViewEnvironmentWithOptionsInternal = unsafe {
+        let dll = WideCString::from_os_str(dll)?;
+        // So that the DLLs next to it are found.
+        let module = LoadLibraryExW(dll.as_ptr(), ptr::null_mut(), LOAD_WITH_ALTERED_SEARCH_PATH);
+        if module.is_null() {
+            return Err(io::Error::last_os_error().into());
+        }
+        let create_fn = GetProcAddress(
+            module,
+            "CreateWebViewEnvironmentWithOptionsInternal\0".as_ptr() as *const i8,
+        );
+        if create_fn.is_null() {
+            return Err(io::Error::last_os_error().into());
+        }
+        std::mem::transmute(create_fn)
+    };
+
+    let user_data_folder = match user_data_folder {
+        Some(folder) => Some(WideCString::from_os_str(folder)?),
+        None => None,
+    };
+    check_hresult(unsafe {
+        create_fn(
+            true,
+            runtime_type,
+            user_data_folder
+                .as_ref()
+                .map(|p| p.as_ptr())
+                .unwrap_or(ptr::null()),
+            options.as_raw(),
+            environment_created_handler,
+        )
+    })
+}
+
+fn runtime_dll(
+    browser_executable_folder: Option<&Path>,
+    options: Option<&ComRc<dyn ICoreWebView2EnvironmentOptions>>,
+) -> Result<(PathBuf, i32)> {
+    if let Some(folder) = browser_executable_folder {
+        return Ok((folder.join(DLL_PATH), RUNTIME_FIXED_VERSION));
+    }
+    let mut search_kind =
+        COREWEBVIEW2_CHANNEL_SEARCH_KIND::COREWEBVIEW2_CHANNEL_SEARCH_KIND_MOST_STABLE;
+    let mut release_channels = !0;
+    if let Some(options) = options {
+        let options: ComRc<dyn ICoreWebView2EnvironmentOptions7> = query_interface(options)?;
+        check_hresult(unsafe { options.get_channel_search_kind(&mut search_kind) })?;
+        check_hresult(unsafe { options.get_release_channels(&mut release_channels) })?;
+    }
+    let canary_first = setting("ReleaseChannelPreference", None).map_or(
+        search_kind
+            == COREWEBVIEW2_CHANNEL_SEARCH_KIND::COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE,
+        |p| p == "1",
+    );
+    let mut channels = Channel::ALL;
+    if canary_first {
+        channels.reverse();
+    }
+    channels
+        .iter()
+        .filter(|channel| release_channels & channel.release_channel() != 0)
+        .find_map(|channel| channel.installed_folder())
+        .map(|folder| (folder.join(DLL_PATH), RUNTIME_INSTALLED))
+        .ok_or_else(|| io::Error::from_raw_os_error(ERROR_FILE_NOT_FOUND as i32).into())
+}
+
+/// A setting from the registry, an environment variable, or else `value`.
+fn setting(name: &str, value: Option<&OsStr>) -> Option<OsString> {
+    let exe = std::env::current_exe().ok().and_then(|exe| {
+        exe.file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+    });
+    let keys = [
+        format!("Software\\Policies\\Microsoft\\Edge\\WebView2\\{}", name),
+        format!(
+            "Software\\Microsoft\\EmbeddedBrowserWebView\\LoaderOverride\\{}",
+            name
+        ),
+    ];
+    for key in &keys {
+        for &root in &[HKEY_LOCAL_MACHINE, HKEY_CURRENT_USER] {
+            for value_name in exe.iter().map(String::as_str).chain(Some("*")) {
+                if let Some(value) = read_string(root, key, value_name, 0) {
+                    return Some(value);
+                }
+            }
+        }
+    }
+    std::env::var_os(variable_name(name)).or_else(|| value.map(OsStr::to_os_string))
+}
+
+/// `BrowserExecutableFolder` to `WEBVIEW2_BROWSER_EXECUTABLE_FOLDER`.
+fn variable_name(setting: &str) -> String {
+    let mut variable = String::from("WEBVIEW2");
+    for c in setting.chars() {
+        if c.is_ascii_uppercase() {
+            variable.push('_');
+        }
+        variable.push(c.to_ascii_uppercase());
+    }
+    variable
+}
+
+fn read_string(root: HKEY, key: &str, value: &str, flags: DWORD) -> Option<OsString> {
+    let key = WideCString::from_str(key).ok()?;
+    let value = WideCString::from_str(value).ok()?;
+    let read = |data: *mut u16, size: &mut DWORD| unsafe {
+        RegGetValueW(
+            root,
+            key.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_SZ | flags,
+            ptr::null_mut(),
+            data as *mut _,
+            size,
+        ) == ERROR_SUCCESS as LONG
+    };
+    let mut size = 0;
+    if !read(ptr::null_mut(), &mut size) {
+        return None;
+    }
+    let mut data = vec![0u16; size as usize / 2];
+    if !read(data.as_mut_ptr(), &mut size) {
+        return None;
+    }
+    let len = data.iter().position(|&c| c == 0).unwrap_or(data.len());
+    Some(OsString::from_wide(&data[..len]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variable_name() {
+        assert_eq!(
+            variable_name("BrowserExecutableFolder"),
+            "WEBVIEW2_BROWSER_EXECUTABLE_FOLDER"
+        );
+        assert_eq!(
+            variable_name("ReleaseChannelPreference"),
+            "WEBVIEW2_RELEASE_CHANNEL_PREFERENCE"
+        );
+    }
+}
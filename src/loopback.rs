@@ -0,0 +1,348 @@
+//! A tiny HTTP server on the loopback interface, for content that can't be
+//! served by intercepting requests (service workers, for example, are not
+//! allowed on intercepted origins).
+//!
+//! Requests are handled by the same `SchemeHandler`s as
+//! `WebView::register_scheme_handler`. Other local processes can connect to
+//! the port too, so every request must carry a random token: navigating to
+//! `LoopbackServer::url` sets it as a cookie, and requests without the cookie
+//! are rejected.
+
+use crate::net::{invalid_data, random_token, read_head_line, token_eq, MAX_HEAD_SIZE};
+use crate::scheme::{split_uri, Request, Responder, Response, SchemeHandler};
+use crate::WebResourceContext;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const TOKEN_NAME: &str = "webview2_token";
+
+/// Request bodies larger than this are rejected.
+const MAX_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+/// Connections that are still sending their request. Others are closed
+/// right away.
+const MAX_PENDING_CONNECTIONS: usize = 64;
+
+/// A HTTP server on `127.0.0.1` with a random port.
+///
+/// Requests are read on a thread per connection, so a slow client doesn't
+/// hold up the others, and handled one at a time on a background thread.
+/// The server stops when this is dropped.
+pub struct LoopbackServer {
+    port: u16,
+    token: String,
+    stopped: Arc<AtomicBool>,
+}
+
+impl LoopbackServer {
+    /// Start the server. `make_handler` is called on the server thread, so
+    /// the handler itself doesn't need to be `Send`.
+    pub fn start<H: SchemeHandler>(
+        make_handler: impl FnOnce() -> H + Send + 'static,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let port = listener.local_addr()?.port();
+        let token = random_token()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        // Ends once the accept loop and the connections it started are done.
+        let (sender, receiver) = mpsc::channel::<(TcpStream, RawRequest)>();
+        {
+            let token = token.clone();
+            thread::spawn(move || {
+                let handler = make_handler();
+                for (stream, request) in receiver {
+                    let _ = handle_request(stream, request, port, &token, &handler);
+                }
+            });
+        }
+        {
+            let stopped = stopped.clone();
+            let pending = Arc::new(AtomicUsize::new(0));
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let stream = match stream {
+                        Ok(stream) => stream,
+                        Err(_) => continue,
+                    };
+                    if pending.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_CONNECTIONS {
+                        pending.fetch_sub(1, Ordering::SeqCst);
+                        continue;
+                    }
+                    let sender = sender.clone();
+                    let pending = pending.clone();
+                    thread::spawn(move || {
+                        if let Ok(request) = read_connection(&stream) {
+                            let _ = sender.send((stream, request));
+                        }
+                        pending.fetch_sub(1, Ordering::SeqCst);
+                    });
+                }
+            });
+        }
+
+        Ok(Self {
+            port,
+            token,
+            stopped,
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The URL of `path` on this server, with the token. Navigate to it
+    /// before making any other request.
+    pub fn url(&self, path: &str) -> String {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        format!(
+            "http://127.0.0.1:{}{}{}{}={}",
+            self.port, path, separator, TOKEN_NAME, self.token
+        )
+    }
+}
+
+impl Drop for LoopbackServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake up the accept loop.
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+    }
+}
+
+struct RawRequest {
+    method: String,
+    target: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+fn read_request(r: &mut impl BufRead) -> io::Result<RawRequest> {
+    let mut head = r.take(MAX_HEAD_SIZE);
+    let request_line = read_head_line(&mut head)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => (method.to_string(), target.to_string()),
+        _ => return Err(invalid_data("invalid request line")),
+    };
+
+    let mut headers = Vec::new();
+    loop {
+        let line = read_head_line(&mut head)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(i) = line.find(':') {
+            headers.push((
+                line[..i].trim().to_string(),
+                line[i + 1..].trim().to_string(),
+            ));
+        }
+    }
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+    if header("Transfer-Encoding").is_some() {
+        return Err(invalid_data("chunked requests are not supported"));
+    }
+    let len = match header("Content-Length") {
+        Some(len) => len
+            .parse::<usize>()
+            .map_err(|_| invalid_data("invalid Content-Length"))?,
+        None => 0,
+    };
+    if len > MAX_BODY_SIZE {
+        return Err(invalid_data("request too large"));
+    }
+    let mut body = vec![0; len];
+    head.into_inner().read_exact(&mut body)?;
+
+    Ok(RawRequest {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+/// Read the request of a new connection, answering invalid ones.
+fn read_connection(stream: &TcpStream) -> io::Result<RawRequest> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    match read_request(&mut BufReader::new(stream)) {
+        Ok(request) => Ok(request),
+        Err(e) => {
+            write_response(stream.try_clone()?, &Response::new(400, "Bad Request"))?;
+            Err(e)
+        }
+    }
+}
+
+fn handle_request(
+    stream: TcpStream,
+    request: RawRequest,
+    port: u16,
+    token: &str,
+    handler: &impl SchemeHandler,
+) -> io::Result<()> {
+    let uri = format!("http://127.0.0.1:{}{}", port, request.target);
+    let (path, query) = split_uri(&uri);
+    let (query, query_token) = take_token(query);
+    if query_token.map_or(false, |t| token_eq(t, token)) {
+        // Move the token to a cookie and drop it from the URL.
+        let location = match &query {
+            Some(query) => format!("{}?{}", path, query),
+            None => path.to_string(),
+        };
+        let response = Response::new(302, "")
+            .with_header("Location", &location)
+            .with_header(
+                "Set-Cookie",
+                &format!(
+                    "{}={}; Path=/; HttpOnly; SameSite=Strict",
+                    TOKEN_NAME, token
+                ),
+            );
+        return write_response(stream, &response);
+    }
+    let authorized = request
+        .headers
+        .iter()
+        .filter(|(n, _)| n.eq_ignore_ascii_case("Cookie"))
+        .filter_map(|(_, v)| cookie_token(v))
+        .any(|t| token_eq(t, token));
+    if !authorized {
+        return write_response(stream, &Response::new(403, "Forbidden"));
+    }
+
+    let request = Request {
+        method: request.method,
+        path: path.to_string(),
+        query,
+        uri,
+        headers: request.headers,
+        body: request.body,
+        resource_context: WebResourceContext::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_OTHER,
+    };
+    handler.handle(request, Responder::loopback(stream));
+    Ok(())
+}
+
+/// Remove the token parameter from `query`. Returns the remaining query and
+/// the token.
+fn take_token(query: Option<&str>) -> (Option<String>, Option<&str>) {
+    let query = match query {
+        Some(query) => query,
+        None => return (None, None),
+    };
+    let mut token = None;
+    let rest: Vec<&str> = query
+        .split('&')
+        .filter(|param| match param.strip_prefix(TOKEN_NAME) {
+            Some(value) if value.starts_with('=') => {
+                token = Some(&value[1..]);
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    let rest = if rest.is_empty() {
+        None
+    } else {
+        Some(rest.join("&"))
+    };
+    (rest, token)
+}
+
+fn cookie_token(cookie: &str) -> Option<&str> {
+    cookie.split(';').find_map(|c| {
+        let c = c.trim();
+        c.strip_prefix(TOKEN_NAME)
+            .and_then(|value| value.strip_prefix('='))
+    })
+}
+
+pub(crate) fn write_response(mut stream: TcpStream, response: &Response) -> io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status_code, response.reason_phrase
+    );
+    let raw_headers = response.raw_headers();
+    if !raw_headers.is_empty() {
+        head.push_str(&raw_headers);
+        head.push_str("\r\n");
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    ));
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() {
+        let mut data: &[u8] =
+            b"POST /api?x=1 HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Length: 5\r\n\r\nhello";
+        let request = read_request(&mut data).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.target, "/api?x=1");
+        assert_eq!(request.headers.len(), 2);
+        assert_eq!(request.body, b"hello");
+    }
+
+    #[test]
+    fn test_read_request_limits() {
+        let mut data: &[u8] = b"GET / HTTP/1.1\r\nHost: 127.0.0.1\r\n";
+        let e = read_request(&mut data).err().unwrap();
+        assert_eq!(e.to_string(), "unexpected end of request");
+
+        let mut data = b"GET /".to_vec();
+        data.extend(vec![b'a'; MAX_HEAD_SIZE as usize]);
+        data.extend(b" HTTP/1.1\r\n\r\n");
+        let e = read_request(&mut &data[..]).err().unwrap();
+        assert_eq!(e.to_string(), "request head too large");
+
+        // The body doesn't count.
+        let mut data = b"POST / HTTP/1.1\r\nContent-Length: 70000\r\n\r\n".to_vec();
+        data.extend(vec![b'a'; 70000]);
+        assert_eq!(read_request(&mut &data[..]).unwrap().body.len(), 70000);
+    }
+
+    #[test]
+    fn test_take_token() {
+        assert_eq!(
+            take_token(Some("a=1&webview2_token=abc&b=2")),
+            (Some("a=1&b=2".to_string()), Some("abc"))
+        );
+        assert_eq!(take_token(Some("webview2_token=abc")), (None, Some("abc")));
+        assert_eq!(
+            take_token(Some("webview2_tokens=abc")),
+            (Some("webview2_tokens=abc".to_string()), None)
+        );
+    }
+
+    #[test]
+    fn test_cookie_token() {
+        assert_eq!(cookie_token("a=b; webview2_token=abc"), Some("abc"));
+        assert_eq!(cookie_token("a=b"), None);
+    }
+}
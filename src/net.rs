@@ -0,0 +1,69 @@
+//! Helpers shared by the servers on the loopback interface.
+
+use std::io::{self, BufRead};
+use winapi::shared::minwindef::ULONG;
+use winapi::um::ntsecapi::RtlGenRandom;
+
+/// Requests whose request line and headers are larger than this are
+/// rejected.
+pub(crate) const MAX_HEAD_SIZE: u64 = 64 * 1024;
+
+/// 128 random bits in hex, from the cryptographic random number generator
+/// of the system.
+pub(crate) fn random_token() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    if unsafe { RtlGenRandom(bytes.as_mut_ptr() as *mut _, bytes.len() as ULONG) } == 0 {
+        return Err(io::Error::new(io::ErrorKind::Other, "RtlGenRandom failed"));
+    }
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Compare a token from a request with the expected one, in a time that
+/// doesn't depend on how much of it matches.
+pub(crate) fn token_eq(token: &str, expected: &str) -> bool {
+    token.len() == expected.len()
+        && token
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+/// A line of a request head read through `take(MAX_HEAD_SIZE)`, with the line
+/// break. `read_line` alone would read lines of any length.
+pub(crate) fn read_head_line(head: &mut io::Take<impl BufRead>) -> io::Result<String> {
+    let mut line = String::new();
+    head.read_line(&mut line)?;
+    if line.ends_with('\n') {
+        Ok(line)
+    } else if head.limit() == 0 {
+        Err(invalid_data("request head too large"))
+    } else {
+        Err(invalid_data("unexpected end of request"))
+    }
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_token() {
+        let token = random_token().unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(token, random_token().unwrap());
+    }
+
+    #[test]
+    fn test_token_eq() {
+        assert!(token_eq("abc", "abc"));
+        assert!(!token_eq("abd", "abc"));
+        assert!(!token_eq("ab", "abc"));
+        assert!(!token_eq("", "abc"));
+    }
+}
@@ -0,0 +1,459 @@
+//! `ICoreWebView2EnvironmentOptions` and its later versions, which the app
+//! implements for `CreateCoreWebView2EnvironmentWithOptions` to read, and
+//! `ICoreWebView2CustomSchemeRegistration` for the custom schemes in them.
+
+use crate::raw::*;
+use crate::scheme::SchemeRegistration;
+use crate::{add_ref_to_rc, Result};
+use com::ComRc;
+use std::cell::{Cell, RefCell};
+use std::mem;
+use std::ptr;
+use widestring::{WideCStr, WideCString};
+use winapi::shared::basetsd::UINT32;
+use winapi::shared::minwindef::{BOOL, FALSE, TRUE};
+use winapi::shared::ntdef::{LPCWSTR, LPWSTR};
+use winapi::shared::winerror::{E_OUTOFMEMORY, E_POINTER, S_OK};
+use winapi::um::combaseapi::{CoTaskMemAlloc, CoTaskMemFree};
+use winapi::um::winnt::HRESULT;
+
+/// The default `TargetCompatibleBrowserVersion`, that of the runtime the SDK
+/// of the bindings came with.
+const TARGET_COMPATIBLE_BROWSER_VERSION: &str = "82.0.430.0";
+
+/// `COREWEBVIEW2_RELEASE_CHANNELS` with all the flags.
+const ALL_RELEASE_CHANNELS: UINT32 = COREWEBVIEW2_RELEASE_CHANNELS_STABLE
+    | COREWEBVIEW2_RELEASE_CHANNELS_BETA
+    | COREWEBVIEW2_RELEASE_CHANNELS_DEV
+    | COREWEBVIEW2_RELEASE_CHANNELS_CANARY;
+
+type CustomSchemeRegistrations = Vec<ComRc<dyn ICoreWebView2CustomSchemeRegistration>>;
+
+#[com::co_class(implements(
+    ICoreWebView2EnvironmentOptions,
+    ICoreWebView2EnvironmentOptions2,
+    ICoreWebView2EnvironmentOptions3,
+    ICoreWebView2EnvironmentOptions4,
+    ICoreWebView2EnvironmentOptions5,
+    ICoreWebView2EnvironmentOptions6,
+    ICoreWebView2EnvironmentOptions7,
+    ICoreWebView2EnvironmentOptions8
+))]
+pub(crate) struct EnvironmentOptions {
+    additional_browser_arguments: RefCell<WideCString>,
+    language: RefCell<WideCString>,
+    target_compatible_browser_version: RefCell<WideCString>,
+    allow_single_sign_on_using_os_primary_account: Cell<BOOL>,
+    exclusive_user_data_folder_access: Cell<BOOL>,
+    is_custom_crash_reporting_enabled: Cell<BOOL>,
+    custom_scheme_registrations: RefCell<CustomSchemeRegistrations>,
+    enable_tracking_prevention: Cell<BOOL>,
+    are_browser_extensions_enabled: Cell<BOOL>,
+    channel_search_kind: Cell<COREWEBVIEW2_CHANNEL_SEARCH_KIND>,
+    release_channels: Cell<UINT32>,
+    scroll_bar_style: Cell<COREWEBVIEW2_SCROLLBAR_STYLE>,
+}
+
+impl EnvironmentOptions {
+    /// With the defaults.
+    fn new() -> Box<Self> {
+        Self::allocate(
+            RefCell::new(WideCString::default()),
+            RefCell::new(WideCString::default()),
+            RefCell::new(WideCString::from_str(TARGET_COMPATIBLE_BROWSER_VERSION).unwrap()),
+            Cell::new(FALSE),
+            Cell::new(FALSE),
+            Cell::new(FALSE),
+            RefCell::new(Vec::new()),
+            Cell::new(TRUE),
+            Cell::new(FALSE),
+            Cell::new(
+                COREWEBVIEW2_CHANNEL_SEARCH_KIND::COREWEBVIEW2_CHANNEL_SEARCH_KIND_MOST_STABLE,
+            ),
+            Cell::new(ALL_RELEASE_CHANNELS),
+            Cell::new(COREWEBVIEW2_SCROLLBAR_STYLE::COREWEBVIEW2_SCROLLBAR_STYLE_DEFAULT),
+        )
+    }
+
+    /// With the defaults.
+    pub(crate) fn create() -> ComRc<dyn ICoreWebView2EnvironmentOptions> {
+        let options = Self::new();
+        unsafe {
+            use com::interfaces::IUnknown;
+            options.add_ref();
+            ComRc::from_raw(
+                Box::into_raw(options) as *mut *mut ICoreWebView2EnvironmentOptionsVTable
+            )
+        }
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions for EnvironmentOptions {
+    unsafe fn get_additional_browser_arguments(&self, value: *mut LPWSTR) -> HRESULT {
+        get_string(&self.additional_browser_arguments, value)
+    }
+
+    unsafe fn put_additional_browser_arguments(&self, value: LPCWSTR) -> HRESULT {
+        put_string(&self.additional_browser_arguments, value)
+    }
+
+    unsafe fn get_language(&self, value: *mut LPWSTR) -> HRESULT {
+        get_string(&self.language, value)
+    }
+
+    unsafe fn put_language(&self, value: LPCWSTR) -> HRESULT {
+        put_string(&self.language, value)
+    }
+
+    unsafe fn get_target_compatible_browser_version(&self, value: *mut LPWSTR) -> HRESULT {
+        get_string(&self.target_compatible_browser_version, value)
+    }
+
+    unsafe fn put_target_compatible_browser_version(&self, value: LPCWSTR) -> HRESULT {
+        put_string(&self.target_compatible_browser_version, value)
+    }
+
+    unsafe fn get_allow_single_sign_on_using_os_primary_account(
+        &self,
+        allow: *mut BOOL,
+    ) -> HRESULT {
+        get(&self.allow_single_sign_on_using_os_primary_account, allow)
+    }
+
+    unsafe fn put_allow_single_sign_on_using_os_primary_account(&self, allow: BOOL) -> HRESULT {
+        put(&self.allow_single_sign_on_using_os_primary_account, allow)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions2 for EnvironmentOptions {
+    unsafe fn get_exclusive_user_data_folder_access(&self, value: *mut BOOL) -> HRESULT {
+        get(&self.exclusive_user_data_folder_access, value)
+    }
+
+    unsafe fn put_exclusive_user_data_folder_access(&self, value: BOOL) -> HRESULT {
+        put(&self.exclusive_user_data_folder_access, value)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions3 for EnvironmentOptions {
+    unsafe fn get_is_custom_crash_reporting_enabled(&self, value: *mut BOOL) -> HRESULT {
+        get(&self.is_custom_crash_reporting_enabled, value)
+    }
+
+    unsafe fn put_is_custom_crash_reporting_enabled(&self, value: BOOL) -> HRESULT {
+        put(&self.is_custom_crash_reporting_enabled, value)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions4 for EnvironmentOptions {
+    unsafe fn get_custom_scheme_registrations(
+        &self,
+        count: *mut UINT32,
+        scheme_registrations: *mut *mut *mut *mut ICoreWebView2CustomSchemeRegistrationVTable,
+    ) -> HRESULT {
+        if count.is_null() || scheme_registrations.is_null() {
+            return E_POINTER;
+        }
+        let registrations = self.custom_scheme_registrations.borrow();
+        let array = CoTaskMemAlloc(registrations.len() * mem::size_of::<usize>())
+            as *mut *mut *mut ICoreWebView2CustomSchemeRegistrationVTable;
+        if array.is_null() && !registrations.is_empty() {
+            return E_OUTOFMEMORY;
+        }
+        // A reference for the caller each.
+        for (i, registration) in registrations.iter().enumerate() {
+            let registration = registration.clone();
+            *array.add(i) = registration.as_raw();
+            mem::forget(registration);
+        }
+        *count = registrations.len() as UINT32;
+        *scheme_registrations = array;
+        S_OK
+    }
+
+    unsafe fn set_custom_scheme_registrations(
+        &self,
+        count: UINT32,
+        scheme_registrations: *mut *mut *mut ICoreWebView2CustomSchemeRegistrationVTable,
+    ) -> HRESULT {
+        if scheme_registrations.is_null() && count != 0 {
+            return E_POINTER;
+        }
+        *self.custom_scheme_registrations.borrow_mut() = (0..count as usize)
+            .map(|i| add_ref_to_rc(*scheme_registrations.add(i)))
+            .collect();
+        S_OK
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions5 for EnvironmentOptions {
+    unsafe fn get_enable_tracking_prevention(&self, value: *mut BOOL) -> HRESULT {
+        get(&self.enable_tracking_prevention, value)
+    }
+
+    unsafe fn put_enable_tracking_prevention(&self, value: BOOL) -> HRESULT {
+        put(&self.enable_tracking_prevention, value)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions6 for EnvironmentOptions {
+    unsafe fn get_are_browser_extensions_enabled(&self, value: *mut BOOL) -> HRESULT {
+        get(&self.are_browser_extensions_enabled, value)
+    }
+
+    unsafe fn put_are_browser_extensions_enabled(&self, value: BOOL) -> HRESULT {
+        put(&self.are_browser_extensions_enabled, value)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions7 for EnvironmentOptions {
+    unsafe fn get_channel_search_kind(
+        &self,
+        value: *mut COREWEBVIEW2_CHANNEL_SEARCH_KIND,
+    ) -> HRESULT {
+        get(&self.channel_search_kind, value)
+    }
+
+    unsafe fn put_channel_search_kind(&self, value: COREWEBVIEW2_CHANNEL_SEARCH_KIND) -> HRESULT {
+        put(&self.channel_search_kind, value)
+    }
+
+    unsafe fn get_release_channels(&self, value: *mut UINT32) -> HRESULT {
+        get(&self.release_channels, value)
+    }
+
+    unsafe fn put_release_channels(&self, value: UINT32) -> HRESULT {
+        put(&self.release_channels, value)
+    }
+}
+
+impl ICoreWebView2EnvironmentOptions8 for EnvironmentOptions {
+    unsafe fn get_scroll_bar_style(&self, value: *mut COREWEBVIEW2_SCROLLBAR_STYLE) -> HRESULT {
+        get(&self.scroll_bar_style, value)
+    }
+
+    unsafe fn put_scroll_bar_style(&self, value: COREWEBVIEW2_SCROLLBAR_STYLE) -> HRESULT {
+        put(&self.scroll_bar_style, value)
+    }
+}
+
+#[com::co_class(implements(ICoreWebView2CustomSchemeRegistration))]
+pub(crate) struct CustomSchemeRegistration {
+    scheme_name: RefCell<WideCString>,
+    treat_as_secure: Cell<BOOL>,
+    allowed_origins: RefCell<Vec<WideCString>>,
+    has_authority_component: Cell<BOOL>,
+}
+
+impl CustomSchemeRegistration {
+    /// An empty scheme name, not secure, no allowed origins and no authority
+    /// component.
+    fn new() -> Box<Self> {
+        Self::allocate(
+            RefCell::new(WideCString::default()),
+            Cell::new(FALSE),
+            RefCell::new(Vec::new()),
+            Cell::new(FALSE),
+        )
+    }
+
+    pub(crate) fn create(
+        registration: &SchemeRegistration,
+    ) -> Result<ComRc<dyn ICoreWebView2CustomSchemeRegistration>> {
+        let allowed_origins = registration
+            .allowed_origins
+            .iter()
+            .map(|origin| WideCString::from_str(origin))
+            .collect::<std::result::Result<_, _>>()?;
+        let scheme_name = WideCString::from_str(&registration.scheme)?;
+        let custom = Self::new();
+        *custom.scheme_name.borrow_mut() = scheme_name;
+        custom
+            .treat_as_secure
+            .set(registration.treat_as_secure as BOOL);
+        *custom.allowed_origins.borrow_mut() = allowed_origins;
+        custom
+            .has_authority_component
+            .set(registration.has_authority_component as BOOL);
+        unsafe {
+            use com::interfaces::IUnknown;
+            custom.add_ref();
+            Ok(ComRc::from_raw(
+                Box::into_raw(custom) as *mut *mut ICoreWebView2CustomSchemeRegistrationVTable
+            ))
+        }
+    }
+}
+
+impl ICoreWebView2CustomSchemeRegistration for CustomSchemeRegistration {
+    unsafe fn get_scheme_name(&self, scheme_name: *mut LPWSTR) -> HRESULT {
+        get_string(&self.scheme_name, scheme_name)
+    }
+
+    unsafe fn get_treat_as_secure(&self, treat_as_secure: *mut BOOL) -> HRESULT {
+        get(&self.treat_as_secure, treat_as_secure)
+    }
+
+    unsafe fn put_treat_as_secure(&self, value: BOOL) -> HRESULT {
+        put(&self.treat_as_secure, value)
+    }
+
+    unsafe fn get_allowed_origins(
+        &self,
+        allowed_origins_count: *mut UINT32,
+        allowed_origins: *mut *mut LPWSTR,
+    ) -> HRESULT {
+        if allowed_origins_count.is_null() || allowed_origins.is_null() {
+            return E_POINTER;
+        }
+        let origins = self.allowed_origins.borrow();
+        let array = CoTaskMemAlloc(origins.len() * mem::size_of::<LPWSTR>()) as *mut LPWSTR;
+        if array.is_null() && !origins.is_empty() {
+            return E_OUTOFMEMORY;
+        }
+        for (i, origin) in origins.iter().enumerate() {
+            let copy = co_task_mem_copy(origin);
+            if copy.is_null() {
+                for j in 0..i {
+                    CoTaskMemFree(*array.add(j) as _);
+                }
+                CoTaskMemFree(array as _);
+                return E_OUTOFMEMORY;
+            }
+            *array.add(i) = copy;
+        }
+        *allowed_origins_count = origins.len() as UINT32;
+        *allowed_origins = array;
+        S_OK
+    }
+
+    unsafe fn set_allowed_origins(
+        &self,
+        allowed_origins_count: UINT32,
+        allowed_origins: *const LPCWSTR,
+    ) -> HRESULT {
+        if allowed_origins.is_null() && allowed_origins_count != 0 {
+            return E_POINTER;
+        }
+        *self.allowed_origins.borrow_mut() = (0..allowed_origins_count as usize)
+            .map(|i| WideCStr::from_ptr_str(*allowed_origins.add(i)).to_ucstring())
+            .collect();
+        S_OK
+    }
+
+    unsafe fn get_has_authority_component(&self, has_authority_component: *mut BOOL) -> HRESULT {
+        get(&self.has_authority_component, has_authority_component)
+    }
+
+    unsafe fn put_has_authority_component(&self, value: BOOL) -> HRESULT {
+        put(&self.has_authority_component, value)
+    }
+}
+
+unsafe fn get<T: Copy>(field: &Cell<T>, value: *mut T) -> HRESULT {
+    if value.is_null() {
+        return E_POINTER;
+    }
+    *value = field.get();
+    S_OK
+}
+
+fn put<T: Copy>(field: &Cell<T>, value: T) -> HRESULT {
+    field.set(value);
+    S_OK
+}
+
+/// Return a copy allocated with `CoTaskMemAlloc`, for the caller to free.
+unsafe fn get_string(field: &RefCell<WideCString>, value: *mut LPWSTR) -> HRESULT {
+    if value.is_null() {
+        return E_POINTER;
+    }
+    let copy = co_task_mem_copy(&field.borrow());
+    if copy.is_null() {
+        return E_OUTOFMEMORY;
+    }
+    *value = copy;
+    S_OK
+}
+
+/// Null if out of memory.
+unsafe fn co_task_mem_copy(s: &WideCStr) -> LPWSTR {
+    let len = s.len() + 1;
+    let copy = CoTaskMemAlloc(len * 2) as LPWSTR;
+    if !copy.is_null() {
+        ptr::copy_nonoverlapping(s.as_ptr(), copy, len);
+    }
+    copy
+}
+
+/// Null is the same as empty.
+unsafe fn put_string(field: &RefCell<WideCString>, value: LPCWSTR) -> HRESULT {
+    *field.borrow_mut() = if value.is_null() {
+        WideCString::default()
+    } else {
+        WideCStr::from_ptr_str(value).to_ucstring()
+    };
+    S_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        let options = EnvironmentOptions::create();
+        let language = WideCString::from_str("de-DE").unwrap();
+        unsafe {
+            assert_eq!(options.put_language(language.as_ptr()), S_OK);
+            let mut value = ptr::null_mut();
+            assert_eq!(options.get_language(&mut value), S_OK);
+            assert_eq!(WideCStr::from_ptr_str(value).to_string_lossy(), "de-DE");
+            CoTaskMemFree(value as _);
+
+            assert_eq!(options.get_additional_browser_arguments(&mut value), S_OK);
+            assert_eq!(WideCStr::from_ptr_str(value).len(), 0);
+            CoTaskMemFree(value as _);
+
+            let options7: ComRc<dyn ICoreWebView2EnvironmentOptions7> =
+                crate::query_interface(&options).unwrap();
+            let mut channels = 0;
+            assert_eq!(options7.get_release_channels(&mut channels), S_OK);
+            assert_eq!(channels, ALL_RELEASE_CHANNELS);
+        }
+    }
+
+    #[test]
+    fn test_custom_scheme_registration() {
+        let registration = CustomSchemeRegistration::create(&SchemeRegistration {
+            scheme: "app".into(),
+            allowed_origins: vec!["app://*".into(), "https://example.com".into()],
+            treat_as_secure: true,
+            has_authority_component: true,
+        })
+        .unwrap();
+        unsafe {
+            let mut name = ptr::null_mut();
+            assert_eq!(registration.get_scheme_name(&mut name), S_OK);
+            assert_eq!(WideCStr::from_ptr_str(name).to_string_lossy(), "app");
+            CoTaskMemFree(name as _);
+
+            let mut count = 0;
+            let mut origins = ptr::null_mut();
+            assert_eq!(
+                registration.get_allowed_origins(&mut count, &mut origins),
+                S_OK
+            );
+            assert_eq!(count, 2);
+            let second = *origins.add(1);
+            assert_eq!(
+                WideCStr::from_ptr_str(second).to_string_lossy(),
+                "https://example.com"
+            );
+            for i in 0..count as usize {
+                CoTaskMemFree(*origins.add(i) as _);
+            }
+            CoTaskMemFree(origins as _);
+        }
+    }
+}
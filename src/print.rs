@@ -0,0 +1,353 @@
+//! Installed printers, print settings that can be saved and printing to PDF.
+//!
+//! This SDK version has no print API (`ICoreWebView2PrintSettings` and
+//! `PrintToPdf` came later). `PrintSettings` has the fields of
+//! `ICoreWebView2PrintSettings2` and serializes with serde, so the last used
+//! settings can be stored. `WebView::print_to_pdf` applies them with the
+//! DevTools protocol. The fields for printers are kept to be applied once the
+//! API is available, `PrintSettings::resolve_printer` checks that the stored
+//! printer is still installed.
+
+use crate::text::escape_html;
+use crate::{Error, Result, WebView};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io;
+use std::mem;
+use std::ptr;
+use std::slice;
+use widestring::WideCStr;
+use winapi::shared::minwindef::*;
+use winapi::shared::winerror::{ERROR_INSUFFICIENT_BUFFER, E_FAIL};
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::winspool::*;
+
+/// An installed printer.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Printer {
+    /// The name to use as `PrintSettings::printer_name`.
+    pub name: String,
+    pub is_default: bool,
+}
+
+/// Local and connected network printers.
+pub fn printers() -> Result<Vec<Printer>> {
+    let default = default_printer()?;
+    let flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+    let mut needed: DWORD = 0;
+    let mut count: DWORD = 0;
+    // `PRINTER_INFO_4W` entries followed by their strings, aligned for the
+    // entries. Printers can be added between the call that gets the size and
+    // the one that fills the buffer, so this is repeated until it fits.
+    let mut buffer: Vec<PRINTER_INFO_4W> = Vec::new();
+    loop {
+        let ok = unsafe {
+            EnumPrintersW(
+                flags,
+                ptr::null_mut(),
+                4,
+                buffer.as_mut_ptr() as *mut BYTE,
+                (buffer.capacity() * mem::size_of::<PRINTER_INFO_4W>()) as DWORD,
+                &mut needed,
+                &mut count,
+            )
+        };
+        if ok != 0 {
+            break;
+        }
+        if unsafe { GetLastError() } != ERROR_INSUFFICIENT_BUFFER {
+            return Err(io::Error::last_os_error().into());
+        }
+        buffer = Vec::with_capacity(needed as usize / mem::size_of::<PRINTER_INFO_4W>() + 1);
+    }
+    let infos = unsafe { slice::from_raw_parts(buffer.as_ptr(), count as usize) };
+    Ok(infos
+        .iter()
+        .map(|info| {
+            let name = unsafe { WideCStr::from_ptr_str(info.pPrinterName) }.to_string_lossy();
+            Printer {
+                is_default: default.as_ref() == Some(&name),
+                name,
+            }
+        })
+        .collect())
+}
+
+/// Name of the default printer of the user, if there is one.
+pub fn default_printer() -> Result<Option<String>> {
+    let mut len: DWORD = 0;
+    unsafe {
+        if GetDefaultPrinterW(ptr::null_mut(), &mut len) == 0
+            && GetLastError() != ERROR_INSUFFICIENT_BUFFER
+        {
+            // `ERROR_FILE_NOT_FOUND`: no default printer.
+            return Ok(None);
+        }
+    }
+    let mut name = vec![0u16; len as usize];
+    if unsafe { GetDefaultPrinterW(name.as_mut_ptr(), &mut len) } == 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    Ok(Some(
+        WideCStr::from_slice_with_nul(&name)
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidData))?
+            .to_string_lossy(),
+    ))
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrintOrientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrintCollation {
+    /// The printer's default.
+    Default,
+    Collated,
+    Uncollated,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrintColorMode {
+    /// The printer's default.
+    Default,
+    Color,
+    Grayscale,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrintDuplex {
+    /// The printer's default.
+    Default,
+    OneSided,
+    TwoSidedLongEdge,
+    TwoSidedShortEdge,
+}
+
+/// Settings of a print job. Lengths are in inches.
+///
+/// `WebView::print_to_pdf` applies the orientation, scale, page size,
+/// margins, backgrounds, header and footer and page ranges. The other fields,
+/// from `should_print_selection_only` on, are for printers and don't apply to
+/// a PDF.
+///
+/// Fields missing from stored settings get their default, so settings saved
+/// by an older version can still be read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrintSettings {
+    pub orientation: PrintOrientation,
+    /// From 0.1 to 2.
+    pub scale_factor: f64,
+    pub page_width: f64,
+    pub page_height: f64,
+    pub margin_top: f64,
+    pub margin_bottom: f64,
+    pub margin_left: f64,
+    pub margin_right: f64,
+    pub should_print_backgrounds: bool,
+    pub should_print_selection_only: bool,
+    pub should_print_header_and_footer: bool,
+    /// Title in the header. The document title when empty.
+    pub header_title: String,
+    /// URI in the footer. The document URI when empty.
+    pub footer_uri: String,
+    /// Like `"1-3, 5"`. All pages when empty.
+    pub page_ranges: String,
+    pub pages_per_side: u32,
+    pub copies: u32,
+    pub collation: PrintCollation,
+    pub color_mode: PrintColorMode,
+    pub duplex: PrintDuplex,
+    /// The default printer when `None`.
+    pub printer_name: Option<String>,
+}
+
+impl Default for PrintSettings {
+    /// The defaults of `ICoreWebView2PrintSettings`: a portrait letter page
+    /// with 1 cm margins.
+    fn default() -> Self {
+        Self {
+            orientation: PrintOrientation::Portrait,
+            scale_factor: 1.0,
+            page_width: 8.5,
+            page_height: 11.0,
+            margin_top: 0.4,
+            margin_bottom: 0.4,
+            margin_left: 0.4,
+            margin_right: 0.4,
+            should_print_backgrounds: false,
+            should_print_selection_only: false,
+            should_print_header_and_footer: false,
+            header_title: String::new(),
+            footer_uri: String::new(),
+            page_ranges: String::new(),
+            pages_per_side: 1,
+            copies: 1,
+            collation: PrintCollation::Default,
+            color_mode: PrintColorMode::Default,
+            duplex: PrintDuplex::Default,
+            printer_name: None,
+        }
+    }
+}
+
+impl PrintSettings {
+    /// `printer_name` if that printer is still installed, otherwise the
+    /// default printer.
+    pub fn resolve_printer(&self) -> Result<Option<String>> {
+        Ok(pick_printer(self.printer_name.as_deref(), &printers()?))
+    }
+
+    /// Parameters of `Page.printToPDF`.
+    fn pdf_parameters(&self) -> Value {
+        let mut parameters = json!({
+            "landscape": self.orientation == PrintOrientation::Landscape,
+            "scale": self.scale_factor,
+            "paperWidth": self.page_width,
+            "paperHeight": self.page_height,
+            "marginTop": self.margin_top,
+            "marginBottom": self.margin_bottom,
+            "marginLeft": self.margin_left,
+            "marginRight": self.margin_right,
+            "printBackground": self.should_print_backgrounds,
+            "displayHeaderFooter": self.should_print_header_and_footer,
+            "pageRanges": self.page_ranges,
+        });
+        // The date and page numbers of the default templates, with the title
+        // or URI replaced.
+        if !self.header_title.is_empty() {
+            parameters["headerTemplate"] = header_footer_template(
+                r#"<span class="date"></span>"#,
+                &escape_html(&self.header_title),
+            )
+            .into();
+        }
+        if !self.footer_uri.is_empty() {
+            parameters["footerTemplate"] = header_footer_template(
+                &escape_html(&self.footer_uri),
+                r#"<span class="pageNumber"></span>/<span class="totalPages"></span>"#,
+            )
+            .into();
+        }
+        parameters
+    }
+}
+
+fn header_footer_template(left: &str, right: &str) -> String {
+    format!(
+        "<div style=\"font-size: 8px; width: 100%; margin: 0 0.4in; display: flex; \
+         justify-content: space-between\"><span>{}</span><span>{}</span></div>",
+        left, right
+    )
+}
+
+impl WebView {
+    /// Print the top level document to a PDF with `Page.printToPDF`, and
+    /// call `callback` with the PDF data.
+    pub fn print_to_pdf(
+        &self,
+        settings: &PrintSettings,
+        callback: impl FnOnce(Vec<u8>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.call_cdp(
+            "Page.printToPDF",
+            settings.pdf_parameters(),
+            move |result| {
+                let data = result["data"].as_str().ok_or_else(|| Error::new(E_FAIL))?;
+                let data = base64::decode(data).map_err(|_| Error::new(E_FAIL))?;
+                callback(data)
+            },
+        )
+    }
+}
+
+fn pick_printer(saved: Option<&str>, printers: &[Printer]) -> Option<String> {
+    printers
+        .iter()
+        .find(|p| Some(p.name.as_str()) == saved)
+        .or_else(|| printers.iter().find(|p| p.is_default))
+        .map(|p| p.name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_settings_serde() {
+        let settings = PrintSettings {
+            orientation: PrintOrientation::Landscape,
+            copies: 2,
+            printer_name: Some("Office".into()),
+            ..PrintSettings::default()
+        };
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PrintSettings>(&json).unwrap(),
+            settings
+        );
+        let partial: PrintSettings = serde_json::from_str(r#"{"copies":3}"#).unwrap();
+        assert_eq!(partial.copies, 3);
+        assert_eq!(partial.page_width, 8.5);
+    }
+
+    #[test]
+    fn test_pdf_parameters() {
+        let parameters = PrintSettings::default().pdf_parameters();
+        assert_eq!(
+            parameters,
+            json!({
+                "landscape": false,
+                "scale": 1.0,
+                "paperWidth": 8.5,
+                "paperHeight": 11.0,
+                "marginTop": 0.4,
+                "marginBottom": 0.4,
+                "marginLeft": 0.4,
+                "marginRight": 0.4,
+                "printBackground": false,
+                "displayHeaderFooter": false,
+                "pageRanges": "",
+            })
+        );
+
+        let settings = PrintSettings {
+            orientation: PrintOrientation::Landscape,
+            should_print_header_and_footer: true,
+            header_title: "<Report>".into(),
+            page_ranges: "1-3, 5".into(),
+            ..PrintSettings::default()
+        };
+        let parameters = settings.pdf_parameters();
+        assert_eq!(parameters["landscape"], true);
+        assert_eq!(parameters["pageRanges"], "1-3, 5");
+        let header = parameters["headerTemplate"].as_str().unwrap();
+        assert!(header.contains(r#"<span class="date"></span>"#));
+        assert!(header.contains("&lt;Report&gt;"));
+        assert!(parameters.get("footerTemplate").is_none());
+    }
+
+    #[test]
+    fn test_pick_printer() {
+        let printers = [
+            Printer {
+                name: "Office".into(),
+                is_default: false,
+            },
+            Printer {
+                name: "Home".into(),
+                is_default: true,
+            },
+        ];
+        assert_eq!(
+            pick_printer(Some("Office"), &printers),
+            Some("Office".into())
+        );
+        assert_eq!(pick_printer(Some("Gone"), &printers), Some("Home".into()));
+        assert_eq!(pick_printer(None, &printers), Some("Home".into()));
+        assert_eq!(pick_printer(None, &[]), None);
+    }
+}
@@ -0,0 +1,316 @@
+//! Resource limits for the browser processes of a webview.
+//!
+//! This SDK version has no way to list the processes of a webview, so they
+//! are found by walking the process tree down from the browser process. Child
+//! processes started later by the browser end up in the same job object
+//! automatically.
+//!
+//! Webviews created with the same user data folder share a browser process,
+//! so the limits apply to all of them.
+
+use crate::{Result, WebView};
+use std::cell::Cell;
+use std::io;
+use std::mem;
+use std::ptr;
+use winapi::shared::minwindef::*;
+use winapi::um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE};
+use winapi::um::jobapi2::{AssignProcessToJobObject, CreateJobObjectW, SetInformationJobObject};
+use winapi::um::processthreadsapi::{GetProcessTimes, OpenProcess, SetPriorityClass};
+use winapi::um::tlhelp32::{
+    CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
+};
+use winapi::um::winbase::{
+    ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+    IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS,
+};
+use winapi::um::winnt::*;
+
+/// Scheduling priority of a process.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PriorityClass {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+}
+
+impl PriorityClass {
+    fn as_raw(self) -> DWORD {
+        match self {
+            PriorityClass::Idle => IDLE_PRIORITY_CLASS,
+            PriorityClass::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            PriorityClass::Normal => NORMAL_PRIORITY_CLASS,
+            PriorityClass::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            PriorityClass::High => HIGH_PRIORITY_CLASS,
+        }
+    }
+}
+
+/// Limits of a `ProcessJob`. `None` means unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct JobLimits {
+    /// Committed memory of each process, in bytes.
+    pub process_memory: Option<usize>,
+    /// Committed memory of all processes together, in bytes.
+    pub job_memory: Option<usize>,
+    /// Percentage of CPU time all processes together may use, from 1 to 100.
+    /// This is a hard cap, the processes are throttled even when the CPU is
+    /// otherwise idle.
+    pub cpu_rate: Option<u32>,
+    pub priority_class: Option<PriorityClass>,
+}
+
+/// A job object that limits the resources of the processes in it.
+///
+/// Processes stay in the job, and the limits stay in effect, after this is
+/// dropped.
+pub struct ProcessJob {
+    handle: HANDLE,
+    /// Whether CPU rate control is enabled. It is only set when it is or was,
+    /// so that jobs without a CPU rate work where it isn't available, e.g.
+    /// before Windows 8.
+    cpu_rate_set: Cell<bool>,
+}
+
+impl ProcessJob {
+    pub fn new(limits: &JobLimits) -> Result<Self> {
+        let handle = unsafe { CreateJobObjectW(ptr::null_mut(), ptr::null()) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error().into());
+        }
+        let job = ProcessJob {
+            handle,
+            cpu_rate_set: Cell::new(false),
+        };
+        job.set_limits(limits)?;
+        Ok(job)
+    }
+
+    /// Replace the limits of the job.
+    pub fn set_limits(&self, limits: &JobLimits) -> Result<()> {
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { mem::zeroed() };
+        if let Some(memory) = limits.process_memory {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+            info.ProcessMemoryLimit = memory;
+        }
+        if let Some(memory) = limits.job_memory {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+            info.JobMemoryLimit = memory;
+        }
+        if let Some(priority_class) = limits.priority_class {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PRIORITY_CLASS;
+            info.BasicLimitInformation.PriorityClass = priority_class.as_raw();
+        }
+        self.set_information(JobObjectExtendedLimitInformation, &mut info)?;
+
+        if limits.cpu_rate.is_none() && !self.cpu_rate_set.get() {
+            return Ok(());
+        }
+        // Zeroed, without flags, disables a previous rate.
+        let mut info: JOBOBJECT_CPU_RATE_CONTROL_INFORMATION = unsafe { mem::zeroed() };
+        if let Some(rate) = limits.cpu_rate {
+            info.ControlFlags =
+                JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+            // In 1/100 of a percent.
+            unsafe { *info.u.CpuRate_mut() = rate.max(1).min(100) * 100 };
+        }
+        self.set_information(JobObjectCpuRateControlInformation, &mut info)?;
+        self.cpu_rate_set.set(limits.cpu_rate.is_some());
+        Ok(())
+    }
+
+    fn set_information<T>(&self, class: JOBOBJECTINFOCLASS, info: &mut T) -> Result<()> {
+        let ok = unsafe {
+            SetInformationJobObject(
+                self.handle,
+                class,
+                info as *mut T as *mut _,
+                mem::size_of::<T>() as DWORD,
+            )
+        };
+        if ok == 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    /// Put the browser process of `webview` and all its child processes in
+    /// the job.
+    pub fn assign(&self, webview: &WebView) -> Result<()> {
+        for id in webview.get_process_ids()? {
+            self.assign_process(id)?;
+        }
+        Ok(())
+    }
+
+    pub fn assign_process(&self, process_id: u32) -> Result<()> {
+        let process = Handle::open_process(process_id, PROCESS_SET_QUOTA | PROCESS_TERMINATE)?;
+        if unsafe { AssignProcessToJobObject(self.handle, process.0) } == 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    pub fn as_raw(&self) -> HANDLE {
+        self.handle
+    }
+}
+
+impl Drop for ProcessJob {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.handle);
+        }
+    }
+}
+
+/// A handle that is closed on drop.
+struct Handle(HANDLE);
+
+impl Handle {
+    fn open_process(process_id: u32, access: DWORD) -> Result<Self> {
+        let handle = unsafe { OpenProcess(access, FALSE, process_id) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(Handle(handle))
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+impl WebView {
+    /// Ids of the browser process and all processes started by it, the
+    /// browser process first.
+    pub fn get_process_ids(&self) -> Result<Vec<u32>> {
+        let browser = self.get_browser_process_id()?;
+        Ok(descendants(browser, &process_parents()?))
+    }
+
+    /// Set the priority class of the browser process and its child
+    /// processes. Processes started later get the default priority, use a
+    /// `ProcessJob` to cover those too.
+    pub fn set_process_priority(&self, priority_class: PriorityClass) -> Result<()> {
+        for id in self.get_process_ids()? {
+            let process = Handle::open_process(id, PROCESS_SET_INFORMATION)?;
+            if unsafe { SetPriorityClass(process.0, priority_class.as_raw()) } == 0 {
+                return Err(io::Error::last_os_error().into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A process in the snapshot of `process_parents`.
+#[derive(Debug, Clone, Copy)]
+struct ProcessEntry {
+    id: u32,
+    parent: u32,
+    /// Creation time as a `FILETIME`.
+    created: u64,
+}
+
+/// All processes that can be queried, with their parent process id.
+fn process_parents() -> Result<Vec<ProcessEntry>> {
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) };
+    if snapshot == INVALID_HANDLE_VALUE {
+        return Err(io::Error::last_os_error().into());
+    }
+    let snapshot = Handle(snapshot);
+    let mut entry: PROCESSENTRY32W = unsafe { mem::zeroed() };
+    entry.dwSize = mem::size_of::<PROCESSENTRY32W>() as DWORD;
+    let mut parents = Vec::new();
+    let mut ok = unsafe { Process32FirstW(snapshot.0, &mut entry) };
+    while ok != 0 {
+        // Others have exited since or belong to other users.
+        if let Some(created) = creation_time(entry.th32ProcessID) {
+            parents.push(ProcessEntry {
+                id: entry.th32ProcessID,
+                parent: entry.th32ParentProcessID,
+                created,
+            });
+        }
+        ok = unsafe { Process32NextW(snapshot.0, &mut entry) };
+    }
+    Ok(parents)
+}
+
+fn creation_time(process_id: u32) -> Option<u64> {
+    let process = Handle::open_process(process_id, PROCESS_QUERY_LIMITED_INFORMATION).ok()?;
+    let mut created: FILETIME = unsafe { mem::zeroed() };
+    let mut exited: FILETIME = unsafe { mem::zeroed() };
+    let mut kernel: FILETIME = unsafe { mem::zeroed() };
+    let mut user: FILETIME = unsafe { mem::zeroed() };
+    if unsafe { GetProcessTimes(process.0, &mut created, &mut exited, &mut kernel, &mut user) } == 0
+    {
+        return None;
+    }
+    Some(u64::from(created.dwHighDateTime) << 32 | u64::from(created.dwLowDateTime))
+}
+
+/// `root` and all its descendants in `parents`.
+fn descendants(root: u32, parents: &[ProcessEntry]) -> Vec<u32> {
+    let mut found = match parents.iter().find(|p| p.id == root) {
+        Some(&entry) => vec![entry],
+        // Exited already.
+        None => return vec![root],
+    };
+    let mut i = 0;
+    while i < found.len() {
+        let parent = found[i];
+        for entry in parents {
+            // Parent ids are not cleared when the parent exits and ids get
+            // reused, so a process whose parent id is now taken by a later
+            // process isn't a child of it.
+            if entry.parent == parent.id
+                && entry.created > parent.created
+                && !found.iter().any(|f| f.id == entry.id)
+            {
+                found.push(*entry);
+            }
+        }
+        i += 1;
+    }
+    found.iter().map(|entry| entry.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(parents: &[(u32, u32, u64)]) -> Vec<ProcessEntry> {
+        parents
+            .iter()
+            .map(|&(id, parent, created)| ProcessEntry {
+                id,
+                parent,
+                created,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_descendants() {
+        let parents = entries(&[(1, 0, 10), (2, 1, 20), (3, 2, 30), (4, 1, 40), (5, 9, 50)]);
+        assert_eq!(descendants(1, &parents), vec![1, 2, 4, 3]);
+        assert_eq!(descendants(5, &parents), vec![5]);
+        assert_eq!(descendants(6, &parents), vec![6]);
+    }
+
+    #[test]
+    fn test_descendants_reused_ids() {
+        // 3 was started after 1, so it took the id of the exited parent of
+        // 1 and isn't its parent.
+        let parents = entries(&[(1, 3, 10), (2, 1, 20), (3, 2, 30)]);
+        assert_eq!(descendants(1, &parents), vec![1, 2, 3]);
+        assert_eq!(descendants(3, &parents), vec![3]);
+    }
+}
@@ -0,0 +1,174 @@
+//! Capping what a webview costs while it is in the background.
+//!
+//! `IsVisible` alone stops painting, but script, timers and layout keep
+//! running, and how much Chromium throttles them depends on its own
+//! heuristics. A `RenderingBudget` sets all of it explicitly with the
+//! DevTools protocol:
+//!
+//! - `visible` is `IsVisible`. A hidden webview produces no frames.
+//! - `cpu_throttling_rate` slows down the renderer's main thread, with
+//!   `Emulation.setCPUThrottlingRate`.
+//! - `frozen` stops timers, animation frames and tasks altogether, with
+//!   `Page.setWebLifecycleState`. The page gets the `freeze` and `resume`
+//!   events, like for a tab discarded by the browser.
+//!
+//! There is no way to render at a lower frame rate than the display's, a
+//! webview either renders at the display's rate or not at all.
+//!
+//! Combined with `Host::track_visibility`, the budget can follow the window:
+//!
+//! ```no_run
+//! # fn f(host: webview2::Host) -> webview2::Result<()> {
+//! use webview2::rendering::RenderingBudget;
+//! use webview2::visibility::VisibilityState;
+//!
+//! let h = host.clone();
+//! let _tracker = host.track_visibility(move |state| {
+//!     h.set_rendering_budget(match state {
+//!         VisibilityState::Visible => RenderingBudget::FULL,
+//!         VisibilityState::Hidden | VisibilityState::Occluded => RenderingBudget::BACKGROUND,
+//!         VisibilityState::Minimized => RenderingBudget::SUSPENDED,
+//!     })
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Host, Result};
+use serde_json::json;
+
+/// How much a webview may render and compute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderingBudget {
+    pub visible: bool,
+    /// How many times slower the main thread of the renderer runs, 1 for no
+    /// throttling. Values below 1 are treated as 1.
+    pub cpu_throttling_rate: f64,
+    pub frozen: bool,
+}
+
+impl RenderingBudget {
+    /// No limits. The default.
+    pub const FULL: Self = Self {
+        visible: true,
+        cpu_throttling_rate: 1.0,
+        frozen: false,
+    };
+
+    /// Not rendered, with script running 4 times slower, so that e.g. chat
+    /// or mail pages still get their updates.
+    pub const BACKGROUND: Self = Self {
+        visible: false,
+        cpu_throttling_rate: 4.0,
+        frozen: false,
+    };
+
+    /// Not rendered and no script runs. Network requests that were started
+    /// still complete, their handlers run on resume.
+    pub const SUSPENDED: Self = Self {
+        visible: false,
+        cpu_throttling_rate: 1.0,
+        frozen: true,
+    };
+}
+
+impl Default for RenderingBudget {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+/// One step of applying a budget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    Lifecycle { frozen: bool },
+    Visible(bool),
+    CpuThrottlingRate(f64),
+}
+
+impl RenderingBudget {
+    /// The steps to apply the budget, in order: a page is resumed before it
+    /// becomes visible and frozen after it is hidden.
+    fn steps(&self) -> Vec<Step> {
+        let mut steps = Vec::with_capacity(3);
+        if !self.frozen {
+            steps.push(Step::Lifecycle { frozen: false });
+        }
+        steps.push(Step::Visible(self.visible));
+        steps.push(Step::CpuThrottlingRate(self.cpu_throttling_rate.max(1.0)));
+        if self.frozen {
+            steps.push(Step::Lifecycle { frozen: true });
+        }
+        steps
+    }
+}
+
+impl Host {
+    /// Apply a rendering budget to the webview.
+    ///
+    /// `IsVisible` changes right away, the DevTools protocol commands apply
+    /// asynchronously, in order: a page is resumed before `IsVisible` changes
+    /// and frozen after, so that going from `SUSPENDED` to `FULL` doesn't
+    /// show a frozen page.
+    pub fn set_rendering_budget(&self, budget: RenderingBudget) -> Result<()> {
+        let webview = self.get_webview()?;
+        for step in budget.steps() {
+            match step {
+                Step::Lifecycle { frozen } => webview.call_cdp(
+                    "Page.setWebLifecycleState",
+                    json!({ "state": if frozen { "frozen" } else { "active" } }),
+                    |_| Ok(()),
+                )?,
+                Step::Visible(visible) => self.put_is_visible(visible)?,
+                Step::CpuThrottlingRate(rate) => webview.call_cdp(
+                    "Emulation.setCPUThrottlingRate",
+                    json!({ "rate": rate }),
+                    |_| Ok(()),
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps() {
+        assert_eq!(
+            RenderingBudget::FULL.steps(),
+            vec![
+                Step::Lifecycle { frozen: false },
+                Step::Visible(true),
+                Step::CpuThrottlingRate(1.0),
+            ]
+        );
+        assert_eq!(
+            RenderingBudget::BACKGROUND.steps(),
+            vec![
+                Step::Lifecycle { frozen: false },
+                Step::Visible(false),
+                Step::CpuThrottlingRate(4.0),
+            ]
+        );
+        assert_eq!(
+            RenderingBudget::SUSPENDED.steps(),
+            vec![
+                Step::Visible(false),
+                Step::CpuThrottlingRate(1.0),
+                Step::Lifecycle { frozen: true },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_steps_clamp_rate() {
+        let budget = RenderingBudget {
+            cpu_throttling_rate: 0.5,
+            ..RenderingBudget::FULL
+        };
+        assert_eq!(budget.steps()[2], Step::CpuThrottlingRate(1.0));
+    }
+}
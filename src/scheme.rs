@@ -0,0 +1,443 @@
+//! Serving requests for a URI scheme from Rust.
+//!
+//! `WebView::register_scheme_handler` intercepts requests to `scheme://...`,
+//! or `scheme:...` for custom schemes without an authority component, with
+//! `WebResourceRequested` and hands them to a `SchemeHandler` as plain
+//! `Request` values. Handlers answer through a `Responder`, either right away
+//! or later, e.g. once a background job is done. `Router` dispatches to
+//! different handlers by path.
+//!
+//! Schemes other than `http` and `https` are only requested once registered
+//! with `EnvironmentBuilder::register_custom_scheme`.
+
+use crate::raw::*;
+use crate::text::origin_of;
+use crate::{
+    check_hresult, Deferral, Environment, EventRegistrationToken, Result, Stream,
+    WebResourceContext, WebResourceRequestedEventArgs, WebView,
+};
+use std::io::Read;
+use std::ptr;
+
+/// A request to a registered scheme.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub uri: String,
+    /// The path of `uri`, always starting with `/`, which is added for
+    /// `scheme:path` URIs.
+    pub path: String,
+    /// The query string of `uri`, without the `?`.
+    pub query: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// Always `OTHER` for requests to a loopback server.
+    pub resource_context: WebResourceContext,
+}
+
+impl Request {
+    /// The value of the first header named `name`, compared case
+    /// insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn from_args(uri: String, args: &WebResourceRequestedEventArgs) -> Result<Self> {
+        let request = args.get_request()?;
+        let mut body = Vec::new();
+        // `get_interface!` can't be used here, there is no content for most
+        // requests.
+        let mut content: *mut *mut IStreamVTable = ptr::null_mut();
+        check_hresult(unsafe { request.as_raw().get_content(&mut content) })?;
+        if !content.is_null() {
+            let mut content = unsafe { Stream::from_raw(content) };
+            content.read_to_end(&mut body)?;
+        }
+        let (path, query) = split_uri(&uri);
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("/{}", path)
+        };
+        Ok(Request {
+            method: request.get_method()?,
+            path,
+            query: query.map(|q| q.to_string()),
+            uri,
+            headers: request.get_headers()?.get_iterator()?.collect(),
+            body,
+            resource_context: args.get_resource_context()?,
+        })
+    }
+}
+
+/// A response to a `Request`.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status_code: i32,
+    pub reason_phrase: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn new(status_code: i32, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status_code,
+            reason_phrase: reason_phrase(status_code).to_string(),
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response with a `Content-Type` header.
+    pub fn ok(content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        Self::new(200, body).with_header("Content-Type", content_type)
+    }
+
+    pub fn not_found() -> Self {
+        Self::new(404, "Not Found").with_header("Content-Type", "text/plain")
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub(crate) fn raw_headers(&self) -> String {
+        self.headers
+            .iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect::<Vec<_>>()
+            .join("\r\n")
+    }
+}
+
+fn reason_phrase(status_code: i32) -> &'static str {
+    match status_code {
+        200 => "OK",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+/// Answers a single request.
+///
+/// Dropping it without calling `respond` lets the request continue as if it
+/// had not been intercepted, which fails for schemes that are not served
+/// otherwise. A loopback server responds with a `404` instead.
+pub struct Responder {
+    inner: ResponderInner,
+}
+
+enum ResponderInner {
+    WebResource {
+        environment: Environment,
+        args: WebResourceRequestedEventArgs,
+        deferral: Option<Deferral>,
+    },
+    #[cfg(feature = "loopback-server")]
+    Loopback(Option<std::net::TcpStream>),
+}
+
+impl Responder {
+    #[cfg(feature = "loopback-server")]
+    pub(crate) fn loopback(stream: std::net::TcpStream) -> Self {
+        Self {
+            inner: ResponderInner::Loopback(Some(stream)),
+        }
+    }
+
+    pub fn respond(mut self, response: Response) -> Result<()> {
+        match &mut self.inner {
+            ResponderInner::WebResource {
+                environment,
+                args,
+                deferral,
+            } => {
+                let content = Stream::from_bytes(&response.body);
+                let response = environment.create_web_resource_response(
+                    Some(&content),
+                    response.status_code,
+                    &response.reason_phrase,
+                    &response.raw_headers(),
+                )?;
+                args.put_response(response)?;
+                deferral.take().unwrap().complete()
+            }
+            #[cfg(feature = "loopback-server")]
+            ResponderInner::Loopback(stream) => {
+                crate::loopback::write_response(stream.take().unwrap(), &response)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Drop for Responder {
+    fn drop(&mut self) {
+        match &mut self.inner {
+            ResponderInner::WebResource { deferral, .. } => {
+                if let Some(deferral) = deferral.take() {
+                    let _ = deferral.complete();
+                }
+            }
+            // There is nothing to fall back to.
+            #[cfg(feature = "loopback-server")]
+            ResponderInner::Loopback(stream) => {
+                if let Some(stream) = stream.take() {
+                    let _ = crate::loopback::write_response(stream, &Response::not_found());
+                }
+            }
+        }
+    }
+}
+
+/// Handles requests to a registered scheme.
+///
+/// Implemented for `Fn(Request) -> Response` closures, for handlers that
+/// answer right away.
+pub trait SchemeHandler: 'static {
+    /// Handle `request`. `responder` can be kept to respond later, on the
+    /// same thread.
+    fn handle(&self, request: Request, responder: Responder);
+}
+
+impl<F> SchemeHandler for F
+where
+    F: Fn(Request) -> Response + 'static,
+{
+    fn handle(&self, request: Request, responder: Responder) {
+        let _ = responder.respond(self(request));
+    }
+}
+
+/// Dispatches requests to handlers by path prefix.
+///
+/// The longest matching prefix wins. Prefixes match whole path segments, so
+/// `/api` matches `/api` and `/api/users` but not `/apis`. Requests that match
+/// no route get a `404`.
+#[derive(Default)]
+pub struct Router {
+    routes: Vec<(String, Box<dyn SchemeHandler>)>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn route(mut self, prefix: &str, handler: impl SchemeHandler) -> Self {
+        let prefix = prefix.trim_end_matches('/').to_string();
+        self.routes.push((prefix, Box::new(handler)));
+        self
+    }
+
+    fn find(&self, path: &str) -> Option<&dyn SchemeHandler> {
+        self.routes
+            .iter()
+            .filter(|(prefix, _)| route_matches(prefix, path))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, handler)| &**handler)
+    }
+}
+
+impl SchemeHandler for Router {
+    fn handle(&self, request: Request, responder: Responder) {
+        match self.find(&request.path) {
+            Some(handler) => handler.handle(request, responder),
+            None => {
+                let _ = responder.respond(Response::not_found());
+            }
+        }
+    }
+}
+
+fn route_matches(prefix: &str, path: &str) -> bool {
+    path.starts_with(prefix)
+        && (path.len() == prefix.len() || path[prefix.len()..].starts_with('/'))
+}
+
+/// A custom scheme for `EnvironmentBuilder::register_custom_scheme`.
+///
+/// Every environment sharing a browser process, i.e. the user data folder,
+/// needs the same registrations.
+#[derive(Debug, Clone, Default)]
+pub struct SchemeRegistration {
+    /// E.g. `app`, without the `:`.
+    pub scheme: String,
+    /// Origins allowed to make requests with an `Origin` header, e.g. fetches
+    /// and all `POST`s, even from the scheme itself. `*` and `?` are
+    /// wildcards, e.g. `app://*`.
+    pub allowed_origins: Vec<String>,
+    /// A secure context, like `https`. Only with an authority component.
+    pub treat_as_secure: bool,
+    /// `scheme://host/path` rather than `scheme:path`, with an origin per
+    /// host.
+    pub has_authority_component: bool,
+}
+
+/// Returned by `WebView::register_scheme_handler`.
+pub struct SchemeHandlerToken {
+    filter: String,
+    token: EventRegistrationToken,
+}
+
+impl WebView {
+    /// Serve all requests to `scheme://...` with `handler`.
+    ///
+    /// `environment` must be the environment of this webview, it is used to
+    /// create responses. Only schemes the browser is willing to request are
+    /// seen: `http` and `https` (to serve a whole origin, e.g. from a fake
+    /// host name), or custom schemes registered with the environment, see
+    /// `register_custom_scheme_handler`.
+    pub fn register_scheme_handler(
+        &self,
+        environment: &Environment,
+        scheme: &str,
+        handler: impl SchemeHandler,
+    ) -> Result<SchemeHandlerToken> {
+        self.register_handler(environment, scheme, true, handler)
+    }
+
+    /// `register_scheme_handler` for a custom scheme registered with the
+    /// environment, with requests to `scheme:...` if it has no authority
+    /// component.
+    pub fn register_custom_scheme_handler(
+        &self,
+        environment: &Environment,
+        registration: &SchemeRegistration,
+        handler: impl SchemeHandler,
+    ) -> Result<SchemeHandlerToken> {
+        self.register_handler(
+            environment,
+            &registration.scheme,
+            registration.has_authority_component,
+            handler,
+        )
+    }
+
+    fn register_handler(
+        &self,
+        environment: &Environment,
+        scheme: &str,
+        has_authority_component: bool,
+        handler: impl SchemeHandler,
+    ) -> Result<SchemeHandlerToken> {
+        let prefix = format!("{}:", scheme.to_ascii_lowercase());
+        let environment = environment.clone();
+        let token = self.add_web_resource_requested(move |_, args| {
+            let uri = args.get_request()?.get_uri()?;
+            // Other filters raise the same event.
+            if !uri.to_ascii_lowercase().starts_with(&prefix) {
+                return Ok(());
+            }
+            let request = Request::from_args(uri, &args)?;
+            let responder = Responder {
+                inner: ResponderInner::WebResource {
+                    environment: environment.clone(),
+                    deferral: Some(args.get_deferral()?),
+                    args,
+                },
+            };
+            handler.handle(request, responder);
+            Ok(())
+        })?;
+        let filter = scheme_filter(scheme, has_authority_component);
+        if let Err(e) = self.add_web_resource_requested_filter(
+            &filter,
+            WebResourceContext::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_ALL,
+        ) {
+            self.remove_web_resource_requested(token)?;
+            return Err(e);
+        }
+        Ok(SchemeHandlerToken { filter, token })
+    }
+
+    pub fn unregister_scheme_handler(&self, token: SchemeHandlerToken) -> Result<()> {
+        self.remove_web_resource_requested_filter(
+            &token.filter,
+            WebResourceContext::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_ALL,
+        )?;
+        self.remove_web_resource_requested(token.token)
+    }
+}
+
+/// The `WebResourceRequested` filter for all the URIs of `scheme`.
+fn scheme_filter(scheme: &str, has_authority_component: bool) -> String {
+    let scheme = scheme.to_ascii_lowercase();
+    if has_authority_component {
+        format!("{}://*", scheme)
+    } else {
+        format!("{}:*", scheme)
+    }
+}
+
+/// Split the path and query out of `uri`, `scheme://host/path?query` or
+/// `scheme:path?query`.
+pub(crate) fn split_uri(uri: &str) -> (&str, Option<&str>) {
+    let rest = match uri.find(':') {
+        Some(i) if uri[i..].starts_with("://") => &uri[origin_of(uri).len()..],
+        Some(i) => &uri[i + 1..],
+        None => uri,
+    };
+    let rest = rest.split('#').next().unwrap();
+    let (path, query) = match rest.find('?') {
+        Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+        None => (rest, None),
+    };
+    (if path.is_empty() { "/" } else { path }, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_uri() {
+        assert_eq!(split_uri("app://host/a/b?c=d#e"), ("/a/b", Some("c=d")));
+        assert_eq!(split_uri("app://host"), ("/", None));
+        assert_eq!(split_uri("app://host?x"), ("/", Some("x")));
+    }
+
+    #[test]
+    fn test_split_uri_without_authority() {
+        assert_eq!(
+            split_uri("app:index.html?c=d#e"),
+            ("index.html", Some("c=d"))
+        );
+        assert_eq!(split_uri("app:/a/b"), ("/a/b", None));
+        assert_eq!(split_uri("app:"), ("/", None));
+        // Not an authority.
+        assert_eq!(
+            split_uri("app:page?next=https://example.com/"),
+            ("page", Some("next=https://example.com/"))
+        );
+    }
+
+    #[test]
+    fn test_scheme_filter() {
+        assert_eq!(scheme_filter("App", true), "app://*");
+        assert_eq!(scheme_filter("App", false), "app:*");
+    }
+
+    #[test]
+    fn test_route_matches() {
+        assert!(route_matches("/api", "/api"));
+        assert!(route_matches("/api", "/api/users"));
+        assert!(!route_matches("/api", "/apis"));
+        // The root route, `/` with the trailing slash trimmed.
+        assert!(route_matches("", "/index.html"));
+    }
+}
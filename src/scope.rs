@@ -0,0 +1,242 @@
+//! Event handlers and callbacks that live as long as a part of the UI.
+//!
+//! A modal dialog or a wizard step often needs handlers on the long-lived
+//! webview only while it is shown. Registering them through a `Scope` ties
+//! them to it: when the scope is dropped every handler is removed, and
+//! callbacks of calls still in flight, like `execute_script`, are not run.
+//!
+//! `webview_events!` registers several handlers in one scope at once.
+
+use crate::{EventRegistrationToken, Result, WebView};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Returned by `WebView::scope`.
+pub struct Scope {
+    webview: WebView,
+    /// Cleared on drop, checked by guarded callbacks.
+    alive: Rc<Cell<bool>>,
+    removers: RefCell<Vec<Box<dyn FnOnce(&WebView)>>>,
+}
+
+impl WebView {
+    /// A scope for handlers and callbacks of this webview.
+    pub fn scope(&self) -> Scope {
+        Scope {
+            webview: self.clone(),
+            alive: Rc::new(Cell::new(true)),
+            removers: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl Scope {
+    pub fn webview(&self) -> &WebView {
+        &self.webview
+    }
+
+    /// Register an event handler with `add` and remove it with `remove` when
+    /// the scope is dropped:
+    ///
+    /// ```no_run
+    /// # fn f(webview: webview2::WebView) -> webview2::Result<()> {
+    /// let scope = webview.scope();
+    /// scope.on(
+    ///     |w| w.add_navigation_starting(|_, _| Ok(())),
+    ///     webview2::WebView::remove_navigation_starting,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on(
+        &self,
+        add: impl FnOnce(&WebView) -> Result<EventRegistrationToken>,
+        remove: impl FnOnce(&WebView, EventRegistrationToken) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let token = add(&self.webview)?;
+        self.removers.borrow_mut().push(Box::new(move |w| {
+            // There is nowhere to report errors to from `drop`.
+            let _ = remove(w, token);
+        }));
+        Ok(())
+    }
+
+    /// Run `cleanup` when the scope is dropped, e.g. to remove a script added
+    /// with `add_script_to_execute_on_document_created`.
+    pub fn on_drop(&self, cleanup: impl FnOnce(&WebView) + 'static) {
+        self.removers.borrow_mut().push(Box::new(cleanup));
+    }
+
+    /// Wrap a completion callback so that it is not run if the scope has been
+    /// dropped by the time the call completes.
+    pub fn guard<T: 'static>(
+        &self,
+        callback: impl FnOnce(T) -> Result<()> + 'static,
+    ) -> impl FnOnce(T) -> Result<()> + 'static {
+        guarded(&self.alive, callback)
+    }
+
+    pub fn execute_script(
+        &self,
+        script: &str,
+        callback: impl FnOnce(String) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.webview.execute_script(script, self.guard(callback))
+    }
+
+    pub fn call_dev_tools_protocol_method(
+        &self,
+        method: &str,
+        parameters: &str,
+        callback: impl FnOnce(String) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.webview
+            .call_dev_tools_protocol_method(method, parameters, self.guard(callback))
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        self.alive.set(false);
+        // In reverse order, like local variables.
+        let removers = self.removers.get_mut().drain(..).rev();
+        for remove in removers {
+            remove(&self.webview);
+        }
+    }
+}
+
+/// Add handlers of several events of a webview, named like the `add_`
+/// methods without the prefix, and return the `Scope` that removes them all
+/// when dropped:
+///
+/// ```no_run
+/// # use webview2::{NavigationCompletedEventArgs, WebMessageReceivedEventArgs, WebView};
+/// # fn on_nav(_: WebView, _: NavigationCompletedEventArgs) -> webview2::Result<()> { Ok(()) }
+/// # fn on_msg(_: WebView, _: WebMessageReceivedEventArgs) -> webview2::Result<()> { Ok(()) }
+/// # fn f(webview: WebView) -> webview2::Result<()> {
+/// let handlers = webview2::webview_events! {
+///     webview => {
+///         navigation_completed => on_nav,
+///         web_message_received => on_msg,
+///         document_title_changed => |w| {
+///             println!("{:?}", w.get_document_title()?);
+///             Ok(())
+///         },
+///     }
+/// }?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// If adding a handler fails, the ones added before are removed again.
+#[macro_export]
+macro_rules! webview_events {
+    ($webview:expr => { $($event:ident => $handler:expr),* $(,)? }) => {
+        (|| -> $crate::Result<$crate::scope::Scope> {
+            let scope = $crate::WebView::scope(&$webview);
+            $($crate::__webview_event!(scope, $event, $handler)?;)*
+            Ok(scope)
+        })()
+    };
+}
+
+// The `add_` and `remove_` methods of each event name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __webview_event {
+    ($scope:ident, navigation_starting, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_navigation_starting, remove_navigation_starting, $handler)
+    };
+    ($scope:ident, content_loading, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_content_loading, remove_content_loading, $handler)
+    };
+    ($scope:ident, source_changed, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_source_changed, remove_source_changed, $handler)
+    };
+    ($scope:ident, history_changed, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_history_changed, remove_history_changed, $handler)
+    };
+    ($scope:ident, navigation_completed, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_navigation_completed, remove_navigation_completed, $handler)
+    };
+    ($scope:ident, frame_navigation_starting, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_frame_navigation_starting, remove_frame_navigation_starting, $handler)
+    };
+    ($scope:ident, script_dialog_opening, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_script_dialog_opening, remove_script_dialog_opening, $handler)
+    };
+    ($scope:ident, permission_requested, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_permission_requested, remove_permission_requested, $handler)
+    };
+    ($scope:ident, process_failed, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_process_failed, remove_process_failed, $handler)
+    };
+    ($scope:ident, document_title_changed, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_document_title_changed, remove_document_title_changed, $handler)
+    };
+    ($scope:ident, web_message_received, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_web_message_received, remove_web_message_received, $handler)
+    };
+    ($scope:ident, new_window_requested, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_new_window_requested, remove_new_window_requested, $handler)
+    };
+    ($scope:ident, contains_full_screen_element_changed, $handler:expr) => {
+        $crate::__webview_event!(
+            @on $scope,
+            add_contains_full_screen_element_changed,
+            remove_contains_full_screen_element_changed,
+            $handler
+        )
+    };
+    ($scope:ident, web_resource_requested, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_web_resource_requested, remove_web_resource_requested, $handler)
+    };
+    ($scope:ident, window_close_requested, $handler:expr) => {
+        $crate::__webview_event!(@on $scope, add_window_close_requested, remove_window_close_requested, $handler)
+    };
+    (@on $scope:ident, $add:ident, $remove:ident, $handler:expr) => {
+        $scope.on(|w| w.$add($handler), $crate::WebView::$remove)
+    };
+    ($scope:ident, $event:ident, $handler:expr) => {
+        compile_error!(concat!("unknown webview event `", stringify!($event), "`"))
+    };
+}
+
+fn guarded<T: 'static>(
+    alive: &Rc<Cell<bool>>,
+    callback: impl FnOnce(T) -> Result<()> + 'static,
+) -> impl FnOnce(T) -> Result<()> + 'static {
+    let alive = alive.clone();
+    move |value| {
+        if alive.get() {
+            callback(value)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded() {
+        let alive = Rc::new(Cell::new(true));
+        let ran = Rc::new(Cell::new(0));
+        let callback = |ran: &Rc<Cell<i32>>| {
+            let ran = ran.clone();
+            move |n: i32| {
+                ran.set(ran.get() + n);
+                Ok(())
+            }
+        };
+
+        guarded(&alive, callback(&ran))(1).unwrap();
+        let pending = guarded(&alive, callback(&ran));
+        alive.set(false);
+        pending(2).unwrap();
+        assert_eq!(ran.get(), 1);
+    }
+}
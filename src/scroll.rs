@@ -0,0 +1,117 @@
+//! Scroll control and scroll position notifications, so that native UI
+//! (minimaps, toolbars, ...) can follow page scrolling.
+
+use crate::cdp::{js_string, MessageScript};
+use crate::{Error, Result, WebView};
+use winapi::shared::winerror::E_INVALIDARG;
+
+/// Message type used by the injected scroll tracking script.
+const SCROLL_MESSAGE_TYPE: &str = "webview2-scroll";
+
+/// Scroll offset of the top level document, in CSS pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollPosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Returned by `WebView::add_scroll_position_changed`.
+pub struct ScrollPositionChangedToken {
+    script: MessageScript,
+}
+
+impl WebView {
+    /// Scroll the top level document to `(x, y)`. Fails with `E_INVALIDARG`
+    /// if they are not finite.
+    pub fn scroll_to(&self, x: f64, y: f64) -> Result<()> {
+        self.execute_script(&scroll_to_script(x, y)?, |_| Ok(()))
+    }
+
+    /// Scroll the first element matching `selector` into view. The callback
+    /// receives whether an element was found.
+    pub fn scroll_into_view(
+        &self,
+        selector: &str,
+        callback: impl FnOnce(bool) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let script = format!(
+            "(() => {{
+                const el = document.querySelector({});
+                if (!el) return false;
+                el.scrollIntoView({{ block: 'nearest' }});
+                return true;
+            }})()",
+            js_string(selector),
+        );
+        self.execute_script(&script, move |result| callback(result == "true"))
+    }
+
+    /// Get notified when the top level document is scrolled.
+    ///
+    /// A script is injected into every document that posts the scroll
+    /// position as a web message, at most once per animation frame. Web
+    /// messages must be enabled, and other `WebMessageReceived` handlers will
+    /// see these messages too (as `{"type":"webview2-scroll",...}` objects).
+    pub fn add_scroll_position_changed(
+        &self,
+        handler: impl Fn(WebView, ScrollPosition) -> Result<()> + 'static,
+    ) -> Result<ScrollPositionChangedToken> {
+        let script = format!(
+            "(() => {{
+                let pending = false;
+                const post = () => {{
+                    pending = false;
+                    window.chrome.webview.postMessage({{
+                        type: {}, x: window.scrollX, y: window.scrollY
+                    }});
+                }};
+                window.addEventListener('scroll', () => {{
+                    if (!pending) {{
+                        pending = true;
+                        requestAnimationFrame(post);
+                    }}
+                }}, {{ passive: true }});
+            }})()",
+            js_string(SCROLL_MESSAGE_TYPE),
+        );
+
+        let script = self.add_message_script(&script, SCROLL_MESSAGE_TYPE, move |w, message| {
+            let position = ScrollPosition {
+                x: message["x"].as_f64().unwrap_or(0.0),
+                y: message["y"].as_f64().unwrap_or(0.0),
+            };
+            handler(w, position)
+        })?;
+        Ok(ScrollPositionChangedToken { script })
+    }
+
+    /// Stop scroll position notifications. The tracking script stays active
+    /// in the current document until the next navigation.
+    pub fn remove_scroll_position_changed(&self, token: ScrollPositionChangedToken) -> Result<()> {
+        self.remove_message_script(token.script)
+    }
+}
+
+/// `NaN` and infinities would be printed as `NaN` and `inf`, which are not
+/// JavaScript numbers.
+fn scroll_to_script(x: f64, y: f64) -> Result<String> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err(Error::new(E_INVALIDARG));
+    }
+    Ok(format!("window.scrollTo({}, {})", x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_to_script() {
+        assert_eq!(
+            scroll_to_script(0.0, 12.5).unwrap(),
+            "window.scrollTo(0, 12.5)"
+        );
+        assert!(scroll_to_script(f64::NAN, 0.0).is_err());
+        assert!(scroll_to_script(0.0, f64::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,383 @@
+//! Soak testing of webview teardown.
+//!
+//! `run` creates and closes a webview thousands of times, navigating,
+//! running a script and adding and removing event handlers each time, and
+//! compares the handles, GDI and USER objects and working set of the process
+//! after a warm-up with those at the end. A leak in teardown shows up as
+//! steady growth. The `stress` example runs it, e.g. as a CI job on a runner
+//! with the WebView2 runtime:
+//!
+//! ```text
+//! cargo run --release --example stress -- 5000
+//! ```
+//!
+//! Only the host process is measured. The browser processes are shared by
+//! the webviews of an environment and exit with it.
+//!
+//! Needs the `sdk-1.0.992` feature, the temporary user data folder is deleted
+//! once `Environment::add_browser_process_exited` reports that it is free.
+
+use crate::{Environment, EnvironmentBuilder, Host, Result};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::PathBuf;
+use std::ptr;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use winapi::shared::minwindef::*;
+use winapi::shared::windef::{HWND, RECT};
+use winapi::um::processthreadsapi::{GetCurrentProcess, GetProcessHandleCount};
+use winapi::um::psapi::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use winapi::um::winbase::INFINITE;
+use winapi::um::winuser::*;
+
+/// Resources held by the current process.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ResourceUsage {
+    pub handles: u32,
+    pub gdi_objects: u32,
+    pub user_objects: u32,
+    /// In bytes.
+    pub working_set: usize,
+}
+
+impl ResourceUsage {
+    pub fn current() -> Result<Self> {
+        unsafe {
+            let process = GetCurrentProcess();
+            let mut handles: DWORD = 0;
+            if GetProcessHandleCount(process, &mut handles) == 0 {
+                return Err(io::Error::last_os_error().into());
+            }
+            let mut memory: PROCESS_MEMORY_COUNTERS = mem::zeroed();
+            memory.cb = mem::size_of::<PROCESS_MEMORY_COUNTERS>() as DWORD;
+            if GetProcessMemoryInfo(process, &mut memory, memory.cb) == 0 {
+                return Err(io::Error::last_os_error().into());
+            }
+            Ok(ResourceUsage {
+                handles,
+                gdi_objects: GetGuiResources(process, GR_GDIOBJECTS),
+                user_objects: GetGuiResources(process, GR_USEROBJECTS),
+                working_set: memory.WorkingSetSize,
+            })
+        }
+    }
+}
+
+impl fmt::Display for ResourceUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} handles, {} GDI objects, {} USER objects, {} KiB working set",
+            self.handles,
+            self.gdi_objects,
+            self.user_objects,
+            self.working_set / 1024
+        )
+    }
+}
+
+/// How much each resource may grow between the warm-up and the end. Some
+/// growth is normal, e.g. caches filling up.
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance {
+    pub handles: u32,
+    pub gdi_objects: u32,
+    pub user_objects: u32,
+    /// In bytes.
+    pub working_set: usize,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            handles: 100,
+            gdi_objects: 20,
+            user_objects: 20,
+            working_set: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// Settings of `run`.
+#[derive(Debug, Clone)]
+pub struct StressOptions {
+    /// Measured iterations. Defaults to 1000.
+    pub iterations: u32,
+    /// Iterations before the baseline is taken. Defaults to 20.
+    pub warmup: u32,
+    pub tolerance: Tolerance,
+    /// Page loaded in every iteration.
+    pub html: String,
+    /// How long each step of an iteration may take. Defaults to 30 seconds.
+    pub step_timeout: Duration,
+    /// Defaults to a folder in the temporary directory, which is deleted
+    /// once the browser process has exited at the end.
+    pub user_data_folder: Option<PathBuf>,
+}
+
+impl Default for StressOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 1000,
+            warmup: 20,
+            tolerance: Tolerance::default(),
+            html: "<!doctype html><p>stress</p>\
+                   <script>window.chrome.webview.postMessage('loaded')</script>"
+                .into(),
+            step_timeout: Duration::from_secs(30),
+            user_data_folder: None,
+        }
+    }
+}
+
+/// Result of `run`.
+#[derive(Debug, Clone)]
+pub struct StressReport {
+    pub iterations: u32,
+    /// After the warm-up.
+    pub baseline: ResourceUsage,
+    /// After the last iteration.
+    pub last: ResourceUsage,
+    pub tolerance: Tolerance,
+}
+
+impl StressReport {
+    /// Resources that grew by more than the tolerance.
+    pub fn leaks(&self) -> Vec<String> {
+        let (b, l, t) = (&self.baseline, &self.last, &self.tolerance);
+        let mut leaks = Vec::new();
+        let mut check = |name: &str, baseline: usize, last: usize, tolerance: usize| {
+            if last > baseline + tolerance {
+                leaks.push(format!(
+                    "{} grew from {} to {}, by more than {}",
+                    name, baseline, last, tolerance
+                ));
+            }
+        };
+        check(
+            "handles",
+            b.handles as usize,
+            l.handles as usize,
+            t.handles as usize,
+        );
+        check(
+            "GDI objects",
+            b.gdi_objects as usize,
+            l.gdi_objects as usize,
+            t.gdi_objects as usize,
+        );
+        check(
+            "USER objects",
+            b.user_objects as usize,
+            l.user_objects as usize,
+            t.user_objects as usize,
+        );
+        check("working set", b.working_set, l.working_set, t.working_set);
+        leaks
+    }
+
+    /// Panic if anything leaked.
+    pub fn assert_stable(&self) {
+        let leaks = self.leaks();
+        assert!(
+            leaks.is_empty(),
+            "resources leaked over {} iterations: {}",
+            self.iterations,
+            leaks.join("; ")
+        );
+    }
+}
+
+/// Run the soak test with webviews in `parent`, with a nested message loop.
+/// COM must be initialized on the thread as a single threaded apartment.
+///
+/// Without a `user_data_folder`, the runtime must have
+/// `ICoreWebView2Environment5` to tell when the temporary one can be deleted.
+pub fn run(parent: HWND, options: StressOptions) -> Result<StressReport> {
+    let temporary = options.user_data_folder.is_none();
+    let user_data_folder = options.user_data_folder.clone().unwrap_or_else(|| {
+        std::env::temp_dir().join(format!("webview2-stress-{}", std::process::id()))
+    });
+    let created: Rc<RefCell<Option<Result<Environment>>>> = Rc::new(RefCell::new(None));
+    {
+        let created = created.clone();
+        EnvironmentBuilder::new()
+            .with_user_data_folder(&user_data_folder)
+            .build(move |env| {
+                *created.borrow_mut() = Some(env);
+                Ok(())
+            })?;
+    }
+    pump_until(options.step_timeout, || created.borrow().is_some())?;
+    let env = created.borrow_mut().take().unwrap()?;
+
+    // Ids of the browser processes that have exited. One may be started for
+    // each webview.
+    let exited = Rc::new(RefCell::new(Vec::new()));
+    let exited_token = if temporary {
+        let exited = exited.clone();
+        Some(env.add_browser_process_exited(move |_, args| {
+            exited.borrow_mut().push(args.get_browser_process_id()?);
+            Ok(())
+        })?)
+    } else {
+        None
+    };
+
+    let mut browser_process_id = 0;
+    let report = (|| {
+        for _ in 0..options.warmup {
+            browser_process_id = iteration(&env, parent, &options)?;
+        }
+        let baseline = ResourceUsage::current()?;
+        for _ in 0..options.iterations {
+            browser_process_id = iteration(&env, parent, &options)?;
+        }
+        Ok(StressReport {
+            iterations: options.iterations,
+            baseline,
+            last: ResourceUsage::current()?,
+            tolerance: options.tolerance,
+        })
+    })();
+
+    let token = match exited_token {
+        Some(token) => token,
+        None => return report,
+    };
+    // The browser process keeps files of the folder open until it exits.
+    let deleted = pump_until(options.step_timeout, || {
+        exited.borrow().contains(&browser_process_id)
+    })
+    .and_then(|_| env.remove_browser_process_exited(token))
+    .and_then(|_| fs::remove_dir_all(&user_data_folder).map_err(Into::into));
+    let report = report?;
+    deleted?;
+    Ok(report)
+}
+
+/// Create a webview, load the page, run a script and close it again. Returns
+/// the id of its browser process.
+fn iteration(env: &Environment, parent: HWND, options: &StressOptions) -> Result<u32> {
+    let created: Rc<RefCell<Option<Result<Host>>>> = Rc::new(RefCell::new(None));
+    {
+        let created = created.clone();
+        env.create_host(parent, move |host| {
+            *created.borrow_mut() = Some(host);
+            Ok(())
+        })?;
+    }
+    pump_until(options.step_timeout, || created.borrow().is_some())?;
+    let host = created.borrow_mut().take().unwrap()?;
+
+    let result = (|| {
+        let mut bounds = RECT {
+            left: 0,
+            top: 0,
+            right: 0,
+            bottom: 0,
+        };
+        unsafe { GetClientRect(parent, &mut bounds) };
+        host.put_bounds(bounds)?;
+        let webview = host.get_webview()?;
+
+        let loaded = Rc::new(Cell::new(false));
+        let navigation_starting = webview.add_navigation_starting(|_, _| Ok(()))?;
+        let navigation_completed = {
+            let loaded = loaded.clone();
+            webview.add_navigation_completed(move |_, _| {
+                loaded.set(true);
+                Ok(())
+            })?
+        };
+        let web_message_received = webview.add_web_message_received(|_, _| Ok(()))?;
+        let browser_process_id = webview.get_browser_process_id()?;
+
+        webview.navigate_to_string(&options.html)?;
+        pump_until(options.step_timeout, || loaded.get())?;
+
+        let answered = Rc::new(Cell::new(false));
+        {
+            let answered = answered.clone();
+            webview.execute_script("document.title", move |_| {
+                answered.set(true);
+                Ok(())
+            })?;
+        }
+        pump_until(options.step_timeout, || answered.get())?;
+
+        webview.remove_navigation_starting(navigation_starting)?;
+        webview.remove_navigation_completed(navigation_completed)?;
+        webview.remove_web_message_received(web_message_received)?;
+        Ok(browser_process_id)
+    })();
+
+    // Closed even if a step failed, so that a failure doesn't leak too.
+    let closed = host.close();
+    let browser_process_id = result?;
+    closed?;
+    Ok(browser_process_id)
+}
+
+/// Run the message loop until `done` returns true, for at most `timeout`.
+fn pump_until(timeout: Duration, done: impl Fn() -> bool) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    unsafe {
+        let mut msg: MSG = mem::zeroed();
+        while !done() {
+            if PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                if msg.message == WM_QUIT {
+                    // Leave it for the outer loop.
+                    PostQuitMessage(msg.wParam as i32);
+                    return Err(io::Error::from(io::ErrorKind::Interrupted).into());
+                }
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+                continue;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(io::Error::from(io::ErrorKind::TimedOut).into());
+            }
+            let remaining = (deadline - now).as_millis().min(u128::from(INFINITE - 1)) as DWORD;
+            MsgWaitForMultipleObjects(0, ptr::null(), FALSE, remaining, QS_ALLINPUT);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaks() {
+        let baseline = ResourceUsage {
+            handles: 400,
+            gdi_objects: 30,
+            user_objects: 20,
+            working_set: 100 << 20,
+        };
+        let mut report = StressReport {
+            iterations: 1000,
+            baseline,
+            last: ResourceUsage {
+                handles: 450,
+                ..baseline
+            },
+            tolerance: Tolerance::default(),
+        };
+        assert!(report.leaks().is_empty());
+        report.assert_stable();
+
+        report.last.handles = 2400;
+        report.last.working_set = 200 << 20;
+        let leaks = report.leaks();
+        assert_eq!(leaks.len(), 2);
+        assert_eq!(leaks[0], "handles grew from 400 to 2400, by more than 100");
+    }
+}
@@ -0,0 +1,275 @@
+//! Progress and badges on the taskbar button of the host window.
+//!
+//! `Taskbar` wraps `ITaskbarList3`. `Host::forward_taskbar_signals` drives it
+//! from the page: the Badging API (`navigator.setAppBadge`) is not available
+//! in a webview, so a script provides it, and progress is posted as a web
+//! message.
+
+use crate::cdp::{js_string, MessageScript};
+use crate::{check_hresult, Host, Result};
+use std::ptr;
+use widestring::WideCString;
+use winapi::shared::windef::{HICON, HWND};
+use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+use winapi::um::combaseapi::CoCreateInstance;
+use winapi::um::shobjidl_core::*;
+use winapi::um::winuser::{GetAncestor, GA_ROOT};
+use winapi::Interface;
+
+/// Message type used by the injected script.
+const TASKBAR_MESSAGE_TYPE: &str = "webview2-taskbar";
+
+/// Steps of `TaskbarProgress` values, `ITaskbarList3` takes integers.
+const PROGRESS_TOTAL: u64 = 10_000;
+
+/// State of the progress bar in a taskbar button. Values are from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskbarProgress {
+    /// No progress bar.
+    None,
+    Indeterminate,
+    Normal(f64),
+    Paused(f64),
+    Error(f64),
+}
+
+/// An app badge as set with `navigator.setAppBadge`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Badge {
+    /// `setAppBadge()` without a count.
+    Flag,
+    Count(u64),
+}
+
+impl Badge {
+    /// Accessible description of the overlay icon.
+    fn description(self) -> String {
+        match self {
+            Badge::Flag => "New notifications".to_string(),
+            Badge::Count(1) => "1 notification".to_string(),
+            Badge::Count(n) => format!("{} notifications", n),
+        }
+    }
+}
+
+/// The taskbar button of a top level window.
+///
+/// The button must exist, i.e. the window has received the
+/// `TaskbarButtonCreated` message, before anything can be set.
+pub struct Taskbar {
+    list: *mut ITaskbarList3,
+    window: HWND,
+}
+
+impl Taskbar {
+    /// The taskbar button of the top level window of `window`. COM must be
+    /// initialized on the thread.
+    pub fn new(window: HWND) -> Result<Self> {
+        let mut list: *mut ITaskbarList3 = ptr::null_mut();
+        check_hresult(unsafe {
+            CoCreateInstance(
+                &CLSID_TaskbarList,
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &ITaskbarList3::uuidof(),
+                &mut list as *mut *mut ITaskbarList3 as *mut _,
+            )
+        })?;
+        let taskbar = Taskbar {
+            list,
+            window: unsafe { GetAncestor(window, GA_ROOT) },
+        };
+        check_hresult(unsafe { (*taskbar.list).HrInit() })?;
+        Ok(taskbar)
+    }
+
+    pub fn set_progress(&self, progress: TaskbarProgress) -> Result<()> {
+        let (state, value) = match progress {
+            TaskbarProgress::None => (TBPF_NOPROGRESS, None),
+            TaskbarProgress::Indeterminate => (TBPF_INDETERMINATE, None),
+            TaskbarProgress::Normal(v) => (TBPF_NORMAL, Some(v)),
+            TaskbarProgress::Paused(v) => (TBPF_PAUSED, Some(v)),
+            TaskbarProgress::Error(v) => (TBPF_ERROR, Some(v)),
+        };
+        unsafe {
+            // Setting a value switches to `TBPF_NORMAL`, so the state goes
+            // last.
+            if let Some(value) = value {
+                let completed = (value.max(0.0).min(1.0) * PROGRESS_TOTAL as f64) as u64;
+                check_hresult((*self.list).SetProgressValue(
+                    self.window,
+                    completed,
+                    PROGRESS_TOTAL,
+                ))?;
+            }
+            check_hresult((*self.list).SetProgressState(self.window, state))
+        }
+    }
+
+    /// Show `icon` over the button. The taskbar keeps its own copy of the
+    /// icon. `description` is read by screen readers.
+    pub fn set_overlay_icon(&self, icon: HICON, description: &str) -> Result<()> {
+        let description = WideCString::from_str(description)?;
+        check_hresult(unsafe {
+            (*self.list).SetOverlayIcon(self.window, icon, description.as_ptr())
+        })
+    }
+
+    pub fn clear_overlay_icon(&self) -> Result<()> {
+        check_hresult(unsafe {
+            (*self.list).SetOverlayIcon(self.window, ptr::null_mut(), ptr::null())
+        })
+    }
+
+    pub fn as_raw(&self) -> *mut ITaskbarList3 {
+        self.list
+    }
+}
+
+impl Drop for Taskbar {
+    fn drop(&mut self) {
+        unsafe {
+            (*self.list).Release();
+        }
+    }
+}
+
+/// What the page asked for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Signal {
+    Progress(TaskbarProgress),
+    Badge(Option<Badge>),
+}
+
+/// Parse a `{"type":"webview2-taskbar",...}` message.
+fn parse_signal(message: &serde_json::Value) -> Option<Signal> {
+    if message["type"] != TASKBAR_MESSAGE_TYPE {
+        return None;
+    }
+    if let Some(badge) = message.get("badge") {
+        // `setAppBadge(0)` clears the badge, as does `clearAppBadge()`.
+        return Some(Signal::Badge(match badge {
+            serde_json::Value::Null => None,
+            serde_json::Value::Bool(true) => Some(Badge::Flag),
+            badge => match badge.as_f64() {
+                Some(n) if n >= 1.0 => Some(Badge::Count(n as u64)),
+                _ => None,
+            },
+        }));
+    }
+    let value = message["value"].as_f64().unwrap_or(0.0);
+    let progress = match message["progress"].as_str()? {
+        "none" => TaskbarProgress::None,
+        "indeterminate" => TaskbarProgress::Indeterminate,
+        "normal" => TaskbarProgress::Normal(value),
+        "paused" => TaskbarProgress::Paused(value),
+        "error" => TaskbarProgress::Error(value),
+        _ => return None,
+    };
+    Some(Signal::Progress(progress))
+}
+
+/// Returned by `Host::forward_taskbar_signals`.
+pub struct TaskbarSignalsToken {
+    script: MessageScript,
+}
+
+impl Host {
+    /// Show progress and app badges of the page on the taskbar button of the
+    /// host window.
+    ///
+    /// The page sets progress with
+    ///
+    /// ```js
+    /// window.chrome.webview.postMessage({ type: "webview2-taskbar", progress: "normal", value: 0.5 });
+    /// ```
+    ///
+    /// where `progress` is one of `"none"`, `"indeterminate"`, `"normal"`,
+    /// `"paused"` and `"error"`, and the badge with `navigator.setAppBadge`
+    /// and `navigator.clearAppBadge`. `badge_icon` returns the overlay icon
+    /// for a badge, or `None` for no icon.
+    ///
+    /// Web messages must be enabled. Other `WebMessageReceived` handlers see
+    /// the messages too.
+    pub fn forward_taskbar_signals(
+        &self,
+        badge_icon: impl Fn(Badge) -> Option<HICON> + 'static,
+    ) -> Result<TaskbarSignalsToken> {
+        let taskbar = Taskbar::new(self.get_parent_window()?)?;
+        let webview = self.get_webview()?;
+
+        let script = format!(
+            "(() => {{
+                const post = badge => window.chrome.webview.postMessage({{ type: {}, badge }});
+                navigator.setAppBadge = count => {{
+                    post(count === undefined ? true : Number(count));
+                    return Promise.resolve();
+                }};
+                navigator.clearAppBadge = () => {{
+                    post(null);
+                    return Promise.resolve();
+                }};
+            }})()",
+            js_string(TASKBAR_MESSAGE_TYPE),
+        );
+
+        let script =
+            webview.add_message_script(&script, TASKBAR_MESSAGE_TYPE, move |_, message| {
+                let signal = match parse_signal(&message) {
+                    Some(signal) => signal,
+                    None => return Ok(()),
+                };
+                match signal {
+                    Signal::Progress(progress) => taskbar.set_progress(progress),
+                    Signal::Badge(Some(badge)) => match badge_icon(badge) {
+                        Some(icon) => taskbar.set_overlay_icon(icon, &badge.description()),
+                        None => taskbar.clear_overlay_icon(),
+                    },
+                    Signal::Badge(None) => taskbar.clear_overlay_icon(),
+                }
+            })?;
+        Ok(TaskbarSignalsToken { script })
+    }
+
+    /// Stop forwarding. Progress and badge stay as they are, use `Taskbar` to
+    /// clear them.
+    pub fn stop_taskbar_signals(&self, token: TaskbarSignalsToken) -> Result<()> {
+        self.get_webview()?.remove_message_script(token.script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_signal() {
+        let parse = |v: serde_json::Value| parse_signal(&v);
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "progress": "paused", "value": 0.25})),
+            Some(Signal::Progress(TaskbarProgress::Paused(0.25)))
+        );
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "progress": "indeterminate"})),
+            Some(Signal::Progress(TaskbarProgress::Indeterminate))
+        );
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "badge": true})),
+            Some(Signal::Badge(Some(Badge::Flag)))
+        );
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "badge": 3})),
+            Some(Signal::Badge(Some(Badge::Count(3))))
+        );
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "badge": 0})),
+            Some(Signal::Badge(None))
+        );
+        assert_eq!(
+            parse(json!({"type": "webview2-taskbar", "progress": "done"})),
+            None
+        );
+        assert_eq!(parse(json!({"type": "other", "badge": 1})), None);
+    }
+}
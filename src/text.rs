@@ -0,0 +1,89 @@
+//! String helpers shared by several modules.
+
+/// The `scheme://host[:port]` part of `uri`.
+pub(crate) fn origin_of(uri: &str) -> &str {
+    match uri.find("://") {
+        Some(i) => {
+            let rest = &uri[i + 3..];
+            let end = rest.find(|c: char| c == '/' || c == '?' || c == '#');
+            &uri[..i + 3 + end.unwrap_or_else(|| rest.len())]
+        }
+        None => uri,
+    }
+}
+
+/// Replace the `{name}` placeholders of `template` with the values in
+/// `values`, in a single pass, so that braces in the values are kept as they
+/// are. Unknown placeholders are left alone.
+pub(crate) fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            let value = values.iter().find(|&&(n, _)| n == name)?.1;
+            Some((value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                filled.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                filled.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+/// Escape `s` for HTML text and attribute values.
+pub(crate) fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_of() {
+        assert_eq!(
+            origin_of("https://example.com/a/b?c"),
+            "https://example.com"
+        );
+        assert_eq!(origin_of("http://localhost:8080"), "http://localhost:8080");
+        assert_eq!(origin_of("about:blank"), "about:blank");
+    }
+
+    #[test]
+    fn test_fill_template() {
+        let values = [("a", "{b}"), ("b", "2")];
+        assert_eq!(fill_template("{a} and {b}", &values), "{b} and 2");
+        assert_eq!(fill_template("{c} {{a}} {a", &values), "{c} {{b}} {a");
+        assert_eq!(fill_template("", &values), "");
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Theme propagation through CSS custom properties.
+
+use crate::cdp::DocumentScript;
+use crate::{Result, WebView};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+
+/// Id of the style element holding the variables.
+const STYLE_ELEMENT_ID: &str = "webview2-css-variables";
+
+thread_local! {
+    /// The script added by the last `set_css_variables` of each webview, by
+    /// the address of its `ICoreWebView2`. The webview is kept alive, so
+    /// that the address isn't reused.
+    static INSTALLED: RefCell<HashMap<usize, (WebView, DocumentScript)>> =
+        RefCell::new(HashMap::new());
+}
+
+impl WebView {
+    /// Set CSS custom properties on `:root` in the current document and every
+    /// document created afterwards, e.g. the colors of the system theme.
+    ///
+    /// Names may be given with or without the leading `--`. Each call replaces
+    /// all variables set by the previous one, in a single style change, so
+    /// pages never see a mix of old and new values. Pass an empty map to
+    /// remove them, which also releases the webview.
+    pub fn set_css_variables(&self, variables: &HashMap<String, String>) -> Result<()> {
+        let key = self.inner.as_raw() as usize;
+        // Not borrowed while calling into the webview.
+        let previous = INSTALLED.with(|installed| installed.borrow_mut().remove(&key));
+        if let Some((_, script)) = previous {
+            self.remove_document_script(script)?;
+        }
+        let script = self.add_document_script(&css_variables_script(variables))?;
+        if variables.is_empty() {
+            // Clears the variables of the current document, no later
+            // document needs it.
+            return self.remove_document_script(script);
+        }
+        INSTALLED.with(|installed| {
+            installed.borrow_mut().insert(key, (self.clone(), script));
+        });
+        Ok(())
+    }
+}
+
+/// A script that replaces the rules of the style element with `variables`.
+fn css_variables_script(variables: &HashMap<String, String>) -> String {
+    // Sorted, so that the same variables always produce the same script.
+    let variables: BTreeMap<String, &str> = variables
+        .iter()
+        .map(|(name, value)| {
+            let name = if name.starts_with("--") {
+                name.clone()
+            } else {
+                format!("--{}", name)
+            };
+            (name, value.as_str())
+        })
+        .collect();
+    format!(
+        "(() => {{
+            const variables = {};
+            const apply = () => {{
+                let style = document.getElementById({});
+                if (!style) {{
+                    style = document.createElement('style');
+                    style.id = {1};
+                    style.textContent = ':root {{}}';
+                    (document.head || document.documentElement).appendChild(style);
+                }}
+                const declaration = style.sheet.cssRules[0].style;
+                declaration.cssText = '';
+                for (const [name, value] of Object.entries(variables)) {{
+                    declaration.setProperty(name, value);
+                }}
+            }};
+            if (document.documentElement) apply();
+            else document.addEventListener('DOMContentLoaded', apply, {{ once: true }});
+        }})()",
+        serde_json::to_string(&variables).unwrap(),
+        serde_json::to_string(STYLE_ELEMENT_ID).unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_variables_script() {
+        let mut variables = HashMap::new();
+        variables.insert("--accent".to_string(), "#0078d4".to_string());
+        variables.insert("background".to_string(), "rgb(0, 0, 0)".to_string());
+        let script = css_variables_script(&variables);
+        assert!(script.contains(
+            r##"const variables = {"--accent":"#0078d4","--background":"rgb(0, 0, 0)"};"##
+        ));
+        assert!(script.contains(r#"document.getElementById("webview2-css-variables")"#));
+        assert!(script.contains(r#"style.id = "webview2-css-variables";"#));
+
+        let script = css_variables_script(&HashMap::new());
+        assert!(script.contains("const variables = {};"));
+    }
+
+    #[test]
+    fn test_css_variables_script_quotes_values() {
+        let mut variables = HashMap::new();
+        variables.insert("font".to_string(), r#""Segoe UI"</style>"#.to_string());
+        let script = css_variables_script(&variables);
+        assert!(script.contains(r#"{"--font":"\"Segoe UI\"</style>"}"#));
+    }
+}
@@ -0,0 +1,319 @@
+//! Picking up browser updates without restarting the application.
+//!
+//! An environment keeps using the browser it was started with. When a newer
+//! one is installed, `NewBrowserVersionAvailable` fires and the webviews have
+//! to be recreated in a new environment to get it, e.g. for security fixes.
+//! A user data folder can only be used by one browser process at a time, so
+//! all webviews of the old environment have to be closed and its browser
+//! process has to exit first.
+//!
+//! `Updater` creates the environment and the webviews, so it can do that.
+//! With `UpdatePolicy::Prompt` it calls `on_available` and the app calls
+//! `restart` when it suits it, e.g. after asking the user or when idle. With
+//! `UpdatePolicy::Immediate` it restarts right away. Either way
+//! `on_restarted` gets the new environment to create the webviews again in.
+//!
+//! ```no_run
+//! # fn f(parent: winapi::shared::windef::HWND) -> webview2::Result<()> {
+//! use webview2::update::{UpdatePolicy, Updater};
+//!
+//! let updater = Updater::new()
+//!     .with_user_data_folder(std::env::temp_dir().join("my-app"))
+//!     .with_policy(UpdatePolicy::Immediate);
+//! updater.on_restarted(move |updater, env| {
+//!     env?;
+//!     updater.create_host(parent, |host| {
+//!         host?.get_webview()?.navigate("https://example.com")
+//!     })
+//! });
+//! let first = updater.clone();
+//! updater.start(move |env| {
+//!     env?;
+//!     first.create_host(parent, |host| {
+//!         host?.get_webview()?.navigate("https://example.com")
+//!     })
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::dispatcher::Dispatcher;
+use crate::{Environment, EnvironmentBuilder, Error, EventRegistrationToken, Host, Result};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::ptr;
+use std::rc::Rc;
+use std::thread;
+use winapi::shared::minwindef::*;
+use winapi::shared::windef::HWND;
+use winapi::um::handleapi::CloseHandle;
+use winapi::um::processthreadsapi::OpenProcess;
+use winapi::um::synchapi::WaitForSingleObject;
+use winapi::um::winnt::{HANDLE, SYNCHRONIZE};
+
+/// How long to wait for the old browser process to exit before creating the
+/// new environment anyway.
+const EXIT_TIMEOUT_MS: DWORD = 30_000;
+
+thread_local! {
+    /// Updaters waiting for their old browser process to exit.
+    static RESTARTING: RefCell<HashMap<usize, Updater>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<usize> = Cell::new(0);
+}
+
+/// What `Updater` does when a new browser version is available.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UpdatePolicy {
+    /// Call `on_available` and wait for `restart`. The default.
+    Prompt,
+    /// Restart right away. The pages are loaded again, so state in them that
+    /// isn't saved is lost.
+    Immediate,
+}
+
+impl Default for UpdatePolicy {
+    fn default() -> Self {
+        UpdatePolicy::Prompt
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    browser_executable_folder: Option<PathBuf>,
+    user_data_folder: Option<PathBuf>,
+    additional_browser_arguments: Option<String>,
+    policy: UpdatePolicy,
+    environment: RefCell<Option<(Environment, EventRegistrationToken)>>,
+    hosts: RefCell<Vec<Host>>,
+    new_version: RefCell<Option<String>>,
+    restarting: Cell<bool>,
+    on_available: RefCell<Option<Rc<dyn Fn(&Updater, &str) -> Result<()>>>>,
+    on_restarted: RefCell<Option<Rc<dyn Fn(&Updater, Result<Environment>) -> Result<()>>>>,
+}
+
+/// Creates an environment and webviews and recreates them when the browser
+/// is updated. Clones share the state.
+#[derive(Clone, Default)]
+pub struct Updater {
+    inner: Rc<Inner>,
+}
+
+impl Updater {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_browser_executable_folder(self, folder: impl Into<PathBuf>) -> Self {
+        self.with(|inner| inner.browser_executable_folder = Some(folder.into()))
+    }
+
+    pub fn with_user_data_folder(self, folder: impl Into<PathBuf>) -> Self {
+        self.with(|inner| inner.user_data_folder = Some(folder.into()))
+    }
+
+    pub fn with_additional_browser_arguments(self, arguments: &str) -> Self {
+        self.with(|inner| inner.additional_browser_arguments = Some(arguments.into()))
+    }
+
+    pub fn with_policy(self, policy: UpdatePolicy) -> Self {
+        self.with(|inner| inner.policy = policy)
+    }
+
+    /// Settings can only be changed before the updater is shared.
+    fn with(mut self, f: impl FnOnce(&mut Inner)) -> Self {
+        f(Rc::get_mut(&mut self.inner).expect("Updater settings changed after cloning"));
+        self
+    }
+
+    /// Called with the new version when an update is available, with
+    /// `UpdatePolicy::Prompt`.
+    pub fn on_available(&self, f: impl Fn(&Updater, &str) -> Result<()> + 'static) {
+        *self.inner.on_available.borrow_mut() = Some(Rc::new(f));
+    }
+
+    /// Called with the new environment after a restart. The webviews are
+    /// closed, so the app creates them again with `create_host`.
+    pub fn on_restarted(&self, f: impl Fn(&Updater, Result<Environment>) -> Result<()> + 'static) {
+        *self.inner.on_restarted.borrow_mut() = Some(Rc::new(f));
+    }
+
+    /// Create the environment.
+    pub fn start(
+        &self,
+        completed: impl FnOnce(Result<Environment>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let inner = &self.inner;
+        let mut builder = EnvironmentBuilder::new();
+        if let Some(folder) = &inner.browser_executable_folder {
+            builder = builder.with_browser_executable_folder(folder);
+        }
+        if let Some(folder) = &inner.user_data_folder {
+            builder = builder.with_user_data_folder(folder);
+        }
+        if let Some(arguments) = &inner.additional_browser_arguments {
+            builder = builder.with_additional_browser_arguments(arguments);
+        }
+        let updater = self.clone();
+        builder.build(move |env| {
+            let env = env.and_then(|env| {
+                // Weak, as the updater holds the environment.
+                let watched = Rc::downgrade(&updater.inner);
+                let token = env.add_new_browser_version_available(move |_, args| match watched
+                    .upgrade()
+                {
+                    Some(inner) => Updater { inner }.available(args.get_new_version()?),
+                    None => Ok(()),
+                })?;
+                *updater.inner.environment.borrow_mut() = Some((env.clone(), token));
+                Ok(env)
+            });
+            completed(env)
+        })
+    }
+
+    /// The current environment, `None` before `start` completes and during a
+    /// restart.
+    pub fn environment(&self) -> Option<Environment> {
+        self.inner
+            .environment
+            .borrow()
+            .as_ref()
+            .map(|(env, _)| env.clone())
+    }
+
+    /// Create a webview in the current environment, which is closed on
+    /// restart.
+    pub fn create_host(
+        &self,
+        parent_window: HWND,
+        completed: impl FnOnce(Result<Host>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let env = self
+            .environment()
+            .ok_or_else(|| Error::from(io::Error::from(io::ErrorKind::NotConnected)))?;
+        let updater = self.clone();
+        env.create_host(parent_window, move |host| {
+            if let Ok(host) = &host {
+                updater.inner.hosts.borrow_mut().push(host.clone());
+            }
+            completed(host)
+        })
+    }
+
+    /// Stop tracking a webview that the app closed itself.
+    pub fn forget_host(&self, host: &Host) {
+        self.inner
+            .hosts
+            .borrow_mut()
+            .retain(|h| h.as_raw().as_raw() != host.as_raw().as_raw());
+    }
+
+    /// The version that is available if there is an update that hasn't been
+    /// restarted into yet.
+    pub fn new_version(&self) -> Option<String> {
+        self.inner.new_version.borrow().clone()
+    }
+
+    fn available(&self, version: String) -> Result<()> {
+        *self.inner.new_version.borrow_mut() = Some(version.clone());
+        match self.inner.policy {
+            UpdatePolicy::Immediate => self.restart(),
+            UpdatePolicy::Prompt => {
+                let on_available = self.inner.on_available.borrow().clone();
+                match on_available {
+                    Some(f) => f(self, &version),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Close the webviews and the environment, wait for the browser process
+    /// to exit, and create a new environment, which is passed to
+    /// `on_restarted`. The message loop has to run meanwhile.
+    ///
+    /// Webviews and environments the app created or kept itself keep the old
+    /// browser process running, and the new environment can only be created
+    /// once the timeout has passed, or not at all.
+    pub fn restart(&self) -> Result<()> {
+        if self.inner.restarting.get() {
+            return Ok(());
+        }
+        let dispatcher = Dispatcher::for_current_thread()?;
+        self.inner.restarting.set(true);
+        let browser = self.close();
+
+        let id = NEXT_ID.with(|n| {
+            let id = n.get();
+            n.set(id + 1);
+            id
+        });
+        RESTARTING.with(|r| r.borrow_mut().insert(id, self.clone()));
+        // `HANDLE` isn't `Send`.
+        let browser = browser as usize;
+        thread::spawn(move || {
+            if browser != 0 {
+                unsafe {
+                    WaitForSingleObject(browser as HANDLE, EXIT_TIMEOUT_MS);
+                    CloseHandle(browser as HANDLE);
+                }
+            }
+            let _ = dispatcher.post(move || {
+                if let Some(updater) = RESTARTING.with(|r| r.borrow_mut().remove(&id)) {
+                    updater.recreate();
+                }
+            });
+        });
+        Ok(())
+    }
+
+    /// Close the webviews and release the environment. Returns a handle to
+    /// wait for the browser process with, opened before closing so that the
+    /// id can't be reused, or null if it isn't known.
+    fn close(&self) -> HANDLE {
+        let hosts: Vec<Host> = self.inner.hosts.borrow_mut().drain(..).collect();
+        let mut browser = ptr::null_mut();
+        for host in &hosts {
+            let id = host.get_webview().and_then(|w| w.get_browser_process_id());
+            if let Ok(id) = id {
+                browser = unsafe { OpenProcess(SYNCHRONIZE, FALSE, id) };
+                if !browser.is_null() {
+                    break;
+                }
+            }
+        }
+        for host in hosts {
+            // Fails for webviews the app closed already.
+            let _ = host.close();
+        }
+        if let Some((env, token)) = self.inner.environment.borrow_mut().take() {
+            let _ = env.remove_new_browser_version_available(token);
+        }
+        browser
+    }
+
+    fn recreate(&self) {
+        let updater = self.clone();
+        let result = self.start(move |env| {
+            updater.restarted(env);
+            Ok(())
+        });
+        if let Err(e) = result {
+            self.restarted(Err(e));
+        }
+    }
+
+    fn restarted(&self, env: Result<Environment>) {
+        self.inner.restarting.set(false);
+        if env.is_ok() {
+            *self.inner.new_version.borrow_mut() = None;
+        }
+        let on_restarted = self.inner.on_restarted.borrow().clone();
+        if let Some(f) = on_restarted {
+            // There is no caller to return errors to.
+            let _ = f(self, env);
+        }
+    }
+}
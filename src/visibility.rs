@@ -0,0 +1,440 @@
+//! Visibility tracking, so that hidden webviews stop rendering.
+//!
+//! A webview keeps rendering at full speed while its window is minimized,
+//! cloaked (e.g. on another virtual desktop), hidden or covered by other
+//! windows, unless `IsVisible` is set to false. `Host::track_visibility`
+//! follows the host window with win event hooks and keeps `IsVisible` in sync.
+//!
+//! A window is occluded when the windows above it in the z-order, together
+//! with the edges of the screen, cover all of it. Windows that are
+//! transparent to input or layered are not counted as covering anything, as
+//! they may be see-through.
+//!
+//! Windows fire location changes continuously while they are dragged, so the
+//! events only mark the trackers they concern, and the states are computed
+//! once the message loop gets to it, for all the events received meanwhile.
+
+use crate::dispatcher::Dispatcher;
+use crate::{Host, Result};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+use winapi::shared::minwindef::*;
+use winapi::shared::ntdef::LONG;
+use winapi::shared::windef::*;
+use winapi::um::dwmapi::{DwmGetWindowAttribute, DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS};
+use winapi::um::winuser::*;
+
+/// Whether the host window can currently be seen.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VisibilityState {
+    Visible,
+    /// The top level window is minimized.
+    Minimized,
+    /// The window is hidden, cloaked or has an empty client area.
+    Hidden,
+    /// The window is covered by other windows or off screen.
+    Occluded,
+}
+
+impl VisibilityState {
+    fn of(window: HWND, root: HWND) -> Self {
+        unsafe {
+            let minimized = IsIconic(root) != 0;
+            let mut rect: RECT = mem::zeroed();
+            GetClientRect(window, &mut rect);
+            let hidden = IsWindowVisible(window) == 0 || is_cloaked(root) || is_empty(&rect);
+            let occluded = !minimized && !hidden && is_occluded(window, root);
+            Self::from_flags(minimized, hidden, occluded)
+        }
+    }
+
+    fn from_flags(minimized: bool, hidden: bool, occluded: bool) -> Self {
+        if minimized {
+            VisibilityState::Minimized
+        } else if hidden {
+            VisibilityState::Hidden
+        } else if occluded {
+            VisibilityState::Occluded
+        } else {
+            VisibilityState::Visible
+        }
+    }
+}
+
+unsafe fn is_cloaked(window: HWND) -> bool {
+    let mut cloaked: DWORD = 0;
+    DwmGetWindowAttribute(
+        window,
+        DWMWA_CLOAKED,
+        &mut cloaked as *mut DWORD as *mut _,
+        mem::size_of::<DWORD>() as DWORD,
+    ) == 0
+        && cloaked != 0
+}
+
+/// The visible bounds of a window in screen coordinates, without the
+/// invisible resize borders of top level windows.
+unsafe fn frame_bounds(window: HWND) -> RECT {
+    let mut rect: RECT = mem::zeroed();
+    if DwmGetWindowAttribute(
+        window,
+        DWMWA_EXTENDED_FRAME_BOUNDS,
+        &mut rect as *mut RECT as *mut _,
+        mem::size_of::<RECT>() as DWORD,
+    ) != 0
+    {
+        GetWindowRect(window, &mut rect);
+    }
+    rect
+}
+
+/// Whether the windows above `root` and the screen edges cover `window`.
+unsafe fn is_occluded(window: HWND, root: HWND) -> bool {
+    let mut rect: RECT = mem::zeroed();
+    GetWindowRect(window, &mut rect);
+    let left = GetSystemMetrics(SM_XVIRTUALSCREEN);
+    let top = GetSystemMetrics(SM_YVIRTUALSCREEN);
+    let screen = RECT {
+        left,
+        top,
+        right: left + GetSystemMetrics(SM_CXVIRTUALSCREEN),
+        bottom: top + GetSystemMetrics(SM_CYVIRTUALSCREEN),
+    };
+    let mut covers = Vec::new();
+    let mut above = GetWindow(root, GW_HWNDPREV);
+    while !above.is_null() {
+        let ex_style = GetWindowLongW(above, GWL_EXSTYLE) as DWORD;
+        if IsWindowVisible(above) != 0
+            && IsIconic(above) == 0
+            && ex_style & (WS_EX_TRANSPARENT | WS_EX_LAYERED) == 0
+            && !is_cloaked(above)
+        {
+            covers.push(frame_bounds(above));
+        }
+        above = GetWindow(above, GW_HWNDPREV);
+    }
+    is_covered(&rect, &screen, &covers)
+}
+
+fn is_empty(rect: &RECT) -> bool {
+    rect.right <= rect.left || rect.bottom <= rect.top
+}
+
+/// The parts of `rect` outside of `cover`, as up to 4 rectangles.
+fn subtract(rect: &RECT, cover: &RECT) -> Vec<RECT> {
+    if cover.left >= rect.right
+        || cover.right <= rect.left
+        || cover.top >= rect.bottom
+        || cover.bottom <= rect.top
+    {
+        return vec![*rect];
+    }
+    let top = rect.top.max(cover.top);
+    let bottom = rect.bottom.min(cover.bottom);
+    let parts = [
+        RECT {
+            bottom: cover.top,
+            ..*rect
+        },
+        RECT {
+            top: cover.bottom,
+            ..*rect
+        },
+        RECT {
+            top,
+            bottom,
+            right: cover.left,
+            ..*rect
+        },
+        RECT {
+            top,
+            bottom,
+            left: cover.right,
+            ..*rect
+        },
+    ];
+    parts.iter().filter(|r| !is_empty(r)).copied().collect()
+}
+
+/// Whether nothing of `rect` is left inside `screen` and outside of `covers`.
+fn is_covered(rect: &RECT, screen: &RECT, covers: &[RECT]) -> bool {
+    let visible = RECT {
+        left: rect.left.max(screen.left),
+        top: rect.top.max(screen.top),
+        right: rect.right.min(screen.right),
+        bottom: rect.bottom.min(screen.bottom),
+    };
+    if is_empty(&visible) {
+        return true;
+    }
+    let mut uncovered = vec![visible];
+    for cover in covers {
+        uncovered = uncovered.iter().flat_map(|r| subtract(r, cover)).collect();
+        if uncovered.is_empty() {
+            return true;
+        }
+    }
+    false
+}
+
+struct Tracked {
+    host: Host,
+    window: HWND,
+    root: HWND,
+    state: Cell<VisibilityState>,
+    handler: Box<dyn Fn(VisibilityState) -> Result<()>>,
+    /// Set by the events that may have changed the state.
+    dirty: Cell<bool>,
+    dispatcher: Dispatcher,
+}
+
+impl Tracked {
+    fn update(&self) {
+        let state = VisibilityState::of(self.window, self.root);
+        if state == self.state.replace(state) {
+            return;
+        }
+        // There is nowhere to report errors to from a win event hook.
+        let _ = self.host.put_is_visible(state == VisibilityState::Visible);
+        let _ = (self.handler)(state);
+    }
+}
+
+thread_local! {
+    static TRACKED: RefCell<HashMap<usize, Rc<Tracked>>> = RefCell::new(HashMap::new());
+    /// Whether `update_dirty` is posted and hasn't run yet.
+    static UPDATE_POSTED: Cell<bool> = Cell::new(false);
+}
+
+/// Update the trackers marked by events since the last time.
+fn update_dirty() {
+    UPDATE_POSTED.with(|posted| posted.set(false));
+    // Collect first, handlers may start or stop tracking.
+    let dirty: Vec<Rc<Tracked>> = TRACKED.with(|tracked| {
+        tracked
+            .borrow()
+            .values()
+            .filter(|t| t.dirty.replace(false))
+            .cloned()
+            .collect()
+    });
+    for t in dirty {
+        t.update();
+    }
+}
+
+/// Returned by `Host::track_visibility`. Tracking stops when this is dropped.
+pub struct VisibilityTracker {
+    tracked: Rc<Tracked>,
+    hooks: Vec<HWINEVENTHOOK>,
+}
+
+impl VisibilityTracker {
+    /// The last observed state.
+    pub fn state(&self) -> VisibilityState {
+        self.tracked.state.get()
+    }
+}
+
+impl Drop for VisibilityTracker {
+    fn drop(&mut self) {
+        for hook in self.hooks.drain(..) {
+            unsafe {
+                UnhookWinEvent(hook);
+            }
+        }
+        let key = &*self.tracked as *const Tracked as usize;
+        TRACKED.with(|tracked| tracked.borrow_mut().remove(&key));
+    }
+}
+
+unsafe extern "system" fn win_event_proc(
+    _hook: HWINEVENTHOOK,
+    _event: DWORD,
+    hwnd: HWND,
+    id_object: LONG,
+    id_child: LONG,
+    _event_thread: DWORD,
+    _event_time: DWORD,
+) {
+    // Not about a window itself, e.g. about its caret or scroll bars.
+    if hwnd.is_null() || id_object != OBJID_WINDOW || id_child != CHILDID_SELF {
+        return;
+    }
+    // Any top level window moving, showing or coming to the front can change
+    // what it covers, other child windows can't.
+    let top_level = GetAncestor(hwnd, GA_ROOT) == hwnd;
+    let dispatcher = TRACKED.with(|tracked| {
+        let mut dispatcher = None;
+        for t in tracked.borrow().values() {
+            if t.window == hwnd || t.root == hwnd || top_level {
+                t.dirty.set(true);
+                dispatcher.get_or_insert_with(|| t.dispatcher.clone());
+            }
+        }
+        dispatcher
+    });
+    let dispatcher = match dispatcher {
+        Some(dispatcher) => dispatcher,
+        None => return,
+    };
+    if UPDATE_POSTED.with(|posted| posted.replace(true)) {
+        return;
+    }
+    if dispatcher.post(update_dirty).is_err() {
+        update_dirty();
+    }
+}
+
+impl Host {
+    /// Follow minimizing, hiding, cloaking, resizing and occlusion of the
+    /// parent window, set `IsVisible` accordingly and call `handler` on every
+    /// change.
+    ///
+    /// `IsVisible` is updated right away to match the current state. The
+    /// thread must run a message loop for changes to be observed. If the
+    /// parent window is changed with `put_parent_window`, start tracking
+    /// again.
+    pub fn track_visibility(
+        &self,
+        handler: impl Fn(VisibilityState) -> Result<()> + 'static,
+    ) -> Result<VisibilityTracker> {
+        let window = self.get_parent_window()?;
+        let root = unsafe { GetAncestor(window, GA_ROOT) };
+        let state = VisibilityState::of(window, root);
+        self.put_is_visible(state == VisibilityState::Visible)?;
+
+        let tracked = Rc::new(Tracked {
+            host: self.clone(),
+            window,
+            root,
+            state: Cell::new(state),
+            handler: Box::new(handler),
+            dirty: Cell::new(false),
+            dispatcher: Dispatcher::for_current_thread()?,
+        });
+        TRACKED.with(|t| {
+            t.borrow_mut()
+                .insert(&*tracked as *const Tracked as usize, tracked.clone())
+        });
+
+        // Other processes' windows can cover the window, so the hooks follow
+        // all of them.
+        let hooks = [
+            (EVENT_SYSTEM_FOREGROUND, EVENT_SYSTEM_FOREGROUND),
+            (EVENT_SYSTEM_MINIMIZESTART, EVENT_SYSTEM_MINIMIZEEND),
+            (EVENT_OBJECT_SHOW, EVENT_OBJECT_HIDE),
+            (EVENT_OBJECT_LOCATIONCHANGE, EVENT_OBJECT_LOCATIONCHANGE),
+            (EVENT_OBJECT_CLOAKED, EVENT_OBJECT_UNCLOAKED),
+        ]
+        .iter()
+        .map(|&(min, max)| unsafe {
+            SetWinEventHook(
+                min,
+                max,
+                ptr::null_mut(),
+                Some(win_event_proc),
+                0,
+                0,
+                WINEVENT_OUTOFCONTEXT,
+            )
+        })
+        .filter(|hook| !hook.is_null())
+        .collect();
+
+        Ok(VisibilityTracker { tracked, hooks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: LONG, top: LONG, right: LONG, bottom: LONG) -> RECT {
+        RECT {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn test_from_flags() {
+        use VisibilityState::*;
+        assert_eq!(VisibilityState::from_flags(false, false, false), Visible);
+        assert_eq!(VisibilityState::from_flags(true, false, false), Minimized);
+        assert_eq!(VisibilityState::from_flags(true, true, true), Minimized);
+        assert_eq!(VisibilityState::from_flags(false, true, false), Hidden);
+        assert_eq!(VisibilityState::from_flags(false, true, true), Hidden);
+        assert_eq!(VisibilityState::from_flags(false, false, true), Occluded);
+    }
+
+    #[test]
+    fn test_is_covered() {
+        let screen = rect(0, 0, 1920, 1080);
+        let window = rect(100, 100, 500, 400);
+        assert!(!is_covered(&window, &screen, &[]));
+        // One window on top of it.
+        assert!(is_covered(&window, &screen, &[rect(0, 0, 600, 600)]));
+        // Two windows side by side.
+        assert!(is_covered(
+            &window,
+            &screen,
+            &[rect(0, 0, 300, 600), rect(300, 0, 600, 600)]
+        ));
+        // A gap in between.
+        assert!(!is_covered(
+            &window,
+            &screen,
+            &[rect(0, 0, 300, 600), rect(301, 0, 600, 600)]
+        ));
+        // Covered by a window in the middle and four around it.
+        assert!(is_covered(
+            &window,
+            &screen,
+            &[
+                rect(200, 200, 400, 300),
+                rect(0, 0, 600, 200),
+                rect(0, 300, 600, 600),
+                rect(0, 0, 200, 600),
+                rect(400, 0, 600, 600),
+            ]
+        ));
+        // Partly off screen, the rest covered.
+        assert!(is_covered(
+            &rect(-200, 100, 200, 400),
+            &screen,
+            &[rect(0, 0, 300, 600)]
+        ));
+        assert!(!is_covered(
+            &rect(-200, 100, 200, 400),
+            &screen,
+            &[rect(100, 0, 300, 600)]
+        ));
+        // Entirely off screen.
+        assert!(is_covered(&rect(2000, 0, 2400, 300), &screen, &[]));
+    }
+
+    #[test]
+    fn test_subtract() {
+        // `RECT` doesn't implement `PartialEq`.
+        let subtract = |r: RECT, cover: RECT| -> Vec<_> {
+            subtract(&r, &cover)
+                .iter()
+                .map(|r| (r.left, r.top, r.right, r.bottom))
+                .collect()
+        };
+        let r = rect(0, 0, 10, 10);
+        assert_eq!(subtract(r, rect(20, 20, 30, 30)), vec![(0, 0, 10, 10)]);
+        assert_eq!(subtract(r, rect(10, 0, 20, 10)), vec![(0, 0, 10, 10)]);
+        assert_eq!(subtract(r, rect(-1, -1, 11, 11)), vec![]);
+        assert_eq!(
+            subtract(r, rect(2, 3, 5, 7)),
+            vec![(0, 0, 10, 3), (0, 7, 10, 10), (0, 3, 2, 7), (5, 3, 10, 7)]
+        );
+    }
+}
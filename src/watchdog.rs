@@ -0,0 +1,181 @@
+//! Detecting a hung renderer with a heartbeat.
+//!
+//! `ProcessFailed` only reports `RENDER_PROCESS_UNRESPONSIVE` after a fixed,
+//! fairly long delay, and only once the user tries to interact with the page.
+//! The watchdog instead runs a trivial script every `interval` and considers
+//! the renderer unresponsive when the result takes longer than `timeout`.
+
+use crate::{EventRegistrationToken, Result, WebView};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ptr;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use winapi::shared::basetsd::UINT_PTR;
+use winapi::shared::minwindef::*;
+use winapi::shared::windef::HWND;
+use winapi::um::winuser::{KillTimer, SetTimer};
+
+/// Reported by the watchdog when the renderer stops or starts responding
+/// again.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Responsiveness {
+    Responsive,
+    Unresponsive,
+}
+
+/// Settings of `WebView::start_watchdog`.
+#[derive(Debug, Clone)]
+pub struct WatchdogOptions {
+    /// How often to check. Defaults to 1 second.
+    pub interval: Duration,
+    /// How long the renderer may take to answer. Defaults to 5 seconds.
+    pub timeout: Duration,
+    /// Reload the page when the renderer becomes unresponsive. Defaults to
+    /// false.
+    pub auto_reload: bool,
+}
+
+impl Default for WatchdogOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(5),
+            auto_reload: false,
+        }
+    }
+}
+
+struct Watched {
+    webview: WebView,
+    timeout: Duration,
+    auto_reload: bool,
+    /// When the unanswered heartbeat was sent.
+    pending: Cell<Option<Instant>>,
+    responsive: Cell<bool>,
+    handler: Box<dyn Fn(Responsiveness) -> Result<()>>,
+}
+
+impl Watched {
+    fn tick(self: &Rc<Self>) {
+        let now = Instant::now();
+        if let Some(sent) = self.pending.get() {
+            if now.duration_since(sent) < self.timeout {
+                return;
+            }
+            if self.responsive.replace(false) {
+                // There is nowhere to report errors to from a timer.
+                let _ = (self.handler)(Responsiveness::Unresponsive);
+                if self.auto_reload {
+                    let _ = self.webview.reload();
+                }
+            }
+        }
+        // Keep sending heartbeats while unresponsive, a single one may have
+        // been lost.
+        self.pending.set(Some(now));
+        let watched = Rc::downgrade(self);
+        let _ = self.webview.execute_script("0", move |_| {
+            if let Some(watched) = watched.upgrade() {
+                watched.answered();
+            }
+            Ok(())
+        });
+    }
+
+    fn answered(&self) {
+        self.pending.set(None);
+        if !self.responsive.replace(true) {
+            let _ = (self.handler)(Responsiveness::Responsive);
+        }
+    }
+}
+
+thread_local! {
+    static WATCHED: RefCell<HashMap<UINT_PTR, Rc<Watched>>> = RefCell::new(HashMap::new());
+}
+
+/// Returned by `WebView::start_watchdog`. The watchdog stops when this is
+/// dropped.
+pub struct Watchdog {
+    timer: UINT_PTR,
+    watched: Rc<Watched>,
+    navigation_starting: Option<EventRegistrationToken>,
+}
+
+impl Watchdog {
+    /// Whether the renderer answered the last heartbeat in time.
+    pub fn is_responsive(&self) -> bool {
+        self.watched.responsive.get()
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        unsafe {
+            KillTimer(ptr::null_mut(), self.timer);
+        }
+        WATCHED.with(|watched| watched.borrow_mut().remove(&self.timer));
+        if let Some(token) = self.navigation_starting.take() {
+            let _ = self.watched.webview.remove_navigation_starting(token);
+        }
+    }
+}
+
+unsafe extern "system" fn timer_proc(_hwnd: HWND, _msg: UINT, id: UINT_PTR, _time: DWORD) {
+    // Clone first, the handler may drop the watchdog.
+    let watched = WATCHED.with(|watched| watched.borrow().get(&id).cloned());
+    if let Some(watched) = watched {
+        watched.tick();
+    }
+}
+
+impl WebView {
+    /// Check that the renderer is responsive every `options.interval`, and
+    /// call `handler` when it stops or starts answering again.
+    ///
+    /// The thread must run a message loop. Heartbeats are not counted across
+    /// navigations, because the script may be dropped with the old document.
+    pub fn start_watchdog(
+        &self,
+        options: WatchdogOptions,
+        handler: impl Fn(Responsiveness) -> Result<()> + 'static,
+    ) -> Result<Watchdog> {
+        let watched = Rc::new(Watched {
+            webview: self.clone(),
+            timeout: options.timeout,
+            auto_reload: options.auto_reload,
+            pending: Cell::new(None),
+            responsive: Cell::new(true),
+            handler: Box::new(handler),
+        });
+
+        let navigation_starting = {
+            let watched = Rc::downgrade(&watched);
+            self.add_navigation_starting(move |_, _| {
+                if let Some(watched) = watched.upgrade() {
+                    watched.pending.set(None);
+                }
+                Ok(())
+            })?
+        };
+
+        let interval = options
+            .interval
+            .as_millis()
+            .max(1)
+            .min(u128::from(UINT::max_value())) as UINT;
+        let timer = unsafe { SetTimer(ptr::null_mut(), 0, interval, Some(timer_proc)) };
+        if timer == 0 {
+            let _ = self.remove_navigation_starting(navigation_starting);
+            return Err(std::io::Error::last_os_error().into());
+        }
+        WATCHED.with(|w| w.borrow_mut().insert(timer, watched.clone()));
+
+        Ok(Watchdog {
+            timer,
+            watched,
+            navigation_starting: Some(navigation_starting),
+        })
+    }
+}
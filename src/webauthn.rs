@@ -0,0 +1,282 @@
+//! Virtual WebAuthn authenticators, for automated tests of sign-in flows.
+//!
+//! This wraps the `WebAuthn` DevTools protocol domain. Once a virtual
+//! authenticator is added, `navigator.credentials.create` and
+//! `navigator.credentials.get` in the page talk to it instead of real
+//! hardware or Windows Hello, so passkey scenarios can run unattended.
+
+use crate::{Error, Result, WebView};
+use serde_json::{json, Value};
+use winapi::shared::winerror::E_FAIL;
+
+/// `WebAuthn.AuthenticatorProtocol`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AuthenticatorProtocol {
+    U2f,
+    Ctap2,
+}
+
+/// `WebAuthn.AuthenticatorTransport`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AuthenticatorTransport {
+    Usb,
+    Nfc,
+    Ble,
+    Cable,
+    Internal,
+}
+
+/// Options for `WebView::add_virtual_authenticator`.
+#[derive(Debug, Clone)]
+pub struct VirtualAuthenticatorOptions {
+    pub protocol: AuthenticatorProtocol,
+    pub transport: AuthenticatorTransport,
+    pub has_resident_key: bool,
+    pub has_user_verification: bool,
+    /// Whether user verification (e.g. a PIN or biometrics) succeeds.
+    pub is_user_verified: bool,
+    /// Whether the authenticator answers requests without user interaction.
+    pub automatic_presence_simulation: bool,
+}
+
+impl Default for VirtualAuthenticatorOptions {
+    /// A platform authenticator supporting passkeys.
+    fn default() -> Self {
+        Self {
+            protocol: AuthenticatorProtocol::Ctap2,
+            transport: AuthenticatorTransport::Internal,
+            has_resident_key: true,
+            has_user_verification: true,
+            is_user_verified: true,
+            automatic_presence_simulation: true,
+        }
+    }
+}
+
+/// A WebAuthn credential stored in a virtual authenticator.
+///
+/// Binary fields are base64 encoded, as in the DevTools protocol.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Credential {
+    pub credential_id: String,
+    pub is_resident_credential: bool,
+    pub rp_id: Option<String>,
+    /// The ECDSA P-256 private key in PKCS#8 format.
+    pub private_key: String,
+    pub user_handle: Option<String>,
+    pub sign_count: u32,
+}
+
+impl Credential {
+    fn to_json(&self) -> Value {
+        let mut v = json!({
+            "credentialId": self.credential_id,
+            "isResidentCredential": self.is_resident_credential,
+            "privateKey": self.private_key,
+            "signCount": self.sign_count,
+        });
+        if let Some(rp_id) = &self.rp_id {
+            v["rpId"] = rp_id.as_str().into();
+        }
+        if let Some(user_handle) = &self.user_handle {
+            v["userHandle"] = user_handle.as_str().into();
+        }
+        v
+    }
+
+    fn from_json(v: &Value) -> Result<Self> {
+        let string = |name: &str| v[name].as_str().map(|s| s.to_string());
+        Ok(Self {
+            credential_id: string("credentialId").ok_or_else(|| Error::new(E_FAIL))?,
+            is_resident_credential: v["isResidentCredential"].as_bool().unwrap_or(false),
+            rp_id: string("rpId"),
+            private_key: string("privateKey").ok_or_else(|| Error::new(E_FAIL))?,
+            user_handle: string("userHandle"),
+            sign_count: v["signCount"].as_u64().unwrap_or(0) as u32,
+        })
+    }
+}
+
+/// A virtual authenticator attached to a webview.
+#[derive(Clone)]
+pub struct VirtualAuthenticator {
+    webview: WebView,
+    id: String,
+}
+
+impl WebView {
+    /// Enable the `WebAuthn` domain and add a virtual authenticator.
+    pub fn add_virtual_authenticator(
+        &self,
+        options: &VirtualAuthenticatorOptions,
+        callback: impl FnOnce(VirtualAuthenticator) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let protocol = match options.protocol {
+            AuthenticatorProtocol::U2f => "u2f",
+            AuthenticatorProtocol::Ctap2 => "ctap2",
+        };
+        let transport = match options.transport {
+            AuthenticatorTransport::Usb => "usb",
+            AuthenticatorTransport::Nfc => "nfc",
+            AuthenticatorTransport::Ble => "ble",
+            AuthenticatorTransport::Cable => "cable",
+            AuthenticatorTransport::Internal => "internal",
+        };
+        let parameters = json!({
+            "options": {
+                "protocol": protocol,
+                "transport": transport,
+                "hasResidentKey": options.has_resident_key,
+                "hasUserVerification": options.has_user_verification,
+                "isUserVerified": options.is_user_verified,
+                "automaticPresenceSimulation": options.automatic_presence_simulation,
+            }
+        });
+        let webview = self.clone();
+        self.call_cdp("WebAuthn.enable", json!({}), move |_| {
+            let w = webview.clone();
+            webview.call_cdp(
+                "WebAuthn.addVirtualAuthenticator",
+                parameters,
+                move |result| {
+                    let id = result["authenticatorId"]
+                        .as_str()
+                        .ok_or_else(|| Error::new(E_FAIL))?
+                        .to_string();
+                    callback(VirtualAuthenticator { webview: w, id })
+                },
+            )
+        })
+    }
+}
+
+impl VirtualAuthenticator {
+    /// The `authenticatorId` assigned by the browser.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn add_credential(
+        &self,
+        credential: &Credential,
+        callback: impl FnOnce() -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = json!({
+            "authenticatorId": self.id,
+            "credential": credential.to_json(),
+        });
+        self.webview
+            .call_cdp("WebAuthn.addCredential", parameters, move |_| callback())
+    }
+
+    pub fn get_credentials(
+        &self,
+        callback: impl FnOnce(Vec<Credential>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = json!({ "authenticatorId": self.id });
+        self.webview
+            .call_cdp("WebAuthn.getCredentials", parameters, move |result| {
+                let credentials = result["credentials"]
+                    .as_array()
+                    .map(|a| {
+                        a.iter()
+                            .map(Credential::from_json)
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .unwrap_or_else(|| Ok(Vec::new()))?;
+                callback(credentials)
+            })
+    }
+
+    pub fn clear_credentials(&self, callback: impl FnOnce() -> Result<()> + 'static) -> Result<()> {
+        let parameters = json!({ "authenticatorId": self.id });
+        self.webview
+            .call_cdp("WebAuthn.clearCredentials", parameters, move |_| callback())
+    }
+
+    /// Make subsequent user verification attempts succeed or fail.
+    pub fn set_user_verified(
+        &self,
+        is_user_verified: bool,
+        callback: impl FnOnce() -> Result<()> + 'static,
+    ) -> Result<()> {
+        let parameters = json!({
+            "authenticatorId": self.id,
+            "isUserVerified": is_user_verified,
+        });
+        self.webview
+            .call_cdp("WebAuthn.setUserVerified", parameters, move |_| callback())
+    }
+
+    /// Remove the authenticator from the webview.
+    pub fn remove(self, callback: impl FnOnce() -> Result<()> + 'static) -> Result<()> {
+        let parameters = json!({ "authenticatorId": self.id });
+        self.webview.call_cdp(
+            "WebAuthn.removeVirtualAuthenticator",
+            parameters,
+            move |_| callback(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credential() -> Credential {
+        Credential {
+            credential_id: "Y3JlZA==".into(),
+            is_resident_credential: true,
+            rp_id: Some("example.com".into()),
+            private_key: "a2V5".into(),
+            user_handle: Some("dXNlcg==".into()),
+            sign_count: 3,
+        }
+    }
+
+    #[test]
+    fn test_credential_to_json() {
+        assert_eq!(
+            credential().to_json(),
+            json!({
+                "credentialId": "Y3JlZA==",
+                "isResidentCredential": true,
+                "rpId": "example.com",
+                "privateKey": "a2V5",
+                "userHandle": "dXNlcg==",
+                "signCount": 3,
+            })
+        );
+
+        let credential = Credential {
+            rp_id: None,
+            user_handle: None,
+            ..credential()
+        };
+        let v = credential.to_json();
+        assert!(v.get("rpId").is_none());
+        assert!(v.get("userHandle").is_none());
+    }
+
+    #[test]
+    fn test_credential_from_json() {
+        let credential = credential();
+        assert_eq!(
+            Credential::from_json(&credential.to_json()).unwrap(),
+            credential
+        );
+
+        let v = json!({ "credentialId": "Y3JlZA==", "privateKey": "a2V5" });
+        assert_eq!(
+            Credential::from_json(&v).unwrap(),
+            Credential {
+                credential_id: "Y3JlZA==".into(),
+                private_key: "a2V5".into(),
+                ..Credential::default()
+            }
+        );
+
+        assert!(Credential::from_json(&json!({ "privateKey": "a2V5" })).is_err());
+        assert!(Credential::from_json(&json!({ "credentialId": "Y3JlZA==" })).is_err());
+    }
+}
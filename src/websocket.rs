@@ -0,0 +1,526 @@
+//! WebSocket emulation for offline apps.
+//!
+//! `WebResourceRequested` does not see WebSocket connections, so apps that
+//! serve their backend from Rust can't intercept sockets the same way as
+//! other requests. `WebSocketProxy` runs a WebSocket server on the loopback
+//! interface instead, and `WebSocketProxy::install` injects a script that
+//! rewrites `new WebSocket(url)` for the configured origins to connect to it.
+//! Each connection is handed to a Rust handler on its own thread, together
+//! with the URL the page asked for.
+//!
+//! The rewritten socket reports the proxy address as its `url`.
+
+use crate::cdp::{js_string, DocumentScript};
+use crate::net::{invalid_data, random_token, read_head_line, token_eq, MAX_HEAD_SIZE};
+use crate::{Result, WebView};
+use serde_json::json;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xa;
+
+/// Messages larger than this are rejected.
+const MAX_MESSAGE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// A WebSocket message.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// The server side of a WebSocket connection.
+pub struct WebSocket {
+    stream: TcpStream,
+}
+
+impl WebSocket {
+    /// Wait for the next message. Returns `None` once the page closed the
+    /// socket.
+    ///
+    /// Pings are answered automatically.
+    pub fn recv(&mut self) -> io::Result<Option<Message>> {
+        let mut message: Option<(u8, Vec<u8>)> = None;
+        loop {
+            let frame = read_frame(&mut self.stream)?;
+            match frame.opcode {
+                OPCODE_PING => write_frame(&mut self.stream, OPCODE_PONG, &frame.payload)?,
+                OPCODE_PONG => {}
+                OPCODE_CLOSE => {
+                    let _ = write_frame(&mut self.stream, OPCODE_CLOSE, &frame.payload);
+                    return Ok(None);
+                }
+                OPCODE_TEXT | OPCODE_BINARY if message.is_none() => {
+                    message = Some((frame.opcode, frame.payload));
+                }
+                OPCODE_CONTINUATION if message.is_some() => {
+                    let (_, payload) = message.as_mut().unwrap();
+                    if (payload.len() + frame.payload.len()) as u64 > MAX_MESSAGE_SIZE {
+                        return Err(invalid_data("message too large"));
+                    }
+                    payload.extend_from_slice(&frame.payload);
+                }
+                _ => return Err(invalid_data("unexpected frame")),
+            }
+            if frame.fin {
+                if let Some((opcode, payload)) = message.take() {
+                    return if opcode == OPCODE_TEXT {
+                        String::from_utf8(payload)
+                            .map(|s| Some(Message::Text(s)))
+                            .map_err(|_| invalid_data("invalid UTF-8"))
+                    } else {
+                        Ok(Some(Message::Binary(payload)))
+                    };
+                }
+            }
+        }
+    }
+
+    pub fn send(&mut self, message: &Message) -> io::Result<()> {
+        match message {
+            Message::Text(text) => write_frame(&mut self.stream, OPCODE_TEXT, text.as_bytes()),
+            Message::Binary(data) => write_frame(&mut self.stream, OPCODE_BINARY, data),
+        }
+    }
+
+    /// Close the connection.
+    pub fn close(mut self) -> io::Result<()> {
+        // Status code 1000, normal closure.
+        write_frame(&mut self.stream, OPCODE_CLOSE, &[0x03, 0xe8])?;
+        self.stream.shutdown(Shutdown::Both)
+    }
+
+    /// Another handle to the same connection, e.g. to send from one thread
+    /// while another one waits in `recv`.
+    pub fn try_clone(&self) -> io::Result<Self> {
+        Ok(Self {
+            stream: self.stream.try_clone()?,
+        })
+    }
+}
+
+/// A WebSocket server on the loopback interface, standing in for the servers
+/// of some origins.
+///
+/// The server stops when this is dropped. Established connections stay open.
+pub struct WebSocketProxy {
+    origins: Vec<String>,
+    port: u16,
+    token: String,
+    stopped: Arc<AtomicBool>,
+}
+
+impl WebSocketProxy {
+    /// Start a server for sockets to `origins`, e.g. `wss://api.example.com`.
+    ///
+    /// `handler` is called on a new thread for every connection, with the
+    /// URL the page connected to.
+    pub fn start(
+        origins: &[&str],
+        handler: impl Fn(String, WebSocket) + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let port = listener.local_addr()?.port();
+        // Keep other local processes from connecting.
+        let token = random_token()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let handler = Arc::new(handler);
+        {
+            let token = token.clone();
+            let stopped = stopped.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let stream = match stream {
+                        Ok(stream) => stream,
+                        Err(_) => continue,
+                    };
+                    let handler = handler.clone();
+                    let token = token.clone();
+                    thread::spawn(move || {
+                        if let Ok((url, socket)) = accept(stream, &token) {
+                            handler(url, socket);
+                        }
+                    });
+                }
+            });
+        }
+
+        Ok(Self {
+            origins: origins
+                .iter()
+                .map(|o| o.trim_end_matches('/').to_ascii_lowercase())
+                .collect(),
+            port,
+            token,
+            stopped,
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Redirect sockets to the configured origins to this proxy, in the
+    /// current document of `webview` and every document created afterwards.
+    pub fn install(&self, webview: &WebView) -> Result<WebSocketProxyToken> {
+        let script = format!(
+            "(() => {{
+                const origins = {};
+                const proxy = {};
+                const NativeWebSocket = window.WebSocket;
+                function WebSocket(url, protocols) {{
+                    const u = new URL(url, location.href);
+                    if (origins.includes(u.protocol + '//' + u.host)) {{
+                        url = proxy + encodeURIComponent(u.href);
+                    }}
+                    return protocols === undefined
+                        ? new NativeWebSocket(url)
+                        : new NativeWebSocket(url, protocols);
+                }}
+                WebSocket.prototype = NativeWebSocket.prototype;
+                for (const state of ['CONNECTING', 'OPEN', 'CLOSING', 'CLOSED']) {{
+                    WebSocket[state] = NativeWebSocket[state];
+                }}
+                window.WebSocket = WebSocket;
+            }})()",
+            json!(self.origins),
+            js_string(&format!("ws://127.0.0.1:{}/{}/", self.port, self.token)),
+        );
+        let script = webview.add_document_script(&script)?;
+        Ok(WebSocketProxyToken { script })
+    }
+
+    /// Stop redirecting sockets in documents created in `webview` from now
+    /// on. The current document keeps redirecting them until the next
+    /// navigation.
+    pub fn uninstall(&self, webview: &WebView, token: WebSocketProxyToken) -> Result<()> {
+        webview.remove_document_script(token.script)
+    }
+}
+
+/// Returned by `WebSocketProxy::install`.
+pub struct WebSocketProxyToken {
+    script: DocumentScript,
+}
+
+impl Drop for WebSocketProxy {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake up the accept loop.
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+    }
+}
+
+/// The parts of an opening handshake request that are used.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Handshake {
+    target: String,
+    key: Option<String>,
+    protocol: Option<String>,
+}
+
+fn read_handshake(r: &mut impl BufRead) -> io::Result<Handshake> {
+    let mut head = r.take(MAX_HEAD_SIZE);
+    let request_line = read_head_line(&mut head)?;
+    let mut handshake = Handshake {
+        target: request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or("")
+            .to_string(),
+        ..Handshake::default()
+    };
+    loop {
+        let line = read_head_line(&mut head)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(i) = line.find(':') {
+            let (name, value) = (line[..i].trim(), line[i + 1..].trim());
+            if name.eq_ignore_ascii_case("Sec-WebSocket-Key") {
+                handshake.key = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("Sec-WebSocket-Protocol") {
+                handshake.protocol = value.split(',').next().map(|p| p.trim().to_string());
+            }
+        }
+    }
+    Ok(handshake)
+}
+
+/// The original URL in `/{token}/{url}`, if the token is right.
+fn proxied_url(target: &str, token: &str) -> Option<String> {
+    let target = target.strip_prefix('/')?;
+    let i = target.find('/')?;
+    if token_eq(&target[..i], token) {
+        Some(percent_decode(&target[i + 1..]))
+    } else {
+        None
+    }
+}
+
+/// Perform the opening handshake. Returns the original URL and the socket.
+fn accept(mut stream: TcpStream, token: &str) -> io::Result<(String, WebSocket)> {
+    // Only while waiting for the handshake, messages may be far apart.
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let handshake = read_handshake(&mut BufReader::new(&stream))?;
+    stream.set_read_timeout(None)?;
+
+    let protocol = handshake.protocol;
+    let (key, url) = match (handshake.key, proxied_url(&handshake.target, token)) {
+        (Some(key), Some(url)) => (key, url),
+        _ => {
+            stream.write_all(b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n")?;
+            return Err(invalid_data("invalid WebSocket request"));
+        }
+    };
+
+    let mut response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n",
+        accept_key(&key)
+    );
+    if let Some(protocol) = protocol {
+        response.push_str(&format!("Sec-WebSocket-Protocol: {}\r\n", protocol));
+    }
+    response.push_str("\r\n");
+    stream.write_all(response.as_bytes())?;
+    Ok((url, WebSocket { stream }))
+}
+
+/// The `Sec-WebSocket-Accept` value for `key`.
+fn accept_key(key: &str) -> String {
+    let mut input = key.as_bytes().to_vec();
+    input.extend_from_slice(b"258EAFA5-E914-47DA-95CA-C5AB0DC85B11");
+    base64::encode(&sha1(&input))
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            decoded.push(u8::from_str_radix(&s[i + 1..i + 3], 16).unwrap());
+            i += 3;
+            continue;
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+struct Frame {
+    fin: bool,
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+/// Read a masked frame, as sent by clients.
+fn read_frame(r: &mut impl Read) -> io::Result<Frame> {
+    let mut header = [0u8; 2];
+    r.read_exact(&mut header)?;
+    let fin = header[0] & 0x80 != 0;
+    let opcode = header[0] & 0x0f;
+    if header[1] & 0x80 == 0 {
+        return Err(invalid_data("unmasked client frame"));
+    }
+    let len = match header[1] & 0x7f {
+        126 => {
+            let mut len = [0u8; 2];
+            r.read_exact(&mut len)?;
+            u64::from(u16::from_be_bytes(len))
+        }
+        127 => {
+            let mut len = [0u8; 8];
+            r.read_exact(&mut len)?;
+            u64::from_be_bytes(len)
+        }
+        len => u64::from(len),
+    };
+    if len > MAX_MESSAGE_SIZE {
+        return Err(invalid_data("message too large"));
+    }
+    let mut mask = [0u8; 4];
+    r.read_exact(&mut mask)?;
+    let mut payload = vec![0u8; len as usize];
+    r.read_exact(&mut payload)?;
+    for (i, b) in payload.iter_mut().enumerate() {
+        *b ^= mask[i % 4];
+    }
+    Ok(Frame {
+        fin,
+        opcode,
+        payload,
+    })
+}
+
+/// Write an unmasked frame, as sent by servers.
+fn write_frame(w: &mut impl Write, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len if len < 126 => frame.push(len as u8),
+        len if len <= 0xffff => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    w.write_all(&frame)
+}
+
+/// SHA-1, only used for the handshake.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for i in 0..16 {
+            w[i] = u32::from_be_bytes([
+                chunk[i * 4],
+                chunk[i * 4 + 1],
+                chunk[i * 4 + 2],
+                chunk[i * 4 + 3],
+            ]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, w) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*w);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (h, v) in h.iter_mut().zip(&[a, b, c, d, e]) {
+            *h = h.wrapping_add(*v);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (i, v) in h.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&v.to_be_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key() {
+        // From RFC 6455.
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn test_read_handshake() {
+        let mut data: &[u8] = b"GET /t/wss%3A%2F%2Fa.com HTTP/1.1\r\n\
+            Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+            Sec-WebSocket-Protocol: chat, superchat\r\n\r\n";
+        assert_eq!(
+            read_handshake(&mut data).unwrap(),
+            Handshake {
+                target: "/t/wss%3A%2F%2Fa.com".into(),
+                key: Some("dGhlIHNhbXBsZSBub25jZQ==".into()),
+                protocol: Some("chat".into()),
+            }
+        );
+
+        let mut data = b"GET / HTTP/1.1\r\nX: ".to_vec();
+        data.extend(vec![b'a'; MAX_HEAD_SIZE as usize]);
+        let e = read_handshake(&mut &data[..]).err().unwrap();
+        assert_eq!(e.to_string(), "request head too large");
+    }
+
+    #[test]
+    fn test_proxied_url() {
+        assert_eq!(
+            proxied_url("/abc/wss%3A%2F%2Fa.com%2Fs", "abc"),
+            Some("wss://a.com/s".into())
+        );
+        assert_eq!(proxied_url("/abd/wss%3A%2F%2Fa.com", "abc"), None);
+        assert_eq!(proxied_url("/abc", "abc"), None);
+        assert_eq!(proxied_url("abc/x", "abc"), None);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(
+            percent_decode("wss%3A%2F%2Fapi.example.com%2Fsocket"),
+            "wss://api.example.com/socket"
+        );
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    #[test]
+    fn test_read_frame() {
+        // A masked "Hello" text frame, from RFC 6455.
+        let data = [
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58,
+        ];
+        let frame = read_frame(&mut &data[..]).unwrap();
+        assert!(frame.fin);
+        assert_eq!(frame.opcode, OPCODE_TEXT);
+        assert_eq!(frame.payload, b"Hello");
+    }
+
+    #[test]
+    fn test_write_frame() {
+        let mut data = Vec::new();
+        write_frame(&mut data, OPCODE_TEXT, b"Hello").unwrap();
+        assert_eq!(data, [0x81, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f]);
+
+        let mut data = Vec::new();
+        write_frame(&mut data, OPCODE_BINARY, &[0; 256]).unwrap();
+        assert_eq!(&data[..4], [0x82, 0x7e, 0x01, 0x00]);
+    }
+}
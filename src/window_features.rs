@@ -0,0 +1,213 @@
+//! The features a page requested for a popup window.
+//!
+//! `NewWindowRequestedEventArgs::get_window_features` returns them from
+//! `ICoreWebView2WindowFeatures`, with the `sdk-1.0.488` feature. Runtimes
+//! before 1.0 don't have it, there the features have to be parsed from the
+//! `features` argument of `window.open` with `WindowFeatures::parse`, e.g.
+//! from a script that wraps it and posts the arguments with
+//! `window.chrome.webview.postMessage`.
+
+#[cfg(feature = "sdk-1.0.488")]
+use crate::raw::*;
+#[cfg(feature = "sdk-1.0.488")]
+use crate::{check_hresult, query_interface, NewWindowRequestedEventArgs, Result};
+#[cfg(feature = "sdk-1.0.488")]
+use com::ComRc;
+#[cfg(feature = "sdk-1.0.488")]
+use std::ptr;
+
+/// Position, size and decorations of a popup window.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WindowFeatures {
+    /// Left edge of the window, in screen coordinates.
+    pub left: Option<i32>,
+    /// Top edge of the window, in screen coordinates.
+    pub top: Option<i32>,
+    /// Width of the content area.
+    pub width: Option<u32>,
+    /// Height of the content area.
+    pub height: Option<u32>,
+    pub should_display_menu_bar: bool,
+    pub should_display_status: bool,
+    pub should_display_toolbar: bool,
+    pub should_display_scroll_bars: bool,
+}
+
+impl Default for WindowFeatures {
+    /// The features of a window opened without a features string: no
+    /// position or size, all decorations.
+    fn default() -> Self {
+        Self {
+            left: None,
+            top: None,
+            width: None,
+            height: None,
+            should_display_menu_bar: true,
+            should_display_status: true,
+            should_display_toolbar: true,
+            should_display_scroll_bars: true,
+        }
+    }
+}
+
+impl WindowFeatures {
+    /// Parse a `window.open` features string like
+    /// `"left=10,top=10,width=400,height=300,scrollbars"`.
+    ///
+    /// Decorations that are not listed are hidden, unless `features` is
+    /// empty. Unknown features and invalid numbers are ignored, as browsers
+    /// do.
+    pub fn parse(features: &str) -> Self {
+        let pairs = tokenize(features);
+        if pairs.is_empty() {
+            return Self::default();
+        }
+        let mut result = Self {
+            should_display_menu_bar: false,
+            should_display_status: false,
+            should_display_toolbar: false,
+            should_display_scroll_bars: false,
+            ..Self::default()
+        };
+        for (name, value) in pairs {
+            match name.as_str() {
+                "left" | "screenx" => result.left = parse_int(&value),
+                "top" | "screeny" => result.top = parse_int(&value),
+                "width" | "innerwidth" => result.width = parse_size(&value),
+                "height" | "innerheight" => result.height = parse_size(&value),
+                "menubar" => result.should_display_menu_bar = parse_bool(&value),
+                "status" => result.should_display_status = parse_bool(&value),
+                "toolbar" | "location" => result.should_display_toolbar = parse_bool(&value),
+                "scrollbars" => result.should_display_scroll_bars = parse_bool(&value),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+#[cfg(feature = "sdk-1.0.488")]
+impl NewWindowRequestedEventArgs {
+    /// The features passed to `window.open`. Fails with `E_NOINTERFACE` on
+    /// runtimes before 1.0.
+    pub fn get_window_features(&self) -> Result<WindowFeatures> {
+        let args: ComRc<dyn ICoreWebView2NewWindowRequestedEventArgs_1> =
+            query_interface(&self.inner)?;
+        let mut features: *mut *mut ICoreWebView2WindowFeaturesVTable = ptr::null_mut();
+        check_hresult(unsafe { args.get_window_features(&mut features) })?;
+        let features: ComRc<dyn ICoreWebView2WindowFeatures> = unsafe { ComRc::from_raw(features) };
+
+        let (mut has_position, mut has_size) = (0, 0);
+        let (mut left, mut top, mut width, mut height) = (0, 0, 0, 0);
+        let (mut menu_bar, mut status, mut toolbar, mut scroll_bars) = (0, 0, 0, 0);
+        unsafe {
+            check_hresult(features.get_has_position(&mut has_position))?;
+            check_hresult(features.get_has_size(&mut has_size))?;
+            check_hresult(features.get_left(&mut left))?;
+            check_hresult(features.get_top(&mut top))?;
+            check_hresult(features.get_width(&mut width))?;
+            check_hresult(features.get_height(&mut height))?;
+            check_hresult(features.get_should_display_menu_bar(&mut menu_bar))?;
+            check_hresult(features.get_should_display_status(&mut status))?;
+            check_hresult(features.get_should_display_toolbar(&mut toolbar))?;
+            check_hresult(features.get_should_display_scroll_bars(&mut scroll_bars))?;
+        }
+        Ok(WindowFeatures {
+            // Screen coordinates left of or above the primary monitor are
+            // negative.
+            left: Some(left as i32).filter(|_| has_position != 0),
+            top: Some(top as i32).filter(|_| has_position != 0),
+            width: Some(width).filter(|_| has_size != 0),
+            height: Some(height).filter(|_| has_size != 0),
+            should_display_menu_bar: menu_bar != 0,
+            should_display_status: status != 0,
+            should_display_toolbar: toolbar != 0,
+            should_display_scroll_bars: scroll_bars != 0,
+        })
+    }
+}
+
+/// Split into lowercase `name=value` pairs. Separators are commas and white
+/// space, and there can be white space around `=`.
+fn tokenize(features: &str) -> Vec<(String, String)> {
+    let is_separator = |c: char| c == ',' || c == '=' || c.is_whitespace();
+    let mut pairs = Vec::new();
+    let mut rest = features;
+    loop {
+        rest = rest.trim_start_matches(is_separator);
+        if rest.is_empty() {
+            return pairs;
+        }
+        let end = rest.find(is_separator).unwrap_or_else(|| rest.len());
+        let name = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start_matches(char::is_whitespace);
+        let mut value = String::new();
+        if rest.starts_with('=') {
+            rest = rest[1..].trim_start_matches(char::is_whitespace);
+            let end = rest
+                .find(|c: char| c == ',' || c.is_whitespace())
+                .unwrap_or_else(|| rest.len());
+            value = rest[..end].to_ascii_lowercase();
+            rest = &rest[end..];
+        }
+        pairs.push((name, value));
+    }
+}
+
+/// Leading optional sign and digits, ignoring the rest like `parseInt`.
+fn parse_int(value: &str) -> Option<i32> {
+    let digits = value
+        .char_indices()
+        .take_while(|&(i, c)| c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+')))
+        .count();
+    value[..digits].parse().ok()
+}
+
+fn parse_size(value: &str) -> Option<u32> {
+    parse_int(value).filter(|&v| v > 0).map(|v| v as u32)
+}
+
+/// A feature without value is on, as are `yes` and non-zero numbers.
+fn parse_bool(value: &str) -> bool {
+    match value {
+        "" | "yes" | "true" => true,
+        _ => parse_int(value).map_or(false, |v| v != 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(WindowFeatures::parse(""), WindowFeatures::default());
+        let features =
+            WindowFeatures::parse("left=10, top = -5,width=400,height=300px,scrollbars,status=no");
+        assert_eq!(
+            features,
+            WindowFeatures {
+                left: Some(10),
+                top: Some(-5),
+                width: Some(400),
+                height: Some(300),
+                should_display_menu_bar: false,
+                should_display_status: false,
+                should_display_toolbar: false,
+                should_display_scroll_bars: true,
+            }
+        );
+        let features = WindowFeatures::parse("WIDTH=0 menubar=1 toolbar=yes");
+        assert_eq!(features.width, None);
+        assert!(features.should_display_menu_bar);
+        assert!(features.should_display_toolbar);
+    }
+
+    #[test]
+    fn test_parse_int() {
+        assert_eq!(parse_int("42"), Some(42));
+        assert_eq!(parse_int("-42px"), Some(-42));
+        assert_eq!(parse_int("px"), None);
+        assert_eq!(parse_int(""), None);
+    }
+}
@@ -0,0 +1,27 @@
+//! Creating hosts in windows of other crates, e.g. winit, with the
+//! `raw-window-handle` feature.
+
+use crate::{Environment, Error, Host, Result};
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::E_INVALIDARG;
+
+impl Environment {
+    /// `create_host` in the HWND of `window`. Fails with `E_INVALIDARG` if it
+    /// isn't a Win32 window or its handle isn't available.
+    pub fn create_host_from_window_handle(
+        &self,
+        window: &impl HasWindowHandle,
+        completed: impl FnOnce(Result<Host>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let handle = window
+            .window_handle()
+            .map_err(|_| Error::new(E_INVALIDARG))?;
+        match handle.as_raw() {
+            RawWindowHandle::Win32(handle) => {
+                self.create_host(handle.hwnd.get() as HWND, completed)
+            }
+            _ => Err(Error::new(E_INVALIDARG)),
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! Named workspaces with isolated storage, for apps with several accounts.
+//!
+//! A workspace is a user data folder under a common root, so cookies, local
+//! storage, the cache and saved passwords of one account never reach another.
+//! This SDK has no profiles, so each workspace has its own environment and
+//! browser processes. The environment is created when the first webview of the
+//! workspace is and shared by the later ones:
+//!
+//! ```no_run
+//! # fn f(parent: winapi::shared::windef::HWND) -> webview2::Result<()> {
+//! use webview2::workspace::Workspaces;
+//!
+//! let workspaces = Workspaces::new(std::env::temp_dir().join("my-app"));
+//! workspaces.create_host("alice@example.com", parent, |host| {
+//!     host?.get_webview()?.navigate("https://example.com")
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Names are case insensitive like the file system, so `Work` and `work` are
+//! the same workspace. They are escaped to make folder names, and any name
+//! that isn't empty can be used.
+
+use crate::{Environment, EnvironmentBuilder, Error, Host, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::{ERROR_BUSY, E_INVALIDARG, HRESULT_FROM_WIN32};
+
+/// Device names Windows reserves in every folder, also with an extension.
+const RESERVED_NAMES: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Longer folder names are shortened, so that the paths of the files in the
+/// user data folder stay within `MAX_PATH`.
+const MAX_FOLDER_NAME_LEN: usize = 64;
+
+/// Length of the escaped name kept in a shortened folder name.
+const SHORTENED_PREFIX_LEN: usize = 40;
+
+type Completed = Box<dyn FnOnce(Result<Environment>) -> Result<()>>;
+
+enum State {
+    /// Waiting for the environment, with the callbacks to call then.
+    /// `closed` is set by `Workspaces::close` meanwhile, the environment is
+    /// then only handed to the callbacks.
+    Creating {
+        waiting: Vec<Completed>,
+        closed: bool,
+    },
+    Ready(Environment),
+}
+
+/// Workspaces in a root folder, with the environments created so far. Clones
+/// share the environments.
+#[derive(Clone)]
+pub struct Workspaces {
+    root: PathBuf,
+    additional_browser_arguments: Option<String>,
+    environments: Rc<RefCell<HashMap<String, State>>>,
+}
+
+impl Workspaces {
+    /// The root folder is created when the first workspace is.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            additional_browser_arguments: None,
+            environments: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Arguments for the browser processes of all workspaces.
+    pub fn with_additional_browser_arguments(self, additional_browser_arguments: &str) -> Self {
+        Self {
+            additional_browser_arguments: Some(additional_browser_arguments.into()),
+            ..self
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The user data folder of the workspace `name`.
+    pub fn user_data_folder(&self, name: &str) -> Result<PathBuf> {
+        Ok(self.root.join(folder_name(name)?))
+    }
+
+    /// The workspaces with a user data folder, as folder names. These are the
+    /// names lowercased, with other characters than letters, digits and `-`
+    /// escaped.
+    pub fn folders(&self) -> Result<Vec<String>> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut folders = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                if let Some(name) = entry.file_name().to_str() {
+                    folders.push(name.to_owned());
+                }
+            }
+        }
+        folders.sort();
+        Ok(folders)
+    }
+
+    /// Get the environment of the workspace `name`, creating it on first use.
+    ///
+    /// If it fails, the next call tries again.
+    pub fn environment(
+        &self,
+        name: &str,
+        completed: impl FnOnce(Result<Environment>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        let key = folder_name(name)?;
+        {
+            let mut environments = self.environments.borrow_mut();
+            match environments.get_mut(&key) {
+                Some(State::Ready(env)) => {
+                    let env = env.clone();
+                    drop(environments);
+                    return completed(Ok(env));
+                }
+                Some(State::Creating { waiting, closed }) => {
+                    // Used again after all.
+                    *closed = false;
+                    waiting.push(Box::new(completed));
+                    return Ok(());
+                }
+                None => {
+                    let state = State::Creating {
+                        waiting: vec![Box::new(completed)],
+                        closed: false,
+                    };
+                    environments.insert(key.clone(), state);
+                }
+            }
+        }
+
+        let user_data_folder = self.root.join(&key);
+        let created = {
+            let environments = self.environments.clone();
+            let key = key.clone();
+            move |env: Result<Environment>| {
+                let waiting = {
+                    let mut environments = environments.borrow_mut();
+                    let (waiting, closed) = match environments.remove(&key) {
+                        Some(State::Creating { waiting, closed }) => (waiting, closed),
+                        _ => (Vec::new(), true),
+                    };
+                    if let (Ok(env), false) = (&env, closed) {
+                        environments.insert(key, State::Ready(env.clone()));
+                    }
+                    waiting
+                };
+                let mut result = Ok(());
+                for completed in waiting {
+                    let env = match &env {
+                        Ok(env) => Ok(env.clone()),
+                        Err(e) => Err(Error::new(e.hresult())),
+                    };
+                    let r = completed(env);
+                    result = result.and(r);
+                }
+                result
+            }
+        };
+        let mut builder = EnvironmentBuilder::new().with_user_data_folder(&user_data_folder);
+        if let Some(arguments) = &self.additional_browser_arguments {
+            builder = builder.with_additional_browser_arguments(arguments);
+        }
+        let result = builder.build(created);
+        if result.is_err() {
+            self.environments.borrow_mut().remove(&key);
+        }
+        result
+    }
+
+    /// Create a webview in the workspace `name`.
+    pub fn create_host(
+        &self,
+        name: &str,
+        parent_window: HWND,
+        completed: impl FnOnce(Result<Host>) -> Result<()> + 'static,
+    ) -> Result<()> {
+        self.environment(name, move |env| match env {
+            Ok(env) => env.create_host(parent_window, completed),
+            Err(e) => completed(Err(e)),
+        })
+    }
+
+    /// Forget the environment of the workspace `name`. Its browser processes
+    /// exit once its webviews are closed, and the next webview starts a new
+    /// environment.
+    ///
+    /// While the environment is being created, the webviews already asked
+    /// for still get it, and it is forgotten right after, unless the
+    /// workspace is used again before.
+    pub fn close(&self, name: &str) -> Result<()> {
+        let key = folder_name(name)?;
+        let mut environments = self.environments.borrow_mut();
+        match environments.get_mut(&key) {
+            Some(State::Ready(_)) => {
+                environments.remove(&key);
+            }
+            Some(State::Creating { closed, .. }) => *closed = true,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Delete the user data folder of the workspace `name`, i.e. sign out of
+    /// everything and clear all its data.
+    ///
+    /// Fails with `ERROR_BUSY` while it has an environment, close it first.
+    /// The browser processes may also keep files open for a moment after the
+    /// last webview is closed, in which case this fails with the error of the
+    /// file system and can be retried.
+    pub fn remove(&self, name: &str) -> Result<()> {
+        let key = folder_name(name)?;
+        if self.environments.borrow().contains_key(&key) {
+            return Err(Error::new(HRESULT_FROM_WIN32(ERROR_BUSY)));
+        }
+        match fs::remove_dir_all(self.root.join(&key)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The folder of a workspace: the name lowercased, with characters other
+/// than ASCII letters, digits and `-` escaped as their code point in hex
+/// between underscores, e.g. `alice_40_example_2e_com`. Fails with
+/// `E_INVALIDARG` for an empty name.
+///
+/// Escapes never use dots, which Windows strips from the end of file names,
+/// so folder names map back to a single name. The last character of a
+/// reserved device name is escaped as well, e.g. `co_6e_` for `con`. Names
+/// longer than `MAX_FOLDER_NAME_LEN` once escaped are cut and end with `~`
+/// and a hash of the whole name, `~` being escaped otherwise.
+fn folder_name(name: &str) -> Result<String> {
+    if name.is_empty() {
+        return Err(Error::new(E_INVALIDARG));
+    }
+    let mut folder = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() || c == '-' {
+            folder.push(c);
+        } else {
+            folder.push_str(&format!("_{:x}_", c as u32));
+        }
+    }
+    // `con.txt` is reserved too, but dots are always escaped.
+    if RESERVED_NAMES.contains(&folder.as_str()) {
+        let last = folder.pop().unwrap();
+        folder.push_str(&format!("_{:x}_", last as u32));
+    }
+    if folder.len() > MAX_FOLDER_NAME_LEN {
+        let hash = fnv1a(folder.as_bytes());
+        // Everything is ASCII, so any index is a char boundary.
+        folder.truncate(SHORTENED_PREFIX_LEN);
+        folder.push_str(&format!("~{:016x}", hash));
+    }
+    Ok(folder)
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust
+/// versions, as folder names must be.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_name() {
+        assert_eq!(
+            folder_name("alice@example.com").unwrap(),
+            "alice_40_example_2e_com"
+        );
+        assert_eq!(folder_name("Work").unwrap(), folder_name("work").unwrap());
+        assert_eq!(folder_name("../x").unwrap(), "_2e__2e__2f_x");
+        assert_eq!(folder_name("my_account-2").unwrap(), "my_5f_account-2");
+        assert!(folder_name("").is_err());
+
+        assert_eq!(folder_name("CON").unwrap(), "co_6e_");
+        assert_eq!(folder_name("nul.txt").unwrap(), "nul_2e_txt");
+        assert_eq!(folder_name("lpt9").unwrap(), "lpt_39_");
+        assert_eq!(folder_name("console").unwrap(), "console");
+        assert_eq!(folder_name("com10").unwrap(), "com10");
+
+        let long = "a".repeat(100);
+        let folder = folder_name(&long).unwrap();
+        assert_eq!(folder.len(), SHORTENED_PREFIX_LEN + 17);
+        assert!(folder.starts_with(&long[..SHORTENED_PREFIX_LEN]));
+        assert_ne!(folder, folder_name(&"a".repeat(101)).unwrap());
+        assert_eq!(folder, folder_name(&long.to_uppercase()).unwrap());
+        assert_eq!(folder_name(&"a".repeat(64)).unwrap(), "a".repeat(64));
+    }
+
+    #[test]
+    fn test_folder_name_round_trip() {
+        // Windows would strip a trailing dot, making `bob.` and `bob` share
+        // a folder.
+        assert_ne!(folder_name("bob.").unwrap(), folder_name("bob").unwrap());
+        assert_eq!(folder_name("bob.").unwrap(), "bob_2e_");
+
+        let workspaces = Workspaces::new(
+            std::env::temp_dir().join(format!("webview2-workspaces-{}", std::process::id())),
+        );
+        for name in &["bob.", "bob", "alice@example.com", "con"] {
+            fs::create_dir_all(workspaces.user_data_folder(name).unwrap()).unwrap();
+        }
+        let mut expected: Vec<String> = ["bob.", "bob", "alice@example.com", "con"]
+            .iter()
+            .map(|name| folder_name(name).unwrap())
+            .collect();
+        expected.sort();
+        let folders = workspaces.folders();
+        fs::remove_dir_all(workspaces.root()).unwrap();
+        assert_eq!(folders.unwrap(), expected);
+    }
+}
@@ -0,0 +1,46 @@
+//! Raw bindings to
+//! [WebView2](https://docs.microsoft.com/en-us/microsoft-edge/hosting/webview2),
+//! generated from `WebView2.idl` of the SDK by `idl2rs`, with the
+//! declarations of later SDKs in `later.idl` appended.
+//!
+//! Every interface of the SDK is here, as a `com` interface trait. The
+//! `webview2` crate wraps them and re-exports this crate as `webview2::raw`,
+//! so use that rather than depending on this crate directly: its `as_raw`
+//! methods give the objects behind the wrappers, for calling methods that are
+//! not wrapped yet.
+//!
+//! With the `serde` feature, the enums and the structs without pointers
+//! implement `Serialize` and `Deserialize`, e.g. to send a
+//! `CORE_WEBVIEW2_WEB_ERROR_STATUS` over IPC.
+//!
+//! Interfaces that came with a later SDK than the runtime an app targets can
+//! be left out: those listed in `sdk-versions.txt` are only compiled with the
+//! `sdk-VERSION` feature of their version, e.g. `sdk-1.0.774`, so calling
+//! them without it doesn't compile. The `webview2` crate has the same
+//! features.
+//!
+//! With the `windows-sys` feature, `HWND`, `HRESULT`, `PCWSTR`, `RECT` etc.
+//! in the signatures are the types of the `windows-sys` crate rather than of
+//! `winapi`, for apps built on the `windows` crates. `GUID` and the
+//! `IStream` declarations still come from `winapi`, which `com` uses.
+//!
+//! To regenerate `sys.rs`, and `sys_windows_sys.rs` with
+//! `--types types/windows-sys.txt` added:
+//!
+//! ```text
+//! cd idl2rs
+//! cat ../Microsoft.Web.WebView2.0.9.430/WebView2.idl ../webview2-sys/later.idl |
+//!     cargo run -- --serde --sdk-versions ../webview2-sys/sdk-versions.txt \
+//!     > ../webview2-sys/src/sys.rs
+//! rustfmt --edition 2018 ../webview2-sys/src/sys.rs
+//! ```
+
+#![cfg(windows)]
+
+#[cfg(not(feature = "windows-sys"))]
+mod sys;
+#[cfg(feature = "windows-sys")]
+#[path = "sys_windows_sys.rs"]
+mod sys;
+
+pub use sys::*;
@@ -0,0 +1,8826 @@
+#![allow(
+    clippy::missing_safety_doc,
+    deprecated,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    // `--types` can leave parts of the prelude unused.
+    unused_imports
+)]
+
+// Generated by idl2rs.
+
+use com::{
+    com_interface,
+    interfaces::{iunknown::IUnknownVTable, IUnknown},
+};
+use std::ffi::c_void;
+use winapi::shared::basetsd::*;
+use winapi::shared::guiddef::GUID;
+use winapi::shared::minwindef::{ULONG, *};
+use winapi::shared::ntdef::*;
+use winapi::shared::windef::*;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::objidlbase::STATSTG;
+
+/// Returned when an event handler is added, to remove it with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventRegistrationToken {
+    pub value: i64,
+}
+
+impl From<i64> for EventRegistrationToken {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<EventRegistrationToken> for i64 {
+    fn from(token: EventRegistrationToken) -> Self {
+        token.value
+    }
+}
+
+#[com_interface("0c733a30-2a1c-11ce-ade5-00aa0044773d")]
+pub trait ISequentialStream: IUnknown {
+    unsafe fn read(&self, pv: *mut c_void, cb: ULONG, pcbRead: *mut ULONG) -> HRESULT;
+    unsafe fn write(&self, pv: *const c_void, cb: ULONG, pcbWritten: *mut ULONG) -> HRESULT;
+}
+
+#[com_interface("0000000c-0000-0000-C000-000000000046")]
+pub trait IStream: ISequentialStream {
+    unsafe fn seek(
+        &self,
+        dlibMove: LARGE_INTEGER,
+        dwOrigin: DWORD,
+        plibNewPosition: *mut ULARGE_INTEGER,
+    ) -> HRESULT;
+    unsafe fn set_size(&self, libNewSize: ULARGE_INTEGER) -> HRESULT;
+    unsafe fn copy_to(
+        &self,
+        pstm: *mut *mut IStreamVTable,
+        cb: ULARGE_INTEGER,
+        pcbRead: *mut ULARGE_INTEGER,
+        pcbWritten: *mut ULARGE_INTEGER,
+    ) -> HRESULT;
+    unsafe fn commit(&self, grfCommitFlags: DWORD) -> HRESULT;
+    unsafe fn revert(&self) -> HRESULT;
+    unsafe fn lock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD,
+    ) -> HRESULT;
+    unsafe fn unlock_region(
+        &self,
+        libOffset: ULARGE_INTEGER,
+        cb: ULARGE_INTEGER,
+        dwLockType: DWORD,
+    ) -> HRESULT;
+    unsafe fn stat(&self, pstatstg: *mut STATSTG, grfStatFlag: DWORD) -> HRESULT;
+    unsafe fn clone(&self, ppstm: *mut *mut *mut IStreamVTable) -> HRESULT;
+}
+
+/// DLL export to create a WebView2 environment with a custom version of Edge,
+/// user data directory and/or additional browser switches.
+///
+/// browserExecutableFolder is the relative path to the folder that
+/// contains the embedded Edge. The embedded Edge can be obtained by
+/// copying the version named folder of an installed Edge, like
+/// 73.0.52.0 sub folder of an installed 73.0.52.0 Edge. The folder
+/// should have msedge.exe, msedge.dll, etc.
+/// Use null or empty string for browserExecutableFolder to create
+/// WebView using Edge installed on the machine, in which case the
+/// API will try to find a compatible version of Edge installed on the
+/// machine according to the channel preference trying to find first
+/// per user install and then per machine install.
+///
+/// The default channel search order is stable, beta, dev, and canary.
+/// When there is an override WEBVIEW2_RELEASE_CHANNEL_PREFERENCE environment
+/// variable or applicable releaseChannelPreference registry value
+/// with the value of 1, the channel search order is reversed.
+///
+/// userDataFolder can be
+/// specified to change the default user data folder location for
+/// WebView2. The path can be an absolute file path or a relative file path
+/// that is interpreted as relative to the current process's executable.
+/// Otherwise, for UWP apps, the default user data folder will be
+/// the app data folder for the package; for non-UWP apps,
+/// the default user data folder `{Executable File Name}.WebView2`
+/// will be created in the same directory next to the app executable.
+/// WebView2 creation can fail if the executable is running in a directory
+/// that the process doesn't have permission to create a new folder in.
+/// The app is responsible to clean up its user data folder
+/// when it is done.
+///
+/// additionalBrowserArguments can be specified to change the behavior of the
+/// WebView. These will be passed to the browser process as part of
+/// the command line. See
+/// [Run Chromium with Flags](https://aka.ms/RunChromiumWithFlags)
+/// for more information about command line switches to browser
+/// process. If the app is launched with a command line switch
+/// `--edge-webview-switches=xxx` the value of that switch (xxx in
+/// the above example) will also be appended to the browser
+/// process command line. Certain switches like `--user-data-dir` are
+/// internal and important to WebView. Those switches will be
+/// ignored even if specified. If the same switches are specified
+/// multiple times, the last one wins. Note that this also applies
+/// to switches like `--enable-features`. There is no attempt to
+/// merge the different values of the same switch. App process's
+/// command line `--edge-webview-switches` value are processed after
+/// the additionalBrowserArguments parameter is processed.
+/// Also note that as a browser process might be shared among
+/// WebViews, the switches are not guaranteed to be applied except
+/// for the first WebView that starts the browser process.
+/// If parsing failed for the specified switches, they will be
+/// ignored. `nullptr` will run browser process with no flags.
+///
+/// environment_created_handler is the handler result to the async operation
+/// which will contain the WebView2Environment that got created.
+///
+/// The browserExecutableFolder, userDataFolder and additionalBrowserArguments
+/// members of the environmentParams may be overridden by
+/// values either specified in environment variables or in the registry.
+///
+/// When creating a WebView2Environment the following environment variables
+/// are checked:
+///
+/// ```
+/// WEBVIEW2_BROWSER_EXECUTABLE_FOLDER
+/// WEBVIEW2_USER_DATA_FOLDER
+/// WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS
+/// WEBVIEW2_RELEASE_CHANNEL_PREFERENCE
+/// ```
+///
+/// If an override environment variable is found then we use the
+/// browserExecutableFolder, userDataFolder and additionalBrowserArguments
+/// values as replacements for the corresponding values in
+/// CreateCoreWebView2EnvironmentWithDetails parameters.
+///
+/// While not strictly overrides, there exists additional environment variables
+/// that can be set:
+///
+/// ```
+/// WEBVIEW2_WAIT_FOR_SCRIPT_DEBUGGER
+/// ```
+///
+/// When found with a non-empty value, this indicates that the WebView is being
+/// launched under a script debugger. In this case, the WebView will issue a
+/// `Page.waitForDebugger` CDP command that will cause script execution inside the
+/// WebView to pause on launch, until a debugger issues a corresponding
+/// `Runtime.runIfWaitingForDebugger` CDP command to resume execution.
+/// Note: There is no registry key equivalent of this environment variable.
+///
+/// ```
+/// WEBVIEW2_PIPE_FOR_SCRIPT_DEBUGGER
+/// ```
+///
+/// When found with a non-empty value, this indicates that the WebView is being
+/// launched under a script debugger that also supports host applications that
+/// use multiple WebViews. The value is used as the identifier for a named pipe
+/// that will be opened and written to when a new WebView is created by the host
+/// application. The payload will match that of the remote-debugging-port JSON
+/// target and can be used by the external debugger to attach to a specific
+/// WebView instance.
+/// The format of the pipe created by the debugger should be:
+/// `\\.\pipe\WebView2\Debugger\{app_name}\{pipe_name}`
+/// where:
+///
+/// - `{app_name}` is the host application exe filename, e.g. WebView2Example.exe
+/// - `{pipe_name}` is the value set for WEBVIEW2_PIPE_FOR_SCRIPT_DEBUGGER.
+///
+/// To enable debugging of the targets identified by the JSON you will also need
+/// to set the WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS environment variable to
+/// send `--remote-debugging-port={port_num}`
+/// where:
+///
+/// - `{port_num}` is the port on which the CDP server will bind.
+///
+/// Be aware that setting both the WEBVIEW2_PIPE_FOR_SCRIPT_DEBUGGER and
+/// WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS environment variables will cause the
+/// WebViews hosted in your application and their contents to be exposed to
+/// 3rd party applications such as debuggers.
+///
+/// Note: There is no registry key equivalent of this environment variable.
+///
+/// If none of those environment variables exist, then the registry is examined next.
+/// The following registry keys are checked:
+///
+/// ```
+/// [{Root}\Software\Policies\Microsoft\EmbeddedBrowserWebView\LoaderOverride\{AppId}]
+/// "releaseChannelPreference"=dword:00000000
+/// "browserExecutableFolder"=""
+/// "userDataFolder"=""
+/// "additionalBrowserArguments"=""
+/// ```
+///
+/// In the unlikely scenario where some instances of WebView are open during
+/// a browser update we could end up blocking the deletion of old Edge browsers.
+/// To avoid running out of disk space a new WebView creation will fail
+/// with the next error if it detects that there are many old versions present.
+///
+/// ```
+/// ERROR_DISK_FULL
+/// ```
+///
+/// The default maximum number of Edge versions allowed is 20.
+///
+/// The maximum number of old Edge versions allowed can be overwritten with the value
+/// of the following environment variable.
+///
+/// ```
+/// WEBVIEW2_MAX_INSTANCES
+/// ```
+///
+/// If the Webview depends on an installed Edge and it is uninstalled
+/// any subsequent creation will fail with the next error
+///
+/// ```
+/// ERROR_PRODUCT_UNINSTALLED
+/// ```
+///
+/// First we check with Root as HKLM and then HKCU.
+/// AppId is first set to the Application User Model ID of the caller's process,
+/// then if there's no corresponding registry key the AppId is
+/// set to the executable name of the caller's process, or if that
+/// isn't a registry key then '*'. If an override registry key is found then we
+/// use the browserExecutableFolder, userDataFolder and additionalBrowserArguments
+/// registry values as replacements for the corresponding values in
+/// CreateCoreWebView2EnvironmentWithDetails parameters. If any of those registry values
+/// isn't present, then the parameter passed to CreateCoreWebView2Environment is used.
+pub type FnCreateCoreWebView2EnvironmentWithDetails = unsafe extern "system" fn(browserExecutableFolder: PCWSTR, userDataFolder: PCWSTR, additionalBrowserArguments: PCWSTR, environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable) -> HRESULT;
+
+/// Creates an evergreen WebView2 Environment using the installed Edge version.
+/// This is equivalent to calling CreateCoreWebView2EnvironmentWithDetails with
+/// nullptr for browserExecutableFolder, userDataFolder, additionalBrowserArguments.
+///  See CreateCoreWebView2EnvironmentWithDetails for more details.
+pub type FnCreateCoreWebView2Environment = unsafe extern "system" fn(environment_created_handler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable) -> HRESULT;
+
+/// Get the browser version info including channel name if it is not the stable channel
+/// or the Embedded Edge.
+/// Channel names are beta, dev, and canary.
+/// If an override exists for the browserExecutableFolder or the channel preference,
+/// the override will be used.
+/// If there isn't an override, then the parameter passed to GetCoreWebView2BrowserVersionInfo is used.
+pub type FnGetCoreWebView2BrowserVersionInfo =
+    unsafe extern "system" fn(browserExecutableFolder: PCWSTR, versionInfo: *mut LPWSTR) -> HRESULT;
+
+/// This method is for anyone want to compare version correctly to determine
+/// which version is newer, older or same. It can be used to determine whether
+/// to use webview2 or certain feature base on version.
+/// Sets the value of result to -1, 0 or 1 if version1 is less than, equal or
+/// greater than version2 respectively.
+/// Returns E_INVALIDARG if it fails to parse any of the version strings or any
+/// input parameter is null.
+/// Input can directly use the versionInfo obtained from GetCoreWebView2BrowserVersionInfo,
+/// channel info will be ignored.
+pub type FnCompareBrowserVersions =
+    unsafe extern "system" fn(version1: PCWSTR, version2: PCWSTR, result: *mut i32) -> HRESULT;
+
+/// Creates an evergreen WebView2 Environment using the installed Edge version.
+/// This is the replacement of CreateCoreWebView2EnvironmentWithDetails, with
+/// the options that came with later SDKs: additional browser arguments,
+/// language, target compatible browser version and single sign on.
+/// environmentOptions can be null for the defaults. The environment
+/// variables and registry overrides of
+/// CreateCoreWebView2EnvironmentWithDetails apply the same way.
+pub type FnCreateCoreWebView2EnvironmentWithOptions = unsafe extern "system" fn(browserExecutableFolder: PCWSTR, userDataFolder: PCWSTR, environmentOptions: *mut *mut ICoreWebView2EnvironmentOptionsVTable, environmentCreatedHandler: *mut *mut ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandlerVTable) -> HRESULT;
+/// WebView2 enables you to host web content using the
+/// latest Edge web browser technology.
+///
+/// ## Navigation events
+/// The normal sequence of navigation events is NavigationStarting,
+/// SourceChanged, ContentLoading and then NavigationCompleted.
+///
+/// \dot
+/// digraph NavigationEvents {
+///    node [fontname=Roboto, shape=rectangle]
+///    edge [fontname=Roboto]
+///
+///    NewDocument -> NavigationStarting;
+///    NavigationStarting -> SourceChanged -> ContentLoading [label="New Document"];
+///    ContentLoading -> HistoryChanged;
+///    SameDocument -> SourceChanged;
+///    SourceChanged -> HistoryChanged [label="Same Document"];
+///    HistoryChanged -> NavigationCompleted;
+///    NavigationStarting -> NavigationStarting [label="Redirect"];
+///    NavigationStarting -> NavigationCompleted [label="Failure"];
+/// }
+/// \enddot
+///
+/// Note that this is for navigation events with the same NavigationId event
+/// arg. Navigations events with different NavigationId event args may overlap.
+/// For instance, if you start a navigation wait for its NavigationStarting
+/// event and then start another navigation you'll see the NavigationStarting
+/// for the first navigate followed by the NavigationStarting of the second
+/// navigate, followed by the NavigationCompleted for the first navigation and
+/// then all the rest of the appropriate navigation events for the second
+/// navigation.
+/// In error cases there may or may not be a ContentLoading event depending
+/// on whether the navigation is continued to an error page.
+/// In case of an HTTP redirect, there will be multiple NavigationStarting
+/// events in a row, with ones following the first will have their IsRedirect
+/// flag set.
+///
+/// To monitor or cancel navigations inside subframes in the WebView, use
+/// FrameNavigationStarting.
+///
+/// ## Process model
+/// WebView2 uses the same process model as the Edge web
+/// browser. There is one Edge browser process per specified user data directory
+/// in a user session that will serve any WebView2 calling
+/// process that specifies that user data directory. This means one Edge browser
+/// process may be serving multiple calling processes and one calling
+/// process may be using multiple Edge browser processes.
+///
+/// \dot
+/// digraph ProcessModelNClientsNServers {
+///     node [fontname=Roboto, shape=rectangle];
+///     edge [fontname=Roboto];
+///
+///     Host1 [label="Calling\nprocess 1"];
+///     Host2 [label="Calling\nprocess 2"];
+///     Browser1 [label="Edge processes\ngroup 1"];
+///     Browser2 [label="Edge processes\ngroup 2"];
+///
+///     Host1 -> Browser1;
+///     Host1 -> Browser2;
+///     Host2 -> Browser2;
+/// }
+/// \enddot
+///
+/// Off of a browser process there will be some number of renderer processes.
+/// These are created as
+/// necessary to service potentially multiple frames in different WebViews. The
+/// number of renderer processes varies based on the site isolation browser
+/// feature and the number of distinct disconnected origins rendered in
+/// associated WebViews.
+///
+/// \dot
+/// digraph ProcessModelClientServer {
+///     node [fontname=Roboto, shape=rectangle];
+///     edge [fontname=Roboto];
+///     graph [fontname=Roboto];
+///
+///     Host [label="Calling process"];
+///     subgraph cluster_0 {
+///         labeljust = "l";
+///         label = "Edge processes group";
+///         Browser [label="Edge browser\nprocess"];
+///         Render1 [label="Edge render\nprocess 1"];
+///         Render2 [label="Edge render\nprocess 2"];
+///         RenderN [label="Edge render\nprocess N"];
+///         GPU [label="Edge GPU\nprocess"];
+///     }
+///
+///     Host -> Browser;
+///     Browser -> Render1;
+///     Browser -> Render2;
+///     Browser -> RenderN;
+///     Browser -> GPU;
+/// }
+/// \enddot
+///
+/// You can react to crashes and hangs in these browser and renderer processes
+/// using the ProcessFailure event.
+///
+/// You can safely shutdown associated browser and renderer processes using the
+/// Close method.
+///
+/// ## Threading model
+/// The WebView2 must be created on a UI thread. Specifically a
+/// thread with a message pump. All callbacks will occur on that thread and
+/// calls into the WebView must be done on that thread. It is not safe to use
+/// the WebView from another thread.
+///
+/// Callbacks including event handlers and completion handlers execute serially.
+/// That is, if you have an event handler running and begin a message loop no
+/// other event handlers or completion callbacks will begin executing
+/// reentrantly.
+///
+/// ## Security
+/// Always check the Source property of the WebView before using ExecuteScript,
+/// PostWebMessageAsJson, PostWebMessageAsString, or any other method to send
+/// information into the WebView. The WebView may have navigated to another page
+/// via the end user interacting with the page or script in the page causing
+/// navigation. Similarly, be very careful with
+/// AddScriptToExecuteOnDocumentCreated. All future navigations will run this
+/// script and if it provides access to information intended only for a certain
+/// origin, any HTML document may have access.
+///
+/// When examining the result of an ExecuteScript method call, a
+/// WebMessageReceived event, always check the Source of the sender, or any
+/// other mechanism of receiving information from an HTML document in a WebView
+/// validate the URI of the HTML document is what you expect.
+///
+/// When constructing a message to send into a WebView, prefer using
+/// PostWebMessageAsJson and construct the JSON string parameter using a JSON
+/// library. This will avoid any potential accidents of encoding information
+/// into a JSON string or script and ensure no attacker controlled input can
+/// modify the rest of the JSON message or run arbitrary script.
+///
+/// ## String types
+/// String out parameters are LPWSTR null terminated strings. The callee
+/// allocates the string using CoTaskMemAlloc. Ownership is transferred to the
+/// caller and it is up to the caller to free the memory using CoTaskMemFree.
+///
+/// String in parameters are LPCWSTR null terminated strings. The caller ensures
+/// the string is valid for the duration of the synchronous function call.
+/// If the callee needs to retain that value to some point after the function
+/// call completes, the callee must allocate its own copy of the string value.
+///
+/// ## URI and JSON parsing
+/// Various methods provide or accept URIs and JSON as strings. Please use your
+/// own preferred library for parsing and generating these strings.
+///
+/// If WinRT is available for your app you can use `RuntimeClass_Windows_Data_Json_JsonObject`
+/// and `IJsonObjectStatics` to parse or produce JSON strings or `RuntimeClass_Windows_Foundation_Uri`
+/// and `IUriRuntimeClassFactory` to parse and produce URIs. Both of these work
+/// in Win32 apps.
+///
+/// If you use IUri and CreateUri to parse URIs you may want to use the
+/// following URI creation flags to have CreateUri behavior more closely match
+/// the URI parsing in the WebView:
+/// `Uri_CREATE_ALLOW_IMPLICIT_FILE_SCHEME | Uri_CREATE_NO_DECODE_EXTRA_INFO`
+///
+/// ## Debugging
+/// Open DevTools with the normal shortcuts: `F12` or `Ctrl+Shift+I`.
+/// You can use the `--auto-open-devtools-for-tabs` command argument switch to
+/// have the DevTools window open immediately when first creating a WebView. See
+/// CreateCoreWebView2Host documentation for how to provide additional command
+/// line arguments to the browser process.
+/// Check out the LoaderOverride registry key for trying out different builds of
+/// WebView2 without modifying your application in the CreateCoreWebView2Host
+/// documentation.
+///
+/// ## Versioning
+/// After you've used a particular version of the SDK to build your app, your
+/// app may end up running with an older or newer version of installed browser
+/// binaries. Until version 1.0.0.0 of WebView2 there may be breaking changes
+/// during updates that will prevent your SDK from working with different
+/// versions of installed browser binaries. After version 1.0.0.0 different
+/// versions of the SDK can work with different versions of the installed
+/// browser by following these best practices:
+///
+/// To account for breaking changes to the API be sure to check for failure when
+/// calling the DLL export CreateCoreWebView2Environment and when
+/// calling QueryInterface on any CoreWebView2 object. A return value of
+/// E_NOINTERFACE can indicate the SDK is not compatible with the Edge
+/// browser binaries.
+///
+/// Checking for failure from QueryInterface will also account for cases where
+/// the SDK is newer than the version of the Edge browser and your app attempts
+/// to use an interface of which the Edge browser is unaware.
+///
+/// When an interface is unavailable, you can consider disabling the associated
+/// feature if possible, or otherwise informing the end user they need to update
+/// their browser.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2>
+#[com_interface("5cc5293d-af6f-41d4-9619-44bd31ba4c93")]
+pub trait ICoreWebView2: IUnknown {
+    /// The ICoreWebView2Settings object contains various modifiable settings for
+    /// the running WebView.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_settings>
+    #[doc(alias = "get_Settings")]
+    unsafe fn get_settings(
+        &self,
+        /* out, retval */ settings: *mut *mut *mut ICoreWebView2SettingsVTable,
+    ) -> HRESULT;
+
+    /// The URI of the current top level document. This value potentially
+    /// changes as a part of the SourceChanged event firing for some cases
+    /// such as navigating to a different site or fragment navigations. It will
+    /// remain the same for other types of navigations such as page reloads or
+    /// history.pushState with the same URL as the current page.
+    ///
+    /// \snippet ControlComponent.cpp SourceChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_source>
+    #[doc(alias = "get_Source")]
+    unsafe fn get_source(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// Cause a navigation of the top level document to the specified URI. See
+    /// the navigation events for more information. Note that this starts a
+    /// navigation and the corresponding NavigationStarting event will fire
+    /// sometime after this Navigate call completes.
+    ///
+    /// \snippet ControlComponent.cpp Navigate
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#navigate>
+    #[doc(alias = "Navigate")]
+    unsafe fn navigate(&self, /* in */ uri: LPCWSTR) -> HRESULT;
+
+    /// Initiates a navigation to htmlContent as source HTML of a new
+    /// document. The htmlContent parameter may not be larger than 2 MB of
+    /// characters. The origin of the new page will be about:blank.
+    ///
+    /// \snippet SettingsComponent.cpp NavigateToString
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#navigatetostring>
+    #[doc(alias = "NavigateToString")]
+    unsafe fn navigate_to_string(&self, /* in */ htmlContent: LPCWSTR) -> HRESULT;
+
+    /// Add an event handler for the NavigationStarting event.
+    /// NavigationStarting fires when the WebView main frame is
+    /// requesting permission to navigate to a different URI. This will fire for
+    /// redirects as well.
+    ///
+    /// \snippet SettingsComponent.cpp NavigationStarting
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_navigationstarting>
+    #[doc(alias = "add_NavigationStarting")]
+    unsafe fn add_navigation_starting(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2NavigationStartingEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_NavigationStarting.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_navigationstarting>
+    #[doc(alias = "remove_NavigationStarting")]
+    unsafe fn remove_navigation_starting(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the ContentLoading event.
+    /// ContentLoading fires before any content is loaded, including scripts added with
+    /// AddScriptToExecuteOnDocumentCreated
+    /// ContentLoading will not fire if a same page navigation occurs
+    /// (such as through fragment navigations or history.pushState navigations).
+    /// This follows the NavigationStarting and SourceChanged events and
+    /// precedes the HistoryChanged and NavigationCompleted events.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_contentloading>
+    #[doc(alias = "add_ContentLoading")]
+    unsafe fn add_content_loading(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2ContentLoadingEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_ContentLoading.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_contentloading>
+    #[doc(alias = "remove_ContentLoading")]
+    unsafe fn remove_content_loading(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// SourceChanged fires when the Source property changes.
+    /// SourceChanged fires for navigating to a different site or fragment navigations.
+    /// It will not fires for other types of navigations such as page reloads or
+    /// history.pushState with the same URL as the current page.
+    /// SourceChanged fires before ContentLoading for navigation to a new document.
+    /// Add an event handler for the SourceChanged event.
+    /// \snippet ControlComponent.cpp SourceChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_sourcechanged>
+    #[doc(alias = "add_SourceChanged")]
+    unsafe fn add_source_changed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2SourceChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_SourceChanged.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_sourcechanged>
+    #[doc(alias = "remove_SourceChanged")]
+    unsafe fn remove_source_changed(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// HistoryChange listen to the change of navigation history for the top level
+    /// document. Use HistoryChange to check if get_CanGoBack/get_CanGoForward value
+    /// has changed. HistoryChanged also fires for using GoBack/GoForward.
+    /// HistoryChanged fires after SourceChanged and ContentLoading.
+    /// Add an event handler for the HistoryChanged event.
+    /// \snippet ControlComponent.cpp HistoryChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_historychanged>
+    #[doc(alias = "add_HistoryChanged")]
+    unsafe fn add_history_changed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2HistoryChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_HistoryChanged.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_historychanged>
+    #[doc(alias = "remove_HistoryChanged")]
+    unsafe fn remove_history_changed(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// Add an event handler for the NavigationCompleted event.
+    /// NavigationCompleted event fires when the WebView has completely loaded
+    /// (body.onload has fired) or loading stopped with error.
+    ///
+    /// \snippet ControlComponent.cpp NavigationCompleted
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_navigationcompleted>
+    #[doc(alias = "add_NavigationCompleted")]
+    unsafe fn add_navigation_completed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2NavigationCompletedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_NavigationCompleted.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_navigationcompleted>
+    #[doc(alias = "remove_NavigationCompleted")]
+    unsafe fn remove_navigation_completed(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the FrameNavigationStarting event.
+    /// FrameNavigationStarting fires when a child frame in the WebView
+    /// requesting permission to navigate to a different URI. This will fire for
+    /// redirects as well.
+    ///
+    /// \snippet SettingsComponent.cpp FrameNavigationStarting
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_framenavigationstarting>
+    #[doc(alias = "add_FrameNavigationStarting")]
+    unsafe fn add_frame_navigation_starting(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2NavigationStartingEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_FrameNavigationStarting.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_framenavigationstarting>
+    #[doc(alias = "remove_FrameNavigationStarting")]
+    unsafe fn remove_frame_navigation_starting(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the ScriptDialogOpening event.
+    /// The event fires when a JavaScript dialog (alert, confirm, or prompt) will
+    /// show for the webview. This event only fires if the
+    /// ICoreWebView2Settings::AreDefaultScriptDialogsEnabled property is set to
+    /// false. The ScriptDialogOpening event can be used to suppress dialogs or
+    /// replace default dialogs with custom dialogs.
+    ///
+    /// \snippet SettingsComponent.cpp ScriptDialogOpening
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_scriptdialogopening>
+    #[doc(alias = "add_ScriptDialogOpening")]
+    unsafe fn add_script_dialog_opening(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2ScriptDialogOpeningEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_ScriptDialogOpening.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_scriptdialogopening>
+    #[doc(alias = "remove_ScriptDialogOpening")]
+    unsafe fn remove_script_dialog_opening(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the PermissionRequested event.
+    /// Fires when content in a WebView requests permission to access some
+    /// privileged resources.
+    ///
+    /// \snippet SettingsComponent.cpp PermissionRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_permissionrequested>
+    #[doc(alias = "add_PermissionRequested")]
+    unsafe fn add_permission_requested(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2PermissionRequestedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_PermissionRequested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_permissionrequested>
+    #[doc(alias = "remove_PermissionRequested")]
+    unsafe fn remove_permission_requested(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the ProcessFailed event.
+    /// Fires when a WebView process terminated unexpectedly or
+    /// become unresponsive.
+    ///
+    /// \snippet ProcessComponent.cpp ProcessFailed
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_processfailed>
+    #[doc(alias = "add_ProcessFailed")]
+    unsafe fn add_process_failed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2ProcessFailedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_ProcessFailed.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_processfailed>
+    #[doc(alias = "remove_ProcessFailed")]
+    unsafe fn remove_process_failed(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// Add the provided JavaScript to a list of scripts
+    /// that should be executed after the global object has been created, but
+    /// before the HTML document has been parsed and before any other script
+    /// included by the HTML document is executed. The
+    /// injected script will apply to all future top level document and child
+    /// frame navigations until removed with RemoveScriptToExecuteOnDocumentCreated.
+    /// This is applied asynchronously and you must wait for the completion
+    /// handler to run before you can be sure that the script is ready to
+    /// execute on future navigations.
+    ///
+    /// Note that if an HTML document has sandboxing of some kind via [sandbox](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/iframe#attr-sandbox)
+    /// properties or the [Content-Security-Policy HTTP header](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy)
+    /// this will affect the script run here. So, for example, if the
+    /// 'allow-modals' keyword is not set then calls to the `alert` function will
+    /// be ignored.
+    ///
+    /// \snippet ScriptComponent.cpp AddScriptToExecuteOnDocumentCreated
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addscripttoexecuteondocumentcreated>
+    #[doc(alias = "AddScriptToExecuteOnDocumentCreated")]
+    unsafe fn add_script_to_execute_on_document_created(
+        &self,
+        /* in */ javaScript: LPCWSTR,
+        /* in */
+        handler: *mut *mut ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandlerVTable,
+    ) -> HRESULT;
+
+    /// Remove the corresponding JavaScript added via AddScriptToExecuteOnDocumentCreated.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removescripttoexecuteondocumentcreated>
+    #[doc(alias = "RemoveScriptToExecuteOnDocumentCreated")]
+    unsafe fn remove_script_to_execute_on_document_created(
+        &self,
+        /* in */ id: LPCWSTR,
+    ) -> HRESULT;
+
+    /// Execute JavaScript code from the javascript parameter in the
+    /// current top level document rendered in the WebView. This will execute
+    /// asynchronously and when complete, if a handler is provided in the
+    /// ExecuteScriptCompletedHandler parameter, its Invoke method will be
+    /// called with the result of evaluating the provided JavaScript. The result
+    /// value is a JSON encoded string.
+    /// If the result is undefined, contains a reference cycle, or otherwise
+    /// cannot be encoded into JSON, the JSON null value will be returned as the
+    /// string 'null'. Note that a function that has no explicit return value
+    /// returns undefined.
+    /// If the executed script throws an unhandled exception, then the result is
+    /// also 'null'.
+    /// This method is applied asynchronously. If the call is made while the
+    /// webview is on one document, and a navigation occurs after the call is
+    /// made but before the JavaScript is executed, then the script will not be
+    /// executed and the handler will be called with E_FAIL for its errorCode
+    /// parameter.
+    /// ExecuteScript will work even if IsScriptEnabled is set to FALSE.
+    ///
+    /// \snippet ScriptComponent.cpp ExecuteScript
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#executescript>
+    #[doc(alias = "ExecuteScript")]
+    unsafe fn execute_script(
+        &self,
+        /* in */ javaScript: LPCWSTR,
+        /* in */ handler: *mut *mut ICoreWebView2ExecuteScriptCompletedHandlerVTable,
+    ) -> HRESULT;
+
+    /// Capture an image of what WebView is displaying. Specify the
+    /// format of the image with the imageFormat parameter.
+    /// The resulting image binary data is written to the provided imageStream
+    /// parameter. When CapturePreview finishes writing to the stream, the Invoke
+    /// method on the provided handler parameter is called.
+    ///
+    /// \snippet FileComponent.cpp CapturePreview
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#capturepreview>
+    #[doc(alias = "CapturePreview")]
+    unsafe fn capture_preview(
+        &self,
+        /* in */ imageFormat: CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT,
+        /* in */ imageStream: *mut *mut IStreamVTable,
+        /* in */ handler: *mut *mut ICoreWebView2CapturePreviewCompletedHandlerVTable,
+    ) -> HRESULT;
+
+    /// Reload the current page. This is similar to navigating to the URI of
+    /// current top level document including all navigation events firing and
+    /// respecting any entries in the HTTP cache. But, the back/forward history
+    /// will not be modified.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#reload>
+    #[doc(alias = "Reload")]
+    unsafe fn reload(&self) -> HRESULT;
+
+    /// Post the specified webMessage to the top level document in this WebView.
+    /// The top level document's window.chrome.webview's message event fires.
+    /// JavaScript in that document may subscribe and unsubscribe to the event
+    /// via the following:
+    /// ```
+    ///    window.chrome.webview.addEventListener('message', handler)
+    ///    window.chrome.webview.removeEventListener('message', handler)
+    /// ```
+    /// The event args is an instance of `MessageEvent`.
+    /// The ICoreWebView2Settings::IsWebMessageEnabled setting must be true or this method
+    /// will fail with E_INVALIDARG.
+    /// The event arg's data property is the webMessage string parameter parsed
+    /// as a JSON string into a JavaScript object.
+    /// The event arg's source property is a reference to the
+    /// `window.chrome.webview` object.
+    /// See SetWebMessageReceivedEventHandler for information on sending messages
+    /// from the HTML document in the webview to the host.
+    /// This message is sent asynchronously. If a navigation occurs before the
+    /// message is posted to the page, then the message will not be sent.
+    ///
+    /// \snippet ScenarioWebMessage.cpp WebMessageReceived
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#postwebmessageasjson>
+    #[doc(alias = "PostWebMessageAsJson")]
+    unsafe fn post_web_message_as_json(&self, /* in */ webMessageAsJson: LPCWSTR) -> HRESULT;
+
+    /// This is a helper for posting a message that is a simple string
+    /// rather than a JSON string representation of a JavaScript object. This
+    /// behaves in exactly the same manner as PostWebMessageAsJson but the
+    /// `window.chrome.webview` message event arg's data property will be a string
+    /// with the same value as webMessageAsString. Use this instead of
+    /// PostWebMessageAsJson if you want to communicate via simple strings rather
+    /// than JSON objects.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#postwebmessageasstring>
+    #[doc(alias = "PostWebMessageAsString")]
+    unsafe fn post_web_message_as_string(
+        &self,
+        /* in */ webMessageAsString: LPCWSTR,
+    ) -> HRESULT;
+
+    /// This event fires when the IsWebMessageEnabled setting is set and the top
+    /// level document of the webview calls `window.chrome.webview.postMessage`.
+    /// The postMessage function is `void postMessage(object)` where
+    /// object is any object supported by JSON conversion.
+    ///
+    /// \snippet ScenarioWebMessage.html chromeWebView
+    ///
+    /// When postMessage is called, the ICoreWebView2WebMessageReceivedEventHandler set via
+    /// this SetWebMessageReceivedEventHandler method will be invoked with the
+    /// postMessage's object parameter converted to a JSON string.
+    ///
+    /// \snippet ScenarioWebMessage.cpp WebMessageReceived
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_webmessagereceived>
+    #[doc(alias = "add_WebMessageReceived")]
+    unsafe fn add_web_message_received(
+        &self,
+        /* in */ handler: *mut *mut ICoreWebView2WebMessageReceivedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_WebMessageReceived.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_webmessagereceived>
+    #[doc(alias = "remove_WebMessageReceived")]
+    unsafe fn remove_web_message_received(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Call an asynchronous DevToolsProtocol method. See the
+    /// [DevTools Protocol Viewer](https://aka.ms/DevToolsProtocolDocs)
+    /// for a list and description of available methods.
+    /// The methodName parameter is the full name of the method in the format
+    /// `{domain}.{method}`.
+    /// The parametersAsJson parameter is a JSON formatted string containing
+    /// the parameters for the corresponding method.
+    /// The handler's Invoke method will be called when the method asynchronously
+    /// completes. Invoke will be called with the method's return object as a
+    /// JSON string.
+    ///
+    /// \snippet ScriptComponent.cpp CallDevToolsProtocolMethod
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#calldevtoolsprotocolmethod>
+    #[doc(alias = "CallDevToolsProtocolMethod")]
+    unsafe fn call_dev_tools_protocol_method(
+        &self,
+        /* in */ methodName: LPCWSTR,
+        /* in */ parametersAsJson: LPCWSTR,
+        /* in */
+        handler: *mut *mut ICoreWebView2CallDevToolsProtocolMethodCompletedHandlerVTable,
+    ) -> HRESULT;
+
+    /// The process id of the browser process that hosts the WebView.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_browserprocessid>
+    #[doc(alias = "get_BrowserProcessId")]
+    unsafe fn get_browser_process_id(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+    /// Returns true if the webview can navigate to a previous page in the navigation history.
+    /// The HistoryChanged event will fire if get_CanGoBack changes value.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_cangoback>
+    #[doc(alias = "get_CanGoBack")]
+    unsafe fn get_can_go_back(&self, /* out, retval */ canGoBack: *mut BOOL) -> HRESULT;
+
+    /// Returns true if the webview can navigate to a next page in the navigation history.
+    /// The HistoryChanged event will fire if get_CanGoForward changes value.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_cangoforward>
+    #[doc(alias = "get_CanGoForward")]
+    unsafe fn get_can_go_forward(&self, /* out, retval */ canGoForward: *mut BOOL) -> HRESULT;
+
+    /// Navigates the WebView to the previous page in the navigation history.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#goback>
+    #[doc(alias = "GoBack")]
+    unsafe fn go_back(&self) -> HRESULT;
+
+    /// Navigates the WebView to the next page in the navigation history.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#goforward>
+    #[doc(alias = "GoForward")]
+    unsafe fn go_forward(&self) -> HRESULT;
+
+    /// Get a DevTools Protocol event receiver that allows you to subscribe to
+    /// a DevTools Protocol event.
+    /// The eventName parameter is the full name of the event in the format
+    /// `{domain}.{event}`.
+    /// See the [DevTools Protocol Viewer](https://aka.ms/DevToolsProtocolDocs)
+    /// for a list of DevTools Protocol events description, and event args.
+    ///
+    /// \snippet ScriptComponent.cpp DevToolsProtocolEventReceived
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#getdevtoolsprotocoleventreceiver>
+    #[doc(alias = "GetDevToolsProtocolEventReceiver")]
+    unsafe fn get_dev_tools_protocol_event_receiver(
+        &self,
+        /* in */ eventName: LPCWSTR,
+        /* out, retval */
+        receiver: *mut *mut *mut ICoreWebView2DevToolsProtocolEventReceiverVTable,
+    ) -> HRESULT;
+
+    /// Stop all navigations and pending resource fetches. Does not stop
+    /// scripts.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#stop>
+    #[doc(alias = "Stop")]
+    unsafe fn stop(&self) -> HRESULT;
+
+    /// Add an event handler for the NewWindowRequested event.
+    /// Fires when content inside the WebView requested to open a new window,
+    /// such as through window.open. The app can pass a target
+    /// webview that will be considered the opened window.
+    ///
+    /// \snippet AppWindow.cpp NewWindowRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_newwindowrequested>
+    #[doc(alias = "add_NewWindowRequested")]
+    unsafe fn add_new_window_requested(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2NewWindowRequestedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_NewWindowRequested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_newwindowrequested>
+    #[doc(alias = "remove_NewWindowRequested")]
+    unsafe fn remove_new_window_requested(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the DocumentTitleChanged event.
+    /// The event fires when the DocumentTitle property of the WebView changes
+    /// and may fire before or after the NavigationCompleted event.
+    ///
+    /// \snippet FileComponent.cpp DocumentTitleChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_documenttitlechanged>
+    #[doc(alias = "add_DocumentTitleChanged")]
+    unsafe fn add_document_title_changed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2DocumentTitleChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_DocumentTitleChanged.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_documenttitlechanged>
+    #[doc(alias = "remove_DocumentTitleChanged")]
+    unsafe fn remove_document_title_changed(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// The title for the current top level document.
+    /// If the document has no explicit title or is otherwise empty,
+    /// a default that may or may not match the URI of the document will be used.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_documenttitle>
+    #[doc(alias = "get_DocumentTitle")]
+    unsafe fn get_document_title(&self, /* out, retval */ title: *mut LPWSTR) -> HRESULT;
+
+    /// Add the provided host object to script running in the WebView with the
+    /// specified name.
+    /// Host objects are exposed as remote object proxies via
+    /// `window.chrome.webview.remoteObjects.<name>`.
+    /// Remote object proxies are promises and will resolve to an object
+    /// representing the host object.
+    /// The promise is rejected if the app has not added an object with the name.
+    /// When JavaScript code access a property or method of the object, a promise
+    /// is return, which will resolve to the value returned from the host for the
+    /// property or method, or rejected in case of error such as there is no such
+    /// property or method on the object or parameters are invalid.
+    /// For example, when the application code does the following:
+    /// ```
+    ///    VARIANT object;
+    ///    object.vt = VT_DISPATCH;
+    ///    object.pdispVal = appObject;
+    ///    webview->AddRemoteObject(L"host_object", &host);
+    /// ```
+    /// JavaScript code in the WebView will be able to access appObject as
+    /// following and then access attributes and methods of appObject:
+    /// ```
+    ///    let app_object = await window.chrome.webview.remoteObjects.host_object;
+    ///    let attr1 = await app_object.attr1;
+    ///    let result = await app_object.method1(parameters);
+    /// ```
+    /// Note that while simple types, IDispatch and array are supported, generic
+    /// IUnknown, VT_DECIMAL, or VT_RECORD variant is not supported.
+    /// Remote JavaScript objects like callback functions are represented as
+    /// an VT_DISPATCH VARIANT with the object implementing IDispatch. The
+    /// JavaScript callback method may be invoked using DISPID_VALUE for the
+    /// DISPID.
+    /// Nested arrays are supported up to a depth of 3.
+    /// Arrays of by reference types are not supported.
+    /// VT_EMPTY and VT_NULL are mapped into JavaScript as null. In JavaScript
+    /// null and undefined are mapped to VT_EMPTY.
+    ///
+    /// Additionally, all remote objects are exposed as
+    /// `window.chrome.webview.remoteObjects.sync.<name>`. Here the host
+    /// objects are exposed as synchronous remote object proxies. These are not
+    /// promises and calls to functions or property access synchronously block
+    /// running script waiting to communicate cross process for the host code to
+    /// run. Accordingly this can result in reliability issues and it is
+    /// recommended that you use the promise based asynchronous
+    /// `window.chrome.webview.remoteObjects.<name>` API described above.
+    ///
+    /// Synchronous remote object proxies and asynchronous remote object proxies
+    /// can both proxy the same remote object. Remote changes made by one proxy
+    /// will be reflected in any other proxy of that same remote object whether
+    /// the other proxies and synchronous or asynchronous.
+    ///
+    /// While JavaScript is blocked on a synchronous call to native code, that
+    /// native code is unable to call back to JavaScript. Attempts to do so will
+    /// fail with HRESULT_FROM_WIN32(ERROR_POSSIBLE_DEADLOCK).
+    ///
+    /// Remote object proxies are JavaScript Proxy objects that intercept all
+    /// property get, property set, and method invocations. Properties or methods
+    /// that are a part of the Function or Object prototype are run locally.
+    /// Additionally any property or method in the array
+    /// `chrome.webview.remoteObjects.options.forceLocalProperties` will also be
+    /// run locally. This defaults to including optional methods that have
+    /// meaning in JavaScript like `toJSON` and `Symbol.toPrimitive`. You can add
+    /// more to this array as required.
+    ///
+    /// There's a method `chrome.webview.remoteObjects.cleanupSome` that will best
+    /// effort garbage collect remote object proxies.
+    ///
+    /// Remote object proxies additionally have the following methods which run
+    /// locally:
+    ///  * applyRemote, getRemote, setRemote: Perform a method invocation,
+    ///    property get, or property set on the remote object. You can use these
+    ///    to explicitly force a method or property to run remotely if there is
+    ///    a conflicting local method or property. For instance, `proxy.toString()`
+    ///    will run the local toString method on the proxy object. But
+    ///    ``proxy.applyRemote('toString')`` runs `toString` on the remote proxied
+    ///    object instead.
+    ///  * getLocal, setLocal: Perform property get, or property set locally. You
+    ///    can use these methods to force getting or setting a property on the
+    ///    remote object proxy itself rather than on the remote object it
+    ///    represents. For instance, `proxy.unknownProperty` will get the
+    ///    property named `unknownProperty` from the remote proxied object. But
+    ///    ``proxy.getLocal('unknownProperty')`` will get the value of the property
+    ///    `unknownProperty` on the proxy object itself.
+    ///  * sync: Asynchronous remote object proxies expose a sync method which
+    ///    returns a promise for a synchronous remote object proxy for the same
+    ///    remote object. For example,
+    ///    `chrome.webview.remoteObjects.sample.methodCall()` returns an
+    ///    asynchronous remote object proxy. You can use the `sync` method to
+    ///    obtain a synchronous remote object proxy instead:
+    ///    `const syncProxy = await chrome.webview.remoteObjects.sample.methodCall().sync()`
+    ///  * async: Synchronous remote object proxies expose an async method which
+    ///    blocks and returns an asynchronous remote object proxy for the same
+    ///    remote object. For example, `chrome.webview.remoteObjects.sync.sample.methodCall()` returns a
+    ///    synchronous remote object proxy. Calling the `async` method on this blocks
+    ///    and then returns an asynchronous remote object proxy for the same remote object:
+    ///    `const asyncProxy = chrome.webview.remoteObjects.sync.sample.methodCall().async()`
+    ///  * then: Asynchronous remote object proxies have a then method. This
+    ///    allows them to be awaitable. `then` will return a promise that resolves
+    ///    with a representation of the remote object. If the proxy represents a
+    ///    JavaScript literal then a copy of that is returned locally. If
+    ///    the proxy represents a function then a non-awaitable proxy is returned.
+    ///    If the proxy represents a JavaScript object with a mix of literal
+    ///    properties and function properties, then the a copy of the object is
+    ///    returned with some properties as remote object proxies.
+    ///
+    /// All other property and method invocations (other than the above Remote
+    /// object proxy methods, forceLocalProperties list, and properties on
+    /// Function and Object prototypes) are run remotely. Asynchronous remote
+    /// object proxies return a promise representing asynchronous completion of
+    /// remotely invoking the method, or getting the property.
+    /// The promise resolves after the remote operations complete and
+    /// the promises resolve to the resulting value of the operation.
+    /// Synchronous remote object proxies work similarly but block JavaScript
+    /// execution and wait for the remote operation to complete.
+    ///
+    /// Setting a property on an asynchronous remote object proxy works slightly
+    /// differently. The set returns immediately and the return value is the value
+    /// that will be set. This is a requirement of the JavaScript Proxy object.
+    /// If you need to asynchronously wait for the property set to complete, use
+    /// the setRemote method which returns a promise as described above.
+    /// Synchronous object property set property synchronously blocks until the
+    /// property is set.
+    ///
+    /// For example, suppose you have a COM object with the following interface
+    ///
+    /// \snippet RemoteObjectSample.idl AddRemoteObjectInterface
+    ///
+    /// We can add an instance of this interface into our JavaScript with
+    /// `AddRemoteObject`. In this case we name it `sample`:
+    ///
+    /// \snippet ScenarioAddRemoteObject.cpp AddRemoteObject
+    ///
+    /// Then in the HTML document we can use this COM object via `chrome.webview.remoteObjects.sample`:
+    ///
+    /// \snippet ScenarioAddRemoteObject.html RemoteObjectUsage
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addremoteobject>
+    #[doc(alias = "AddRemoteObject")]
+    unsafe fn add_remote_object(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* in */ object: *const VARIANT,
+    ) -> HRESULT;
+
+    /// Remove the host object specified by the name so that it is no longer
+    /// accessible from JavaScript code in the WebView.
+    /// While new access attempts will be denied, if the object is already
+    /// obtained by JavaScript code in the WebView, the JavaScript code will
+    /// continue to have access to that object.
+    /// Calling this method for a name that is already removed or never added will
+    /// fail.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removeremoteobject>
+    #[doc(alias = "RemoveRemoteObject")]
+    unsafe fn remove_remote_object(&self, /* in */ name: LPCWSTR) -> HRESULT;
+
+    /// Opens the DevTools window for the current document in the WebView.
+    /// Does nothing if called when the DevTools window is already open
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#opendevtoolswindow>
+    #[doc(alias = "OpenDevToolsWindow")]
+    unsafe fn open_dev_tools_window(&self) -> HRESULT;
+
+    /// Notifies when the ContainsFullScreenElement property changes. This means
+    /// that an HTML element inside the WebView is entering fullscreen to the size
+    /// of the WebView or leaving fullscreen.
+    /// This event is useful when, for example, a video element requests to go
+    /// fullscreen. The listener of ContainsFullScreenElementChanged can then
+    /// resize the WebView in response.
+    ///
+    /// \snippet AppWindow.cpp ContainsFullScreenElementChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_containsfullscreenelementchanged>
+    #[doc(alias = "add_ContainsFullScreenElementChanged")]
+    unsafe fn add_contains_full_screen_element_changed(
+        &self,
+        /* in */
+        eventHandler: *mut *mut ICoreWebView2ContainsFullScreenElementChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with the corresponding add_
+    /// event method.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_containsfullscreenelementchanged>
+    #[doc(alias = "remove_ContainsFullScreenElementChanged")]
+    unsafe fn remove_contains_full_screen_element_changed(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Indicates if the WebView contains a fullscreen HTML element.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_containsfullscreenelement>
+    #[doc(alias = "get_ContainsFullScreenElement")]
+    unsafe fn get_contains_full_screen_element(
+        &self,
+        /* out, retval */ containsFullScreenElement: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Add an event handler for the WebResourceRequested event. Fires when the
+    /// WebView is performing an HTTP request to a matching URL and resource context
+    /// filter that was added with AddWebResourceRequestedFilter. At least one
+    /// filter must be added for the event to fire.
+    ///
+    /// \snippet SettingsComponent.cpp WebResourceRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_webresourcerequested>
+    #[doc(alias = "add_WebResourceRequested")]
+    unsafe fn add_web_resource_requested(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2WebResourceRequestedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_WebResourceRequested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_webresourcerequested>
+    #[doc(alias = "remove_WebResourceRequested")]
+    unsafe fn remove_web_resource_requested(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Adds a URI and resource context filter to the WebResourceRequested event.
+    /// URI parameter can be a wildcard string ('': zero or more, '?': exactly one).
+    /// nullptr is equivalent to L"".
+    /// See CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT enum for description of resource context filters.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addwebresourcerequestedfilter>
+    #[doc(alias = "AddWebResourceRequestedFilter")]
+    unsafe fn add_web_resource_requested_filter(
+        &self,
+        /* in */ uri: LPCWSTR,
+        /* in */ resourceContext: CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+    ) -> HRESULT;
+
+    /// Removes a matching WebResource filter that was previously added for the
+    /// WebResourceRequested event. If the same filter was added multiple times, then it
+    /// will need to be removed as many times as it was added for the removal to be
+    /// effective. Returns E_INVALIDARG for a filter that was never added.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removewebresourcerequestedfilter>
+    #[doc(alias = "RemoveWebResourceRequestedFilter")]
+    unsafe fn remove_web_resource_requested_filter(
+        &self,
+        /* in */ uri: LPCWSTR,
+        /* in */ resourceContext: CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+    ) -> HRESULT;
+
+    /// Add an event handler for the WindowCloseRequested event.
+    /// Fires when content inside the WebView requested to close the window,
+    /// such as after window.close is called. The app should close the WebView
+    /// and related app window if that makes sense to the app.
+    ///
+    /// \snippet AppWindow.cpp WindowCloseRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_windowcloserequested>
+    #[doc(alias = "add_WindowCloseRequested")]
+    unsafe fn add_window_close_requested(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2WindowCloseRequestedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_WindowCloseRequested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_windowcloserequested>
+    #[doc(alias = "remove_WindowCloseRequested")]
+    unsafe fn remove_window_close_requested(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2`.
+pub const IID_ICoreWebView2: GUID = GUID {
+    Data1: 0x5cc5293d,
+    Data2: 0xaf6f,
+    Data3: 0x41d4,
+    Data4: [0x96, 0x19, 0x44, 0xbd, 0x31, 0xba, 0x4c, 0x93],
+};
+
+/// Image format used by the ICoreWebView2::CapturePreview method.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT {
+    /// PNG image format.
+    CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG,
+    /// JPEG image format.
+    CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_JPEG,
+}
+
+impl CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG),
+            1 => Ok(Self::CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_JPEG),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Kind of JavaScript dialog used in the ICoreWebView2ScriptDialogOpeningEventHandler
+/// interface.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_SCRIPT_DIALOG_KIND {
+    /// A dialog invoked via the window.alert JavaScript function.
+    CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_ALERT,
+    /// A dialog invoked via the window.confirm JavaScript function.
+    CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_CONFIRM,
+    /// A dialog invoked via the window.prompt JavaScript function.
+    CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_PROMPT,
+    /// A dialog invoked via the beforeunload JavaScript event.
+    CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_BEFOREUNLOAD,
+}
+
+impl CORE_WEBVIEW2_SCRIPT_DIALOG_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_SCRIPT_DIALOG_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_ALERT),
+            1 => Ok(Self::CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_CONFIRM),
+            2 => Ok(Self::CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_PROMPT),
+            3 => Ok(Self::CORE_WEBVIEW2_SCRIPT_DIALOG_KIND_BEFOREUNLOAD),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Kind of process failure used in the ICoreWebView2ProcessFailedEventHandler interface.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_PROCESS_FAILED_KIND {
+    /// Indicates the browser process terminated unexpectedly.
+    /// The WebView automatically goes into the Closed state.
+    /// The app has to recreate a new WebView to recover from this failure.
+    CORE_WEBVIEW2_PROCESS_FAILED_KIND_BROWSER_PROCESS_EXITED,
+    /// Indicates the render process terminated unexpectedly.
+    /// A new render process will be created automatically and navigated to an
+    /// error page.
+    /// The app can use Reload to try to recover from this failure.
+    CORE_WEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_EXITED,
+    /// Indicates the render process becomes unresponsive.
+    /// The app can try to navigate away from the page to recover from the
+    /// failure.
+    CORE_WEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_UNRESPONSIVE,
+}
+
+impl CORE_WEBVIEW2_PROCESS_FAILED_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_PROCESS_FAILED_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_PROCESS_FAILED_KIND_BROWSER_PROCESS_EXITED),
+            1 => Ok(Self::CORE_WEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_EXITED),
+            2 => Ok(Self::CORE_WEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_UNRESPONSIVE),
+            _ => Err(value),
+        }
+    }
+}
+
+/// The type of a permission request.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_PERMISSION_KIND {
+    /// Unknown permission.
+    CORE_WEBVIEW2_PERMISSION_KIND_UNKNOWN_PERMISSION,
+    /// Permission to capture audio.
+    CORE_WEBVIEW2_PERMISSION_KIND_MICROPHONE,
+    /// Permission to capture video.
+    CORE_WEBVIEW2_PERMISSION_KIND_CAMERA,
+    /// Permission to access geolocation.
+    CORE_WEBVIEW2_PERMISSION_KIND_GEOLOCATION,
+    /// Permission to send web notifications.
+    /// This permission request is currently auto rejected and
+    /// no event is fired for it.
+    CORE_WEBVIEW2_PERMISSION_KIND_NOTIFICATIONS,
+    /// Permission to access generic sensor.
+    /// Generic Sensor covering ambient-light-sensor, accelerometer, gyroscope
+    /// and magnetometer.
+    CORE_WEBVIEW2_PERMISSION_KIND_OTHER_SENSORS,
+    /// Permission to read system clipboard without a user gesture.
+    CORE_WEBVIEW2_PERMISSION_KIND_CLIPBOARD_READ,
+}
+
+impl CORE_WEBVIEW2_PERMISSION_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_PERMISSION_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_UNKNOWN_PERMISSION),
+            1 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_MICROPHONE),
+            2 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_CAMERA),
+            3 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_GEOLOCATION),
+            4 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_NOTIFICATIONS),
+            5 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_OTHER_SENSORS),
+            6 => Ok(Self::CORE_WEBVIEW2_PERMISSION_KIND_CLIPBOARD_READ),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Response to a permission request.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_PERMISSION_STATE {
+    /// Use default browser behavior, which normally prompt users for decision.
+    CORE_WEBVIEW2_PERMISSION_STATE_DEFAULT,
+    /// Grant the permission request.
+    CORE_WEBVIEW2_PERMISSION_STATE_ALLOW,
+    /// Deny the permission request.
+    CORE_WEBVIEW2_PERMISSION_STATE_DENY,
+}
+
+impl CORE_WEBVIEW2_PERMISSION_STATE {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_PERMISSION_STATE {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_PERMISSION_STATE_DEFAULT),
+            1 => Ok(Self::CORE_WEBVIEW2_PERMISSION_STATE_ALLOW),
+            2 => Ok(Self::CORE_WEBVIEW2_PERMISSION_STATE_DENY),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Error status values for web navigations.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_WEB_ERROR_STATUS {
+    /// An unknown error occurred.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_UNKNOWN,
+    /// The SSL certificate common name does not match the web address.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_COMMON_NAME_IS_INCORRECT,
+    /// The SSL certificate has expired.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_EXPIRED,
+    /// The SSL client certificate contains errors.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CLIENT_CERTIFICATE_CONTAINS_ERRORS,
+    /// The SSL certificate has been revoked.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_REVOKED,
+    /// The SSL certificate is invalid -- this could mean the certificate did not
+    /// match the public key pins for the host name, the certificate is signed
+    /// by an untrusted authority or using a weak sign algorithm, the
+    /// certificate claimed DNS names violate name constraints, the certificate
+    /// contains a weak key, the certificate's validity period is too long, lack
+    /// of revocation information or revocation mechanism, non-unique host name,
+    /// lack of certificate transparency information, or the certificate is
+    /// chained to a [legacy Symantec
+    /// root](https://security.googleblog.com/2018/03/distrust-of-symantec-pki-immediate.html).
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_IS_INVALID,
+    /// The host is unreachable.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_SERVER_UNREACHABLE,
+    /// The connection has timed out.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_TIMEOUT,
+    /// The server returned an invalid or unrecognized response.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_ERROR_HTTP_INVALID_SERVER_RESPONSE,
+    /// The connection was aborted.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_ABORTED,
+    /// The connection was reset.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_RESET,
+    /// The Internet connection has been lost.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_DISCONNECTED,
+    /// Cannot connect to destination.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_CANNOT_CONNECT,
+    /// Could not resolve provided host name.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_HOST_NAME_NOT_RESOLVED,
+    /// The operation was canceled.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED,
+    /// The request redirect failed.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_REDIRECT_FAILED,
+    /// An unexpected error occurred.
+    CORE_WEBVIEW2_WEB_ERROR_STATUS_UNEXPECTED_ERROR,
+}
+
+impl CORE_WEBVIEW2_WEB_ERROR_STATUS {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_WEB_ERROR_STATUS {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_UNKNOWN),
+            1 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_COMMON_NAME_IS_INCORRECT),
+            2 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_EXPIRED),
+            3 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CLIENT_CERTIFICATE_CONTAINS_ERRORS),
+            4 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_REVOKED),
+            5 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CERTIFICATE_IS_INVALID),
+            6 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_SERVER_UNREACHABLE),
+            7 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_TIMEOUT),
+            8 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_ERROR_HTTP_INVALID_SERVER_RESPONSE),
+            9 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_ABORTED),
+            10 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CONNECTION_RESET),
+            11 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_DISCONNECTED),
+            12 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_CANNOT_CONNECT),
+            13 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_HOST_NAME_NOT_RESOLVED),
+            14 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_OPERATION_CANCELED),
+            15 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_REDIRECT_FAILED),
+            16 => Ok(Self::CORE_WEBVIEW2_WEB_ERROR_STATUS_UNEXPECTED_ERROR),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Enum for web resource request contexts.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT {
+    /// All resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_ALL,
+    /// Document resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_DOCUMENT,
+    /// CSS resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_STYLESHEET,
+    /// Image resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_IMAGE,
+    /// Other media resources such as videos
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_MEDIA,
+    /// Font resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_FONT,
+    /// Script resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_SCRIPT,
+    /// XML HTTP requests
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_XML_HTTP_REQUEST,
+    /// Fetch API communication
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_FETCH,
+    /// TextTrack resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_TEXT_TRACK,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_EVENT_SOURCE,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_WEBSOCKET,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_MANIFEST,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_SIGNED_EXCHANGE,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_PING,
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_CSP_VIOLATION_REPORT,
+    /// Other resources
+    CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_OTHER,
+}
+
+impl CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_ALL),
+            1 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_DOCUMENT),
+            2 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_STYLESHEET),
+            3 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_IMAGE),
+            4 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_MEDIA),
+            5 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_FONT),
+            6 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_SCRIPT),
+            7 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_XML_HTTP_REQUEST),
+            8 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_FETCH),
+            9 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_TEXT_TRACK),
+            10 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_EVENT_SOURCE),
+            11 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_WEBSOCKET),
+            12 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_MANIFEST),
+            13 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_SIGNED_EXCHANGE),
+            14 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_PING),
+            15 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_CSP_VIOLATION_REPORT),
+            16 => Ok(Self::CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT_OTHER),
+            _ => Err(value),
+        }
+    }
+}
+
+/// This interface is the owner of the CoreWebView2 object, and provides support
+/// for resizing, showing and hiding, focusing, and other functionality related
+/// to windowing and composition. The CoreWebView2Host owns the CoreWebView2,
+/// and if all references to the CoreWebView2Host go away, the WebView will
+/// be closed.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller>
+#[com_interface("6ddf7138-a19b-4e55-8994-8a198b07f492")]
+pub trait ICoreWebView2Host: IUnknown {
+    /// The IsVisible property determines whether to show or hide the webview.
+    /// If IsVisible is set to false, the webview will be transparent and will
+    /// not be rendered.  However, this will not affect the window containing
+    /// the webview (the HWND parameter that was passed to CreateCoreWebView2Host).
+    /// If you want that window to disappear too, call ShowWindow on it directly
+    /// in addition to modifying the IsVisible property.
+    /// WebView as a child window won't get window messages when the top window
+    /// is minimized or restored. For performance reason, developer should set
+    /// IsVisible property of the WebView to false when the app window is
+    /// minimized and back to true when app window is restored. App window can do
+    /// this by handling SC_MINIMIZE and SC_RESTORE command upon receiving
+    /// WM_SYSCOMMAND message.
+    ///
+    /// \snippet ViewComponent.cpp ToggleIsVisible
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#get_isvisible>
+    #[doc(alias = "get_IsVisible")]
+    unsafe fn get_is_visible(&self, /* out, retval */ isVisible: *mut BOOL) -> HRESULT;
+
+    /// Set the IsVisible property.
+    ///
+    /// \snippet ViewComponent.cpp ToggleIsVisibleOnMinimize
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#put_isvisible>
+    #[doc(alias = "put_IsVisible")]
+    unsafe fn put_is_visible(&self, /* in */ isVisible: BOOL) -> HRESULT;
+
+    /// The webview bounds.
+    /// Bounds are relative to the parent HWND. The app has two ways it can
+    /// position a WebView:
+    /// 1. Create a child HWND that is the WebView parent HWND. Position this
+    ///    window where the WebView should be. In this case, use (0, 0) for the
+    ///    WebView's Bound's top left corner (the offset).
+    /// 2. Use the app's top most window as the WebView parent HWND. Set the
+    ///    WebView's Bound's top left corner so that the WebView is positioned
+    ///    correctly in the app.
+    /// The Bound's values are in the host's coordinate space.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#get_bounds>
+    #[doc(alias = "get_Bounds")]
+    unsafe fn get_bounds(&self, /* out, retval */ bounds: *mut RECT) -> HRESULT;
+
+    /// Set the Bounds property.
+    ///
+    /// \snippet ViewComponent.cpp ResizeWebView
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#put_bounds>
+    #[doc(alias = "put_Bounds")]
+    unsafe fn put_bounds(&self, /* in */ bounds: RECT) -> HRESULT;
+
+    /// The zoom factor for the WebView.
+    /// Note that changing zoom factor could cause `window.innerWidth/innerHeight`
+    /// and page layout to change.
+    /// A zoom factor that is applied by the host by calling put_ZoomFactor
+    /// becomes the new default zoom for the WebView. This zoom factor applies
+    /// across navigations and is the zoom factor WebView is returned to when the
+    /// user presses ctrl+0. When the zoom factor is changed by the user
+    /// (resulting in the app receiving ZoomFactorChanged), that zoom applies
+    /// only for the current page. Any user applied zoom is only for the current
+    /// page and is reset on a navigation.
+    /// Specifying a zoomFactor less than or equal to 0 is not allowed.
+    /// WebView also has an internal supported zoom factor range. When a specified
+    /// zoom factor is out of that range, it will be normalized to be within the
+    /// range, and a ZoomFactorChanged event will be fired for the real
+    /// applied zoom factor. When this range normalization happens, the
+    /// ZoomFactor property will report the zoom factor specified during the
+    /// previous modification of the ZoomFactor property until the
+    /// ZoomFactorChanged event is received after webview applies the normalized
+    /// zoom factor.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#get_zoomfactor>
+    #[doc(alias = "get_ZoomFactor")]
+    unsafe fn get_zoom_factor(&self, /* out, retval */ zoomFactor: *mut f64) -> HRESULT;
+
+    /// Set the ZoomFactor property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#put_zoomfactor>
+    #[doc(alias = "put_ZoomFactor")]
+    unsafe fn put_zoom_factor(&self, /* in */ zoomFactor: f64) -> HRESULT;
+
+    /// Add an event handler for the ZoomFactorChanged event.
+    /// The event fires when the ZoomFactor property of the WebView changes.
+    /// The event could fire because the caller modified the ZoomFactor property,
+    /// or due to the user manually modifying the zoom. When it is modified by the
+    /// caller via the ZoomFactor property, the internal zoom factor is updated
+    /// immediately and there will be no ZoomFactorChanged event.
+    /// WebView associates the last used zoom factor for each site. Therefore, it
+    /// is possible for the zoom factor to change when navigating to a different
+    /// page. When the zoom factor changes due to this, the ZoomFactorChanged
+    /// event fires right after the ContentLoading event.
+    ///
+    /// \snippet ViewComponent.cpp ZoomFactorChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#add_zoomfactorchanged>
+    #[doc(alias = "add_ZoomFactorChanged")]
+    unsafe fn add_zoom_factor_changed(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2ZoomFactorChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_ZoomFactorChanged.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#remove_zoomfactorchanged>
+    #[doc(alias = "remove_ZoomFactorChanged")]
+    unsafe fn remove_zoom_factor_changed(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Update Bounds and ZoomFactor properties at the same time. This operation
+    /// is atomic from the host's perspecive. After returning from this function,
+    /// the Bounds and ZoomFactor properties will have both been updated if the
+    /// function is successful, or neither will be updated if the function fails.
+    /// If Bounds and ZoomFactor are both updated by the same scale (i.e. Bounds
+    /// and ZoomFactor are both doubled), then the page will not see a change in
+    /// window.innerWidth/innerHeight and the WebView will render the content at
+    /// the new size and zoom without intermediate renderings.
+    /// This function can also be used to update just one of ZoomFactor or Bounds
+    /// by passing in the new value for one and the current value for the other.
+    ///
+    /// \snippet ViewComponent.cpp SetBoundsAndZoomFactor
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#setboundsandzoomfactor>
+    #[doc(alias = "SetBoundsAndZoomFactor")]
+    unsafe fn set_bounds_and_zoom_factor(
+        &self,
+        /* in */ bounds: RECT,
+        /* in */ zoomFactor: f64,
+    ) -> HRESULT;
+
+    /// Move focus into WebView. WebView will get focus and focus will be set to
+    /// correspondent element in the page hosted in the WebView.
+    /// For Programmatic reason, focus is set to previously focused element or
+    /// the default element if there is no previously focused element.
+    /// For Next reason, focus is set to the first element.
+    /// For Previous reason, focus is set to the last element.
+    /// WebView can also got focus through user interaction like clicking into
+    /// WebView or Tab into it.
+    /// For tabbing, the app can call MoveFocus with Next or Previous to align
+    /// with tab and shift+tab respectively when it decides the WebView is the
+    /// next tabbable element. Or, the app can call IsDialogMessage as part of
+    /// its message loop to allow the platform to auto handle tabbing. The
+    /// platform will rotate through all windows with WS_TABSTOP. When the
+    /// WebView gets focus from IsDialogMessage, it will internally put the focus
+    /// on the first or last element for tab and shift+tab respectively.
+    ///
+    /// \snippet App.cpp MoveFocus0
+    ///
+    /// \snippet ControlComponent.cpp MoveFocus1
+    ///
+    /// \snippet ControlComponent.cpp MoveFocus2
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#movefocus>
+    #[doc(alias = "MoveFocus")]
+    unsafe fn move_focus(&self, /* in */ reason: CORE_WEBVIEW2_MOVE_FOCUS_REASON) -> HRESULT;
+
+    /// Add an event handler for the MoveFocusRequested event.
+    /// MoveFocusRequested fires when user tries to tab out of the WebView.
+    /// The WebView's focus has not changed when this event is fired.
+    ///
+    /// \snippet ControlComponent.cpp MoveFocusRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#add_movefocusrequested>
+    #[doc(alias = "add_MoveFocusRequested")]
+    unsafe fn add_move_focus_requested(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2MoveFocusRequestedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_MoveFocusRequested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#remove_movefocusrequested>
+    #[doc(alias = "remove_MoveFocusRequested")]
+    unsafe fn remove_move_focus_requested(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Add an event handler for the GotFocus event.
+    /// GotFocus fires when WebView got focus.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#add_gotfocus>
+    #[doc(alias = "add_GotFocus")]
+    unsafe fn add_got_focus(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2FocusChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_GotFocus.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#remove_gotfocus>
+    #[doc(alias = "remove_GotFocus")]
+    unsafe fn remove_got_focus(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// Add an event handler for the LostFocus event.
+    /// LostFocus fires when WebView lost focus.
+    /// In the case where MoveFocusRequested event is fired, the focus is still
+    /// on WebView when MoveFocusRequested event fires. Lost focus only fires
+    /// afterwards when app's code or default action of MoveFocusRequested event
+    /// set focus away from WebView.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#add_lostfocus>
+    #[doc(alias = "add_LostFocus")]
+    unsafe fn add_lost_focus(
+        &self,
+        /* in */ eventHandler: *mut *mut ICoreWebView2FocusChangedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_LostFocus.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#remove_lostfocus>
+    #[doc(alias = "remove_LostFocus")]
+    unsafe fn remove_lost_focus(&self, /* in */ token: EventRegistrationToken) -> HRESULT;
+
+    /// Add an event handler for the AcceleratorKeyPressed event.
+    /// AcceleratorKeyPressed fires when an accelerator key or key combo is
+    /// pressed or released while the WebView is focused. A key is considered an
+    /// accelerator if either:
+    ///   1. Ctrl or Alt is currently being held, or
+    ///   2. the pressed key does not map to a character.
+    /// A few specific keys are never considered accelerators, such as Shift.
+    /// The Escape key is always considered an accelerator.
+    ///
+    /// Autorepeated key events caused by holding the key down will also fire this
+    /// event.  You can filter these out by checking the event args'
+    /// KeyEventLParam or PhysicalKeyStatus.
+    ///
+    /// In windowed mode, this event handler is called synchronously. Until you
+    /// call Handle() on the event args or the event handler returns, the browser
+    /// process will be blocked and outgoing cross-process COM calls will fail
+    /// with RPC_E_CANTCALLOUT_ININPUTSYNCCALL. All CoreWebView2 API methods will
+    /// work, however.
+    ///
+    /// In windowless mode, the event handler is called asynchronously.  Further
+    /// input will not reach the browser until the event handler returns or
+    /// Handle() is called, but the browser process itself will not be blocked,
+    /// and outgoing COM calls will work normally.
+    ///
+    /// It is recommended to call Handle(TRUE) as early as you can know that you want
+    /// to handle the accelerator key.
+    ///
+    /// \snippet ControlComponent.cpp AcceleratorKeyPressed
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#add_acceleratorkeypressed>
+    #[doc(alias = "add_AcceleratorKeyPressed")]
+    unsafe fn add_accelerator_key_pressed(
+        &self,
+        /* in */
+        eventHandler: *mut *mut ICoreWebView2AcceleratorKeyPressedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_AcceleratorKeyPressed.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#remove_acceleratorkeypressed>
+    #[doc(alias = "remove_AcceleratorKeyPressed")]
+    unsafe fn remove_accelerator_key_pressed(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// The parent window provided by the app that this WebView is using to
+    /// render content. This API initially returns the window passed into
+    /// CreateCoreWebView2Host.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#get_parentwindow>
+    #[doc(alias = "get_ParentWindow")]
+    unsafe fn get_parent_window(&self, /* out, retval */ topLevelWindow: *mut HWND) -> HRESULT;
+
+    /// Set the parent window for the WebView. This will cause the WebView to
+    /// reparent its window to the newly provided window.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#put_parentwindow>
+    #[doc(alias = "put_ParentWindow")]
+    unsafe fn put_parent_window(&self, /* in */ topLevelWindow: HWND) -> HRESULT;
+
+    /// This is a notification separate from put_Bounds that tells WebView its
+    /// parent (or any ancestor) HWND moved. This is needed for accessibility and
+    /// certain dialogs in WebView to work correctly.
+    /// \snippet AppWindow.cpp NotifyParentWindowPositionChanged
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#notifyparentwindowpositionchanged>
+    #[doc(alias = "NotifyParentWindowPositionChanged")]
+    unsafe fn notify_parent_window_position_changed(&self) -> HRESULT;
+
+    /// Closes the WebView and cleans up the underlying browser instance.
+    /// Cleaning up the browser instace will release the resources powering the WebView.
+    /// The browser instance will be shut down if there are no other WebViews using it.
+    ///
+    /// After calling Close, all method calls will fail and event handlers
+    /// will stop firing. Specifically, the WebView will release its references
+    /// to its event handlers when Close is called.
+    ///
+    /// Close is implicitly called when the CoreWebView2Host loses its final
+    /// reference and is destructed. But it is best practice to explicitly call
+    /// Close to avoid any accidental cycle of references between the WebView
+    /// and the app code. Specifically, if you capture a reference to the WebView
+    /// in an event handler you will create a reference cycle between the WebView
+    /// and the event handler. Calling Close will break this cycle by releasing
+    /// all event handlers. But to avoid this situation it is best practice both
+    /// to explicitly call Close on the WebView and to not capture a reference to
+    /// the WebView to ensure the WebView can be cleaned up correctly.
+    ///
+    /// \snippet AppWindow.cpp Close
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#close>
+    #[doc(alias = "Close")]
+    unsafe fn close(&self) -> HRESULT;
+
+    /// Gets the CoreWebView2 associated with this CoreWebView2Host.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller#get_corewebview2>
+    #[doc(alias = "get_CoreWebView2")]
+    unsafe fn get_core_web_view2(
+        &self,
+        /* out, retval */ coreWebView2: *mut *mut *mut ICoreWebView2VTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2Host`.
+pub const IID_ICoreWebView2Host: GUID = GUID {
+    Data1: 0x6ddf7138,
+    Data2: 0xa19b,
+    Data3: 0x4e55,
+    Data4: [0x89, 0x94, 0x8a, 0x19, 0x8b, 0x07, 0xf4, 0x92],
+};
+
+/// Reason for moving focus.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_MOVE_FOCUS_REASON {
+    /// Code setting focus into WebView.
+    CORE_WEBVIEW2_MOVE_FOCUS_REASON_PROGRAMMATIC,
+    /// Moving focus due to Tab traversal forward.
+    CORE_WEBVIEW2_MOVE_FOCUS_REASON_NEXT,
+    /// Moving focus due to Tab traversal backward.
+    CORE_WEBVIEW2_MOVE_FOCUS_REASON_PREVIOUS,
+}
+
+impl CORE_WEBVIEW2_MOVE_FOCUS_REASON {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_MOVE_FOCUS_REASON {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_MOVE_FOCUS_REASON_PROGRAMMATIC),
+            1 => Ok(Self::CORE_WEBVIEW2_MOVE_FOCUS_REASON_NEXT),
+            2 => Ok(Self::CORE_WEBVIEW2_MOVE_FOCUS_REASON_PREVIOUS),
+            _ => Err(value),
+        }
+    }
+}
+
+/// The type of key event that triggered an AcceleratorKeyPressed event.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CORE_WEBVIEW2_KEY_EVENT_KIND {
+    /// Correspond to window message WM_KEYDOWN.
+    CORE_WEBVIEW2_KEY_EVENT_KIND_KEY_DOWN,
+    /// Correspond to window message WM_KEYUP.
+    CORE_WEBVIEW2_KEY_EVENT_KIND_KEY_UP,
+    /// Correspond to window message WM_SYSKEYDOWN.
+    CORE_WEBVIEW2_KEY_EVENT_KIND_SYSTEM_KEY_DOWN,
+    /// Correspond to window message WM_SYSKEYUP.
+    CORE_WEBVIEW2_KEY_EVENT_KIND_SYSTEM_KEY_UP,
+}
+
+impl CORE_WEBVIEW2_KEY_EVENT_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for CORE_WEBVIEW2_KEY_EVENT_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::CORE_WEBVIEW2_KEY_EVENT_KIND_KEY_DOWN),
+            1 => Ok(Self::CORE_WEBVIEW2_KEY_EVENT_KIND_KEY_UP),
+            2 => Ok(Self::CORE_WEBVIEW2_KEY_EVENT_KIND_SYSTEM_KEY_DOWN),
+            3 => Ok(Self::CORE_WEBVIEW2_KEY_EVENT_KIND_SYSTEM_KEY_UP),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A structure representing the information packed into the LPARAM given
+/// to a Win32 key event.  See the documentation for WM_KEYDOWN for details
+/// at https://docs.microsoft.com/windows/win32/inputdev/wm-keydown
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CORE_WEBVIEW2_PHYSICAL_KEY_STATUS {
+    /// The repeat count for the current message.
+    pub RepeatCount: UINT32,
+    /// The scan code.
+    pub ScanCode: UINT32,
+    /// Indicates whether the key is an extended key.
+    pub IsExtendedKey: BOOL,
+    /// The context code.
+    pub IsMenuKeyDown: BOOL,
+    /// The previous key state.
+    pub WasKeyDown: BOOL,
+    /// The transition state.
+    pub IsKeyReleased: BOOL,
+}
+
+const _: () = assert!(std::mem::size_of::<CORE_WEBVIEW2_PHYSICAL_KEY_STATUS>() == 24);
+
+/// This interface is used to complete deferrals on event args that
+/// support getting deferrals via their GetDeferral method.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2deferral>
+#[com_interface("C1000D7C-4817-40EB-A2AE-3B929D5A8EE3")]
+pub trait ICoreWebView2Deferral: IUnknown {
+    /// Completes the associated deferred event. Complete should only be
+    /// called once for each deferral taken.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2deferral#complete>
+    #[doc(alias = "Complete")]
+    unsafe fn complete(&self) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2Deferral`.
+pub const IID_ICoreWebView2Deferral: GUID = GUID {
+    Data1: 0xC1000D7C,
+    Data2: 0x4817,
+    Data3: 0x40EB,
+    Data4: [0xA2, 0xAE, 0x3B, 0x92, 0x9D, 0x5A, 0x8E, 0xE3],
+};
+
+/// Defines properties that enable, disable, or modify WebView
+/// features. Setting changes made after NavigationStarting event will not
+/// apply until the next top level navigation.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings>
+#[com_interface("D58A964A-13C4-44FB-81AD-64AE242E9ADC")]
+pub trait ICoreWebView2Settings: IUnknown {
+    /// Controls if JavaScript execution is enabled in all future
+    /// navigations in the WebView.  This only affects scripts in the document;
+    /// scripts injected with ExecuteScript will run even if script is disabled.
+    /// It is true by default.
+    ///
+    /// \snippet SettingsComponent.cpp IsScriptEnabled
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_isscriptenabled>
+    #[doc(alias = "get_IsScriptEnabled")]
+    unsafe fn get_is_script_enabled(
+        &self,
+        /* out, retval */ isScriptEnabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the IsScriptEnabled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_isscriptenabled>
+    #[doc(alias = "put_IsScriptEnabled")]
+    unsafe fn put_is_script_enabled(&self, /* in */ isScriptEnabled: BOOL) -> HRESULT;
+
+    /// The IsWebMessageEnabled property is used when loading a new
+    /// HTML document. If set to true, communication from the host to the
+    /// webview's top level HTML document is allowed via PostWebMessageAsJson,
+    /// PostWebMessageAsString, and window.chrome.webview's message event
+    /// (see PostWebMessageAsJson documentation for details).
+    /// Communication from the webview's top level HTML document
+    /// to the host is allowed via window.chrome.webview's postMessage function
+    /// and the SetWebMessageReceivedEventHandler method (see the
+    /// SetWebMessageReceivedEventHandler documentation for details).
+    /// If set to false, then communication is disallowed.
+    /// PostWebMessageAsJson and PostWebMessageAsString will
+    /// fail with E_ACCESSDENIED and window.chrome.webview.postMessage will fail
+    /// by throwing an instance of an Error object.
+    /// It is true by default.
+    ///
+    /// \snippet ScenarioWebMessage.cpp IsWebMessageEnabled
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_iswebmessageenabled>
+    #[doc(alias = "get_IsWebMessageEnabled")]
+    unsafe fn get_is_web_message_enabled(
+        &self,
+        /* out, retval */ isWebMessageEnabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the IsWebMessageEnabled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_iswebmessageenabled>
+    #[doc(alias = "put_IsWebMessageEnabled")]
+    unsafe fn put_is_web_message_enabled(&self, /* in */ isWebMessageEnabled: BOOL) -> HRESULT;
+
+    /// AreDefaultScriptDialogsEnabled is used when loading a new
+    /// HTML document. If set to false, then WebView won't render the default
+    /// javascript dialog box (Specifically those shown by the javascript alert,
+    /// confirm, prompt functions and beforeunload event). Instead, if an event
+    /// handler is set by SetScriptDialogOpeningEventHandler, WebView will send an
+    /// event that will contain all of the information for the dialog and allow
+    /// the host app to show its own custom UI.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_aredefaultscriptdialogsenabled>
+    #[doc(alias = "get_AreDefaultScriptDialogsEnabled")]
+    unsafe fn get_are_default_script_dialogs_enabled(
+        &self,
+        /* out, retval */ areDefaultScriptDialogsEnabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the AreDefaultScriptDialogsEnabled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_aredefaultscriptdialogsenabled>
+    #[doc(alias = "put_AreDefaultScriptDialogsEnabled")]
+    unsafe fn put_are_default_script_dialogs_enabled(
+        &self,
+        /* in */ areDefaultScriptDialogsEnabled: BOOL,
+    ) -> HRESULT;
+
+    /// IsStatusBarEnabled controls whether the status bar will be displayed. The
+    /// status bar is usually displayed in the lower left of the WebView and shows
+    /// things such as the URI of a link when the user hovers over it and other
+    /// information. It is true by default.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_isstatusbarenabled>
+    #[doc(alias = "get_IsStatusBarEnabled")]
+    unsafe fn get_is_status_bar_enabled(
+        &self,
+        /* out, retval */ isStatusBarEnabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the IsStatusBarEnabled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_isstatusbarenabled>
+    #[doc(alias = "put_IsStatusBarEnabled")]
+    unsafe fn put_is_status_bar_enabled(&self, /* in */ isStatusBarEnabled: BOOL) -> HRESULT;
+
+    /// AreDevToolsEnabled controls whether the user is able to use the context
+    /// menu or keyboard shortcuts to open the DevTools window.
+    /// It is true by default.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_aredevtoolsenabled>
+    #[doc(alias = "get_AreDevToolsEnabled")]
+    unsafe fn get_are_dev_tools_enabled(
+        &self,
+        /* out, retval */ areDevToolsEnabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the AreDevToolsEnabled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_aredevtoolsenabled>
+    #[doc(alias = "put_AreDevToolsEnabled")]
+    unsafe fn put_are_dev_tools_enabled(&self, /* in */ areDevToolsEnabled: BOOL) -> HRESULT;
+
+    /// The AreDefaultContextMenusEnabled property is used to prevent
+    /// default context menus from being shown to user in webview. Defaults to TRUE.
+    ///
+    /// \snippet SettingsComponent.cpp DisableContextMenu
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_aredefaultcontextmenusenabled>
+    #[doc(alias = "get_AreDefaultContextMenusEnabled")]
+    unsafe fn get_are_default_context_menus_enabled(
+        &self,
+        /* out, retval */ enabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the AreDefaultContextMenusEnabled property
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_aredefaultcontextmenusenabled>
+    #[doc(alias = "put_AreDefaultContextMenusEnabled")]
+    unsafe fn put_are_default_context_menus_enabled(&self, /* in */ enabled: BOOL) -> HRESULT;
+
+    /// The AreRemoteObjectsAllowed property is used to control whether
+    /// remote objects are accessible from the page in webview. Defaults to TRUE.
+    ///
+    /// \snippet SettingsComponent.cpp RemoteObjectsAccess
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_areremoteobjectsallowed>
+    #[doc(alias = "get_AreRemoteObjectsAllowed")]
+    unsafe fn get_are_remote_objects_allowed(
+        &self,
+        /* out, retval */ allowed: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the AreRemoteObjectsAllowed property
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_areremoteobjectsallowed>
+    #[doc(alias = "put_AreRemoteObjectsAllowed")]
+    unsafe fn put_are_remote_objects_allowed(&self, /* in */ allowed: BOOL) -> HRESULT;
+
+    /// The IsZoomControlEnabled property is used to prevent the user from
+    /// impacting the zoom of the WebView. Defaults to TRUE.
+    /// When disabled, user will not be able to zoom using ctrl+/- or
+    /// ctrl+mouse wheel, but the zoom can be set via put_ZoomFactor API.
+    ///
+    /// \snippet SettingsComponent.cpp DisableZoomControl
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#get_iszoomcontrolenabled>
+    #[doc(alias = "get_IsZoomControlEnabled")]
+    unsafe fn get_is_zoom_control_enabled(
+        &self,
+        /* out, retval */ enabled: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the IsZoomControlEnabled property
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2settings#put_iszoomcontrolenabled>
+    #[doc(alias = "put_IsZoomControlEnabled")]
+    unsafe fn put_is_zoom_control_enabled(&self, /* in */ enabled: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2Settings`.
+pub const IID_ICoreWebView2Settings: GUID = GUID {
+    Data1: 0xD58A964A,
+    Data2: 0x13C4,
+    Data3: 0x44FB,
+    Data4: [0x81, 0xAD, 0x64, 0xAE, 0x24, 0x2E, 0x9A, 0xDC],
+};
+
+/// Event args for the ProcessFailed event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processfailedeventargs>
+#[com_interface("9E354785-CFA2-480A-84E0-57837ADD8E36")]
+pub trait ICoreWebView2ProcessFailedEventArgs: IUnknown {
+    /// The kind of process failure that has occurred.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processfailedeventargs#get_processfailedkind>
+    #[doc(alias = "get_ProcessFailedKind")]
+    unsafe fn get_process_failed_kind(
+        &self,
+        /* out, retval */ processFailedKind: *mut CORE_WEBVIEW2_PROCESS_FAILED_KIND,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ProcessFailedEventArgs`.
+pub const IID_ICoreWebView2ProcessFailedEventArgs: GUID = GUID {
+    Data1: 0x9E354785,
+    Data2: 0xCFA2,
+    Data3: 0x480A,
+    Data4: [0x84, 0xE0, 0x57, 0x83, 0x7A, 0xDD, 0x8E, 0x36],
+};
+
+/// The caller implements this interface to receive ProcessFailed events.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processfailedeventhandler>
+#[com_interface("A85C66A9-DE47-47F7-AD64-ABB32F1CF14D")]
+pub trait ICoreWebView2ProcessFailedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processfailedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2ProcessFailedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ProcessFailedEventHandler`.
+pub const IID_ICoreWebView2ProcessFailedEventHandler: GUID = GUID {
+    Data1: 0xA85C66A9,
+    Data2: 0xDE47,
+    Data3: 0x47F7,
+    Data4: [0xAD, 0x64, 0xAB, 0xB3, 0x2F, 0x1C, 0xF1, 0x4D],
+};
+
+/// The caller implements this interface to receive ZoomFactorChanged
+/// events. Use the ICoreWebView2Host.ZoomFactor property to get the
+/// modified zoom factor.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2zoomfactorchangedeventhandler>
+#[com_interface("1B03A40F-92B7-443A-87E0-B65714B6CB9D")]
+pub trait ICoreWebView2ZoomFactorChangedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event. There are no event args and the args
+    /// parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2zoomfactorchangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2HostVTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ZoomFactorChangedEventHandler`.
+pub const IID_ICoreWebView2ZoomFactorChangedEventHandler: GUID = GUID {
+    Data1: 0x1B03A40F,
+    Data2: 0x92B7,
+    Data3: 0x443A,
+    Data4: [0x87, 0xE0, 0xB6, 0x57, 0x14, 0xB6, 0xCB, 0x9D],
+};
+
+/// Iterator for a collection of HTTP headers. See ICoreWebView2HttpRequestHeaders
+/// and ICoreWebView2HttpResponseHeaders.
+/// \snippet ScenarioWebViewEventMonitor.cpp HttpRequestHeaderIterator
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpheaderscollectioniterator>
+#[com_interface("B0F8A736-CC49-4414-BB9C-FDBC02599622")]
+pub trait ICoreWebView2HttpHeadersCollectionIterator: IUnknown {
+    /// Get the name and value of the current HTTP header of the iterator. This
+    /// method will fail if the last call to MoveNext set has_next to FALSE.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpheaderscollectioniterator#getcurrentheader>
+    #[doc(alias = "GetCurrentHeader")]
+    unsafe fn get_current_header(
+        &self,
+        /* out */ name: *mut LPWSTR,
+        /* out */ value: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// True when the iterator hasn't run out of headers. If the collection over
+    /// which the iterator is iterating is empty or if the iterator has gone past
+    /// the end of the collection then this is false.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpheaderscollectioniterator#get_hascurrentheader>
+    #[doc(alias = "get_HasCurrentHeader")]
+    unsafe fn get_has_current_header(
+        &self,
+        /* out, retval */ hasCurrent: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Move the iterator to the next HTTP header in the collection. The hasNext
+    /// parameter will be set to FALSE if there are no more HTTP headers. After
+    /// this occurs the GetCurrentHeader method will fail if called.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpheaderscollectioniterator#movenext>
+    #[doc(alias = "MoveNext")]
+    unsafe fn move_next(&self, /* out */ hasNext: *mut BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2HttpHeadersCollectionIterator`.
+pub const IID_ICoreWebView2HttpHeadersCollectionIterator: GUID = GUID {
+    Data1: 0xB0F8A736,
+    Data2: 0xCC49,
+    Data3: 0x4414,
+    Data4: [0xBB, 0x9C, 0xFD, 0xBC, 0x02, 0x59, 0x96, 0x22],
+};
+
+/// HTTP request headers. Used to inspect the HTTP request on
+/// WebResourceRequested event and NavigationStarting event.
+/// Note, you can modify the HTTP request headers from a WebResourceRequested event,
+/// but not from a NavigationStarting event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders>
+#[com_interface("160B895B-D0AF-4A42-A14F-5571CFA68B03")]
+pub trait ICoreWebView2HttpRequestHeaders: IUnknown {
+    /// Gets the header value matching the name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#getheader>
+    #[doc(alias = "GetHeader")]
+    unsafe fn get_header(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */ value: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// Gets the header value matching the name via an iterator.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#getheaders>
+    #[doc(alias = "GetHeaders")]
+    unsafe fn get_headers(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */
+        iterator: *mut *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable,
+    ) -> HRESULT;
+
+    /// Checks whether the headers contain an entry matching the header name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#contains>
+    #[doc(alias = "Contains")]
+    unsafe fn contains(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */ contains: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Adds or updates header that matches the name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#setheader>
+    #[doc(alias = "SetHeader")]
+    unsafe fn set_header(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* in */ value: LPCWSTR,
+    ) -> HRESULT;
+
+    /// Removes header that matches the name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#removeheader>
+    #[doc(alias = "RemoveHeader")]
+    unsafe fn remove_header(&self, /* in */ name: LPCWSTR) -> HRESULT;
+
+    /// Gets an iterator over the collection of request headers.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httprequestheaders#getiterator>
+    #[doc(alias = "GetIterator")]
+    unsafe fn get_iterator(
+        &self,
+        /* out, retval */
+        iterator: *mut *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2HttpRequestHeaders`.
+pub const IID_ICoreWebView2HttpRequestHeaders: GUID = GUID {
+    Data1: 0x160B895B,
+    Data2: 0xD0AF,
+    Data3: 0x4A42,
+    Data4: [0xA1, 0x4F, 0x55, 0x71, 0xCF, 0xA6, 0x8B, 0x03],
+};
+
+/// HTTP response headers. Used to construct a WebResourceResponse for the
+/// WebResourceRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders>
+#[com_interface("3E81928E-DDAE-4B3C-BCEF-DB2752BCFA1E")]
+pub trait ICoreWebView2HttpResponseHeaders: IUnknown {
+    /// Appends header line with name and value.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders#appendheader>
+    #[doc(alias = "AppendHeader")]
+    unsafe fn append_header(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* in */ value: LPCWSTR,
+    ) -> HRESULT;
+
+    /// Checks whether the headers contain entries matching the header name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders#contains>
+    #[doc(alias = "Contains")]
+    unsafe fn contains(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */ contains: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Gets the first header value in the collection matching the name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders#getheader>
+    #[doc(alias = "GetHeader")]
+    unsafe fn get_header(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */ value: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// Gets the header values matching the name.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders#getheaders>
+    #[doc(alias = "GetHeaders")]
+    unsafe fn get_headers(
+        &self,
+        /* in */ name: LPCWSTR,
+        /* out, retval */
+        iterator: *mut *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable,
+    ) -> HRESULT;
+
+    /// Gets an iterator over the collection of entire response headers.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2httpresponseheaders#getiterator>
+    #[doc(alias = "GetIterator")]
+    unsafe fn get_iterator(
+        &self,
+        /* out, retval */
+        iterator: *mut *mut *mut ICoreWebView2HttpHeadersCollectionIteratorVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2HttpResponseHeaders`.
+pub const IID_ICoreWebView2HttpResponseHeaders: GUID = GUID {
+    Data1: 0x3E81928E,
+    Data2: 0xDDAE,
+    Data3: 0x4B3C,
+    Data4: [0xBC, 0xEF, 0xDB, 0x27, 0x52, 0xBC, 0xFA, 0x1E],
+};
+
+/// An HTTP request used with the WebResourceRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest>
+#[com_interface("7471A125-D5E8-45A8-B119-F9E9230D4D0B")]
+pub trait ICoreWebView2WebResourceRequest: IUnknown {
+    /// The request URI.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#get_uri>
+    #[doc(alias = "get_Uri")]
+    unsafe fn get_uri(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// Set the Uri property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#put_uri>
+    #[doc(alias = "put_Uri")]
+    unsafe fn put_uri(&self, /* in */ uri: LPCWSTR) -> HRESULT;
+
+    /// The HTTP request method.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#get_method>
+    #[doc(alias = "get_Method")]
+    unsafe fn get_method(&self, /* out, retval */ method: *mut LPWSTR) -> HRESULT;
+
+    /// Set the Method property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#put_method>
+    #[doc(alias = "put_Method")]
+    unsafe fn put_method(&self, /* in */ method: LPCWSTR) -> HRESULT;
+
+    /// The HTTP request message body as stream. POST data would be here.
+    /// If a stream is set, which will override the message body, the stream must
+    /// have all the content data available by the time this
+    /// response's WebResourceRequested event deferral is completed. Stream
+    /// should be agile or be created from a background STA to prevent performance
+    /// impact to the UI thread. Null means no content data. IStream semantics
+    /// apply (return S_OK to Read calls until all data is exhausted)
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#get_content>
+    #[doc(alias = "get_Content")]
+    unsafe fn get_content(
+        &self,
+        /* out, retval */ content: *mut *mut *mut IStreamVTable,
+    ) -> HRESULT;
+
+    /// Set the Content property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#put_content>
+    #[doc(alias = "put_Content")]
+    unsafe fn put_content(&self, /* in */ content: *mut *mut IStreamVTable) -> HRESULT;
+
+    /// The mutable HTTP request headers
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequest#get_headers>
+    #[doc(alias = "get_Headers")]
+    unsafe fn get_headers(
+        &self,
+        /* out, retval */ headers: *mut *mut *mut ICoreWebView2HttpRequestHeadersVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebResourceRequest`.
+pub const IID_ICoreWebView2WebResourceRequest: GUID = GUID {
+    Data1: 0x7471A125,
+    Data2: 0xD5E8,
+    Data3: 0x45A8,
+    Data4: [0xB1, 0x19, 0xF9, 0xE9, 0x23, 0x0D, 0x4D, 0x0B],
+};
+
+/// An HTTP response used with the WebResourceRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse>
+#[com_interface("2B842125-E3B4-40A2-8BB8-C31AABF70E0A")]
+pub trait ICoreWebView2WebResourceResponse: IUnknown {
+    /// HTTP response content as stream. Stream must have all the
+    /// content data available by the time this response's WebResourceRequested
+    /// event deferral is completed. Stream should be agile or be created from
+    /// a background thread to prevent performance impact to the UI thread.
+    /// Null means no content data. IStream semantics
+    /// apply (return S_OK to Read calls until all data is exhausted)
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#get_content>
+    #[doc(alias = "get_Content")]
+    unsafe fn get_content(
+        &self,
+        /* out, retval */ content: *mut *mut *mut IStreamVTable,
+    ) -> HRESULT;
+
+    /// Set the Content property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#put_content>
+    #[doc(alias = "put_Content")]
+    unsafe fn put_content(&self, /* in */ content: *mut *mut IStreamVTable) -> HRESULT;
+
+    /// Overridden HTTP response headers.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#get_headers>
+    #[doc(alias = "get_Headers")]
+    unsafe fn get_headers(
+        &self,
+        /* out, retval */ headers: *mut *mut *mut ICoreWebView2HttpResponseHeadersVTable,
+    ) -> HRESULT;
+
+    /// The HTTP response status code.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#get_statuscode>
+    #[doc(alias = "get_StatusCode")]
+    unsafe fn get_status_code(&self, /* out, retval */ statusCode: *mut i32) -> HRESULT;
+
+    /// Set the StatusCode property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#put_statuscode>
+    #[doc(alias = "put_StatusCode")]
+    unsafe fn put_status_code(&self, /* in */ statusCode: i32) -> HRESULT;
+
+    /// The HTTP response reason phrase
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#get_reasonphrase>
+    #[doc(alias = "get_ReasonPhrase")]
+    unsafe fn get_reason_phrase(&self, /* out, retval */ reasonPhrase: *mut LPWSTR) -> HRESULT;
+
+    /// Set the ReasonPhrase property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourceresponse#put_reasonphrase>
+    #[doc(alias = "put_ReasonPhrase")]
+    unsafe fn put_reason_phrase(&self, /* in */ reasonPhrase: LPCWSTR) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebResourceResponse`.
+pub const IID_ICoreWebView2WebResourceResponse: GUID = GUID {
+    Data1: 0x2B842125,
+    Data2: 0xE3B4,
+    Data3: 0x40A2,
+    Data4: [0x8B, 0xB8, 0xC3, 0x1A, 0xAB, 0xF7, 0x0E, 0x0A],
+};
+
+/// Event args for the NavigationStarting event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs>
+#[com_interface("1C81A448-575B-44A1-9ABD-1B93A3DE9E03")]
+pub trait ICoreWebView2NavigationStartingEventArgs: IUnknown {
+    /// The uri of the requested navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_uri>
+    #[doc(alias = "get_Uri")]
+    unsafe fn get_uri(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// True when the navigation was initiated through a user gesture as opposed
+    /// to programmatic navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_isuserinitiated>
+    #[doc(alias = "get_IsUserInitiated")]
+    unsafe fn get_is_user_initiated(
+        &self,
+        /* out, retval */ isUserInitiated: *mut BOOL,
+    ) -> HRESULT;
+
+    /// True when the navigation is redirected.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_isredirected>
+    #[doc(alias = "get_IsRedirected")]
+    unsafe fn get_is_redirected(&self, /* out, retval */ isRedirected: *mut BOOL) -> HRESULT;
+
+    /// The HTTP request headers for the navigation.
+    /// Note, you cannot modify the HTTP request headers in a NavigationStarting event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_requestheaders>
+    #[doc(alias = "get_RequestHeaders")]
+    unsafe fn get_request_headers(
+        &self,
+        /* out, retval */
+        requestHeaders: *mut *mut *mut ICoreWebView2HttpRequestHeadersVTable,
+    ) -> HRESULT;
+
+    /// The host may set this flag to cancel the navigation.
+    /// If set, it will be as if the navigation never happened and the current
+    /// page's content will be intact. For performance reasons, GET HTTP requests
+    /// may happen, while the host is responding. This means cookies can be set
+    /// and used part of a request for the navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_cancel>
+    #[doc(alias = "get_Cancel")]
+    unsafe fn get_cancel(&self, /* out, retval */ cancel: *mut BOOL) -> HRESULT;
+
+    /// Set the Cancel property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#put_cancel>
+    #[doc(alias = "put_Cancel")]
+    unsafe fn put_cancel(&self, /* in */ cancel: BOOL) -> HRESULT;
+
+    /// The ID of the navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventargs#get_navigationid>
+    #[doc(alias = "get_NavigationId")]
+    unsafe fn get_navigation_id(
+        &self,
+        /* out, retval */ navigation_id: *mut UINT64,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NavigationStartingEventArgs`.
+pub const IID_ICoreWebView2NavigationStartingEventArgs: GUID = GUID {
+    Data1: 0x1C81A448,
+    Data2: 0x575B,
+    Data3: 0x44A1,
+    Data4: [0x9A, 0xBD, 0x1B, 0x93, 0xA3, 0xDE, 0x9E, 0x03],
+};
+
+/// The caller implements this interface to receive the NavigationStarting
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventhandler>
+#[com_interface("CD2F4CAE-BA09-47F3-94EE-A785CEC7C907")]
+pub trait ICoreWebView2NavigationStartingEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationstartingeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2NavigationStartingEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NavigationStartingEventHandler`.
+pub const IID_ICoreWebView2NavigationStartingEventHandler: GUID = GUID {
+    Data1: 0xCD2F4CAE,
+    Data2: 0xBA09,
+    Data3: 0x47F3,
+    Data4: [0x94, 0xEE, 0xA7, 0x85, 0xCE, 0xC7, 0xC9, 0x07],
+};
+
+/// Event args for the ContentLoading event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contentloadingeventargs>
+#[com_interface("696ED8C1-4657-4769-928F-10EF8040ED25")]
+pub trait ICoreWebView2ContentLoadingEventArgs: IUnknown {
+    /// True if the loaded content is an error page.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contentloadingeventargs#get_iserrorpage>
+    #[doc(alias = "get_IsErrorPage")]
+    unsafe fn get_is_error_page(&self, /* out, retval */ isErrorPage: *mut BOOL) -> HRESULT;
+
+    /// The ID of the navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contentloadingeventargs#get_navigationid>
+    #[doc(alias = "get_NavigationId")]
+    unsafe fn get_navigation_id(
+        &self,
+        /* out, retval */ navigation_id: *mut UINT64,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ContentLoadingEventArgs`.
+pub const IID_ICoreWebView2ContentLoadingEventArgs: GUID = GUID {
+    Data1: 0x696ED8C1,
+    Data2: 0x4657,
+    Data3: 0x4769,
+    Data4: [0x92, 0x8F, 0x10, 0xEF, 0x80, 0x40, 0xED, 0x25],
+};
+
+/// The caller implements this interface to receive the ContentLoading event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contentloadingeventhandler>
+#[com_interface("70057D5C-0BAA-4219-97B0-FFF1C088ED32")]
+pub trait ICoreWebView2ContentLoadingEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contentloadingeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ webview: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2ContentLoadingEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ContentLoadingEventHandler`.
+pub const IID_ICoreWebView2ContentLoadingEventHandler: GUID = GUID {
+    Data1: 0x70057D5C,
+    Data2: 0x0BAA,
+    Data3: 0x4219,
+    Data4: [0x97, 0xB0, 0xFF, 0xF1, 0xC0, 0x88, 0xED, 0x32],
+};
+
+/// Event args for the SourceChanged event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2sourcechangedeventargs>
+#[com_interface("26D4B817-9496-4F67-AEAB-24EB38482037")]
+pub trait ICoreWebView2SourceChangedEventArgs: IUnknown {
+    /// True if the page being navigated to is a new document.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2sourcechangedeventargs#get_isnewdocument>
+    #[doc(alias = "get_IsNewDocument")]
+    unsafe fn get_is_new_document(
+        &self,
+        /* out, retval */ isNewDocument: *mut BOOL,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2SourceChangedEventArgs`.
+pub const IID_ICoreWebView2SourceChangedEventArgs: GUID = GUID {
+    Data1: 0x26D4B817,
+    Data2: 0x9496,
+    Data3: 0x4F67,
+    Data4: [0xAE, 0xAB, 0x24, 0xEB, 0x38, 0x48, 0x20, 0x37],
+};
+
+/// The caller implements this interface to receive the SourceChanged event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2sourcechangedeventhandler>
+#[com_interface("E345159A-B573-41AB-A4F7-F94CB238AF45")]
+pub trait ICoreWebView2SourceChangedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2sourcechangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ webview: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2SourceChangedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2SourceChangedEventHandler`.
+pub const IID_ICoreWebView2SourceChangedEventHandler: GUID = GUID {
+    Data1: 0xE345159A,
+    Data2: 0xB573,
+    Data3: 0x41AB,
+    Data4: [0xA4, 0xF7, 0xF9, 0x4C, 0xB2, 0x38, 0xAF, 0x45],
+};
+
+/// The caller implements this interface to receive the HistoryChanged event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2historychangedeventhandler>
+#[com_interface("29211B19-F775-48CC-9757-5DA3CA1F626A")]
+pub trait ICoreWebView2HistoryChangedEventHandler: IUnknown {
+    /// There are no event args and the args parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2historychangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ webview: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2HistoryChangedEventHandler`.
+pub const IID_ICoreWebView2HistoryChangedEventHandler: GUID = GUID {
+    Data1: 0x29211B19,
+    Data2: 0xF775,
+    Data3: 0x48CC,
+    Data4: [0x97, 0x57, 0x5D, 0xA3, 0xCA, 0x1F, 0x62, 0x6A],
+};
+
+/// Event args for the ScriptDialogOpening event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs>
+#[com_interface("49C08E35-FCE1-4C6A-8DBD-6F58666C0CBE")]
+pub trait ICoreWebView2ScriptDialogOpeningEventArgs: IUnknown {
+    /// The URI of the page that requested the dialog box.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#get_uri>
+    #[doc(alias = "get_Uri")]
+    unsafe fn get_uri(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// The kind of JavaScript dialog box. Accept, confirm, prompt, or
+    /// beforeunload.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#get_kind>
+    #[doc(alias = "get_Kind")]
+    unsafe fn get_kind(
+        &self,
+        /* out, retval */ kind: *mut CORE_WEBVIEW2_SCRIPT_DIALOG_KIND,
+    ) -> HRESULT;
+
+    /// The message of the dialog box. From JavaScript this is the first parameter
+    /// passed to alert, confirm, and prompt and is empty for beforeunload.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#get_message>
+    #[doc(alias = "get_Message")]
+    unsafe fn get_message(&self, /* out, retval */ message: *mut LPWSTR) -> HRESULT;
+
+    /// The host may call this to respond with OK to confirm, prompt, and
+    /// beforeunload dialogs or not call this method to indicate cancel. From
+    /// JavaScript, this means that the confirm and beforeunload function returns
+    /// true if Accept is called. And for the prompt function it returns the value
+    /// of ResultText if Accept is called and returns false otherwise.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#accept>
+    #[doc(alias = "Accept")]
+    unsafe fn accept(&self) -> HRESULT;
+
+    /// The second parameter passed to the JavaScript prompt dialog. This is the
+    /// the default value to use for the result of the prompt JavaScript function.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#get_defaulttext>
+    #[doc(alias = "get_DefaultText")]
+    unsafe fn get_default_text(&self, /* out, retval */ defaultText: *mut LPWSTR) -> HRESULT;
+
+    /// The return value from the JavaScript prompt function if Accept is called.
+    /// This is ignored for dialog kinds other than prompt. If Accept is not
+    /// called this value is ignored and false is returned from prompt.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#get_resulttext>
+    #[doc(alias = "get_ResultText")]
+    unsafe fn get_result_text(&self, /* out, retval */ resultText: *mut LPWSTR) -> HRESULT;
+
+    /// Set the ResultText property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#put_resulttext>
+    #[doc(alias = "put_ResultText")]
+    unsafe fn put_result_text(&self, /* in */ resultText: LPCWSTR) -> HRESULT;
+
+    /// GetDeferral can be called to return an ICoreWebView2Deferral object.
+    /// You can use this to complete the event at a later time.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventargs#getdeferral>
+    #[doc(alias = "GetDeferral")]
+    unsafe fn get_deferral(
+        &self,
+        /* out, retval */ deferral: *mut *mut *mut ICoreWebView2DeferralVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ScriptDialogOpeningEventArgs`.
+pub const IID_ICoreWebView2ScriptDialogOpeningEventArgs: GUID = GUID {
+    Data1: 0x49C08E35,
+    Data2: 0xFCE1,
+    Data3: 0x4C6A,
+    Data4: [0x8D, 0xBD, 0x6F, 0x58, 0x66, 0x6C, 0x0C, 0xBE],
+};
+
+/// The caller implements this interface to receive the ScriptDialogOpening
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventhandler>
+#[com_interface("E4CDFD7A-AA15-4738-8A8F-4C8C28A9BAC1")]
+pub trait ICoreWebView2ScriptDialogOpeningEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2scriptdialogopeningeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2ScriptDialogOpeningEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ScriptDialogOpeningEventHandler`.
+pub const IID_ICoreWebView2ScriptDialogOpeningEventHandler: GUID = GUID {
+    Data1: 0xE4CDFD7A,
+    Data2: 0xAA15,
+    Data3: 0x4738,
+    Data4: [0x8A, 0x8F, 0x4C, 0x8C, 0x28, 0xA9, 0xBA, 0xC1],
+};
+
+/// Event args for the NavigationCompleted event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventargs>
+#[com_interface("1337EED4-BC5B-48FB-9672-80D18733CFD5")]
+pub trait ICoreWebView2NavigationCompletedEventArgs: IUnknown {
+    /// True when the navigation is successful. This
+    /// is false for a navigation that ended up in an error page (failures due to
+    /// no network, DNS lookup failure, HTTP server responds with 4xx), but could
+    /// also be false for additional things such as window.stop() called on
+    /// navigated page.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventargs#get_issuccess>
+    #[doc(alias = "get_IsSuccess")]
+    unsafe fn get_is_success(&self, /* out, retval */ isSuccess: *mut BOOL) -> HRESULT;
+
+    /// The error code if the navigation failed.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventargs#get_weberrorstatus>
+    #[doc(alias = "get_WebErrorStatus")]
+    unsafe fn get_web_error_status(
+        &self,
+        /* out, retval */
+        CORE_WEBVIEW2_WEB_ERROR_STATUS: *mut CORE_WEBVIEW2_WEB_ERROR_STATUS,
+    ) -> HRESULT;
+
+    /// The ID of the navigation.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventargs#get_navigationid>
+    #[doc(alias = "get_NavigationId")]
+    unsafe fn get_navigation_id(
+        &self,
+        /* out, retval */ navigation_id: *mut UINT64,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NavigationCompletedEventArgs`.
+pub const IID_ICoreWebView2NavigationCompletedEventArgs: GUID = GUID {
+    Data1: 0x1337EED4,
+    Data2: 0xBC5B,
+    Data3: 0x48FB,
+    Data4: [0x96, 0x72, 0x80, 0xD1, 0x87, 0x33, 0xCF, 0xD5],
+};
+
+/// The caller implements this interface to receive the NavigationCompleted
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventhandler>
+#[com_interface("17EB2F75-B65B-4E5F-A0E1-933126DDD5BB")]
+pub trait ICoreWebView2NavigationCompletedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2navigationcompletedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2NavigationCompletedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NavigationCompletedEventHandler`.
+pub const IID_ICoreWebView2NavigationCompletedEventHandler: GUID = GUID {
+    Data1: 0x17EB2F75,
+    Data2: 0xB65B,
+    Data3: 0x4E5F,
+    Data4: [0xA0, 0xE1, 0x93, 0x31, 0x26, 0xDD, 0xD5, 0xBB],
+};
+
+/// Event args for the PermissionRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs>
+#[com_interface("DBB6C9C9-FBB5-40FD-8843-5BE65807FD8A")]
+pub trait ICoreWebView2PermissionRequestedEventArgs: IUnknown {
+    /// The origin of the web content that requests the permission.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#get_uri>
+    #[doc(alias = "get_Uri")]
+    unsafe fn get_uri(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// The type of the permission that is requested.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#get_permissionkind>
+    #[doc(alias = "get_PermissionKind")]
+    unsafe fn get_permission_kind(
+        &self,
+        /* out, retval */ value: *mut CORE_WEBVIEW2_PERMISSION_KIND,
+    ) -> HRESULT;
+
+    /// True when the permission request was initiated through a user gesture.
+    /// Note that being initiated through a user gesture doesn't mean that user
+    /// intended to access the associated resource.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#get_isuserinitiated>
+    #[doc(alias = "get_IsUserInitiated")]
+    unsafe fn get_is_user_initiated(
+        &self,
+        /* out, retval */ isUserInitiated: *mut BOOL,
+    ) -> HRESULT;
+
+    /// The status of a permission request, i.e. whether the request is granted.
+    /// Default value is CORE_WEBVIEW2_PERMISSION_STATE_DEFAULT.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#get_state>
+    #[doc(alias = "get_State")]
+    unsafe fn get_state(
+        &self,
+        /* out, retval */ value: *mut CORE_WEBVIEW2_PERMISSION_STATE,
+    ) -> HRESULT;
+
+    /// Set the State property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#put_state>
+    #[doc(alias = "put_State")]
+    unsafe fn put_state(&self, /* in */ value: CORE_WEBVIEW2_PERMISSION_STATE) -> HRESULT;
+
+    /// GetDeferral can be called to return an ICoreWebView2Deferral object.
+    /// Developer can use the deferral object to make the permission decision
+    /// at a later time.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventargs#getdeferral>
+    #[doc(alias = "GetDeferral")]
+    unsafe fn get_deferral(
+        &self,
+        /* out, retval */ deferral: *mut *mut *mut ICoreWebView2DeferralVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2PermissionRequestedEventArgs`.
+pub const IID_ICoreWebView2PermissionRequestedEventArgs: GUID = GUID {
+    Data1: 0xDBB6C9C9,
+    Data2: 0xFBB5,
+    Data3: 0x40FD,
+    Data4: [0x88, 0x43, 0x5B, 0xE6, 0x58, 0x07, 0xFD, 0x8A],
+};
+
+/// The caller implements this interface to receive the PermissionRequested
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventhandler>
+#[com_interface("7079A1F0-CF14-4046-8E26-46BF54163673")]
+pub trait ICoreWebView2PermissionRequestedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2permissionrequestedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2PermissionRequestedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2PermissionRequestedEventHandler`.
+pub const IID_ICoreWebView2PermissionRequestedEventHandler: GUID = GUID {
+    Data1: 0x7079A1F0,
+    Data2: 0xCF14,
+    Data3: 0x4046,
+    Data4: [0x8E, 0x26, 0x46, 0xBF, 0x54, 0x16, 0x36, 0x73],
+};
+
+/// The caller implements this interface to receive the result of the
+/// AddScriptToExecuteOnDocumentCreated method.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2addscripttoexecuteondocumentcreatedcompletedhandler>
+#[com_interface("8889C588-9DC7-4266-9BB3-369AFDDE2A7F")]
+pub trait ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status and result
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2addscripttoexecuteondocumentcreatedcompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(&self, /* in */ errorCode: HRESULT, /* in */ id: LPCWSTR) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler`.
+pub const IID_ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler: GUID = GUID {
+    Data1: 0x8889C588,
+    Data2: 0x9DC7,
+    Data3: 0x4266,
+    Data4: [0x9B, 0xB3, 0x36, 0x9A, 0xFD, 0xDE, 0x2A, 0x7F],
+};
+
+/// The caller implements this interface to receive the result of the
+/// ExecuteScript method.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2executescriptcompletedhandler>
+#[com_interface("51457AE2-93FD-404E-A957-3D6034EAD733")]
+pub trait ICoreWebView2ExecuteScriptCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status and result
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2executescriptcompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ errorCode: HRESULT,
+        /* in */ resultObjectAsJson: LPCWSTR,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ExecuteScriptCompletedHandler`.
+pub const IID_ICoreWebView2ExecuteScriptCompletedHandler: GUID = GUID {
+    Data1: 0x51457AE2,
+    Data2: 0x93FD,
+    Data3: 0x404E,
+    Data4: [0xA9, 0x57, 0x3D, 0x60, 0x34, 0xEA, 0xD7, 0x33],
+};
+
+/// Event args for the WebResourceRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs>
+#[com_interface("6EF9912F-5A9D-42A9-8C17-9BB53E1D5C63")]
+pub trait ICoreWebView2WebResourceRequestedEventArgs: IUnknown {
+    /// The HTTP request.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs#get_request>
+    #[doc(alias = "get_Request")]
+    unsafe fn get_request(
+        &self,
+        /* out, retval */ request: *mut *mut *mut ICoreWebView2WebResourceRequestVTable,
+    ) -> HRESULT;
+
+    /// The HTTP response.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs#get_response>
+    #[doc(alias = "get_Response")]
+    unsafe fn get_response(
+        &self,
+        /* out, retval */ response: *mut *mut *mut ICoreWebView2WebResourceResponseVTable,
+    ) -> HRESULT;
+
+    /// Set the Response property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs#put_response>
+    #[doc(alias = "put_Response")]
+    unsafe fn put_response(
+        &self,
+        /* in */ response: *mut *mut ICoreWebView2WebResourceResponseVTable,
+    ) -> HRESULT;
+
+    /// Obtain an ICoreWebView2Deferral object and put the event into a deferred state.
+    /// You can use the ICoreWebView2Deferral object to complete the network request at a
+    /// later time.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs#getdeferral>
+    #[doc(alias = "GetDeferral")]
+    unsafe fn get_deferral(
+        &self,
+        /* out, retval */ deferral: *mut *mut *mut ICoreWebView2DeferralVTable,
+    ) -> HRESULT;
+
+    /// The web resource request contexts.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventargs#get_resourcecontext>
+    #[doc(alias = "get_ResourceContext")]
+    unsafe fn get_resource_context(
+        &self,
+        /* out, retval */ context: *mut CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebResourceRequestedEventArgs`.
+pub const IID_ICoreWebView2WebResourceRequestedEventArgs: GUID = GUID {
+    Data1: 0x6EF9912F,
+    Data2: 0x5A9D,
+    Data3: 0x42A9,
+    Data4: [0x8C, 0x17, 0x9B, 0xB5, 0x3E, 0x1D, 0x5C, 0x63],
+};
+
+/// Fires when an HTTP request is made in the webview. The host can override
+/// request, response headers and response content.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventhandler>
+#[com_interface("A8DC0663-3C2C-4190-8129-5F1F598CA7B8")]
+pub trait ICoreWebView2WebResourceRequestedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webresourcerequestedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2WebResourceRequestedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebResourceRequestedEventHandler`.
+pub const IID_ICoreWebView2WebResourceRequestedEventHandler: GUID = GUID {
+    Data1: 0xA8DC0663,
+    Data2: 0x3C2C,
+    Data3: 0x4190,
+    Data4: [0x81, 0x29, 0x5F, 0x1F, 0x59, 0x8C, 0xA7, 0xB8],
+};
+
+/// The caller implements this method to receive the result of the
+/// CapturePreview method. The result is written to the stream provided in
+/// the CapturePreview method call.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2capturepreviewcompletedhandler>
+#[com_interface("A1A2EC1C-B5C3-4EB2-9BCB-9166AFAA0E85")]
+pub trait ICoreWebView2CapturePreviewCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2capturepreviewcompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(&self, /* in */ result: HRESULT) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2CapturePreviewCompletedHandler`.
+pub const IID_ICoreWebView2CapturePreviewCompletedHandler: GUID = GUID {
+    Data1: 0xA1A2EC1C,
+    Data2: 0xB5C3,
+    Data3: 0x4EB2,
+    Data4: [0x9B, 0xCB, 0x91, 0x66, 0xAF, 0xAA, 0x0E, 0x85],
+};
+
+/// The caller implements this method to receive the GotFocus and LostFocus
+/// events. There are no event args for this event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2focuschangedeventhandler>
+#[com_interface("19F31771-9BB5-422B-9A0A-6EDDAF4FFE0F")]
+pub trait ICoreWebView2FocusChangedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event. There are no event args and the args
+    /// parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2focuschangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2HostVTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2FocusChangedEventHandler`.
+pub const IID_ICoreWebView2FocusChangedEventHandler: GUID = GUID {
+    Data1: 0x19F31771,
+    Data2: 0x9BB5,
+    Data3: 0x422B,
+    Data4: [0x9A, 0x0A, 0x6E, 0xDD, 0xAF, 0x4F, 0xFE, 0x0F],
+};
+
+/// Event args for the MoveFocusRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventargs>
+#[com_interface("CE31A597-E202-49B9-A9BE-825481ED517E")]
+pub trait ICoreWebView2MoveFocusRequestedEventArgs: IUnknown {
+    /// The reason for WebView to fire the MoveFocus Requested event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventargs#get_reason>
+    #[doc(alias = "get_Reason")]
+    unsafe fn get_reason(
+        &self,
+        /* out, retval */ value: *mut CORE_WEBVIEW2_MOVE_FOCUS_REASON,
+    ) -> HRESULT;
+
+    /// Indicate whether the event has been handled by the app.
+    /// If the app has moved the focus to its desired location, it should set
+    /// Handled property to TRUE.
+    /// When Handled property is false after the event handler returns, default
+    /// action will be taken. The default action is to try to find the next tab
+    /// stop child window in the app and try to move focus to that window. If
+    /// there is no other such window to move focus to, focus will be cycled
+    /// within the WebView's web content.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventargs#get_handled>
+    #[doc(alias = "get_Handled")]
+    unsafe fn get_handled(&self, /* out, retval */ value: *mut BOOL) -> HRESULT;
+
+    /// Set the Handled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventargs#put_handled>
+    #[doc(alias = "put_Handled")]
+    unsafe fn put_handled(&self, /* in */ value: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2MoveFocusRequestedEventArgs`.
+pub const IID_ICoreWebView2MoveFocusRequestedEventArgs: GUID = GUID {
+    Data1: 0xCE31A597,
+    Data2: 0xE202,
+    Data3: 0x49B9,
+    Data4: [0xA9, 0xBE, 0x82, 0x54, 0x81, 0xED, 0x51, 0x7E],
+};
+
+/// The caller implements this method to receive the MoveFocusRequested event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventhandler>
+#[com_interface("01BA7131-3DBE-4C83-A789-99C467A2C3F5")]
+pub trait ICoreWebView2MoveFocusRequestedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2movefocusrequestedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2HostVTable,
+        /* in */ args: *mut *mut ICoreWebView2MoveFocusRequestedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2MoveFocusRequestedEventHandler`.
+pub const IID_ICoreWebView2MoveFocusRequestedEventHandler: GUID = GUID {
+    Data1: 0x01BA7131,
+    Data2: 0x3DBE,
+    Data3: 0x4C83,
+    Data4: [0xA7, 0x89, 0x99, 0xC4, 0x67, 0xA2, 0xC3, 0xF5],
+};
+
+/// Event args for the WebMessageReceived event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventargs>
+#[com_interface("B21D70E2-942E-44EB-B843-22C156FDE288")]
+pub trait ICoreWebView2WebMessageReceivedEventArgs: IUnknown {
+    /// The URI of the document that sent this web message.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventargs#get_source>
+    #[doc(alias = "get_Source")]
+    unsafe fn get_source(&self, /* out, retval */ source: *mut LPWSTR) -> HRESULT;
+
+    /// The message posted from the webview content to the host converted to a
+    /// JSON string. Use this to communicate via JavaScript objects.
+    ///
+    /// For example the following postMessage calls result in the
+    /// following WebMessageAsJson values:
+    ///
+    /// ```
+    ///    postMessage({'a': 'b'})      L"{\"a\": \"b\"}"
+    ///    postMessage(1.2)             L"1.2"
+    ///    postMessage('example')       L"\"example\""
+    /// ```
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventargs#get_webmessageasjson>
+    #[doc(alias = "get_WebMessageAsJson")]
+    unsafe fn get_web_message_as_json(
+        &self,
+        /* out, retval */ webMessageAsJson: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// If the message posted from the webview content to the host is a
+    /// string type, this method will return the value of that string. If the
+    /// message posted is some other kind of JavaScript type this method will fail
+    /// with E_INVALIDARG. Use this to communicate via simple strings.
+    ///
+    /// For example the following postMessage calls result in the
+    /// following WebMessageAsString values:
+    ///
+    /// ```
+    ///    postMessage({'a': 'b'})      E_INVALIDARG
+    ///    postMessage(1.2)             E_INVALIDARG
+    ///    postMessage('example')       L"example"
+    /// ```
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventargs#trygetwebmessageasstring>
+    #[doc(alias = "TryGetWebMessageAsString")]
+    unsafe fn try_get_web_message_as_string(
+        &self,
+        /* out, retval */ webMessageAsString: *mut LPWSTR,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebMessageReceivedEventArgs`.
+pub const IID_ICoreWebView2WebMessageReceivedEventArgs: GUID = GUID {
+    Data1: 0xB21D70E2,
+    Data2: 0x942E,
+    Data3: 0x44EB,
+    Data4: [0xB8, 0x43, 0x22, 0xC1, 0x56, 0xFD, 0xE2, 0x88],
+};
+
+/// The caller implements this interface to receive the WebMessageReceived
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventhandler>
+#[com_interface("ABABDC66-DF8D-487D-A737-7B25E8F835AA")]
+pub trait ICoreWebView2WebMessageReceivedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2webmessagereceivedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2WebMessageReceivedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WebMessageReceivedEventHandler`.
+pub const IID_ICoreWebView2WebMessageReceivedEventHandler: GUID = GUID {
+    Data1: 0xABABDC66,
+    Data2: 0xDF8D,
+    Data3: 0x487D,
+    Data4: [0xA7, 0x37, 0x7B, 0x25, 0xE8, 0xF8, 0x35, 0xAA],
+};
+
+/// Event args for the DevToolsProtocolEventReceived event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventargs>
+#[com_interface("7EF09904-8B46-4FE1-87FF-5A28EFAF7723")]
+pub trait ICoreWebView2DevToolsProtocolEventReceivedEventArgs: IUnknown {
+    /// The parameter object of the corresponding DevToolsProtocol event
+    /// represented as a JSON string.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventargs#get_parameterobjectasjson>
+    #[doc(alias = "get_ParameterObjectAsJson")]
+    unsafe fn get_parameter_object_as_json(
+        &self,
+        /* out, retval */ parameterObjectAsJson: *mut LPWSTR,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2DevToolsProtocolEventReceivedEventArgs`.
+pub const IID_ICoreWebView2DevToolsProtocolEventReceivedEventArgs: GUID = GUID {
+    Data1: 0x7EF09904,
+    Data2: 0x8B46,
+    Data3: 0x4FE1,
+    Data4: [0x87, 0xFF, 0x5A, 0x28, 0xEF, 0xAF, 0x77, 0x23],
+};
+
+/// The caller implements this interface to receive
+/// DevToolsProtocolEventReceived events from the WebView.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventhandler>
+#[com_interface("8B0DF849-2D94-47FB-8072-FE7A4D5FBA6A")]
+pub trait ICoreWebView2DevToolsProtocolEventReceivedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2DevToolsProtocolEventReceivedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2DevToolsProtocolEventReceivedEventHandler`.
+pub const IID_ICoreWebView2DevToolsProtocolEventReceivedEventHandler: GUID = GUID {
+    Data1: 0x8B0DF849,
+    Data2: 0x2D94,
+    Data3: 0x47FB,
+    Data4: [0x80, 0x72, 0xFE, 0x7A, 0x4D, 0x5F, 0xBA, 0x6A],
+};
+
+/// The caller implements this interface to receive CallDevToolsProtocolMethod
+/// completion results.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2calldevtoolsprotocolmethodcompletedhandler>
+#[com_interface("B7627F5F-8723-4ED3-AC20-F93104CDEA51")]
+pub trait ICoreWebView2CallDevToolsProtocolMethodCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status and result
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2calldevtoolsprotocolmethodcompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ errorCode: HRESULT,
+        /* in */ returnObjectAsJson: LPCWSTR,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2CallDevToolsProtocolMethodCompletedHandler`.
+pub const IID_ICoreWebView2CallDevToolsProtocolMethodCompletedHandler: GUID = GUID {
+    Data1: 0xB7627F5F,
+    Data2: 0x8723,
+    Data3: 0x4ED3,
+    Data4: [0xAC, 0x20, 0xF9, 0x31, 0x04, 0xCD, 0xEA, 0x51],
+};
+
+/// The caller implements this interface to receive the CoreWebView2Host created
+/// via CreateCoreWebView2Host.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2controllercompletedhandler>
+#[com_interface("E09F5D38-91E3-49D1-8182-70A616AA06B9")]
+pub trait ICoreWebView2CreateCoreWebView2HostCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status and result
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2controllercompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        result: HRESULT,
+        created_host: *mut *mut ICoreWebView2HostVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2CreateCoreWebView2HostCompletedHandler`.
+pub const IID_ICoreWebView2CreateCoreWebView2HostCompletedHandler: GUID = GUID {
+    Data1: 0xE09F5D38,
+    Data2: 0x91E3,
+    Data3: 0x49D1,
+    Data4: [0x81, 0x82, 0x70, 0xA6, 0x16, 0xAA, 0x06, 0xB9],
+};
+
+/// Event args for the NewWindowRequested event. The event is fired when content
+/// inside webview requested to a open a new window (through window.open() etc.)
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs>
+#[com_interface("DDBF77B3-3411-44AB-AA15-FDFC93AFFCF8")]
+pub trait ICoreWebView2NewWindowRequestedEventArgs: IUnknown {
+    /// The target uri of the NewWindowRequest.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#get_uri>
+    #[doc(alias = "get_Uri")]
+    unsafe fn get_uri(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+    /// Sets a WebView as a result of the NewWindowRequest. The target
+    /// webview should not be navigated. If the NewWindow is set, its top level
+    /// window will return as the opened WindowProxy.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#put_newwindow>
+    #[doc(alias = "put_NewWindow")]
+    unsafe fn put_new_window(
+        &self,
+        /* in */ newWindow: *mut *mut ICoreWebView2VTable,
+    ) -> HRESULT;
+
+    /// Gets the new window.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#get_newwindow>
+    #[doc(alias = "get_NewWindow")]
+    unsafe fn get_new_window(
+        &self,
+        /* out, retval */ newWindow: *mut *mut *mut ICoreWebView2VTable,
+    ) -> HRESULT;
+
+    /// Sets whether the NewWindowRequestedEvent is handled by host. If this is false
+    /// and no NewWindow is set, the WebView will open a popup
+    /// window and it will be returned as opened WindowProxy.
+    /// If set to true and no NewWindow is set for a window.open call, the opened
+    /// WindowProxy will be for an dummy window object and no window will load.
+    /// Default is false.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#put_handled>
+    #[doc(alias = "put_Handled")]
+    unsafe fn put_handled(&self, /* in */ handled: BOOL) -> HRESULT;
+
+    /// Gets whether the NewWindowRequestedEvent is handled by host.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#get_handled>
+    #[doc(alias = "get_Handled")]
+    unsafe fn get_handled(&self, /* out, retval */ handled: *mut BOOL) -> HRESULT;
+
+    /// IsUserInitiated is true when the new window request was initiated through a user gesture
+    /// such as clicking an anchor tag with target.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#get_isuserinitiated>
+    #[doc(alias = "get_IsUserInitiated")]
+    unsafe fn get_is_user_initiated(
+        &self,
+        /* out, retval */ isUserInitiated: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Obtain an ICoreWebView2Deferral object and put the event into a deferred state.
+    /// You can use the ICoreWebView2Deferral object to complete the window open
+    /// request at a later time.
+    /// While this event is deferred the opener window will be returned a WindowProxy
+    /// to an unnavigated window, which will navigate when the deferral is complete.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#getdeferral>
+    #[doc(alias = "GetDeferral")]
+    unsafe fn get_deferral(
+        &self,
+        /* out, retval */ deferral: *mut *mut *mut ICoreWebView2DeferralVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NewWindowRequestedEventArgs`.
+pub const IID_ICoreWebView2NewWindowRequestedEventArgs: GUID = GUID {
+    Data1: 0xDDBF77B3,
+    Data2: 0x3411,
+    Data3: 0x44AB,
+    Data4: [0xAA, 0x15, 0xFD, 0xFC, 0x93, 0xAF, 0xFC, 0xF8],
+};
+
+/// The caller implements this interface to receive NewWindowRequested
+/// events.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventhandler>
+#[com_interface("715E10DD-2323-4F03-B6B3-AB34006B96D5")]
+pub trait ICoreWebView2NewWindowRequestedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut ICoreWebView2NewWindowRequestedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NewWindowRequestedEventHandler`.
+pub const IID_ICoreWebView2NewWindowRequestedEventHandler: GUID = GUID {
+    Data1: 0x715E10DD,
+    Data2: 0x2323,
+    Data3: 0x4F03,
+    Data4: [0xB6, 0xB3, 0xAB, 0x34, 0x00, 0x6B, 0x96, 0xD5],
+};
+
+/// The caller implements this interface to receive DocumentTitleChanged
+/// events. Use the DocumentTitle property to get the modified
+/// title.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2documenttitlechangedeventhandler>
+#[com_interface("CF313728-68BC-4577-9A35-08E660544AD9")]
+pub trait ICoreWebView2DocumentTitleChangedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event. There are no event args and the args
+    /// parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2documenttitlechangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2DocumentTitleChangedEventHandler`.
+pub const IID_ICoreWebView2DocumentTitleChangedEventHandler: GUID = GUID {
+    Data1: 0xCF313728,
+    Data2: 0x68BC,
+    Data3: 0x4577,
+    Data4: [0x9A, 0x35, 0x08, 0xE6, 0x60, 0x54, 0x4A, 0xD9],
+};
+
+/// Event args for the AcceleratorKeyPressed event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs>
+#[com_interface("AF1587DD-E2FF-4BFF-8C1A-699D6D34C683")]
+pub trait ICoreWebView2AcceleratorKeyPressedEventArgs: IUnknown {
+    /// The key event type that caused the event to be fired.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#get_keyeventkind>
+    #[doc(alias = "get_KeyEventKind")]
+    unsafe fn get_key_event_kind(
+        &self,
+        /* out, retval */ keyEventKind: *mut CORE_WEBVIEW2_KEY_EVENT_KIND,
+    ) -> HRESULT;
+
+    /// The Win32 virtual key code of the key that was pressed or released.
+    /// This will be one of the Win32 virtual key constants such as VK_RETURN or
+    /// an (uppercase) ASCII value such as 'A'. You can check whether Ctrl or Alt
+    /// are pressed by calling GetKeyState(VK_CONTROL) or GetKeyState(VK_MENU).
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#get_virtualkey>
+    #[doc(alias = "get_VirtualKey")]
+    unsafe fn get_virtual_key(&self, /* out, retval */ virtualKey: *mut UINT) -> HRESULT;
+
+    /// The LPARAM value that accompanied the window message. See the
+    /// documentation for the WM_KEYDOWN and WM_KEYUP messages.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#get_keyeventlparam>
+    #[doc(alias = "get_KeyEventLParam")]
+    unsafe fn get_key_event_lparam(&self, /* out, retval */ lParam: *mut i32) -> HRESULT;
+
+    /// A structure representing the information passed in the LPARAM of the
+    /// window message.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#get_physicalkeystatus>
+    #[doc(alias = "get_PhysicalKeyStatus")]
+    unsafe fn get_physical_key_status(
+        &self,
+        /* out, retval */ physicalKeyStatus: *mut CORE_WEBVIEW2_PHYSICAL_KEY_STATUS,
+    ) -> HRESULT;
+
+    /// During AcceleratorKeyPressedEvent handler invocation the WebView is blocked
+    /// waiting for the decision of if the accelerator will be handled by the host
+    /// or not. If the Handled property is set to TRUE then this will
+    /// prevent the WebView from performing the default action for this
+    /// accelerator key. Otherwise the WebView will perform the default action for
+    /// the accelerator key.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#get_handled>
+    #[doc(alias = "get_Handled")]
+    unsafe fn get_handled(&self, /* out, retval */ handled: *mut BOOL) -> HRESULT;
+
+    /// Sets the Handled property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventargs#put_handled>
+    #[doc(alias = "put_Handled")]
+    unsafe fn put_handled(&self, /* in */ handled: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2AcceleratorKeyPressedEventArgs`.
+pub const IID_ICoreWebView2AcceleratorKeyPressedEventArgs: GUID = GUID {
+    Data1: 0xAF1587DD,
+    Data2: 0xE2FF,
+    Data3: 0x4BFF,
+    Data4: [0x8C, 0x1A, 0x69, 0x9D, 0x6D, 0x34, 0xC6, 0x83],
+};
+
+/// The caller implements this interface to receive the AcceleratorKeyPressed
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventhandler>
+#[com_interface("253D0AA2-6F85-4FB2-9D6B-0DC5FEDBB085")]
+pub trait ICoreWebView2AcceleratorKeyPressedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2acceleratorkeypressedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2HostVTable,
+        /* in */ args: *mut *mut ICoreWebView2AcceleratorKeyPressedEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2AcceleratorKeyPressedEventHandler`.
+pub const IID_ICoreWebView2AcceleratorKeyPressedEventHandler: GUID = GUID {
+    Data1: 0x253D0AA2,
+    Data2: 0x6F85,
+    Data3: 0x4FB2,
+    Data4: [0x9D, 0x6B, 0x0D, 0xC5, 0xFE, 0xDB, 0xB0, 0x85],
+};
+
+/// Event args for the NewBrowserVersionAvailable event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newbrowserversionavailableeventargs>
+#[com_interface("5A86C3E7-511B-4F99-BC20-8A8ED5449C12")]
+pub trait ICoreWebView2NewBrowserVersionAvailableEventArgs: IUnknown {
+    /// The browser version info of the current ICoreWebView2Environment
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newbrowserversionavailableeventargs#get_newversion>
+    #[doc(alias = "get_NewVersion")]
+    unsafe fn get_new_version(&self, /* out, retval */ newVersion: *mut LPWSTR) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NewBrowserVersionAvailableEventArgs`.
+pub const IID_ICoreWebView2NewBrowserVersionAvailableEventArgs: GUID = GUID {
+    Data1: 0x5A86C3E7,
+    Data2: 0x511B,
+    Data3: 0x4F99,
+    Data4: [0xBC, 0x20, 0x8A, 0x8E, 0xD5, 0x44, 0x9C, 0x12],
+};
+
+/// The caller implements this interface to receive NewBrowserVersionAvailable events.
+/// Use the get_NewVersion method of ICoreWebView2NewBrowserVersionAvailableEventArgs
+/// to get the new version number.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newbrowserversionavailableeventhandler>
+#[com_interface("865E16C4-A24D-4AC1-BC23-2E608CA313F9")]
+pub trait ICoreWebView2NewBrowserVersionAvailableEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newbrowserversionavailableeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ webviewEnvironment: *mut *mut ICoreWebView2EnvironmentVTable,
+        /* in */ args: *mut *mut ICoreWebView2NewBrowserVersionAvailableEventArgsVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2NewBrowserVersionAvailableEventHandler`.
+pub const IID_ICoreWebView2NewBrowserVersionAvailableEventHandler: GUID = GUID {
+    Data1: 0x865E16C4,
+    Data2: 0xA24D,
+    Data3: 0x4AC1,
+    Data4: [0xBC, 0x23, 0x2E, 0x60, 0x8C, 0xA3, 0x13, 0xF9],
+};
+
+/// The caller implements this method to receive the
+/// ContainsFullScreenElementChanged events. There are no event args for this
+/// event.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2containsfullscreenelementchangedeventhandler>
+#[com_interface("EC2AF7C6-4579-40AB-8C36-5CCEE58EB7CB")]
+pub trait ICoreWebView2ContainsFullScreenElementChangedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event. There are no event args and the args
+    /// parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2containsfullscreenelementchangedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2ContainsFullScreenElementChangedEventHandler`.
+pub const IID_ICoreWebView2ContainsFullScreenElementChangedEventHandler: GUID = GUID {
+    Data1: 0xEC2AF7C6,
+    Data2: 0x4579,
+    Data3: 0x40AB,
+    Data4: [0x8C, 0x36, 0x5C, 0xCE, 0xE5, 0x8E, 0xB7, 0xCB],
+};
+
+/// The caller implements this interface to receive NewWindowRequested
+/// events.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowcloserequestedeventhandler>
+#[com_interface("1AE0297A-9671-4ED6-902A-4544B9B4AECD")]
+pub trait ICoreWebView2WindowCloseRequestedEventHandler: IUnknown {
+    /// Called to provide the implementer with the event args for the
+    /// corresponding event. There are no event args and the args
+    /// parameter will be null.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowcloserequestedeventhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        /* in */ sender: *mut *mut ICoreWebView2VTable,
+        /* in */ args: *mut *mut IUnknownVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2WindowCloseRequestedEventHandler`.
+pub const IID_ICoreWebView2WindowCloseRequestedEventHandler: GUID = GUID {
+    Data1: 0x1AE0297A,
+    Data2: 0x9671,
+    Data3: 0x4ED6,
+    Data4: [0x90, 0x2A, 0x45, 0x44, 0xB9, 0xB4, 0xAE, 0xCD],
+};
+
+/// This represents the WebView2 Environment. WebViews created from an
+/// environment run on the Browser process specified with environment parameters
+/// and objects created from an environment should be used in the same environment.
+/// Using it in different environments are not guaranteed to be compatible and may fail.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment>
+#[com_interface("7dc2ec84-56cb-4fcc-b4c6-a9f85c7b2894")]
+pub trait ICoreWebView2Environment: IUnknown {
+    /// Asynchronously create a new WebView.
+    ///
+    /// parentWindow is the HWND in which the WebView should be displayed and
+    /// from which receive input. The WebView will add a child window to the
+    /// provided window during WebView creation. Z-order and other things impacted
+    /// by sibling window order will be affected accordingly.
+    ///
+    /// It is recommended that the application set Application User Model ID for
+    /// the process or the application window. If none is set, during WebView
+    /// creation a generated Application User Model ID is set to root window of
+    /// parentWindow.
+    /// \snippet AppWindow.cpp CreateCoreWebView2Host
+    ///
+    /// It is recommended that the application handles restart manager messages
+    /// so that it can be restarted gracefully in the case when the app is using
+    /// Edge for webview from a certain installation and that installation is being
+    /// uninstalled. For example, if a user installs Edge from Dev channel and
+    /// opts to use Edge from that channel for testing the app, and then uninstalls
+    /// Edge from that channel without closing the app, the app will be restarted
+    /// to allow uninstallation of the dev channel to succeed.
+    /// \snippet AppWindow.cpp RestartManager
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment#createcorewebview2host>
+    #[doc(alias = "CreateCoreWebView2Host")]
+    unsafe fn create_core_web_view2_host(
+        &self,
+        parentWindow: HWND,
+        handler: *mut *mut ICoreWebView2CreateCoreWebView2HostCompletedHandlerVTable,
+    ) -> HRESULT;
+
+    /// Create a new web resource response object. The headers is the
+    /// raw response header string delimited by newline. It's also possible to
+    /// create this object with empty headers string and then use the
+    /// ICoreWebView2HttpResponseHeaders to construct the headers line by line.
+    /// For information on other parameters see ICoreWebView2WebResourceResponse.
+    ///
+    /// \snippet SettingsComponent.cpp WebResourceRequested
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment#createwebresourceresponse>
+    #[doc(alias = "CreateWebResourceResponse")]
+    unsafe fn create_web_resource_response(
+        &self,
+        /* in */ content: *mut *mut IStreamVTable,
+        /* in */ statusCode: i32,
+        /* in */ reasonPhrase: LPCWSTR,
+        /* in */ headers: LPCWSTR,
+        /* out, retval */ response: *mut *mut *mut ICoreWebView2WebResourceResponseVTable,
+    ) -> HRESULT;
+
+    /// The browser version info of the current ICoreWebView2Environment,
+    /// including channel name if it is not the stable channel.
+    /// This matches the format of the GetCoreWebView2BrowserVersionInfo API.
+    /// Channel names are 'beta', 'dev', and 'canary'.
+    ///
+    /// \snippet AppWindow.cpp GetBrowserVersionInfo
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment#get_browserversioninfo>
+    #[doc(alias = "get_BrowserVersionInfo")]
+    unsafe fn get_browser_version_info(
+        &self,
+        /* out, retval */ versionInfo: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// The NewBrowserVersionAvailable event fires when a newer version of the
+    /// Edge browser is installed and available to use via WebView2.
+    /// To use the newer version of the browser you must create a new
+    /// environment and WebView.
+    /// This event will only be fired for new version from the same Edge channel
+    /// that the code is running from. When not running with installed Edge,
+    /// no event will be fired.
+    ///
+    /// Because a user data folder can only be used by one browser process at
+    /// a time, if you want to use the same user data folder in the WebViews
+    /// using the new version of the browser,
+    /// you must close the environment and WebViews that are using the older
+    /// version of the browser first. Or simply prompt the user to restart the
+    /// app.
+    ///
+    /// \snippet AppWindow.cpp NewBrowserVersionAvailable
+    ///
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment#add_newbrowserversionavailable>
+    #[doc(alias = "add_NewBrowserVersionAvailable")]
+    unsafe fn add_new_browser_version_available(
+        &self,
+        /* in */
+        eventHandler: *mut *mut ICoreWebView2NewBrowserVersionAvailableEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with add_NewBrowserVersionAvailable.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment#remove_newbrowserversionavailable>
+    #[doc(alias = "remove_NewBrowserVersionAvailable")]
+    unsafe fn remove_new_browser_version_available(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2Environment`.
+pub const IID_ICoreWebView2Environment: GUID = GUID {
+    Data1: 0x7dc2ec84,
+    Data2: 0x56cb,
+    Data3: 0x4fcc,
+    Data4: [0xb4, 0xc6, 0xa9, 0xf8, 0x5c, 0x7b, 0x28, 0x94],
+};
+
+/// The caller implements this interface to receive the WebView2Environment created
+/// via CreateCoreWebView2Environment.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2environmentcompletedhandler>
+#[com_interface("7ED79562-90E1-47CD-A4E0-01D9211D7E3D")]
+pub trait ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler: IUnknown {
+    /// Called to provide the implementer with the completion status and result
+    /// of the corresponding asynchronous method call.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2environmentcompletedhandler#invoke>
+    #[doc(alias = "Invoke")]
+    unsafe fn invoke(
+        &self,
+        result: HRESULT,
+        created_environment: *mut *mut ICoreWebView2EnvironmentVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler`.
+pub const IID_ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler: GUID = GUID {
+    Data1: 0x7ED79562,
+    Data2: 0x90E1,
+    Data3: 0x47CD,
+    Data4: [0xA4, 0xE0, 0x01, 0xD9, 0x21, 0x1D, 0x7E, 0x3D],
+};
+
+/// A Receiver is created for a particular DevTools Protocol event and allows
+/// you to subscribe and unsubsribe from that event.
+/// Obtained from the WebView object via GetDevToolsProtocolEventReceiver.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceiver>
+#[com_interface("13FC668D-1F6D-4955-A4F4-D1EE7DEB5B74")]
+pub trait ICoreWebView2DevToolsProtocolEventReceiver: IUnknown {
+    /// Subscribe to a DevToolsProtocol event.
+    /// The handler's Invoke method will be called whenever the corresponding
+    /// DevToolsProtocol event fires. Invoke will be called with the
+    /// an event args object containing the DevTools Protocol event's parameter
+    /// object as a JSON string.
+    ///
+    /// \snippet ScriptComponent.cpp DevToolsProtocolEventReceived
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceiver#add_devtoolsprotocoleventreceived>
+    #[doc(alias = "add_DevToolsProtocolEventReceived")]
+    unsafe fn add_dev_tools_protocol_event_received(
+        &self,
+        /* in */
+        handler: *mut *mut ICoreWebView2DevToolsProtocolEventReceivedEventHandlerVTable,
+        /* out */ token: *mut EventRegistrationToken,
+    ) -> HRESULT;
+
+    /// Remove an event handler previously added with
+    /// add_DevToolsProtocolEventReceived.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceiver#remove_devtoolsprotocoleventreceived>
+    #[doc(alias = "remove_DevToolsProtocolEventReceived")]
+    unsafe fn remove_dev_tools_protocol_event_received(
+        &self,
+        /* in */ token: EventRegistrationToken,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2DevToolsProtocolEventReceiver`.
+pub const IID_ICoreWebView2DevToolsProtocolEventReceiver: GUID = GUID {
+    Data1: 0x13FC668D,
+    Data2: 0x1F6D,
+    Data3: 0x4955,
+    Data4: [0xA4, 0xF4, 0xD1, 0xEE, 0x7D, 0xEB, 0x5B, 0x74],
+};
+
+/// Options used to create WebView2 Environment.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions>
+#[com_interface("2FDE08A8-1E9A-4766-8C05-95A9CEB9D1C5")]
+pub trait ICoreWebView2EnvironmentOptions: IUnknown {
+    /// AdditionalBrowserArguments can be specified to change the behavior of the
+    /// WebView. These will be passed to the browser process as part of
+    /// the command line. See
+    /// [Run Chromium with Flags](https://aka.ms/RunChromiumWithFlags)
+    /// for more information about command line switches to browser
+    /// process. If the app is launched with a command line switch
+    /// `--edge-webview-switches=xxx` the value of that switch (xxx in
+    /// the above example) will also be appended to the browser
+    /// process command line. Certain switches like `--user-data-dir` are
+    /// internal and important to WebView. Those switches will be
+    /// ignored even if specified. If the same switches are specified
+    /// multiple times, the last one wins. There is no attempt to
+    /// merge the different values of the same switch, except for disabled
+    /// and enabled features. The features specified by `--enable-features`
+    /// and `--disable-features` will be merged with simple logic: the features
+    /// will be the union of the specified features and built-in features, and if
+    /// a feature is disabled, it will be removed from the enabled features list.
+    /// App process's command line `--edge-webview-switches` value are processed
+    /// after the additionalBrowserArguments parameter is processed. Certain
+    /// features are disabled internally and can't be enabled.
+    /// If parsing failed for the specified switches, they will be
+    /// ignored. Default is to run browser process with no extra flags.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#get_additionalbrowserarguments>
+    #[doc(alias = "get_AdditionalBrowserArguments")]
+    unsafe fn get_additional_browser_arguments(
+        &self,
+        /* out, retval */ value: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// Set the AdditionalBrowserArguments property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#put_additionalbrowserarguments>
+    #[doc(alias = "put_AdditionalBrowserArguments")]
+    unsafe fn put_additional_browser_arguments(&self, /* in */ value: LPCWSTR) -> HRESULT;
+
+    /// The default language that WebView will run with. It applies to browser UIs
+    /// like context menu and dialogs. It also applies to the accept-languages
+    /// HTTP header that WebView sends to web sites.
+    /// It is in the format of `language[-country]` where `language` is the 2 letter
+    /// code from ISO 639 and `country` is the 2 letter code from ISO 3166.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#get_language>
+    #[doc(alias = "get_Language")]
+    unsafe fn get_language(&self, /* out, retval */ value: *mut LPWSTR) -> HRESULT;
+
+    /// Set the Language property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#put_language>
+    #[doc(alias = "put_Language")]
+    unsafe fn put_language(&self, /* in */ value: LPCWSTR) -> HRESULT;
+
+    /// The version of the Edge WebView2 Runtime binaries required to be
+    /// compatible with the calling application. This defaults to the Edge
+    /// WebView2 Runtime version
+    /// that corresponds with the version of the SDK the application is using.
+    /// The format of this value is the same as the format of the
+    /// BrowserVersionString property and other BrowserVersion values.
+    /// Only the version part of the BrowserVersion value is respected. The
+    /// channel suffix, if it exists, is ignored.
+    /// The version of the Edge WebView2 Runtime binaries actually used may be
+    /// different from the specified TargetCompatibleBrowserVersion. They are only
+    /// guaranteed to be compatible. You can check the actual version on the
+    /// BrowserVersionString property on the ICoreWebView2Environment.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#get_targetcompatiblebrowserversion>
+    #[doc(alias = "get_TargetCompatibleBrowserVersion")]
+    unsafe fn get_target_compatible_browser_version(
+        &self,
+        /* out, retval */ value: *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// Set the TargetCompatibleBrowserVersion property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#put_targetcompatiblebrowserversion>
+    #[doc(alias = "put_TargetCompatibleBrowserVersion")]
+    unsafe fn put_target_compatible_browser_version(&self, /* in */ value: LPCWSTR) -> HRESULT;
+
+    /// The AllowSingleSignOnUsingOSPrimaryAccount property is used to enable
+    /// single sign on with Azure Active Directory (AAD) resources inside WebView
+    /// using the logged in Windows account and single sign on with web sites using
+    /// Microsoft account associated with the login in Windows account.
+    /// Default is disabled.
+    /// Universal Windows Platform apps must also declare enterpriseCloudSSO
+    /// [restricted capability](https://docs.microsoft.com/windows/uwp/packaging/app-capability-declarations#restricted-capabilities)
+    /// for the single sign on to work.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#get_allowsinglesignonusingosprimaryaccount>
+    #[doc(alias = "get_AllowSingleSignOnUsingOSPrimaryAccount")]
+    unsafe fn get_allow_single_sign_on_using_os_primary_account(
+        &self,
+        /* out, retval */ allow: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set the AllowSingleSignOnUsingOSPrimaryAccount property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions#put_allowsinglesignonusingosprimaryaccount>
+    #[doc(alias = "put_AllowSingleSignOnUsingOSPrimaryAccount")]
+    unsafe fn put_allow_single_sign_on_using_os_primary_account(
+        &self,
+        /* in */ allow: BOOL,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions`.
+pub const IID_ICoreWebView2EnvironmentOptions: GUID = GUID {
+    Data1: 0x2FDE08A8,
+    Data2: 0x1E9A,
+    Data3: 0x4766,
+    Data4: [0x8C, 0x05, 0x95, 0xA9, 0xCE, 0xB9, 0xD1, 0xC5],
+};
+
+/// Additional options used to create WebView2 Environment. A default implementation is
+/// provided in `WebView2EnvironmentOptions.h`.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions2>
+#[com_interface("FF85C98A-1BA7-4A6B-90C8-2B752C89E9E2")]
+pub trait ICoreWebView2EnvironmentOptions2: IUnknown {
+    /// Whether other processes can create WebView2 from WebView2Environment created with the
+    /// same user data folder and therefore sharing the same WebView browser process instance.
+    /// Default is FALSE.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions2#get_exclusiveuserdatafolderaccess>
+    #[doc(alias = "get_ExclusiveUserDataFolderAccess")]
+    unsafe fn get_exclusive_user_data_folder_access(
+        &self,
+        /* out, retval */ value: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Sets the `ExclusiveUserDataFolderAccess` property.
+    /// The `ExclusiveUserDataFolderAccess` property specifies that the WebView environment
+    /// obtains exclusive access to the user data folder.
+    /// If the user data folder is already being used by another WebView environment with a
+    /// different value for `ExclusiveUserDataFolderAccess` property, the creation of a WebView2Controller
+    /// using the environment object will fail with `HRESULT_FROM_WIN32(ERROR_INVALID_STATE)`.
+    /// When set as TRUE, no other WebView can be created from other processes using WebView2Environment
+    /// objects with the same UserDataFolder. This prevents other processes from creating WebViews
+    /// which share the same browser process instance, since sharing is performed among
+    /// WebViews that have the same UserDataFolder. When another process tries to create a
+    /// WebView2Controller from an WebView2Environment object created with the same user data folder,
+    /// it will fail with `HRESULT_FROM_WIN32(ERROR_INVALID_STATE)`.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions2#put_exclusiveuserdatafolderaccess>
+    #[doc(alias = "put_ExclusiveUserDataFolderAccess")]
+    unsafe fn put_exclusive_user_data_folder_access(&self, /* in */ value: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions2`.
+pub const IID_ICoreWebView2EnvironmentOptions2: GUID = GUID {
+    Data1: 0xFF85C98A,
+    Data2: 0x1BA7,
+    Data3: 0x4A6B,
+    Data4: [0x90, 0xC8, 0x2B, 0x75, 0x2C, 0x89, 0xE9, 0xE2],
+};
+
+/// Additional options used to create WebView2 Environment to manage crash
+/// reporting.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions3>
+#[com_interface("4A5C436E-A9E3-4A2E-89C3-910D3513F5CC")]
+pub trait ICoreWebView2EnvironmentOptions3: IUnknown {
+    /// When `IsCustomCrashReportingEnabled` is set to `TRUE`, Windows won't send crash data to Microsoft endpoint.
+    /// `IsCustomCrashReportingEnabled` is default to be `FALSE`, in this case, WebView will respect OS consent.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions3#get_iscustomcrashreportingenabled>
+    #[doc(alias = "get_IsCustomCrashReportingEnabled")]
+    unsafe fn get_is_custom_crash_reporting_enabled(
+        &self,
+        /* out, retval */ value: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Sets the `IsCustomCrashReportingEnabled` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions3#put_iscustomcrashreportingenabled>
+    #[doc(alias = "put_IsCustomCrashReportingEnabled")]
+    unsafe fn put_is_custom_crash_reporting_enabled(&self, /* in */ value: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions3`.
+pub const IID_ICoreWebView2EnvironmentOptions3: GUID = GUID {
+    Data1: 0x4A5C436E,
+    Data2: 0xA9E3,
+    Data3: 0x4A2E,
+    Data4: [0x89, 0xC3, 0x91, 0x0D, 0x35, 0x13, 0xF5, 0xCC],
+};
+
+/// Represents the registration of a custom scheme with the
+/// CoreWebView2Environment.
+/// This allows the WebView2 app to be able to handle WebResourceRequested
+/// event for requests with the specified scheme and be able to navigate the
+/// WebView2 to the custom scheme. Once the environment is created, the
+/// registrations are valid and immutable throughout the lifetime of the
+/// associated WebView2s' browser process and any WebView2 environments
+/// sharing the browser process must be created with identical custom scheme
+/// registrations, otherwise the environment creation will fail.
+/// Any further attempts to register the same scheme will fail during environment creation.
+/// The URIs of registered custom schemes will be treated similar to http
+/// URIs for their origins.
+/// They will have tuple origins for URIs with authority component and opaque origins for
+/// URIs without authority component as specified in
+/// [7.5 Origin - HTML Living Standard](https://html.spec.whatwg.org/multipage/origin.html)
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration>
+#[com_interface("d60ac92c-37a6-4b26-a39e-95cfe59047bb")]
+pub trait ICoreWebView2CustomSchemeRegistration: IUnknown {
+    /// The name of the custom scheme to register.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#get_schemename>
+    #[doc(alias = "get_SchemeName")]
+    unsafe fn get_scheme_name(&self, /* out, retval */ schemeName: *mut LPWSTR) -> HRESULT;
+
+    /// Whether the sites with this scheme will be treated as a
+    /// [Secure Context](https://developer.mozilla.org/docs/Web/Security/Secure_Contexts)
+    /// like an HTTPS site. This flag is only effective when HasAuthorityComponent
+    /// is also set to `true`.
+    /// `false` by default.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#get_treatassecure>
+    #[doc(alias = "get_TreatAsSecure")]
+    unsafe fn get_treat_as_secure(
+        &self,
+        /* out, retval */ treatAsSecure: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Set if the scheme will be treated as a Secure Context.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#put_treatassecure>
+    #[doc(alias = "put_TreatAsSecure")]
+    unsafe fn put_treat_as_secure(&self, /* in */ value: BOOL) -> HRESULT;
+
+    /// List of origins that are allowed to issue requests with the custom
+    /// scheme, such as XHRs and subresource requests that have an Origin header.
+    /// The origin of any request (requests that have the
+    /// [Origin header](https://developer.mozilla.org/docs/Web/HTTP/Headers/Origin))
+    /// to the custom scheme URI needs to be in this list. No-origin requests
+    /// are requests that do not have an Origin header, such as link
+    /// navigations, embedded images and are always allowed.
+    /// Note: POST requests always contain an Origin header, therefore
+    /// AllowedOrigins must be set for even for same origin POST requests.
+    /// Note that cross-origin restrictions still apply.
+    /// From any opaque origin (Origin header is null), no cross-origin requests
+    /// are allowed.
+    /// If the list is empty, no cross-origin request to this scheme is
+    /// allowed.
+    /// Origins are specified as a string in the format of
+    /// scheme://host:port.
+    /// The origins are string pattern matched with `*` (matches 0 or more
+    /// characters) and `?` (matches 0 or 1 character) wildcards just like
+    /// the URI matching in the
+    /// [AddWebResourceRequestedFilter API](/microsoft-edge/webview2/reference/win32/icorewebview2#addwebresourcerequestedfilter).
+    /// For example, "http://*.example.com:80".
+    /// Here's a set of examples of what is allowed and not:
+    ///
+    /// | Request URI | Originating URL | AllowedOrigins | Allowed |
+    /// | -- | -- | -- | -- |
+    /// | `custom-scheme:request` | `https://www.example.com` | {"https://www.example.com"} | Yes |
+    /// | `custom-scheme:request` | `https://www.example.com` | {"https://*.example.com"} | Yes |
+    /// | `custom-scheme:request` | `https://www.example.com` | {"https://www.example2.com"} | No |
+    /// | `custom-scheme-with-authority://host/path` | `custom-scheme-with-authority://host2` | {""} | No |
+    /// | `custom-scheme-with-authority://host/path` | `custom-scheme-with-authority2://host` | {"custom-scheme-with-authority2://*"} | Yes |
+    /// | `custom-scheme-without-authority:path` | custom-scheme-without-authority:path2 | {"custom-scheme-without-authority:*"} | No |
+    /// | `custom-scheme-without-authority:path` | custom-scheme-without-authority:path2 | {"*"} | Yes |
+    ///
+    /// The returned strings and the array itself must be deallocated with
+    /// CoTaskMemFree.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#getallowedorigins>
+    #[doc(alias = "GetAllowedOrigins")]
+    unsafe fn get_allowed_origins(
+        &self,
+        /* out */ allowedOriginsCount: *mut UINT32,
+        /* out */ allowedOrigins: *mut *mut LPWSTR,
+    ) -> HRESULT;
+
+    /// Set the array of origins that are allowed to use the scheme.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#setallowedorigins>
+    #[doc(alias = "SetAllowedOrigins")]
+    unsafe fn set_allowed_origins(
+        &self,
+        /* in */ allowedOriginsCount: UINT32,
+        /* in */ allowedOrigins: *const LPCWSTR,
+    ) -> HRESULT;
+
+    /// Set this property to `true` if the URIs with this custom
+    /// scheme will have an authority component (a host for custom schemes).
+    /// Specifically, if you have a URI of the following form you should set the
+    /// `HasAuthorityComponent` value as listed.
+    ///
+    /// | URI | Recommended HasAuthorityComponent value |
+    /// | -- | -- |
+    /// | `custom-scheme-with-authority://host/path` | `true` |
+    /// | `custom-scheme-without-authority:path` | `false` |
+    ///
+    /// When this property is set to `true`, the URIs with this scheme will be
+    /// interpreted as having a
+    /// [scheme and host](https://html.spec.whatwg.org/multipage/origin.html#concept-origin-tuple)
+    /// origin similar to an http URI. Note that the port and user
+    /// information are never included in the computation of origins for
+    /// custom schemes.
+    /// If this property is set to `false`, URIs with this scheme will have an
+    /// [opaque origin](https://html.spec.whatwg.org/multipage/origin.html#concept-origin-opaque)
+    /// similar to a data URI.
+    /// This property is `false` by default.
+    ///
+    /// Note: For custom schemes registered as having authority component,
+    /// navigations to URIs without authority of such custom schemes will fail.
+    /// However, if the content inside WebView2 references
+    /// a subresource with a URI that does not have
+    /// an authority component, but of a custom scheme that is registered as
+    /// having authority component, the URI will be interpreted as a relative path
+    /// as specified in [RFC3986](https://www.rfc-editor.org/rfc/rfc3986).
+    /// For example, `custom-scheme-with-authority:path` will be interpreted
+    /// as `custom-scheme-with-authority://host/path`.
+    /// However, this behavior cannot be guaranteed to remain in future
+    /// releases so it is recommended not to rely on this behavior.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#get_hasauthoritycomponent>
+    #[doc(alias = "get_HasAuthorityComponent")]
+    unsafe fn get_has_authority_component(
+        &self,
+        /* out, retval */ hasAuthorityComponent: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Get has authority component.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2customschemeregistration#put_hasauthoritycomponent>
+    #[doc(alias = "put_HasAuthorityComponent")]
+    unsafe fn put_has_authority_component(
+        &self,
+        /* in */ hasAuthorityComponent: BOOL,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2CustomSchemeRegistration`.
+pub const IID_ICoreWebView2CustomSchemeRegistration: GUID = GUID {
+    Data1: 0xd60ac92c,
+    Data2: 0x37a6,
+    Data3: 0x4b26,
+    Data4: [0xa3, 0x9e, 0x95, 0xcf, 0xe5, 0x90, 0x47, 0xbb],
+};
+
+/// Additional options used to create WebView2 Environment that manages custom scheme registration.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions4>
+#[com_interface("AC52D13F-0D38-475A-9DCA-876580D6793E")]
+pub trait ICoreWebView2EnvironmentOptions4: IUnknown {
+    /// Array of custom scheme registrations. The returned
+    /// ICoreWebView2CustomSchemeRegistration pointers must be released, and the
+    /// array itself must be deallocated with CoTaskMemFree.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions4#getcustomschemeregistrations>
+    #[doc(alias = "GetCustomSchemeRegistrations")]
+    unsafe fn get_custom_scheme_registrations(
+        &self,
+        /* out */ count: *mut UINT32,
+        /* out */
+        schemeRegistrations: *mut *mut *mut *mut ICoreWebView2CustomSchemeRegistrationVTable,
+    ) -> HRESULT;
+
+    /// Set the array of custom scheme registrations to be used.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions4#setcustomschemeregistrations>
+    #[doc(alias = "SetCustomSchemeRegistrations")]
+    unsafe fn set_custom_scheme_registrations(
+        &self,
+        /* in */ count: UINT32,
+        /* in */
+        schemeRegistrations: *mut *mut *mut ICoreWebView2CustomSchemeRegistrationVTable,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions4`.
+pub const IID_ICoreWebView2EnvironmentOptions4: GUID = GUID {
+    Data1: 0xAC52D13F,
+    Data2: 0x0D38,
+    Data3: 0x475A,
+    Data4: [0x9D, 0xCA, 0x87, 0x65, 0x80, 0xD6, 0x79, 0x3E],
+};
+
+/// Additional options used to create WebView2 Environment to manage tracking
+/// prevention.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions5>
+#[com_interface("0AE35D64-C47F-4464-814E-259C345D1501")]
+pub trait ICoreWebView2EnvironmentOptions5: IUnknown {
+    /// The `EnableTrackingPrevention` property is used to enable/disable tracking prevention
+    /// feature in WebView2. This property enable/disable tracking prevention for all the
+    /// WebView2's created in the same environment. By default this feature is enabled to block
+    /// potentially harmful trackers and trackers from sites that aren't visited before and set to
+    /// `COREWEBVIEW2_TRACKING_PREVENTION_LEVEL_BALANCED` or whatever value was last changed/persisted
+    /// on the profile.
+    ///
+    /// You can set this property to false to disable the tracking prevention feature if the app only
+    /// renders content in the WebView2 that is known to be safe. Disabling this feature when creating
+    /// environment also improves runtime performance by skipping related code.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions5#get_enabletrackingprevention>
+    #[doc(alias = "get_EnableTrackingPrevention")]
+    unsafe fn get_enable_tracking_prevention(
+        &self,
+        /* out, retval */ value: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Sets the `EnableTrackingPrevention` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions5#put_enabletrackingprevention>
+    #[doc(alias = "put_EnableTrackingPrevention")]
+    unsafe fn put_enable_tracking_prevention(&self, /* in */ value: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions5`.
+pub const IID_ICoreWebView2EnvironmentOptions5: GUID = GUID {
+    Data1: 0x0AE35D64,
+    Data2: 0xC47F,
+    Data3: 0x4464,
+    Data4: [0x81, 0x4E, 0x25, 0x9C, 0x34, 0x5D, 0x15, 0x01],
+};
+
+/// Additional options used to create WebView2 Environment to manage browser extensions.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions6>
+#[com_interface("57D29CC3-C84F-42A0-B0E2-EFFBD5E179DE")]
+pub trait ICoreWebView2EnvironmentOptions6: IUnknown {
+    /// When `AreBrowserExtensionsEnabled` is set to `TRUE`, new extensions can be added to user
+    /// profile and used. `AreBrowserExtensionsEnabled` is default to be `FALSE`, in this case,
+    /// new extensions can't be installed, and already installed extension won't be
+    /// available to use in user profile.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions6#get_arebrowserextensionsenabled>
+    #[doc(alias = "get_AreBrowserExtensionsEnabled")]
+    unsafe fn get_are_browser_extensions_enabled(
+        &self,
+        /* out, retval */ value: *mut BOOL,
+    ) -> HRESULT;
+
+    /// Sets the `AreBrowserExtensionsEnabled` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions6#put_arebrowserextensionsenabled>
+    #[doc(alias = "put_AreBrowserExtensionsEnabled")]
+    unsafe fn put_are_browser_extensions_enabled(&self, /* in */ value: BOOL) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions6`.
+pub const IID_ICoreWebView2EnvironmentOptions6: GUID = GUID {
+    Data1: 0x57D29CC3,
+    Data2: 0xC84F,
+    Data3: 0x42A0,
+    Data4: [0xB0, 0xE2, 0xEF, 0xFB, 0xD5, 0xE1, 0x79, 0xDE],
+};
+
+/// Additional options used to create WebView2 Environment to manage release
+/// channel selection.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions7>
+#[com_interface("C48D539F-E39F-441C-AE68-1F66E570BDC5")]
+pub trait ICoreWebView2EnvironmentOptions7: IUnknown {
+    /// Gets the `ChannelSearchKind` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions7#get_channelsearchkind>
+    #[doc(alias = "get_ChannelSearchKind")]
+    unsafe fn get_channel_search_kind(
+        &self,
+        /* out, retval */ value: *mut COREWEBVIEW2_CHANNEL_SEARCH_KIND,
+    ) -> HRESULT;
+
+    /// The `ChannelSearchKind` property is `COREWEBVIEW2_CHANNEL_SEARCH_KIND_MOST_STABLE`
+    /// by default; environment creation searches for a release channel on the machine
+    /// from most to least stable using the first channel found. Set it to
+    /// `COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE` to reverse the search order.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions7#put_channelsearchkind>
+    #[doc(alias = "put_ChannelSearchKind")]
+    unsafe fn put_channel_search_kind(
+        &self,
+        /* in */ value: COREWEBVIEW2_CHANNEL_SEARCH_KIND,
+    ) -> HRESULT;
+
+    /// Gets the `ReleaseChannels` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions7#get_releasechannels>
+    #[doc(alias = "get_ReleaseChannels")]
+    unsafe fn get_release_channels(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+    /// Sets the `ReleaseChannels`, which is a mask of one or more
+    /// `COREWEBVIEW2_RELEASE_CHANNELS` indicating which channels environment
+    /// creation should search for. OR operation(s) can be applied to multiple
+    /// `COREWEBVIEW2_RELEASE_CHANNELS` to create a mask. The default value is a
+    /// mask of all the channels. By default, environment creation searches for
+    /// channels from most to least stable, using the first channel found on the
+    /// device. When `ReleaseChannels` is provided, environment creation will only
+    /// search for the channels specified in the set. Set `ChannelSearchKind` to
+    /// `COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE` to reverse the search order
+    /// so that environment creation searches for least stable build first.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions7#put_releasechannels>
+    #[doc(alias = "put_ReleaseChannels")]
+    unsafe fn put_release_channels(&self, /* in */ value: UINT32) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions7`.
+pub const IID_ICoreWebView2EnvironmentOptions7: GUID = GUID {
+    Data1: 0xC48D539F,
+    Data2: 0xE39F,
+    Data3: 0x441C,
+    Data4: [0xAE, 0x68, 0x1F, 0x66, 0xE5, 0x70, 0xBD, 0xC5],
+};
+
+/// The channel search kind determines the order that release channels are
+/// searched for during environment creation. The default behavior is to
+/// search for and use the most stable channel found on the device. The order
+/// from most to least stable is: WebView2 Runtime -> Beta -> Dev -> Canary.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum COREWEBVIEW2_CHANNEL_SEARCH_KIND {
+    /// Search for a release channel from most to least stable:
+    /// WebView2 Runtime -> Beta -> Dev -> Canary. This is the default behavior.
+    COREWEBVIEW2_CHANNEL_SEARCH_KIND_MOST_STABLE,
+    /// Search for a release channel from least to most stable:
+    /// Canary -> Dev -> Beta -> WebView2 Runtime.
+    COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE,
+}
+
+impl COREWEBVIEW2_CHANNEL_SEARCH_KIND {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for COREWEBVIEW2_CHANNEL_SEARCH_KIND {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::COREWEBVIEW2_CHANNEL_SEARCH_KIND_MOST_STABLE),
+            1 => Ok(Self::COREWEBVIEW2_CHANNEL_SEARCH_KIND_LEAST_STABLE),
+            _ => Err(value),
+        }
+    }
+}
+
+/// No release channel.
+pub const COREWEBVIEW2_RELEASE_CHANNELS_NONE: UINT32 = 0x0;
+
+/// The stable WebView2 Runtime that is released every 4 weeks.
+pub const COREWEBVIEW2_RELEASE_CHANNELS_STABLE: UINT32 = 0x1;
+
+/// The Beta release channel that is released every 4 weeks, a week before the
+/// stable release.
+pub const COREWEBVIEW2_RELEASE_CHANNELS_BETA: UINT32 = 0x2;
+
+/// The Dev release channel that is released weekly.
+pub const COREWEBVIEW2_RELEASE_CHANNELS_DEV: UINT32 = 0x4;
+
+/// The Canary release channel that is released daily.
+pub const COREWEBVIEW2_RELEASE_CHANNELS_CANARY: UINT32 = 0x8;
+
+/// Additional options used to create WebView2 Environment to manage scroll bar style.
+///
+/// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions8>
+#[com_interface("7C7ECF51-E918-5CAF-853C-E9A2BCC27775")]
+pub trait ICoreWebView2EnvironmentOptions8: IUnknown {
+    /// Gets the `ScrollBarStyle` property.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions8#get_scrollbarstyle>
+    #[doc(alias = "get_ScrollBarStyle")]
+    unsafe fn get_scroll_bar_style(
+        &self,
+        /* out, retval */ value: *mut COREWEBVIEW2_SCROLLBAR_STYLE,
+    ) -> HRESULT;
+
+    /// Sets the `ScrollBarStyle` property. The default is
+    /// `COREWEBVIEW2_SCROLLBAR_STYLE_DEFAULT`, the Chromium scroll bars.
+    /// `COREWEBVIEW2_SCROLLBAR_STYLE_FLUENT_OVERLAY` only applies to new
+    /// WebViews, and to the whole environment.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environmentoptions8#put_scrollbarstyle>
+    #[doc(alias = "put_ScrollBarStyle")]
+    unsafe fn put_scroll_bar_style(
+        &self,
+        /* in */ value: COREWEBVIEW2_SCROLLBAR_STYLE,
+    ) -> HRESULT;
+}
+
+/// IID of `ICoreWebView2EnvironmentOptions8`.
+pub const IID_ICoreWebView2EnvironmentOptions8: GUID = GUID {
+    Data1: 0x7C7ECF51,
+    Data2: 0xE918,
+    Data3: 0x5CAF,
+    Data4: [0x85, 0x3C, 0xE9, 0xA2, 0xBC, 0xC2, 0x77, 0x75],
+};
+
+/// Specifies the scrollbar style.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum COREWEBVIEW2_SCROLLBAR_STYLE {
+    /// Browser default ScrollBar style
+    COREWEBVIEW2_SCROLLBAR_STYLE_DEFAULT,
+    /// Window style fluent overlay scroll bar.
+    /// Please see [Fluent UI](https://developer.microsoft.com/fluentui#/)
+    /// for more details on fluent UI.
+    COREWEBVIEW2_SCROLLBAR_STYLE_FLUENT_OVERLAY,
+}
+
+impl COREWEBVIEW2_SCROLLBAR_STYLE {
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Fails with the value if it is not a known variant.
+impl std::convert::TryFrom<u32> for COREWEBVIEW2_SCROLLBAR_STYLE {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::COREWEBVIEW2_SCROLLBAR_STYLE_DEFAULT),
+            1 => Ok(Self::COREWEBVIEW2_SCROLLBAR_STYLE_FLUENT_OVERLAY),
+            _ => Err(value),
+        }
+    }
+}
+
+/// An interface with an IID, for finding interfaces by name or IID at
+/// runtime.
+#[derive(Clone, Copy)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub parent: &'static str,
+    pub iid: GUID,
+    /// Number of methods declared by the interface itself.
+    pub methods: usize,
+}
+
+impl InterfaceInfo {
+    /// Number of vtable entries, including those of the parents. A parent
+    /// that is not in the registry is taken to be `IUnknown`.
+    pub fn vtable_len(&self) -> usize {
+        match interface_by_name(self.parent) {
+            Some(parent) => parent.vtable_len() + self.methods,
+            None => 3 + self.methods,
+        }
+    }
+}
+
+pub fn interface_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| i.name == name)
+}
+
+pub fn interface_by_iid(iid: &GUID) -> Option<&'static InterfaceInfo> {
+    interfaces().find(|i| {
+        i.iid.Data1 == iid.Data1
+            && i.iid.Data2 == iid.Data2
+            && i.iid.Data3 == iid.Data3
+            && i.iid.Data4 == iid.Data4
+    })
+}
+
+/// Interfaces with an IID, in IDL order.
+pub const INTERFACES: &[InterfaceInfo] = &[
+    InterfaceInfo {
+        name: "ICoreWebView2",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2,
+        methods: 56,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2Host",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2Host,
+        methods: 23,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2Deferral",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2Deferral,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2Settings",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2Settings,
+        methods: 16,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ProcessFailedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ProcessFailedEventArgs,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ProcessFailedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ProcessFailedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ZoomFactorChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ZoomFactorChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2HttpHeadersCollectionIterator",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2HttpHeadersCollectionIterator,
+        methods: 3,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2HttpRequestHeaders",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2HttpRequestHeaders,
+        methods: 6,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2HttpResponseHeaders",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2HttpResponseHeaders,
+        methods: 5,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebResourceRequest",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebResourceRequest,
+        methods: 7,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebResourceResponse",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebResourceResponse,
+        methods: 7,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NavigationStartingEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NavigationStartingEventArgs,
+        methods: 7,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NavigationStartingEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NavigationStartingEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ContentLoadingEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ContentLoadingEventArgs,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ContentLoadingEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ContentLoadingEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2SourceChangedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2SourceChangedEventArgs,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2SourceChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2SourceChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2HistoryChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2HistoryChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ScriptDialogOpeningEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ScriptDialogOpeningEventArgs,
+        methods: 8,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ScriptDialogOpeningEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ScriptDialogOpeningEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NavigationCompletedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NavigationCompletedEventArgs,
+        methods: 3,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NavigationCompletedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NavigationCompletedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2PermissionRequestedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2PermissionRequestedEventArgs,
+        methods: 6,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2PermissionRequestedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2PermissionRequestedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ExecuteScriptCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ExecuteScriptCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebResourceRequestedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebResourceRequestedEventArgs,
+        methods: 5,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebResourceRequestedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebResourceRequestedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2CapturePreviewCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2CapturePreviewCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2FocusChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2FocusChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2MoveFocusRequestedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2MoveFocusRequestedEventArgs,
+        methods: 3,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2MoveFocusRequestedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2MoveFocusRequestedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebMessageReceivedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebMessageReceivedEventArgs,
+        methods: 3,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WebMessageReceivedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WebMessageReceivedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2DevToolsProtocolEventReceivedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2DevToolsProtocolEventReceivedEventArgs,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2DevToolsProtocolEventReceivedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2DevToolsProtocolEventReceivedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2CallDevToolsProtocolMethodCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2CallDevToolsProtocolMethodCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2CreateCoreWebView2HostCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2CreateCoreWebView2HostCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NewWindowRequestedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NewWindowRequestedEventArgs,
+        methods: 7,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NewWindowRequestedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NewWindowRequestedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2DocumentTitleChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2DocumentTitleChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2AcceleratorKeyPressedEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2AcceleratorKeyPressedEventArgs,
+        methods: 6,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2AcceleratorKeyPressedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2AcceleratorKeyPressedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NewBrowserVersionAvailableEventArgs",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NewBrowserVersionAvailableEventArgs,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2NewBrowserVersionAvailableEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2NewBrowserVersionAvailableEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2ContainsFullScreenElementChangedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ContainsFullScreenElementChangedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2WindowCloseRequestedEventHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2WindowCloseRequestedEventHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2Environment",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2Environment,
+        methods: 5,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2CreateCoreWebView2EnvironmentCompletedHandler,
+        methods: 1,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2DevToolsProtocolEventReceiver",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2DevToolsProtocolEventReceiver,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions,
+        methods: 8,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions2",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions2,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions3",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions3,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2CustomSchemeRegistration",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2CustomSchemeRegistration,
+        methods: 7,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions4",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions4,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions5",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions5,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions6",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions6,
+        methods: 2,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions7",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions7,
+        methods: 4,
+    },
+    InterfaceInfo {
+        name: "ICoreWebView2EnvironmentOptions8",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2EnvironmentOptions8,
+        methods: 2,
+    },
+];
+
+pub fn interfaces() -> impl Iterator<Item = &'static InterfaceInfo> {
+    let interfaces = INTERFACES.iter();
+    #[cfg(feature = "sdk-1.0.488")]
+    let interfaces = interfaces.chain(SDK_1_0_488_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.705")]
+    let interfaces = interfaces.chain(SDK_1_0_705_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.774")]
+    let interfaces = interfaces.chain(SDK_1_0_774_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.902")]
+    let interfaces = interfaces.chain(SDK_1_0_902_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.961")]
+    let interfaces = interfaces.chain(SDK_1_0_961_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.992")]
+    let interfaces = interfaces.chain(SDK_1_0_992_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1020")]
+    let interfaces = interfaces.chain(SDK_1_0_1020_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1054")]
+    let interfaces = interfaces.chain(SDK_1_0_1054_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1072")]
+    let interfaces = interfaces.chain(SDK_1_0_1072_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1108")]
+    let interfaces = interfaces.chain(SDK_1_0_1108_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1150")]
+    let interfaces = interfaces.chain(SDK_1_0_1150_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1185")]
+    let interfaces = interfaces.chain(SDK_1_0_1185_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1245")]
+    let interfaces = interfaces.chain(SDK_1_0_1245_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1518")]
+    let interfaces = interfaces.chain(SDK_1_0_1518_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1661")]
+    let interfaces = interfaces.chain(SDK_1_0_1661_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.1774")]
+    let interfaces = interfaces.chain(SDK_1_0_1774_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.2210")]
+    let interfaces = interfaces.chain(SDK_1_0_2210_INTERFACES.iter());
+    #[cfg(feature = "sdk-1.0.2739")]
+    let interfaces = interfaces.chain(SDK_1_0_2739_INTERFACES.iter());
+    interfaces
+}
+
+#[cfg(feature = "sdk-1.0.488")]
+mod sdk_1_0_488 {
+    use super::*;
+
+    /// `ICoreWebView2` as released in SDK 1.0. It differs from the 0.9.430 one
+    /// by the `FrameNavigationCompleted` event, so the later `ICoreWebView2_N`
+    /// interfaces extend this one. Only declared for their vtables, the webview
+    /// is used through the 0.9.430 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2>
+    #[com_interface("76eceacb-0462-4d94-ac83-423a6793775e")]
+    pub trait ICoreWebView2_1: IUnknown {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_settings>
+        #[doc(alias = "get_Settings")]
+        unsafe fn get_settings(
+            &self,
+            /* out, retval */ settings: *mut *mut *mut ICoreWebView2SettingsVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_source>
+        #[doc(alias = "get_Source")]
+        unsafe fn get_source(&self, /* out, retval */ uri: *mut LPWSTR) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#navigate>
+        #[doc(alias = "Navigate")]
+        unsafe fn navigate(&self, /* in */ uri: LPCWSTR) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#navigatetostring>
+        #[doc(alias = "NavigateToString")]
+        unsafe fn navigate_to_string(&self, /* in */ htmlContent: LPCWSTR) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_navigationstarting>
+        #[doc(alias = "add_NavigationStarting")]
+        unsafe fn add_navigation_starting(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2NavigationStartingEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_navigationstarting>
+        #[doc(alias = "remove_NavigationStarting")]
+        unsafe fn remove_navigation_starting(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_contentloading>
+        #[doc(alias = "add_ContentLoading")]
+        unsafe fn add_content_loading(
+            &self,
+            /* in */ eventHandler: *mut *mut ICoreWebView2ContentLoadingEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_contentloading>
+        #[doc(alias = "remove_ContentLoading")]
+        unsafe fn remove_content_loading(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_sourcechanged>
+        #[doc(alias = "add_SourceChanged")]
+        unsafe fn add_source_changed(
+            &self,
+            /* in */ eventHandler: *mut *mut ICoreWebView2SourceChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_sourcechanged>
+        #[doc(alias = "remove_SourceChanged")]
+        unsafe fn remove_source_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_historychanged>
+        #[doc(alias = "add_HistoryChanged")]
+        unsafe fn add_history_changed(
+            &self,
+            /* in */ eventHandler: *mut *mut ICoreWebView2HistoryChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_historychanged>
+        #[doc(alias = "remove_HistoryChanged")]
+        unsafe fn remove_history_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_navigationcompleted>
+        #[doc(alias = "add_NavigationCompleted")]
+        unsafe fn add_navigation_completed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2NavigationCompletedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_navigationcompleted>
+        #[doc(alias = "remove_NavigationCompleted")]
+        unsafe fn remove_navigation_completed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_framenavigationstarting>
+        #[doc(alias = "add_FrameNavigationStarting")]
+        unsafe fn add_frame_navigation_starting(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2NavigationStartingEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_framenavigationstarting>
+        #[doc(alias = "remove_FrameNavigationStarting")]
+        unsafe fn remove_frame_navigation_starting(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_framenavigationcompleted>
+        #[doc(alias = "add_FrameNavigationCompleted")]
+        unsafe fn add_frame_navigation_completed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2NavigationCompletedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_framenavigationcompleted>
+        #[doc(alias = "remove_FrameNavigationCompleted")]
+        unsafe fn remove_frame_navigation_completed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_scriptdialogopening>
+        #[doc(alias = "add_ScriptDialogOpening")]
+        unsafe fn add_script_dialog_opening(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2ScriptDialogOpeningEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_scriptdialogopening>
+        #[doc(alias = "remove_ScriptDialogOpening")]
+        unsafe fn remove_script_dialog_opening(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_permissionrequested>
+        #[doc(alias = "add_PermissionRequested")]
+        unsafe fn add_permission_requested(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2PermissionRequestedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_permissionrequested>
+        #[doc(alias = "remove_PermissionRequested")]
+        unsafe fn remove_permission_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_processfailed>
+        #[doc(alias = "add_ProcessFailed")]
+        unsafe fn add_process_failed(
+            &self,
+            /* in */ eventHandler: *mut *mut ICoreWebView2ProcessFailedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_processfailed>
+        #[doc(alias = "remove_ProcessFailed")]
+        unsafe fn remove_process_failed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addscripttoexecuteondocumentcreated>
+        #[doc(alias = "AddScriptToExecuteOnDocumentCreated")]
+        unsafe fn add_script_to_execute_on_document_created(
+            &self,
+            /* in */ javaScript: LPCWSTR,
+            /* in */
+            handler: *mut *mut ICoreWebView2AddScriptToExecuteOnDocumentCreatedCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removescripttoexecuteondocumentcreated>
+        #[doc(alias = "RemoveScriptToExecuteOnDocumentCreated")]
+        unsafe fn remove_script_to_execute_on_document_created(
+            &self,
+            /* in */ id: LPCWSTR,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#executescript>
+        #[doc(alias = "ExecuteScript")]
+        unsafe fn execute_script(
+            &self,
+            /* in */ javaScript: LPCWSTR,
+            /* in */ handler: *mut *mut ICoreWebView2ExecuteScriptCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#capturepreview>
+        #[doc(alias = "CapturePreview")]
+        unsafe fn capture_preview(
+            &self,
+            /* in */ imageFormat: CORE_WEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT,
+            /* in */ imageStream: *mut *mut IStreamVTable,
+            /* in */ handler: *mut *mut ICoreWebView2CapturePreviewCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#reload>
+        #[doc(alias = "Reload")]
+        unsafe fn reload(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#postwebmessageasjson>
+        #[doc(alias = "PostWebMessageAsJson")]
+        unsafe fn post_web_message_as_json(
+            &self,
+            /* in */ webMessageAsJson: LPCWSTR,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#postwebmessageasstring>
+        #[doc(alias = "PostWebMessageAsString")]
+        unsafe fn post_web_message_as_string(
+            &self,
+            /* in */ webMessageAsString: LPCWSTR,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_webmessagereceived>
+        #[doc(alias = "add_WebMessageReceived")]
+        unsafe fn add_web_message_received(
+            &self,
+            /* in */ handler: *mut *mut ICoreWebView2WebMessageReceivedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_webmessagereceived>
+        #[doc(alias = "remove_WebMessageReceived")]
+        unsafe fn remove_web_message_received(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#calldevtoolsprotocolmethod>
+        #[doc(alias = "CallDevToolsProtocolMethod")]
+        unsafe fn call_dev_tools_protocol_method(
+            &self,
+            /* in */ methodName: LPCWSTR,
+            /* in */ parametersAsJson: LPCWSTR,
+            /* in */
+            handler: *mut *mut ICoreWebView2CallDevToolsProtocolMethodCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_browserprocessid>
+        #[doc(alias = "get_BrowserProcessId")]
+        unsafe fn get_browser_process_id(
+            &self,
+            /* out, retval */ value: *mut UINT32,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_cangoback>
+        #[doc(alias = "get_CanGoBack")]
+        unsafe fn get_can_go_back(&self, /* out, retval */ canGoBack: *mut BOOL) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_cangoforward>
+        #[doc(alias = "get_CanGoForward")]
+        unsafe fn get_can_go_forward(
+            &self,
+            /* out, retval */ canGoForward: *mut BOOL,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#goback>
+        #[doc(alias = "GoBack")]
+        unsafe fn go_back(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#goforward>
+        #[doc(alias = "GoForward")]
+        unsafe fn go_forward(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#getdevtoolsprotocoleventreceiver>
+        #[doc(alias = "GetDevToolsProtocolEventReceiver")]
+        unsafe fn get_dev_tools_protocol_event_receiver(
+            &self,
+            /* in */ eventName: LPCWSTR,
+            /* out, retval */
+            receiver: *mut *mut *mut ICoreWebView2DevToolsProtocolEventReceiverVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#stop>
+        #[doc(alias = "Stop")]
+        unsafe fn stop(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_newwindowrequested>
+        #[doc(alias = "add_NewWindowRequested")]
+        unsafe fn add_new_window_requested(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2NewWindowRequestedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_newwindowrequested>
+        #[doc(alias = "remove_NewWindowRequested")]
+        unsafe fn remove_new_window_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_documenttitlechanged>
+        #[doc(alias = "add_DocumentTitleChanged")]
+        unsafe fn add_document_title_changed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2DocumentTitleChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_documenttitlechanged>
+        #[doc(alias = "remove_DocumentTitleChanged")]
+        unsafe fn remove_document_title_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_documenttitle>
+        #[doc(alias = "get_DocumentTitle")]
+        unsafe fn get_document_title(&self, /* out, retval */ title: *mut LPWSTR) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addhostobjecttoscript>
+        #[doc(alias = "AddHostObjectToScript")]
+        unsafe fn add_host_object_to_script(
+            &self,
+            /* in */ name: LPCWSTR,
+            /* in */ object: *const VARIANT,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removehostobjectfromscript>
+        #[doc(alias = "RemoveHostObjectFromScript")]
+        unsafe fn remove_host_object_from_script(&self, /* in */ name: LPCWSTR) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#opendevtoolswindow>
+        #[doc(alias = "OpenDevToolsWindow")]
+        unsafe fn open_dev_tools_window(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_containsfullscreenelementchanged>
+        #[doc(alias = "add_ContainsFullScreenElementChanged")]
+        unsafe fn add_contains_full_screen_element_changed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2ContainsFullScreenElementChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_containsfullscreenelementchanged>
+        #[doc(alias = "remove_ContainsFullScreenElementChanged")]
+        unsafe fn remove_contains_full_screen_element_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#get_containsfullscreenelement>
+        #[doc(alias = "get_ContainsFullScreenElement")]
+        unsafe fn get_contains_full_screen_element(
+            &self,
+            /* out, retval */ containsFullScreenElement: *mut BOOL,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_webresourcerequested>
+        #[doc(alias = "add_WebResourceRequested")]
+        unsafe fn add_web_resource_requested(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2WebResourceRequestedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_webresourcerequested>
+        #[doc(alias = "remove_WebResourceRequested")]
+        unsafe fn remove_web_resource_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#addwebresourcerequestedfilter>
+        #[doc(alias = "AddWebResourceRequestedFilter")]
+        unsafe fn add_web_resource_requested_filter(
+            &self,
+            /* in */ uri: LPCWSTR,
+            /* in */ resourceContext: CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#removewebresourcerequestedfilter>
+        #[doc(alias = "RemoveWebResourceRequestedFilter")]
+        unsafe fn remove_web_resource_requested_filter(
+            &self,
+            /* in */ uri: LPCWSTR,
+            /* in */ resourceContext: CORE_WEBVIEW2_WEB_RESOURCE_CONTEXT,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#add_windowcloserequested>
+        #[doc(alias = "add_WindowCloseRequested")]
+        unsafe fn add_window_close_requested(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2WindowCloseRequestedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2#remove_windowcloserequested>
+        #[doc(alias = "remove_WindowCloseRequested")]
+        unsafe fn remove_window_close_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_1`.
+    pub const IID_ICoreWebView2_1: GUID = GUID {
+        Data1: 0x76eceacb,
+        Data2: 0x0462,
+        Data3: 0x4d94,
+        Data4: [0xac, 0x83, 0x42, 0x3a, 0x67, 0x93, 0x77, 0x5e],
+    };
+
+    /// `ICoreWebView2NewWindowRequestedEventArgs` as released in SDK 1.0, which
+    /// appended `WindowFeatures` to the 0.9.430 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs>
+    #[com_interface("34acb11c-fc37-4418-9132-f9c21d1eaf9a")]
+    pub trait ICoreWebView2NewWindowRequestedEventArgs_1:
+        ICoreWebView2NewWindowRequestedEventArgs
+    {
+        /// Window features specified by the `window.open`. The features should be
+        /// considered for positioning and sizing of new webview windows.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2newwindowrequestedeventargs#get_windowfeatures>
+        #[doc(alias = "get_WindowFeatures")]
+        unsafe fn get_window_features(
+            &self,
+            /* out, retval */ value: *mut *mut *mut ICoreWebView2WindowFeaturesVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2NewWindowRequestedEventArgs_1`.
+    pub const IID_ICoreWebView2NewWindowRequestedEventArgs_1: GUID = GUID {
+        Data1: 0x34acb11c,
+        Data2: 0xfc37,
+        Data3: 0x4418,
+        Data4: [0x91, 0x32, 0xf9, 0xc2, 0x1d, 0x1e, 0xaf, 0x9a],
+    };
+
+    /// The window features for a WebView popup window. The fields match the
+    /// `windowFeatures` passed to `window.open` as specified in
+    /// [Window features](https://developer.mozilla.org/docs/Web/API/Window/open#Window_features)
+    /// on MDN.
+    ///
+    /// There is no requirement for you to respect the values. If your app does
+    /// not have corresponding UI features (for example, no toolbar) or if all
+    /// instance of WebView are opened in tabs and do not have distinct size or
+    /// positions, then your app does not respect the values. You may want to
+    /// respect values, but perhaps only some apply to the UI of you app.
+    /// Accordingly, you may respect all, some, or none of the properties as
+    /// appropriate for your app. For all numeric properties, if the value that is
+    /// passed to `window.open` is outside the range of an unsigned 32bit int, the
+    /// resulting value is the absolute value of the maximum for unsigned 32bit
+    /// integer. If you are not able to parse the value an integer, it is
+    /// considered `0`. If the value is a floating point value, it is rounded down
+    /// to an integer.
+    ///
+    /// In runtime versions 98 or later, the values of `ShouldDisplayMenuBar`,
+    /// `ShouldDisplayStatus`, `ShouldDisplayToolbar`, and `ShouldDisplayScrollBars`
+    /// will not directly depend on the equivalent fields in the `windowFeatures`
+    /// string. Instead, they will all be false if the window is expected to be a
+    /// popup, and true if it is not.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures>
+    #[com_interface("5eaf559f-b46e-4397-8860-e422f287ff1e")]
+    pub trait ICoreWebView2WindowFeatures: IUnknown {
+        /// Specifies left and top values.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_hasposition>
+        #[doc(alias = "get_HasPosition")]
+        unsafe fn get_has_position(&self, /* out, retval */ value: *mut BOOL) -> HRESULT;
+
+        /// Specifies height and width values.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_hassize>
+        #[doc(alias = "get_HasSize")]
+        unsafe fn get_has_size(&self, /* out, retval */ value: *mut BOOL) -> HRESULT;
+
+        /// Specifies the left position of the window. If `HasPosition` is set to
+        /// `FALSE`, this field is ignored.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_left>
+        #[doc(alias = "get_Left")]
+        unsafe fn get_left(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+        /// Specifies the top position of the window. If `HasPosition` is set to
+        /// `FALSE`, this field is ignored.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_top>
+        #[doc(alias = "get_Top")]
+        unsafe fn get_top(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+        /// Specifies the height of the window. Minimum value is `100`. If
+        /// `HasSize` is set to `FALSE`, this field is ignored.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_height>
+        #[doc(alias = "get_Height")]
+        unsafe fn get_height(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+        /// Specifies the width of the window. Minimum value is `100`. If `HasSize`
+        /// is set to `FALSE`, this field is ignored.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_width>
+        #[doc(alias = "get_Width")]
+        unsafe fn get_width(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+        /// Indicates that the menu bar is displayed.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_shoulddisplaymenubar>
+        #[doc(alias = "get_ShouldDisplayMenuBar")]
+        unsafe fn get_should_display_menu_bar(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Indicates that the status bar is displayed.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_shoulddisplaystatus>
+        #[doc(alias = "get_ShouldDisplayStatus")]
+        unsafe fn get_should_display_status(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Indicates that the browser toolbar is displayed.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_shoulddisplaytoolbar>
+        #[doc(alias = "get_ShouldDisplayToolbar")]
+        unsafe fn get_should_display_toolbar(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Indicates that the scroll bars are displayed.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2windowfeatures#get_shoulddisplayscrollbars>
+        #[doc(alias = "get_ShouldDisplayScrollBars")]
+        unsafe fn get_should_display_scroll_bars(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2WindowFeatures`.
+    pub const IID_ICoreWebView2WindowFeatures: GUID = GUID {
+        Data1: 0x5eaf559f,
+        Data2: 0xb46e,
+        Data3: 0x4397,
+        Data4: [0x88, 0x60, 0xe4, 0x22, 0xf2, 0x87, 0xff, 0x1e],
+    };
+
+    pub const SDK_1_0_488_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2_1",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2_1,
+            methods: 58,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2NewWindowRequestedEventArgs_1",
+            parent: "ICoreWebView2NewWindowRequestedEventArgs",
+            iid: IID_ICoreWebView2NewWindowRequestedEventArgs_1,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2WindowFeatures",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2WindowFeatures,
+            methods: 10,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.488")]
+pub use self::sdk_1_0_488::*;
+
+#[cfg(feature = "sdk-1.0.705")]
+mod sdk_1_0_705 {
+    use super::*;
+
+    /// A continuation of the ICoreWebView2Environment interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment2>
+    #[com_interface("41F3632B-5EF4-404F-AD82-2D606C5A9A21")]
+    pub trait ICoreWebView2Environment2: ICoreWebView2Environment {
+        /// Create a new web resource request object.
+        /// URI parameter must be absolute URI.
+        /// The headers string is the raw request header string delimited by CRLF
+        /// (optional in last header).
+        /// It's also possible to create this object with null headers string
+        /// and then use the ICoreWebView2HttpRequestHeaders to construct the headers
+        /// line by line.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment2#createwebresourcerequest>
+        #[doc(alias = "CreateWebResourceRequest")]
+        unsafe fn create_web_resource_request(
+            &self,
+            /* in */ uri: LPCWSTR,
+            /* in */ method: LPCWSTR,
+            /* in */ postData: *mut *mut IStreamVTable,
+            /* in */ headers: LPCWSTR,
+            /* out, retval */ request: *mut *mut *mut ICoreWebView2WebResourceRequestVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment2`.
+    pub const IID_ICoreWebView2Environment2: GUID = GUID {
+        Data1: 0x41F3632B,
+        Data2: 0x5EF4,
+        Data3: 0x404F,
+        Data4: [0xAD, 0x82, 0x2D, 0x60, 0x6C, 0x5A, 0x9A, 0x21],
+    };
+
+    /// A continuation of the `ICoreWebView2` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2>
+    #[com_interface("9E8F0CF8-E670-4B5E-B2BC-73E061E3184C")]
+    pub trait ICoreWebView2_2: ICoreWebView2_1 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#add_webresourceresponsereceived>
+        #[doc(alias = "add_WebResourceResponseReceived")]
+        unsafe fn add_web_resource_response_received(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#remove_webresourceresponsereceived>
+        #[doc(alias = "remove_WebResourceResponseReceived")]
+        unsafe fn remove_web_resource_response_received(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#navigatewithwebresourcerequest>
+        #[doc(alias = "NavigateWithWebResourceRequest")]
+        unsafe fn navigate_with_web_resource_request(
+            &self,
+            /* in */ request: *mut *mut ICoreWebView2WebResourceRequestVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#add_domcontentloaded>
+        #[doc(alias = "add_DOMContentLoaded")]
+        unsafe fn add_dom_content_loaded(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#remove_domcontentloaded>
+        #[doc(alias = "remove_DOMContentLoaded")]
+        unsafe fn remove_dom_content_loaded(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#get_cookiemanager>
+        #[doc(alias = "get_CookieManager")]
+        unsafe fn get_cookie_manager(
+            &self,
+            /* out, retval */ cookieManager: *mut *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_2#get_environment>
+        #[doc(alias = "get_Environment")]
+        unsafe fn get_environment(
+            &self,
+            /* out, retval */ environment: *mut *mut *mut ICoreWebView2EnvironmentVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_2`.
+    pub const IID_ICoreWebView2_2: GUID = GUID {
+        Data1: 0x9E8F0CF8,
+        Data2: 0xE670,
+        Data3: 0x4B5E,
+        Data4: [0xB2, 0xBC, 0x73, 0xE0, 0x61, 0xE3, 0x18, 0x4C],
+    };
+
+    pub const SDK_1_0_705_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2Environment2",
+            parent: "ICoreWebView2Environment",
+            iid: IID_ICoreWebView2Environment2,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_2",
+            parent: "ICoreWebView2_1",
+            iid: IID_ICoreWebView2_2,
+            methods: 7,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.705")]
+pub use self::sdk_1_0_705::*;
+
+#[cfg(feature = "sdk-1.0.774")]
+mod sdk_1_0_774 {
+    use super::*;
+
+    /// This interface is an extension of the ICoreWebView2Controller interface to
+    /// support visual hosting. An object implementing the
+    /// ICoreWebView2CompositionController interface will also implement
+    /// ICoreWebView2Controller. Callers are expected to use
+    /// ICoreWebView2Controller for resizing, visibility, focus, and so on, and
+    /// then use ICoreWebView2CompositionController to connect to a composition
+    /// tree and provide input meant for the WebView.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller>
+    #[com_interface("3df9b733-b9ae-4a15-86b4-eb9ee9826469")]
+    pub trait ICoreWebView2CompositionController: IUnknown {
+        /// The RootVisualTarget is a visual in the hosting app's visual tree. This
+        /// visual is where the WebView will connect its visual tree. The app uses
+        /// this visual to position the WebView within the app. The app still needs
+        /// to use the Bounds property to size the WebView. The RootVisualTarget
+        /// property can be an IDCompositionVisual or a
+        /// Windows::UI::Composition::ContainerVisual. WebView will connect its
+        /// visual tree to the provided visual before returning from the property
+        /// setter. The app needs to commit on its device setting the
+        /// RootVisualTarget property. The RootVisualTarget property supports being
+        /// set to nullptr to disconnect the WebView from the app's visual tree.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#get_rootvisualtarget>
+        #[doc(alias = "get_RootVisualTarget")]
+        unsafe fn get_root_visual_target(
+            &self,
+            /* out, retval */ target: *mut *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+
+        /// Set the RootVisualTarget property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#put_rootvisualtarget>
+        #[doc(alias = "put_RootVisualTarget")]
+        unsafe fn put_root_visual_target(
+            &self,
+            /* in */ target: *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+
+        /// If eventKind is COREWEBVIEW2_MOUSE_EVENT_KIND_HORIZONTAL_WHEEL or
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_WHEEL, then mouseData specifies the amount
+        /// of wheel movement. A positive value indicates that the wheel was rotated
+        /// forward, away from the user; a negative value indicates that the wheel
+        /// was rotated backward, toward the user. One wheel click is defined as
+        /// WHEEL_DELTA, which is 120.
+        /// If eventKind is COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_DOUBLE_CLICK,
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_DOWN, or
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_UP, then mouseData specifies which
+        /// X buttons were pressed or released. This value should be 1 if the first
+        /// X button is pressed/released and 2 if the second X button is
+        /// pressed/released.
+        /// If eventKind is COREWEBVIEW2_MOUSE_EVENT_KIND_LEAVE, then virtualKeys,
+        /// mouseData, and point should all be zero.
+        /// If eventKind is any other value, then mouseData should be zero.
+        /// Point is expected to be in the client coordinate space of the WebView.
+        /// To track mouse events that start in the WebView and can potentially move
+        /// outside of the WebView and host application, calling SetCapture and
+        /// ReleaseCapture is recommended.
+        /// To dismiss hover popups, it is also recommended to send
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_LEAVE messages.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#sendmouseinput>
+        #[doc(alias = "SendMouseInput")]
+        unsafe fn send_mouse_input(
+            &self,
+            /* in */ eventKind: UINT32,
+            /* in */ virtualKeys: UINT32,
+            /* in */ mouseData: UINT32,
+            /* in */ point: POINT,
+        ) -> HRESULT;
+
+        /// SendPointerInput accepts touch or pen pointer input of types defined in
+        /// COREWEBVIEW2_POINTER_EVENT_KIND. Any pointer input from the system must
+        /// be converted into an ICoreWebView2PointerInfo first.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#sendpointerinput>
+        #[doc(alias = "SendPointerInput")]
+        unsafe fn send_pointer_input(
+            &self,
+            /* in */ eventKind: UINT32,
+            /* in */ pointerInfo: *mut *mut ICoreWebView2PointerInfoVTable,
+        ) -> HRESULT;
+
+        /// The current cursor that WebView thinks it should be. The cursor should
+        /// be set in WM_SETCURSOR through ::SetCursor or set on the corresponding
+        /// parent/ancestor HWND of the WebView through ::SetClassLongPtr. The
+        /// HCURSOR can be freed so CopyCursor/DestroyCursor is recommended to keep
+        /// your own copy if you are doing more than immediately setting the cursor.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#get_cursor>
+        #[doc(alias = "get_Cursor")]
+        unsafe fn get_cursor(&self, /* out, retval */ cursor: *mut HCURSOR) -> HRESULT;
+
+        /// The current system cursor ID reported by the underlying rendering
+        /// engine for WebView. For example, most of the time, when the cursor is
+        /// over text, this will return the int value for IDC_IBEAM. The
+        /// SystemCursorId is only valid if the rendering engine reports a default
+        /// Windows cursor resource value. Otherwise, if custom CSS cursors are
+        /// being used, this will return 0. To actually use systemCursorId in
+        /// LoadCursor or LoadImage, MAKEINTRESOURCE must be called on it first.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#get_systemcursorid>
+        #[doc(alias = "get_SystemCursorId")]
+        unsafe fn get_system_cursor_id(
+            &self,
+            /* out, retval */ systemCursorId: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Add an event handler for the CursorChanged event.
+        /// The event is raised when WebView thinks the cursor should be changed.
+        /// For example, when the mouse cursor is currently the default cursor but
+        /// is then moved over text, it may try to change to the IBeam cursor.
+        ///
+        /// It is expected for the developer to send
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_LEAVE messages (in addition to
+        /// COREWEBVIEW2_MOUSE_EVENT_KIND_MOVE messages) through the SendMouseInput
+        /// API. This is to ensure that the mouse is actually within the WebView
+        /// that sends out CursorChanged events.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#add_cursorchanged>
+        #[doc(alias = "add_CursorChanged")]
+        unsafe fn add_cursor_changed(
+            &self,
+            /* in */ eventHandler: *mut *mut ICoreWebView2CursorChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// Remove an event handler previously added with add_CursorChanged.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller#remove_cursorchanged>
+        #[doc(alias = "remove_CursorChanged")]
+        unsafe fn remove_cursor_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2CompositionController`.
+    pub const IID_ICoreWebView2CompositionController: GUID = GUID {
+        Data1: 0x3df9b733,
+        Data2: 0xb9ae,
+        Data3: 0x4a15,
+        Data4: [0x86, 0xb4, 0xeb, 0x9e, 0xe9, 0x82, 0x64, 0x69],
+    };
+
+    /// Mouse event type used by SendMouseInput to convey the type of mouse
+    /// event being sent to WebView. The values of this enum align with the
+    /// matching WM_* window messages.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_HORIZONTAL_WHEEL: UINT32 = 0x020E;
+
+    /// Left button double click mouse event, WM_LBUTTONDBLCLK.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_LEFT_BUTTON_DOUBLE_CLICK: UINT32 = 0x0203;
+
+    /// Left button down mouse event, WM_LBUTTONDOWN.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_LEFT_BUTTON_DOWN: UINT32 = 0x0201;
+
+    /// Left button up mouse event, WM_LBUTTONUP.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_LEFT_BUTTON_UP: UINT32 = 0x0202;
+
+    /// Mouse leave event, WM_MOUSELEAVE.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_LEAVE: UINT32 = 0x02A3;
+
+    /// Middle button double click mouse event, WM_MBUTTONDBLCLK.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_MIDDLE_BUTTON_DOUBLE_CLICK: UINT32 = 0x0209;
+
+    /// Middle button down mouse event, WM_MBUTTONDOWN.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_MIDDLE_BUTTON_DOWN: UINT32 = 0x0207;
+
+    /// Middle button up mouse event, WM_MBUTTONUP.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_MIDDLE_BUTTON_UP: UINT32 = 0x0208;
+
+    /// Mouse move event, WM_MOUSEMOVE.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_MOVE: UINT32 = 0x0200;
+
+    /// Right button double click mouse event, WM_RBUTTONDBLCLK.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_RIGHT_BUTTON_DOUBLE_CLICK: UINT32 = 0x0206;
+
+    /// Right button down mouse event, WM_RBUTTONDOWN.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_RIGHT_BUTTON_DOWN: UINT32 = 0x0204;
+
+    /// Right button up mouse event, WM_RBUTTONUP.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_RIGHT_BUTTON_UP: UINT32 = 0x0205;
+
+    /// Mouse wheel delta event, WM_MOUSEWHEEL.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_WHEEL: UINT32 = 0x020A;
+
+    /// First or second X button double click mouse event, WM_XBUTTONDBLCLK.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_DOUBLE_CLICK: UINT32 = 0x020D;
+
+    /// First or second X button down mouse event, WM_XBUTTONDOWN.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_DOWN: UINT32 = 0x020B;
+
+    /// First or second X button up mouse event, WM_XBUTTONUP.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_X_BUTTON_UP: UINT32 = 0x020C;
+
+    /// Mouse Right Button Down event over a nonclient area, WM_NCRBUTTONDOWN.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_NON_CLIENT_RIGHT_BUTTON_DOWN: UINT32 = 0x00A4;
+
+    /// Mouse Right Button up event over a nonclient area, WM_NCRBUTTONUP.
+    pub const COREWEBVIEW2_MOUSE_EVENT_KIND_NON_CLIENT_RIGHT_BUTTON_UP: UINT32 = 0x00A5;
+
+    /// Mouse event virtual keys associated with a
+    /// COREWEBVIEW2_MOUSE_EVENT_KIND for SendMouseInput. These values can be
+    /// combined into a bit flag if more than one virtual key is pressed for the
+    /// event. The values of this enum align with the matching MK_* mouse keys.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_NONE: UINT32 = 0x0;
+
+    /// Left mouse button is down, MK_LBUTTON.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_LEFT_BUTTON: UINT32 = 0x0001;
+
+    /// Right mouse button is down, MK_RBUTTON.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_RIGHT_BUTTON: UINT32 = 0x0002;
+
+    /// SHIFT key is down, MK_SHIFT.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_SHIFT: UINT32 = 0x0004;
+
+    /// CTRL key is down, MK_CONTROL.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_CONTROL: UINT32 = 0x0008;
+
+    /// Middle mouse button is down, MK_MBUTTON.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_MIDDLE_BUTTON: UINT32 = 0x0010;
+
+    /// First X button is down, MK_XBUTTON1.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_X_BUTTON1: UINT32 = 0x0020;
+
+    /// Second X button is down, MK_XBUTTON2.
+    pub const COREWEBVIEW2_MOUSE_EVENT_VIRTUAL_KEYS_X_BUTTON2: UINT32 = 0x0040;
+
+    /// Pointer event type used by SendPointerInput to convey the type of
+    /// pointer event being sent to WebView. The values of this enum align with
+    /// the matching WM_POINTER* window messages.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_ACTIVATE: UINT32 = 0x024B;
+
+    /// Corresponds to WM_POINTERDOWN.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_DOWN: UINT32 = 0x0246;
+
+    /// Corresponds to WM_POINTERENTER.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_ENTER: UINT32 = 0x0249;
+
+    /// Corresponds to WM_POINTERLEAVE.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_LEAVE: UINT32 = 0x024A;
+
+    /// Corresponds to WM_POINTERUP.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_UP: UINT32 = 0x0247;
+
+    /// Corresponds to WM_POINTERUPDATE.
+    pub const COREWEBVIEW2_POINTER_EVENT_KIND_UPDATE: UINT32 = 0x0245;
+
+    /// A continuation of the ICoreWebView2CompositionController interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller2>
+    #[com_interface("0b6a3d24-49cb-4806-ba20-b5e0734a7b26")]
+    pub trait ICoreWebView2CompositionController2: ICoreWebView2CompositionController {
+        /// Returns the Automation Provider for the WebView. This object implements
+        /// IRawElementProviderSimple.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller2#get_automationprovider>
+        #[doc(alias = "get_AutomationProvider")]
+        unsafe fn get_automation_provider(
+            &self,
+            /* out, retval */ provider: *mut *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2CompositionController2`.
+    pub const IID_ICoreWebView2CompositionController2: GUID = GUID {
+        Data1: 0x0b6a3d24,
+        Data2: 0x49cb,
+        Data3: 0x4806,
+        Data4: [0xba, 0x20, 0xb5, 0xe0, 0x73, 0x4a, 0x7b, 0x26],
+    };
+
+    /// The caller implements this interface to receive CursorChanged events. Use
+    /// the Cursor property to get the new cursor.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2cursorchangedeventhandler>
+    #[com_interface("9da43ccc-26e1-4dad-b56c-d8961c94c571")]
+    pub trait ICoreWebView2CursorChangedEventHandler: IUnknown {
+        /// Called to provide the implementer with the event args for the
+        /// corresponding event. There are no event args and the args
+        /// parameter will be null.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2cursorchangedeventhandler#invoke>
+        #[doc(alias = "Invoke")]
+        unsafe fn invoke(
+            &self,
+            /* in */ sender: *mut *mut ICoreWebView2CompositionControllerVTable,
+            /* in */ args: *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2CursorChangedEventHandler`.
+    pub const IID_ICoreWebView2CursorChangedEventHandler: GUID = GUID {
+        Data1: 0x9da43ccc,
+        Data2: 0x26e1,
+        Data3: 0x4dad,
+        Data4: [0xb5, 0x6c, 0xd8, 0x96, 0x1c, 0x94, 0xc5, 0x71],
+    };
+
+    /// The caller implements this interface to receive the CoreWebView2Controller
+    /// created via CreateCoreWebView2CompositionController.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2compositioncontrollercompletedhandler>
+    #[com_interface("02fab84b-1428-4fb7-ad45-1b2e64736184")]
+    pub trait ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler:
+        IUnknown
+    {
+        /// Called to provide the implementer with the completion status and result
+        /// of the corresponding asynchronous method call.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2createcorewebview2compositioncontrollercompletedhandler#invoke>
+        #[doc(alias = "Invoke")]
+        unsafe fn invoke(
+            &self,
+            errorCode: HRESULT,
+            webView: *mut *mut ICoreWebView2CompositionControllerVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler`.
+    pub const IID_ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler: GUID =
+        GUID {
+            Data1: 0x02fab84b,
+            Data2: 0x1428,
+            Data3: 0x4fb7,
+            Data4: [0xad, 0x45, 0x1b, 0x2e, 0x64, 0x73, 0x61, 0x84],
+        };
+
+    /// This mostly represents a combined win32
+    /// POINTER_INFO/POINTER_TOUCH_INFO/POINTER_PEN_INFO object. It takes fields
+    /// from all three and excludes some win32 specific data types like HWND and
+    /// HANDLE. Note, sourceDevice is taken out but we expect the PointerDeviceRect
+    /// and DisplayRect to cover the existing use cases of sourceDevice.
+    /// Another big difference is that any of the point or rect locations are
+    /// expected to be in WebView physical coordinates. That is, coordinates
+    /// relative to the WebView and no DPI scaling applied.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo>
+    #[com_interface("e6995887-d10d-4f5d-9359-4ce46e4f96b9")]
+    pub trait ICoreWebView2PointerInfo: IUnknown {
+        /// Get the PointerKind of the pointer event. This corresponds to the
+        /// pointerKind property of the POINTER_INFO struct. The values are defined by
+        /// the POINTER_INPUT_KIND enum in the Windows SDK (winuser.h). Supports
+        /// PT_PEN and PT_TOUCH.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pointerkind>
+        #[doc(alias = "get_PointerKind")]
+        unsafe fn get_pointer_kind(
+            &self,
+            /* out, retval */ pointerKind: *mut DWORD,
+        ) -> HRESULT;
+
+        /// Set the PointerKind of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pointerkind>
+        #[doc(alias = "put_PointerKind")]
+        unsafe fn put_pointer_kind(&self, /* in */ pointerKind: DWORD) -> HRESULT;
+
+        /// Get the PointerId of the pointer event. This corresponds to the pointerId
+        /// property of the POINTER_INFO struct as defined in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pointerid>
+        #[doc(alias = "get_PointerId")]
+        unsafe fn get_pointer_id(&self, /* out, retval */ pointerId: *mut UINT32) -> HRESULT;
+
+        /// Set the PointerId of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pointerid>
+        #[doc(alias = "put_PointerId")]
+        unsafe fn put_pointer_id(&self, /* in */ pointerId: UINT32) -> HRESULT;
+
+        /// Get the FrameID of the pointer event. This corresponds to the frameId
+        /// property of the POINTER_INFO struct as defined in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_frameid>
+        #[doc(alias = "get_FrameId")]
+        unsafe fn get_frame_id(&self, /* out, retval */ frameId: *mut UINT32) -> HRESULT;
+
+        /// Set the FrameID of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_frameid>
+        #[doc(alias = "put_FrameId")]
+        unsafe fn put_frame_id(&self, /* in */ frameId: UINT32) -> HRESULT;
+
+        /// Get the PointerFlags of the pointer event. This corresponds to the
+        /// pointerFlags property of the POINTER_INFO struct. The values are defined
+        /// by the POINTER_FLAGS constants in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pointerflags>
+        #[doc(alias = "get_PointerFlags")]
+        unsafe fn get_pointer_flags(
+            &self,
+            /* out, retval */ pointerFlags: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the PointerFlags of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pointerflags>
+        #[doc(alias = "put_PointerFlags")]
+        unsafe fn put_pointer_flags(&self, /* in */ pointerFlags: UINT32) -> HRESULT;
+
+        /// Get the PointerDeviceRect of the sourceDevice property of the
+        /// POINTER_INFO struct as defined in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pointerdevicerect>
+        #[doc(alias = "get_PointerDeviceRect")]
+        unsafe fn get_pointer_device_rect(
+            &self,
+            /* out, retval */ pointerDeviceRect: *mut RECT,
+        ) -> HRESULT;
+
+        /// Set the PointerDeviceRect of the sourceDevice property of the
+        /// POINTER_INFO struct as defined in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pointerdevicerect>
+        #[doc(alias = "put_PointerDeviceRect")]
+        unsafe fn put_pointer_device_rect(&self, /* in */ pointerDeviceRect: RECT) -> HRESULT;
+
+        /// Get the DisplayRect of the sourceDevice property of the POINTER_INFO
+        /// struct as defined in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_displayrect>
+        #[doc(alias = "get_DisplayRect")]
+        unsafe fn get_display_rect(&self, /* out, retval */ displayRect: *mut RECT) -> HRESULT;
+
+        /// Set the DisplayRect of the sourceDevice property of the POINTER_INFO
+        /// struct as defined in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_displayrect>
+        #[doc(alias = "put_DisplayRect")]
+        unsafe fn put_display_rect(&self, /* in */ displayRect: RECT) -> HRESULT;
+
+        /// Get the PixelLocation of the pointer event. This corresponds to the
+        /// ptPixelLocation property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pixellocation>
+        #[doc(alias = "get_PixelLocation")]
+        unsafe fn get_pixel_location(
+            &self,
+            /* out, retval */ pixelLocation: *mut POINT,
+        ) -> HRESULT;
+
+        /// Set the PixelLocation of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pixellocation>
+        #[doc(alias = "put_PixelLocation")]
+        unsafe fn put_pixel_location(&self, /* in */ pixelLocation: POINT) -> HRESULT;
+
+        /// Get the HimetricLocation of the pointer event. This corresponds to the
+        /// ptHimetricLocation property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_himetriclocation>
+        #[doc(alias = "get_HimetricLocation")]
+        unsafe fn get_himetric_location(
+            &self,
+            /* out, retval */ himetricLocation: *mut POINT,
+        ) -> HRESULT;
+
+        /// Set the HimetricLocation of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_himetriclocation>
+        #[doc(alias = "put_HimetricLocation")]
+        unsafe fn put_himetric_location(&self, /* in */ himetricLocation: POINT) -> HRESULT;
+
+        /// Get the PixelLocationRaw of the pointer event. This corresponds to the
+        /// ptPixelLocationRaw property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pixellocationraw>
+        #[doc(alias = "get_PixelLocationRaw")]
+        unsafe fn get_pixel_location_raw(
+            &self,
+            /* out, retval */ pixelLocationRaw: *mut POINT,
+        ) -> HRESULT;
+
+        /// Set the PixelLocationRaw of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pixellocationraw>
+        #[doc(alias = "put_PixelLocationRaw")]
+        unsafe fn put_pixel_location_raw(&self, /* in */ pixelLocationRaw: POINT) -> HRESULT;
+
+        /// Get the HimetricLocationRaw of the pointer event. This corresponds to the
+        /// ptHimetricLocationRaw property of the POINTER_INFO struct as defined in
+        /// the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_himetriclocationraw>
+        #[doc(alias = "get_HimetricLocationRaw")]
+        unsafe fn get_himetric_location_raw(
+            &self,
+            /* out, retval */ himetricLocationRaw: *mut POINT,
+        ) -> HRESULT;
+
+        /// Set the HimetricLocationRaw of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_himetriclocationraw>
+        #[doc(alias = "put_HimetricLocationRaw")]
+        unsafe fn put_himetric_location_raw(
+            &self,
+            /* in */ himetricLocationRaw: POINT,
+        ) -> HRESULT;
+
+        /// Get the Time of the pointer event. This corresponds to the dwTime
+        /// property of the POINTER_INFO struct as defined in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_time>
+        #[doc(alias = "get_Time")]
+        unsafe fn get_time(&self, /* out, retval */ time: *mut DWORD) -> HRESULT;
+
+        /// Set the Time of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_time>
+        #[doc(alias = "put_Time")]
+        unsafe fn put_time(&self, /* in */ time: DWORD) -> HRESULT;
+
+        /// Get the HistoryCount of the pointer event. This corresponds to the
+        /// historyCount property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_historycount>
+        #[doc(alias = "get_HistoryCount")]
+        unsafe fn get_history_count(
+            &self,
+            /* out, retval */ historyCount: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the HistoryCount of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_historycount>
+        #[doc(alias = "put_HistoryCount")]
+        unsafe fn put_history_count(&self, /* in */ historyCount: UINT32) -> HRESULT;
+
+        /// Get the InputData of the pointer event. This corresponds to the
+        /// InputData property of the POINTER_INFO struct as defined in the Windows
+        /// SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_inputdata>
+        #[doc(alias = "get_InputData")]
+        unsafe fn get_input_data(&self, /* out, retval */ inputData: *mut INT32) -> HRESULT;
+
+        /// Set the InputData of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_inputdata>
+        #[doc(alias = "put_InputData")]
+        unsafe fn put_input_data(&self, /* in */ inputData: INT32) -> HRESULT;
+
+        /// Get the KeyStates of the pointer event. This corresponds to the
+        /// dwKeyStates property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_keystates>
+        #[doc(alias = "get_KeyStates")]
+        unsafe fn get_key_states(&self, /* out, retval */ keyStates: *mut DWORD) -> HRESULT;
+
+        /// Set the KeyStates of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_keystates>
+        #[doc(alias = "put_KeyStates")]
+        unsafe fn put_key_states(&self, /* in */ keyStates: DWORD) -> HRESULT;
+
+        /// Get the PerformanceCount of the pointer event. This corresponds to the
+        /// PerformanceCount property of the POINTER_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_performancecount>
+        #[doc(alias = "get_PerformanceCount")]
+        unsafe fn get_performance_count(
+            &self,
+            /* out, retval */ performanceCount: *mut UINT64,
+        ) -> HRESULT;
+
+        /// Set the PerformanceCount of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_performancecount>
+        #[doc(alias = "put_PerformanceCount")]
+        unsafe fn put_performance_count(&self, /* in */ performanceCount: UINT64) -> HRESULT;
+
+        /// Get the ButtonChangeKind of the pointer event. This corresponds to the
+        /// ButtonChangeKind property of the POINTER_INFO struct. The values are
+        /// defined by the POINTER_BUTTON_CHANGE_KIND enum in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_buttonchangekind>
+        #[doc(alias = "get_ButtonChangeKind")]
+        unsafe fn get_button_change_kind(
+            &self,
+            /* out, retval */ buttonChangeKind: *mut INT32,
+        ) -> HRESULT;
+
+        /// Set the ButtonChangeKind of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_buttonchangekind>
+        #[doc(alias = "put_ButtonChangeKind")]
+        unsafe fn put_button_change_kind(&self, /* in */ buttonChangeKind: INT32) -> HRESULT;
+
+        /// Get the PenFlags of the pointer event. This corresponds to the penFlags
+        /// property of the POINTER_PEN_INFO struct. The values are defined by the
+        /// PEN_FLAGS constants in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_penflags>
+        #[doc(alias = "get_PenFlags")]
+        unsafe fn get_pen_flags(&self, /* out, retval */ penFLags: *mut UINT32) -> HRESULT;
+
+        /// Set the PenFlags of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_penflags>
+        #[doc(alias = "put_PenFlags")]
+        unsafe fn put_pen_flags(&self, /* in */ penFLags: UINT32) -> HRESULT;
+
+        /// Get the PenMask of the pointer event. This corresponds to the penMask
+        /// property of the POINTER_PEN_INFO struct. The values are defined by the
+        /// PEN_MASK constants in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_penmask>
+        #[doc(alias = "get_PenMask")]
+        unsafe fn get_pen_mask(&self, /* out, retval */ penMask: *mut UINT32) -> HRESULT;
+
+        /// Set the PenMask of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_penmask>
+        #[doc(alias = "put_PenMask")]
+        unsafe fn put_pen_mask(&self, /* in */ penMask: UINT32) -> HRESULT;
+
+        /// Get the PenPressure of the pointer event. This corresponds to the
+        /// pressure property of the POINTER_PEN_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_penpressure>
+        #[doc(alias = "get_PenPressure")]
+        unsafe fn get_pen_pressure(
+            &self,
+            /* out, retval */ penPressure: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the PenPressure of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_penpressure>
+        #[doc(alias = "put_PenPressure")]
+        unsafe fn put_pen_pressure(&self, /* in */ penPressure: UINT32) -> HRESULT;
+
+        /// Get the PenRotation of the pointer event. This corresponds to the
+        /// rotation property of the POINTER_PEN_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_penrotation>
+        #[doc(alias = "get_PenRotation")]
+        unsafe fn get_pen_rotation(
+            &self,
+            /* out, retval */ penRotation: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the PenRotation of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_penrotation>
+        #[doc(alias = "put_PenRotation")]
+        unsafe fn put_pen_rotation(&self, /* in */ penRotation: UINT32) -> HRESULT;
+
+        /// Get the PenTiltX of the pointer event. This corresponds to the tiltX
+        /// property of the POINTER_PEN_INFO struct as defined in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pentiltx>
+        #[doc(alias = "get_PenTiltX")]
+        unsafe fn get_pen_tilt_x(&self, /* out, retval */ penTiltX: *mut INT32) -> HRESULT;
+
+        /// Set the PenTiltX of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pentiltx>
+        #[doc(alias = "put_PenTiltX")]
+        unsafe fn put_pen_tilt_x(&self, /* in */ penTiltX: INT32) -> HRESULT;
+
+        /// Get the PenTiltY of the pointer event. This corresponds to the tiltY
+        /// property of the POINTER_PEN_INFO struct as defined in the Windows SDK
+        /// (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_pentilty>
+        #[doc(alias = "get_PenTiltY")]
+        unsafe fn get_pen_tilt_y(&self, /* out, retval */ penTiltY: *mut INT32) -> HRESULT;
+
+        /// Set the PenTiltY of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_pentilty>
+        #[doc(alias = "put_PenTiltY")]
+        unsafe fn put_pen_tilt_y(&self, /* in */ penTiltY: INT32) -> HRESULT;
+
+        /// Get the TouchFlags of the pointer event. This corresponds to the
+        /// touchFlags property of the POINTER_TOUCH_INFO struct. The values are
+        /// defined by the TOUCH_FLAGS constants in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchflags>
+        #[doc(alias = "get_TouchFlags")]
+        unsafe fn get_touch_flags(&self, /* out, retval */ touchFlags: *mut UINT32) -> HRESULT;
+
+        /// Set the TouchFlags of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchflags>
+        #[doc(alias = "put_TouchFlags")]
+        unsafe fn put_touch_flags(&self, /* in */ touchFlags: UINT32) -> HRESULT;
+
+        /// Get the TouchMask of the pointer event. This corresponds to the
+        /// touchMask property of the POINTER_TOUCH_INFO struct. The values are
+        /// defined by the TOUCH_MASK constants in the Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchmask>
+        #[doc(alias = "get_TouchMask")]
+        unsafe fn get_touch_mask(&self, /* out, retval */ touchMask: *mut UINT32) -> HRESULT;
+
+        /// Set the TouchMask of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchmask>
+        #[doc(alias = "put_TouchMask")]
+        unsafe fn put_touch_mask(&self, /* in */ touchMask: UINT32) -> HRESULT;
+
+        /// Get the TouchContact of the pointer event. This corresponds to the
+        /// rcContact property of the POINTER_TOUCH_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchcontact>
+        #[doc(alias = "get_TouchContact")]
+        unsafe fn get_touch_contact(
+            &self,
+            /* out, retval */ touchContact: *mut RECT,
+        ) -> HRESULT;
+
+        /// Set the TouchContact of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchcontact>
+        #[doc(alias = "put_TouchContact")]
+        unsafe fn put_touch_contact(&self, /* in */ touchContact: RECT) -> HRESULT;
+
+        /// Get the TouchContactRaw of the pointer event. This corresponds to the
+        /// rcContactRaw property of the POINTER_TOUCH_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchcontactraw>
+        #[doc(alias = "get_TouchContactRaw")]
+        unsafe fn get_touch_contact_raw(
+            &self,
+            /* out, retval */ touchContactRaw: *mut RECT,
+        ) -> HRESULT;
+
+        /// Set the TouchContactRaw of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchcontactraw>
+        #[doc(alias = "put_TouchContactRaw")]
+        unsafe fn put_touch_contact_raw(&self, /* in */ touchContactRaw: RECT) -> HRESULT;
+
+        /// Get the TouchOrientation of the pointer event. This corresponds to the
+        /// orientation property of the POINTER_TOUCH_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchorientation>
+        #[doc(alias = "get_TouchOrientation")]
+        unsafe fn get_touch_orientation(
+            &self,
+            /* out, retval */ touchOrientation: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the TouchOrientation of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchorientation>
+        #[doc(alias = "put_TouchOrientation")]
+        unsafe fn put_touch_orientation(&self, /* in */ touchOrientation: UINT32) -> HRESULT;
+
+        /// Get the TouchPressure of the pointer event. This corresponds to the
+        /// pressure property of the POINTER_TOUCH_INFO struct as defined in the
+        /// Windows SDK (winuser.h).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#get_touchpressure>
+        #[doc(alias = "get_TouchPressure")]
+        unsafe fn get_touch_pressure(
+            &self,
+            /* out, retval */ touchPressure: *mut UINT32,
+        ) -> HRESULT;
+
+        /// Set the TouchPressure of the pointer event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2pointerinfo#put_touchpressure>
+        #[doc(alias = "put_TouchPressure")]
+        unsafe fn put_touch_pressure(&self, /* in */ touchPressure: UINT32) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2PointerInfo`.
+    pub const IID_ICoreWebView2PointerInfo: GUID = GUID {
+        Data1: 0xe6995887,
+        Data2: 0xd10d,
+        Data3: 0x4f5d,
+        Data4: [0x93, 0x59, 0x4c, 0xe4, 0x6e, 0x4f, 0x96, 0xb9],
+    };
+
+    /// A continuation of the ICoreWebView2Controller interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller2>
+    #[com_interface("c979903e-d4ca-4228-92eb-47ee3fa96eab")]
+    pub trait ICoreWebView2Controller2: ICoreWebView2Host {
+        /// The `DefaultBackgroundColor` property is the color WebView renders
+        /// underneath all web content. This means WebView renders this color when
+        /// there is no web content loaded such as before the initial navigation or
+        /// between navigations. This also means web pages with undefined css
+        /// background properties or background properties containing transparent
+        /// pixels will render their contents over this color. Web pages with defined
+        /// and opaque background properties that span the page will obscure the
+        /// `DefaultBackgroundColor` and display normally. The default value for this
+        /// property is white to resemble the native browser experience.
+        ///
+        /// The Color is specified by the COREWEBVIEW2_COLOR that represents an RGBA
+        /// value. The `A` represents an Alpha value, meaning
+        /// `DefaultBackgroundColor` can be transparent. In the case of a transparent
+        /// `DefaultBackgroundColor` WebView will render hosting app content as the
+        /// background. This Alpha value is not supported on Windows 7. Any `A` value
+        /// other than 255 will result in E_INVALIDARG on Windows 7.
+        /// It is supported on all other WebView compatible platforms.
+        ///
+        /// Semi-transparent colors are not currently supported by this API and
+        /// setting `DefaultBackgroundColor` to a semi-transparent color will fail
+        /// with E_INVALIDARG. The only supported alpha values are 0 and 255, all
+        /// other values will result in E_INVALIDARG.
+        /// `DefaultBackgroundColor` can only be an opaque color or transparent.
+        ///
+        /// This value may also be set by using the
+        /// `WEBVIEW2_DEFAULT_BACKGROUND_COLOR` environment variable. There is a
+        /// known issue with background color where setting the color by API can
+        /// still leave the app with a white flicker before the
+        /// `DefaultBackgroundColor` takes effect. Setting the color via environment
+        /// variable solves this issue. The value must be a hex value that can
+        /// optionally prepend a 0x. The value must account for the alpha value
+        /// which is represented by the first 2 digits. So any hex value fewer than
+        /// 8 digits will assume a prepended 00 to the hex value and result in a
+        /// transparent color.
+        /// `get_DefaultBackgroundColor` will return the result of this environment
+        /// variable if used. This environment variable can only set the
+        /// `DefaultBackgroundColor` once. Subsequent updates to background color
+        /// must be done through API call.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller2#get_defaultbackgroundcolor>
+        #[doc(alias = "get_DefaultBackgroundColor")]
+        unsafe fn get_default_background_color(
+            &self,
+            /* out, retval */ backgroundColor: *mut COREWEBVIEW2_COLOR,
+        ) -> HRESULT;
+
+        /// Sets the `DefaultBackgroundColor` property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller2#put_defaultbackgroundcolor>
+        #[doc(alias = "put_DefaultBackgroundColor")]
+        unsafe fn put_default_background_color(
+            &self,
+            /* in */ backgroundColor: COREWEBVIEW2_COLOR,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Controller2`.
+    pub const IID_ICoreWebView2Controller2: GUID = GUID {
+        Data1: 0xc979903e,
+        Data2: 0xd4ca,
+        Data3: 0x4228,
+        Data4: [0x92, 0xeb, 0x47, 0xee, 0x3f, 0xa9, 0x6e, 0xab],
+    };
+
+    /// A value representing an RGBA color (Red, Green, Blue, Alpha) for
+    /// WebView2. Each component takes a value from 0 to 255, with 0 being no
+    /// intensity and 255 being the highest intensity.
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct COREWEBVIEW2_COLOR {
+        /// Specifies the intensity of the Alpha ie. opacity value. 0 is
+        /// transparent, 255 is opaque.
+        pub A: BYTE,
+        /// Specifies the intensity of the Red color.
+        pub R: BYTE,
+        /// Specifies the intensity of the Green color.
+        pub G: BYTE,
+        /// Specifies the intensity of the Blue color.
+        pub B: BYTE,
+    }
+
+    const _: () = assert!(std::mem::size_of::<COREWEBVIEW2_COLOR>() == 4);
+
+    /// A continuation of the ICoreWebView2Controller2 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3>
+    #[com_interface("f9614724-5d2b-41dc-aef7-73d62b51543b")]
+    pub trait ICoreWebView2Controller3: ICoreWebView2Controller2 {
+        /// The rasterization scale for the WebView. The rasterization scale is the
+        /// combination of the monitor DPI scale and text scaling set by the user.
+        /// This value should be updated when the DPI scale of the app's top level
+        /// window changes (i.e. monitor DPI scale changes or window changes
+        /// monitor) or when the text scale factor of the system changes.
+        ///
+        /// Rasterization scale applies to the WebView content, as well as
+        /// popups, context menus, scroll bars, and so on. Normal app scaling
+        /// scenarios should use the ZoomFactor property or SetBoundsAndZoomFactor
+        /// API which only scale the rendered HTML content and not popups, context
+        /// menus, scroll bars, and so on.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#get_rasterizationscale>
+        #[doc(alias = "get_RasterizationScale")]
+        unsafe fn get_rasterization_scale(&self, /* out, retval */ scale: *mut f64) -> HRESULT;
+
+        /// Set the rasterization scale property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#put_rasterizationscale>
+        #[doc(alias = "put_RasterizationScale")]
+        unsafe fn put_rasterization_scale(&self, /* in */ scale: f64) -> HRESULT;
+
+        /// ShouldDetectMonitorScaleChanges property determines whether the WebView
+        /// attempts to track monitor DPI scale changes. When true, the WebView will
+        /// track monitor DPI scale changes, update the RasterizationScale property,
+        /// and raises RasterizationScaleChanged event. When false, the WebView will
+        /// not track monitor DPI scale changes, and the app must update the
+        /// RasterizationScale property itself. RasterizationScaleChanged event will
+        /// never raise when ShouldDetectMonitorScaleChanges is false. Apps that want
+        /// to set their own rasterization scale should set this property to false
+        /// to avoid the WebView2 updating the RasterizationScale property to match
+        /// the monitor DPI scale.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#get_shoulddetectmonitorscalechanges>
+        #[doc(alias = "get_ShouldDetectMonitorScaleChanges")]
+        unsafe fn get_should_detect_monitor_scale_changes(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Set the ShouldDetectMonitorScaleChanges property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#put_shoulddetectmonitorscalechanges>
+        #[doc(alias = "put_ShouldDetectMonitorScaleChanges")]
+        unsafe fn put_should_detect_monitor_scale_changes(
+            &self,
+            /* in */ value: BOOL,
+        ) -> HRESULT;
+
+        /// Add an event handler for the RasterizationScaleChanged event.
+        /// The event is raised when the WebView detects that the monitor DPI scale
+        /// has changed, ShouldDetectMonitorScaleChanges is true, and the WebView has
+        /// changed the RasterizationScale property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#add_rasterizationscalechanged>
+        #[doc(alias = "add_RasterizationScaleChanged")]
+        unsafe fn add_rasterization_scale_changed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2RasterizationScaleChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// Remove an event handler previously added with
+        /// add_RasterizationScaleChanged.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#remove_rasterizationscalechanged>
+        #[doc(alias = "remove_RasterizationScaleChanged")]
+        unsafe fn remove_rasterization_scale_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// BoundsMode affects how setting the Bounds and RasterizationScale
+        /// properties work. Bounds mode can either be in
+        /// COREWEBVIEW2_BOUNDS_MODE_USE_RAW_PIXELS mode or
+        /// COREWEBVIEW2_BOUNDS_MODE_USE_RASTERIZATION_SCALE mode.
+        ///
+        /// When the mode is in COREWEBVIEW2_BOUNDS_MODE_USE_RAW_PIXELS, setting the
+        /// bounds property will set the size of the WebView in raw screen pixels.
+        /// Changing the rasterization scale in this mode won't change the raw pixel
+        /// size of the WebView and will only change the rasterization scale.
+        ///
+        /// When the mode is in COREWEBVIEW2_BOUNDS_MODE_USE_RASTERIZATION_SCALE,
+        /// setting the bounds property will change the logical size of the WebView
+        /// which can be described by the following equation:
+        /// ```text
+        /// Logical size * rasterization scale = Raw Pixel size
+        /// ```
+        /// In this case, changing the rasterization scale will keep the logical size
+        /// the same and change the raw pixel size.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#get_boundsmode>
+        #[doc(alias = "get_BoundsMode")]
+        unsafe fn get_bounds_mode(
+            &self,
+            /* out, retval */ boundsMode: *mut COREWEBVIEW2_BOUNDS_MODE,
+        ) -> HRESULT;
+
+        /// Set the BoundsMode property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller3#put_boundsmode>
+        #[doc(alias = "put_BoundsMode")]
+        unsafe fn put_bounds_mode(
+            &self,
+            /* in */ boundsMode: COREWEBVIEW2_BOUNDS_MODE,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Controller3`.
+    pub const IID_ICoreWebView2Controller3: GUID = GUID {
+        Data1: 0xf9614724,
+        Data2: 0x5d2b,
+        Data3: 0x41dc,
+        Data4: [0xae, 0xf7, 0x73, 0xd6, 0x2b, 0x51, 0x54, 0x3b],
+    };
+
+    /// Mode for how the Bounds property is interpreted in relation to the
+    /// RasterizationScale property.
+    #[repr(u32)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum COREWEBVIEW2_BOUNDS_MODE {
+        /// Bounds property represents raw pixels. Physical size of Webview is not
+        /// impacted by RasterizationScale.
+        COREWEBVIEW2_BOUNDS_MODE_USE_RAW_PIXELS,
+        /// Bounds property represents logical pixels and the RasterizationScale
+        /// property is used to get the physical size of the WebView.
+        COREWEBVIEW2_BOUNDS_MODE_USE_RASTERIZATION_SCALE,
+    }
+
+    impl COREWEBVIEW2_BOUNDS_MODE {
+        pub const fn as_u32(self) -> u32 {
+            self as u32
+        }
+    }
+
+    /// Fails with the value if it is not a known variant.
+    impl std::convert::TryFrom<u32> for COREWEBVIEW2_BOUNDS_MODE {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0 => Ok(Self::COREWEBVIEW2_BOUNDS_MODE_USE_RAW_PIXELS),
+                1 => Ok(Self::COREWEBVIEW2_BOUNDS_MODE_USE_RASTERIZATION_SCALE),
+                _ => Err(value),
+            }
+        }
+    }
+
+    /// Receives RasterizationScaleChanged events. Use the RasterizationScale
+    /// property to get the modified scale.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2rasterizationscalechangedeventhandler>
+    #[com_interface("9c98c8b1-ac53-427e-a345-3049b5524bbe")]
+    pub trait ICoreWebView2RasterizationScaleChangedEventHandler: IUnknown {
+        /// Called to provide the implementer with the event args for the
+        /// corresponding event. There are no event args and the args
+        /// parameter will be null.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2rasterizationscalechangedeventhandler#invoke>
+        #[doc(alias = "Invoke")]
+        unsafe fn invoke(
+            &self,
+            /* in */ sender: *mut *mut ICoreWebView2HostVTable,
+            /* in */ args: *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2RasterizationScaleChangedEventHandler`.
+    pub const IID_ICoreWebView2RasterizationScaleChangedEventHandler: GUID = GUID {
+        Data1: 0x9c98c8b1,
+        Data2: 0xac53,
+        Data3: 0x427e,
+        Data4: [0xa3, 0x45, 0x30, 0x49, 0xb5, 0x52, 0x4b, 0xbe],
+    };
+
+    /// A continuation of the ICoreWebView2Environment2 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment3>
+    #[com_interface("80a22ae3-be7c-4ce2-afe1-5a50056cdeeb")]
+    pub trait ICoreWebView2Environment3: ICoreWebView2Environment2 {
+        /// Asynchronously create a new WebView for use with visual hosting.
+        ///
+        /// parentWindow is the HWND in which the app will connect the visual tree of
+        /// the WebView. This will be the HWND that the app will receive pointer/
+        /// mouse input meant for the WebView (and will need to use SendMouseInput/
+        /// SendPointerInput to forward). If the app moves the WebView visual tree to
+        /// underneath a different window, then it needs to call put_ParentWindow to
+        /// update the new parent HWND of the visual tree.
+        ///
+        /// HWND_MESSAGE is not a valid parameter for `parentWindow` for visual
+        /// hosting. The underlying implementation of supporting HWND_MESSAGE would
+        /// break accessibility for visual hosting. This is supported in windowed
+        /// WebViews - see CreateCoreWebView2Controller.
+        ///
+        /// Use put_RootVisualTarget on the created CoreWebView2CompositionController
+        /// to provide a visual to host the browser's visual tree.
+        ///
+        /// It is recommended that the application set Application User Model ID for
+        /// the process or the application window. If none is set, during WebView
+        /// creation a generated Application User Model ID is set to root window of
+        /// parentWindow.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment3#createcorewebview2compositioncontroller>
+        #[doc(alias = "CreateCoreWebView2CompositionController")]
+        unsafe fn create_core_web_view2_composition_controller(
+            &self,
+            parentWindow: HWND,
+            handler: *mut *mut ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// Create an empty ICoreWebView2PointerInfo. The returned
+        /// ICoreWebView2PointerInfo needs to be populated with all of the relevant
+        /// info before calling SendPointerInput.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment3#createcorewebview2pointerinfo>
+        #[doc(alias = "CreateCoreWebView2PointerInfo")]
+        unsafe fn create_core_web_view2_pointer_info(
+            &self,
+            /* out, retval */ pointerInfo: *mut *mut *mut ICoreWebView2PointerInfoVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment3`.
+    pub const IID_ICoreWebView2Environment3: GUID = GUID {
+        Data1: 0x80a22ae3,
+        Data2: 0xbe7c,
+        Data3: 0x4ce2,
+        Data4: [0xaf, 0xe1, 0x5a, 0x50, 0x05, 0x6c, 0xde, 0xeb],
+    };
+
+    /// A continuation of the `ICoreWebView2_2` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3>
+    #[com_interface("A0D6DF20-3B92-416D-AA0C-437A9C727857")]
+    pub trait ICoreWebView2_3: ICoreWebView2_2 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3#trysuspend>
+        #[doc(alias = "TrySuspend")]
+        unsafe fn try_suspend(&self, /* in */ handler: *mut *mut IUnknownVTable) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3#resume>
+        #[doc(alias = "Resume")]
+        unsafe fn resume(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3#get_issuspended>
+        #[doc(alias = "get_IsSuspended")]
+        unsafe fn get_is_suspended(&self, /* out, retval */ isSuspended: *mut BOOL) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3#setvirtualhostnametofoldermapping>
+        #[doc(alias = "SetVirtualHostNameToFolderMapping")]
+        unsafe fn set_virtual_host_name_to_folder_mapping(
+            &self,
+            /* in */ hostName: LPCWSTR,
+            /* in */ folderPath: LPCWSTR,
+            /* in */ accessKind: UINT32,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_3#clearvirtualhostnametofoldermapping>
+        #[doc(alias = "ClearVirtualHostNameToFolderMapping")]
+        unsafe fn clear_virtual_host_name_to_folder_mapping(
+            &self,
+            /* in */ hostName: LPCWSTR,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_3`.
+    pub const IID_ICoreWebView2_3: GUID = GUID {
+        Data1: 0xA0D6DF20,
+        Data2: 0x3B92,
+        Data3: 0x416D,
+        Data4: [0xAA, 0x0C, 0x43, 0x7A, 0x9C, 0x72, 0x78, 0x57],
+    };
+
+    pub const SDK_1_0_774_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2CompositionController",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2CompositionController,
+            methods: 8,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2CompositionController2",
+            parent: "ICoreWebView2CompositionController",
+            iid: IID_ICoreWebView2CompositionController2,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2CursorChangedEventHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2CursorChangedEventHandler,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandler,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2PointerInfo",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2PointerInfo,
+            methods: 56,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Controller2",
+            parent: "ICoreWebView2Host",
+            iid: IID_ICoreWebView2Controller2,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Controller3",
+            parent: "ICoreWebView2Controller2",
+            iid: IID_ICoreWebView2Controller3,
+            methods: 8,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2RasterizationScaleChangedEventHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2RasterizationScaleChangedEventHandler,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment3",
+            parent: "ICoreWebView2Environment2",
+            iid: IID_ICoreWebView2Environment3,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_3",
+            parent: "ICoreWebView2_2",
+            iid: IID_ICoreWebView2_3,
+            methods: 5,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.774")]
+pub use self::sdk_1_0_774::*;
+
+#[cfg(feature = "sdk-1.0.902")]
+mod sdk_1_0_902 {
+    use super::*;
+
+    /// A continuation of the ICoreWebView2Environment3 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment4>
+    #[com_interface("20944379-6dcf-41d6-a0a0-abc0fc50de0d")]
+    pub trait ICoreWebView2Environment4: ICoreWebView2Environment3 {
+        /// Returns the Automation Provider for the WebView that matches the provided
+        /// window. Host apps are expected to implement
+        /// IRawElementProviderHwndOverride. When GetOverrideProviderForHwnd is
+        /// called, the app can pass the HWND to GetAutomationProviderForWindow to
+        /// find the matching WebView Automation Provider.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment4#getautomationproviderforwindow>
+        #[doc(alias = "GetAutomationProviderForWindow")]
+        unsafe fn get_automation_provider_for_window(
+            &self,
+            /* in */ hwnd: HWND,
+            /* out, retval */ provider: *mut *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment4`.
+    pub const IID_ICoreWebView2Environment4: GUID = GUID {
+        Data1: 0x20944379,
+        Data2: 0x6dcf,
+        Data3: 0x41d6,
+        Data4: [0xa0, 0xa0, 0xab, 0xc0, 0xfc, 0x50, 0xde, 0x0d],
+    };
+
+    /// A continuation of the `ICoreWebView2_3` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_4>
+    #[com_interface("20d02d59-6df2-42dc-bd06-f98a694b1302")]
+    pub trait ICoreWebView2_4: ICoreWebView2_3 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_4#add_framecreated>
+        #[doc(alias = "add_FrameCreated")]
+        unsafe fn add_frame_created(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_4#remove_framecreated>
+        #[doc(alias = "remove_FrameCreated")]
+        unsafe fn remove_frame_created(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_4#add_downloadstarting>
+        #[doc(alias = "add_DownloadStarting")]
+        unsafe fn add_download_starting(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_4#remove_downloadstarting>
+        #[doc(alias = "remove_DownloadStarting")]
+        unsafe fn remove_download_starting(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_4`.
+    pub const IID_ICoreWebView2_4: GUID = GUID {
+        Data1: 0x20d02d59,
+        Data2: 0x6df2,
+        Data3: 0x42dc,
+        Data4: [0xbd, 0x06, 0xf9, 0x8a, 0x69, 0x4b, 0x13, 0x02],
+    };
+
+    pub const SDK_1_0_902_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2Environment4",
+            parent: "ICoreWebView2Environment3",
+            iid: IID_ICoreWebView2Environment4,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_4",
+            parent: "ICoreWebView2_3",
+            iid: IID_ICoreWebView2_4,
+            methods: 4,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.902")]
+pub use self::sdk_1_0_902::*;
+
+#[cfg(feature = "sdk-1.0.961")]
+mod sdk_1_0_961 {
+    use super::*;
+
+    /// A continuation of the `ICoreWebView2_4` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_5>
+    #[com_interface("bedb11b8-d63c-11eb-b8bc-0242ac130003")]
+    pub trait ICoreWebView2_5: ICoreWebView2_4 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_5#add_clientcertificaterequested>
+        #[doc(alias = "add_ClientCertificateRequested")]
+        unsafe fn add_client_certificate_requested(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_5#remove_clientcertificaterequested>
+        #[doc(alias = "remove_ClientCertificateRequested")]
+        unsafe fn remove_client_certificate_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_5`.
+    pub const IID_ICoreWebView2_5: GUID = GUID {
+        Data1: 0xbedb11b8,
+        Data2: 0xd63c,
+        Data3: 0x11eb,
+        Data4: [0xb8, 0xbc, 0x02, 0x42, 0xac, 0x13, 0x00, 0x03],
+    };
+
+    pub const SDK_1_0_961_INTERFACES: &[InterfaceInfo] = &[InterfaceInfo {
+        name: "ICoreWebView2_5",
+        parent: "ICoreWebView2_4",
+        iid: IID_ICoreWebView2_5,
+        methods: 2,
+    }];
+}
+
+#[cfg(feature = "sdk-1.0.961")]
+pub use self::sdk_1_0_961::*;
+
+#[cfg(feature = "sdk-1.0.992")]
+mod sdk_1_0_992 {
+    use super::*;
+
+    /// Event args for the BrowserProcessExited event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2browserprocessexitedeventargs>
+    #[com_interface("1f00663f-af8c-4782-9cdd-dd01c52e34cb")]
+    pub trait ICoreWebView2BrowserProcessExitedEventArgs: IUnknown {
+        /// The kind of browser process exit that has occurred.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2browserprocessexitedeventargs#get_browserprocessexitkind>
+        #[doc(alias = "get_BrowserProcessExitKind")]
+        unsafe fn get_browser_process_exit_kind(
+            &self,
+            /* out, retval */
+            browserProcessExitKind: *mut COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND,
+        ) -> HRESULT;
+
+        /// The process ID of the browser process that has exited.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2browserprocessexitedeventargs#get_browserprocessid>
+        #[doc(alias = "get_BrowserProcessId")]
+        unsafe fn get_browser_process_id(
+            &self,
+            /* out, retval */ value: *mut UINT32,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2BrowserProcessExitedEventArgs`.
+    pub const IID_ICoreWebView2BrowserProcessExitedEventArgs: GUID = GUID {
+        Data1: 0x1f00663f,
+        Data2: 0xaf8c,
+        Data3: 0x4782,
+        Data4: [0x9c, 0xdd, 0xdd, 0x01, 0xc5, 0x2e, 0x34, 0xcb],
+    };
+
+    /// Specifies the browser process exit type for
+    /// `ICoreWebView2BrowserProcessExitedEventArgs`.
+    #[repr(u32)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND {
+        /// Indicates that the browser process ended normally.
+        COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_NORMAL,
+        /// Indicates that the browser process ended unexpectedly.
+        /// A `ProcessFailed` event will also be sent to listening WebViews from
+        /// the `ICoreWebView2Environment` associated to the failed process.
+        COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_FAILED,
+    }
+
+    impl COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND {
+        pub const fn as_u32(self) -> u32 {
+            self as u32
+        }
+    }
+
+    /// Fails with the value if it is not a known variant.
+    impl std::convert::TryFrom<u32> for COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0 => Ok(Self::COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_NORMAL),
+                1 => Ok(Self::COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_FAILED),
+                _ => Err(value),
+            }
+        }
+    }
+
+    /// Receives `BrowserProcessExited` events.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2browserprocessexitedeventhandler>
+    #[com_interface("fa504257-a216-4911-a860-fe8825712861")]
+    pub trait ICoreWebView2BrowserProcessExitedEventHandler: IUnknown {
+        /// Provides the event args for the corresponding event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2browserprocessexitedeventhandler#invoke>
+        #[doc(alias = "Invoke")]
+        unsafe fn invoke(
+            &self,
+            /* in */ sender: *mut *mut ICoreWebView2EnvironmentVTable,
+            /* in */ args: *mut *mut ICoreWebView2BrowserProcessExitedEventArgsVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2BrowserProcessExitedEventHandler`.
+    pub const IID_ICoreWebView2BrowserProcessExitedEventHandler: GUID = GUID {
+        Data1: 0xfa504257,
+        Data2: 0xa216,
+        Data3: 0x4911,
+        Data4: [0xa8, 0x60, 0xfe, 0x88, 0x25, 0x71, 0x28, 0x61],
+    };
+
+    /// A continuation of the `ICoreWebView2Environment4` interface that supports
+    /// the `BrowserProcessExited` event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment5>
+    #[com_interface("319e423d-e0d7-4b8d-9254-ae9475de9b17")]
+    pub trait ICoreWebView2Environment5: ICoreWebView2Environment4 {
+        /// Add an event handler for the `BrowserProcessExited` event.
+        /// The `BrowserProcessExited` event is raised when the collection of WebView2
+        /// Runtime processes for the browser process of this environment terminate
+        /// due to browser process failure or normal shutdown (for example, when all
+        /// associated WebViews are closed), after all resources have been released
+        /// (including the user data folder). To learn about what these processes are,
+        /// go to [Process model](/microsoft-edge/webview2/concepts/process-model).
+        ///
+        /// A handler added with this method is called until removed with
+        /// `remove_BrowserProcessExited`, even if a new browser process is bound to
+        /// this environment after earlier `BrowserProcessExited` events are raised.
+        ///
+        /// Multiple app processes can share a browser process by creating their
+        /// webviews from a `ICoreWebView2Environment` with the same user data folder.
+        /// When the entire collection of WebView2Runtime processes for the browser
+        /// process in use by the app terminates, this event is raised with a
+        /// `COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_NORMAL` exit kind if the browser
+        /// process exited normally, or `COREWEBVIEW2_BROWSER_PROCESS_EXIT_KIND_FAILED`
+        /// otherwise.
+        ///
+        /// Note this is an event from the `ICoreWebView2Environment5` interface, not
+        /// the `ICoreWebView2` one. The difference between `BrowserProcessExited`
+        /// and `ICoreWebView2`'s `ProcessFailed` is that `BrowserProcessExited` is
+        /// raised for any **browser process** exit (expected or unexpected, after all
+        /// associated processes have exited too), while `ProcessFailed` is raised for
+        /// **unexpected** process exits of any kind (browser, render, GPU, and all
+        /// other types), or for main frame **render process** unresponsiveness.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment5#add_browserprocessexited>
+        #[doc(alias = "add_BrowserProcessExited")]
+        unsafe fn add_browser_process_exited(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2BrowserProcessExitedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// Remove an event handler previously added with `add_BrowserProcessExited`.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment5#remove_browserprocessexited>
+        #[doc(alias = "remove_BrowserProcessExited")]
+        unsafe fn remove_browser_process_exited(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment5`.
+    pub const IID_ICoreWebView2Environment5: GUID = GUID {
+        Data1: 0x319e423d,
+        Data2: 0xe0d7,
+        Data3: 0x4b8d,
+        Data4: [0x92, 0x54, 0xae, 0x94, 0x75, 0xde, 0x9b, 0x17],
+    };
+
+    /// A continuation of the `ICoreWebView2_5` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_6>
+    #[com_interface("499aadac-d92c-4589-8a75-111bfc167795")]
+    pub trait ICoreWebView2_6: ICoreWebView2_5 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_6#opentaskmanagerwindow>
+        #[doc(alias = "OpenTaskManagerWindow")]
+        unsafe fn open_task_manager_window(&self) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_6`.
+    pub const IID_ICoreWebView2_6: GUID = GUID {
+        Data1: 0x499aadac,
+        Data2: 0xd92c,
+        Data3: 0x4589,
+        Data4: [0x8a, 0x75, 0x11, 0x1b, 0xfc, 0x16, 0x77, 0x95],
+    };
+
+    pub const SDK_1_0_992_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2BrowserProcessExitedEventArgs",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2BrowserProcessExitedEventArgs,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2BrowserProcessExitedEventHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2BrowserProcessExitedEventHandler,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment5",
+            parent: "ICoreWebView2Environment4",
+            iid: IID_ICoreWebView2Environment5,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_6",
+            parent: "ICoreWebView2_5",
+            iid: IID_ICoreWebView2_6,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.992")]
+pub use self::sdk_1_0_992::*;
+
+#[cfg(feature = "sdk-1.0.1020")]
+mod sdk_1_0_1020 {
+    use super::*;
+
+    /// Settings used by the `PrintToPdf` method. Only passed through here, its
+    /// members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2printsettings>
+    #[com_interface("377f3721-c74e-48ca-8db1-df68e51d60e2")]
+    pub trait ICoreWebView2PrintSettings: IUnknown {}
+
+    /// IID of `ICoreWebView2PrintSettings`.
+    pub const IID_ICoreWebView2PrintSettings: GUID = GUID {
+        Data1: 0x377f3721,
+        Data2: 0xc74e,
+        Data3: 0x48ca,
+        Data4: [0x8d, 0xb1, 0xdf, 0x68, 0xe5, 0x1d, 0x60, 0xe2],
+    };
+
+    /// This interface is an extension of the ICoreWebView2Environment that
+    /// supports creating print settings for printing to PDF.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment6>
+    #[com_interface("e59ee362-acbd-4857-9a8e-d3644d9459a9")]
+    pub trait ICoreWebView2Environment6: ICoreWebView2Environment5 {
+        /// Creates the `ICoreWebView2PrintSettings` used by the `PrintToPdf`
+        /// method.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment6#createprintsettings>
+        #[doc(alias = "CreatePrintSettings")]
+        unsafe fn create_print_settings(
+            &self,
+            /* out, retval */ printSettings: *mut *mut *mut ICoreWebView2PrintSettingsVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment6`.
+    pub const IID_ICoreWebView2Environment6: GUID = GUID {
+        Data1: 0xe59ee362,
+        Data2: 0xacbd,
+        Data3: 0x4857,
+        Data4: [0x9a, 0x8e, 0xd3, 0x64, 0x4d, 0x94, 0x59, 0xa9],
+    };
+
+    /// A continuation of the `ICoreWebView2_6` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_7>
+    #[com_interface("79c24d83-09a3-45ae-9418-487f32a58740")]
+    pub trait ICoreWebView2_7: ICoreWebView2_6 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_7#printtopdf>
+        #[doc(alias = "PrintToPdf")]
+        unsafe fn print_to_pdf(
+            &self,
+            /* in */ resultFilePath: LPCWSTR,
+            /* in */ printSettings: *mut *mut ICoreWebView2PrintSettingsVTable,
+            /* in */ handler: *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_7`.
+    pub const IID_ICoreWebView2_7: GUID = GUID {
+        Data1: 0x79c24d83,
+        Data2: 0x09a3,
+        Data3: 0x45ae,
+        Data4: [0x94, 0x18, 0x48, 0x7f, 0x32, 0xa5, 0x87, 0x40],
+    };
+
+    pub const SDK_1_0_1020_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2PrintSettings",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2PrintSettings,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment6",
+            parent: "ICoreWebView2Environment5",
+            iid: IID_ICoreWebView2Environment6,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_7",
+            parent: "ICoreWebView2_6",
+            iid: IID_ICoreWebView2_7,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1020")]
+pub use self::sdk_1_0_1020::*;
+
+#[cfg(feature = "sdk-1.0.1054")]
+mod sdk_1_0_1054 {
+    use super::*;
+
+    /// This interface is an extension of the ICoreWebView2Environment. An object
+    /// implementing the ICoreWebView2Environment7 interface will also
+    /// implement ICoreWebView2Environment.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment7>
+    #[com_interface("43C22296-3BBD-43A4-9C00-5C0DF6DD29A2")]
+    pub trait ICoreWebView2Environment7: ICoreWebView2Environment6 {
+        /// Returns the user data folder that all CoreWebView2's created from this
+        /// environment are using.
+        /// This could be either the value passed in by the developer when creating
+        /// the environment object or the calculated one for default handling. It
+        /// will always be an absolute path.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment7#get_userdatafolder>
+        #[doc(alias = "get_UserDataFolder")]
+        unsafe fn get_user_data_folder(&self, /* out, retval */ value: *mut LPWSTR) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment7`.
+    pub const IID_ICoreWebView2Environment7: GUID = GUID {
+        Data1: 0x43C22296,
+        Data2: 0x3BBD,
+        Data3: 0x43A4,
+        Data4: [0x9C, 0x00, 0x5C, 0x0D, 0xF6, 0xDD, 0x29, 0xA2],
+    };
+
+    pub const SDK_1_0_1054_INTERFACES: &[InterfaceInfo] = &[InterfaceInfo {
+        name: "ICoreWebView2Environment7",
+        parent: "ICoreWebView2Environment6",
+        iid: IID_ICoreWebView2Environment7,
+        methods: 1,
+    }];
+}
+
+#[cfg(feature = "sdk-1.0.1054")]
+pub use self::sdk_1_0_1054::*;
+
+#[cfg(feature = "sdk-1.0.1072")]
+mod sdk_1_0_1072 {
+    use super::*;
+
+    /// A continuation of the `ICoreWebView2_7` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8>
+    #[com_interface("E9632730-6E1E-43AB-B7B8-7B2C9E62E094")]
+    pub trait ICoreWebView2_8: ICoreWebView2_7 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#add_ismutedchanged>
+        #[doc(alias = "add_IsMutedChanged")]
+        unsafe fn add_is_muted_changed(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#remove_ismutedchanged>
+        #[doc(alias = "remove_IsMutedChanged")]
+        unsafe fn remove_is_muted_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#get_ismuted>
+        #[doc(alias = "get_IsMuted")]
+        unsafe fn get_is_muted(&self, /* out, retval */ value: *mut BOOL) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#put_ismuted>
+        #[doc(alias = "put_IsMuted")]
+        unsafe fn put_is_muted(&self, /* in */ value: BOOL) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#add_isdocumentplayingaudiochanged>
+        #[doc(alias = "add_IsDocumentPlayingAudioChanged")]
+        unsafe fn add_is_document_playing_audio_changed(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#remove_isdocumentplayingaudiochanged>
+        #[doc(alias = "remove_IsDocumentPlayingAudioChanged")]
+        unsafe fn remove_is_document_playing_audio_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_8#get_isdocumentplayingaudio>
+        #[doc(alias = "get_IsDocumentPlayingAudio")]
+        unsafe fn get_is_document_playing_audio(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_8`.
+    pub const IID_ICoreWebView2_8: GUID = GUID {
+        Data1: 0xE9632730,
+        Data2: 0x6E1E,
+        Data3: 0x43AB,
+        Data4: [0xB7, 0xB8, 0x7B, 0x2C, 0x9E, 0x62, 0xE0, 0x94],
+    };
+
+    pub const SDK_1_0_1072_INTERFACES: &[InterfaceInfo] = &[InterfaceInfo {
+        name: "ICoreWebView2_8",
+        parent: "ICoreWebView2_7",
+        iid: IID_ICoreWebView2_8,
+        methods: 7,
+    }];
+}
+
+#[cfg(feature = "sdk-1.0.1072")]
+pub use self::sdk_1_0_1072::*;
+
+#[cfg(feature = "sdk-1.0.1108")]
+mod sdk_1_0_1108 {
+    use super::*;
+
+    /// A collection of ICoreWebView2ProcessInfo. Only passed through here, its
+    /// members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processinfocollection>
+    #[com_interface("402b99cd-a0cc-4fa5-b7a5-51d86a1d2339")]
+    pub trait ICoreWebView2ProcessInfoCollection: IUnknown {}
+
+    /// IID of `ICoreWebView2ProcessInfoCollection`.
+    pub const IID_ICoreWebView2ProcessInfoCollection: GUID = GUID {
+        Data1: 0x402b99cd,
+        Data2: 0xa0cc,
+        Data3: 0x4fa5,
+        Data4: [0xb7, 0xa5, 0x51, 0xd8, 0x6a, 0x1d, 0x23, 0x39],
+    };
+
+    /// An event handler for the `ProcessInfosChanged` event. Only passed through
+    /// here, its members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2processinfoschangedeventhandler>
+    #[com_interface("f4af0c39-44b9-40e9-8b11-0484cfb9e0a1")]
+    pub trait ICoreWebView2ProcessInfosChangedEventHandler: IUnknown {}
+
+    /// IID of `ICoreWebView2ProcessInfosChangedEventHandler`.
+    pub const IID_ICoreWebView2ProcessInfosChangedEventHandler: GUID = GUID {
+        Data1: 0xf4af0c39,
+        Data2: 0x44b9,
+        Data3: 0x40e9,
+        Data4: [0x8b, 0x11, 0x04, 0x84, 0xcf, 0xb9, 0xe0, 0xa1],
+    };
+
+    /// A continuation of the `ICoreWebView2Environment7` interface that supports
+    /// the `ProcessInfosChanged` event.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment8>
+    #[com_interface("D6EB91DD-C3D2-45E5-BD29-6DC2BC4DE9CF")]
+    pub trait ICoreWebView2Environment8: ICoreWebView2Environment7 {
+        /// Adds an event handler for the `ProcessInfosChanged` event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment8#add_processinfoschanged>
+        #[doc(alias = "add_ProcessInfosChanged")]
+        unsafe fn add_process_infos_changed(
+            &self,
+            /* in */
+            eventHandler: *mut *mut ICoreWebView2ProcessInfosChangedEventHandlerVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// Remove an event handler previously added with `add_ProcessInfosChanged`.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment8#remove_processinfoschanged>
+        #[doc(alias = "remove_ProcessInfosChanged")]
+        unsafe fn remove_process_infos_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// Returns the `ICoreWebView2ProcessInfoCollection`
+        /// Provide a list of all process using same user data folder except for
+        /// crashpad process.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment8#getprocessinfos>
+        #[doc(alias = "GetProcessInfos")]
+        unsafe fn get_process_infos(
+            &self,
+            /* out, retval */ value: *mut *mut *mut ICoreWebView2ProcessInfoCollectionVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment8`.
+    pub const IID_ICoreWebView2Environment8: GUID = GUID {
+        Data1: 0xD6EB91DD,
+        Data2: 0xC3D2,
+        Data3: 0x45E5,
+        Data4: [0xBD, 0x29, 0x6D, 0xC2, 0xBC, 0x4D, 0xE9, 0xCF],
+    };
+
+    /// A continuation of the `ICoreWebView2_8` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9>
+    #[com_interface("4d7b2eab-9fdc-468d-b998-a9260b5ed651")]
+    pub trait ICoreWebView2_9: ICoreWebView2_8 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#add_isdefaultdownloaddialogopenchanged>
+        #[doc(alias = "add_IsDefaultDownloadDialogOpenChanged")]
+        unsafe fn add_is_default_download_dialog_open_changed(
+            &self,
+            /* in */ handler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#remove_isdefaultdownloaddialogopenchanged>
+        #[doc(alias = "remove_IsDefaultDownloadDialogOpenChanged")]
+        unsafe fn remove_is_default_download_dialog_open_changed(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#get_isdefaultdownloaddialogopen>
+        #[doc(alias = "get_IsDefaultDownloadDialogOpen")]
+        unsafe fn get_is_default_download_dialog_open(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#opendefaultdownloaddialog>
+        #[doc(alias = "OpenDefaultDownloadDialog")]
+        unsafe fn open_default_download_dialog(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#closedefaultdownloaddialog>
+        #[doc(alias = "CloseDefaultDownloadDialog")]
+        unsafe fn close_default_download_dialog(&self) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#get_defaultdownloaddialogcorneralignment>
+        #[doc(alias = "get_DefaultDownloadDialogCornerAlignment")]
+        unsafe fn get_default_download_dialog_corner_alignment(
+            &self,
+            /* out, retval */ value: *mut UINT32,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#put_defaultdownloaddialogcorneralignment>
+        #[doc(alias = "put_DefaultDownloadDialogCornerAlignment")]
+        unsafe fn put_default_download_dialog_corner_alignment(
+            &self,
+            /* in */ value: UINT32,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#get_defaultdownloaddialogmargin>
+        #[doc(alias = "get_DefaultDownloadDialogMargin")]
+        unsafe fn get_default_download_dialog_margin(
+            &self,
+            /* out, retval */ value: *mut POINT,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_9#put_defaultdownloaddialogmargin>
+        #[doc(alias = "put_DefaultDownloadDialogMargin")]
+        unsafe fn put_default_download_dialog_margin(&self, /* in */ value: POINT) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_9`.
+    pub const IID_ICoreWebView2_9: GUID = GUID {
+        Data1: 0x4d7b2eab,
+        Data2: 0x9fdc,
+        Data3: 0x468d,
+        Data4: [0xb9, 0x98, 0xa9, 0x26, 0x0b, 0x5e, 0xd6, 0x51],
+    };
+
+    pub const SDK_1_0_1108_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2ProcessInfoCollection",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2ProcessInfoCollection,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2ProcessInfosChangedEventHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2ProcessInfosChangedEventHandler,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment8",
+            parent: "ICoreWebView2Environment7",
+            iid: IID_ICoreWebView2Environment8,
+            methods: 3,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_9",
+            parent: "ICoreWebView2_8",
+            iid: IID_ICoreWebView2_9,
+            methods: 9,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1108")]
+pub use self::sdk_1_0_1108::*;
+
+#[cfg(feature = "sdk-1.0.1150")]
+mod sdk_1_0_1150 {
+    use super::*;
+
+    /// A continuation of the `ICoreWebView2_9` interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_10>
+    #[com_interface("b1690564-6f5a-4983-8e48-31d1143fecdb")]
+    pub trait ICoreWebView2_10: ICoreWebView2_9 {
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_10#add_basicauthenticationrequested>
+        #[doc(alias = "add_BasicAuthenticationRequested")]
+        unsafe fn add_basic_authentication_requested(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_10#remove_basicauthenticationrequested>
+        #[doc(alias = "remove_BasicAuthenticationRequested")]
+        unsafe fn remove_basic_authentication_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_10`.
+    pub const IID_ICoreWebView2_10: GUID = GUID {
+        Data1: 0xb1690564,
+        Data2: 0x6f5a,
+        Data3: 0x4983,
+        Data4: [0x8e, 0x48, 0x31, 0xd1, 0x14, 0x3f, 0xec, 0xdb],
+    };
+
+    pub const SDK_1_0_1150_INTERFACES: &[InterfaceInfo] = &[InterfaceInfo {
+        name: "ICoreWebView2_10",
+        parent: "ICoreWebView2_9",
+        iid: IID_ICoreWebView2_10,
+        methods: 2,
+    }];
+}
+
+#[cfg(feature = "sdk-1.0.1150")]
+pub use self::sdk_1_0_1150::*;
+
+#[cfg(feature = "sdk-1.0.1185")]
+mod sdk_1_0_1185 {
+    use super::*;
+
+    /// Represents a context menu item of a context menu displayed by WebView.
+    /// Only passed through here, its members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2contextmenuitem>
+    #[com_interface("7aed49e3-a93f-497a-811c-749c6b6b6c65")]
+    pub trait ICoreWebView2ContextMenuItem: IUnknown {}
+
+    /// IID of `ICoreWebView2ContextMenuItem`.
+    pub const IID_ICoreWebView2ContextMenuItem: GUID = GUID {
+        Data1: 0x7aed49e3,
+        Data2: 0xa93f,
+        Data3: 0x497a,
+        Data4: [0x81, 0x1c, 0x74, 0x9c, 0x6b, 0x6b, 0x6c, 0x65],
+    };
+
+    /// A continuation of the ICoreWebView2Environment interface for
+    /// creating CoreWebView2 ContextMenuItem objects.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment9>
+    #[com_interface("f06f41bf-4b5a-49d8-b9f6-fa16cd29f274")]
+    pub trait ICoreWebView2Environment9: ICoreWebView2Environment8 {
+        /// Create a custom `ContextMenuItem` object to insert into the WebView
+        /// context menu. CoreWebView2 will rewind the icon stream before decoding.
+        /// There is a limit of 1000 active custom context menu items at a given
+        /// time. Attempting to create more before deleting existing ones will fail
+        /// with ERROR_NOT_ENOUGH_QUOTA. It is recommended to reuse ContextMenuItems
+        /// across ContextMenuRequested events for performance.
+        /// The returned ContextMenuItem object's `IsEnabled` property will default
+        /// to `TRUE` and `IsChecked` property will default to `FALSE`. A `CommandId`
+        /// will be assigned to the ContextMenuItem object that's unique across
+        /// active custom context menu items, but command ID values of deleted
+        /// ContextMenuItems can be reassigned.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment9#createcontextmenuitem>
+        #[doc(alias = "CreateContextMenuItem")]
+        unsafe fn create_context_menu_item(
+            &self,
+            /* in */ label: LPCWSTR,
+            /* in */ iconStream: *mut *mut IStreamVTable,
+            /* in */ kind: COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND,
+            /* out, retval */ item: *mut *mut *mut ICoreWebView2ContextMenuItemVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment9`.
+    pub const IID_ICoreWebView2Environment9: GUID = GUID {
+        Data1: 0xf06f41bf,
+        Data2: 0x4b5a,
+        Data3: 0x49d8,
+        Data4: [0xb9, 0xf6, 0xfa, 0x16, 0xcd, 0x29, 0xf2, 0x74],
+    };
+
+    /// Specifies the menu item kind
+    /// for the `ICoreWebView2ContextMenuItem::get_Kind` method
+    #[repr(u32)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND {
+        /// Specifies a command menu item kind.
+        COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_COMMAND,
+        /// Specifies a check box menu item kind. `ContextMenuItem` objects of this
+        /// kind will need the `IsChecked` property to determine current state of
+        /// the check box.
+        COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_CHECK_BOX,
+        /// Specifies a radio button menu item kind. `ContextMenuItem` objects of
+        /// this kind will need the `IsChecked` property to determine current state
+        /// of the radio button.
+        COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_RADIO,
+        /// Specifies a separator menu item kind. `ContextMenuItem` objects of this
+        /// kind are used to signal a visual separator with no functionality.
+        COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_SEPARATOR,
+        /// Specifies a submenu menu item kind. `ContextMenuItem` objects of this
+        /// kind will contain a `ContextMenuItemCollection` of its children
+        /// `ContextMenuItem` objects.
+        COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_SUBMENU,
+    }
+
+    impl COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND {
+        pub const fn as_u32(self) -> u32 {
+            self as u32
+        }
+    }
+
+    /// Fails with the value if it is not a known variant.
+    impl std::convert::TryFrom<u32> for COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0 => Ok(Self::COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_COMMAND),
+                1 => Ok(Self::COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_CHECK_BOX),
+                2 => Ok(Self::COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_RADIO),
+                3 => Ok(Self::COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_SEPARATOR),
+                4 => Ok(Self::COREWEBVIEW2_CONTEXT_MENU_ITEM_KIND_SUBMENU),
+                _ => Err(value),
+            }
+        }
+    }
+
+    /// A continuation of the `ICoreWebView2_10` interface that supports sending
+    /// DevTools protocol messages to sessions.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_11>
+    #[com_interface("0be78e56-c193-4051-b943-23b460c08bdb")]
+    pub trait ICoreWebView2_11: ICoreWebView2_10 {
+        /// Runs an asynchronous `DevToolsProtocol` method for a specific session of
+        /// an attached target. There could be multiple `DevToolsProtocol` targets
+        /// in a WebView. Besides the top level page, iframes from different origin
+        /// and web workers are also separate targets. Attaching to these targets
+        /// allows interaction with them. When the DevToolsProtocol is attached to a
+        /// target, the connection is identified by a sessionId. To use this API,
+        /// you must set the `flatten` parameter to TRUE when calling
+        /// `Target.attachToTarget` or `Target.setAutoAttach` `DevToolsProtocol`
+        /// method. Using `Target.setAutoAttach` is recommended as that would allow
+        /// you to attach to dedicated worker targets, which are not discoverable
+        /// via other APIs like `Target.getTargets`.
+        ///
+        /// The sessionId for attached targets is available from the
+        /// `Target.attachedToTarget` event. An empty `sessionId` is the same as
+        /// calling `CallDevToolsProtocolMethod`.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_11#calldevtoolsprotocolmethodforsession>
+        #[doc(alias = "CallDevToolsProtocolMethodForSession")]
+        unsafe fn call_dev_tools_protocol_method_for_session(
+            &self,
+            /* in */ sessionId: LPCWSTR,
+            /* in */ methodName: LPCWSTR,
+            /* in */ parametersAsJson: LPCWSTR,
+            /* in */
+            handler: *mut *mut ICoreWebView2CallDevToolsProtocolMethodCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_11#add_contextmenurequested>
+        #[doc(alias = "add_ContextMenuRequested")]
+        unsafe fn add_context_menu_requested(
+            &self,
+            /* in */ eventHandler: *mut *mut IUnknownVTable,
+            /* out */ token: *mut EventRegistrationToken,
+        ) -> HRESULT;
+
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2_11#remove_contextmenurequested>
+        #[doc(alias = "remove_ContextMenuRequested")]
+        unsafe fn remove_context_menu_requested(
+            &self,
+            /* in */ token: EventRegistrationToken,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2_11`.
+    pub const IID_ICoreWebView2_11: GUID = GUID {
+        Data1: 0x0be78e56,
+        Data2: 0xc193,
+        Data3: 0x4051,
+        Data4: [0xb9, 0x43, 0x23, 0xb4, 0x60, 0xc0, 0x8b, 0xdb],
+    };
+
+    /// This is a continuation of the `ICoreWebView2DevToolsProtocolEventReceivedEventArgs`
+    /// interface that provides the session ID of the target where the event originates from.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventargs2>
+    #[com_interface("2DC4959D-1494-4393-95BA-BEA4CB9EBD1B")]
+    pub trait ICoreWebView2DevToolsProtocolEventReceivedEventArgs2:
+        ICoreWebView2DevToolsProtocolEventReceivedEventArgs
+    {
+        /// The sessionId of the target where the event originates from.
+        /// Empty string is returned as sessionId if the event comes from the default
+        /// session for the top page.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2devtoolsprotocoleventreceivedeventargs2#get_sessionid>
+        #[doc(alias = "get_SessionId")]
+        unsafe fn get_session_id(&self, /* out, retval */ sessionId: *mut LPWSTR) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2DevToolsProtocolEventReceivedEventArgs2`.
+    pub const IID_ICoreWebView2DevToolsProtocolEventReceivedEventArgs2: GUID = GUID {
+        Data1: 0x2DC4959D,
+        Data2: 0x1494,
+        Data3: 0x4393,
+        Data4: [0x95, 0xBA, 0xBE, 0xA4, 0xCB, 0x9E, 0xBD, 0x1B],
+    };
+
+    pub const SDK_1_0_1185_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2ContextMenuItem",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2ContextMenuItem,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment9",
+            parent: "ICoreWebView2Environment8",
+            iid: IID_ICoreWebView2Environment9,
+            methods: 1,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2_11",
+            parent: "ICoreWebView2_10",
+            iid: IID_ICoreWebView2_11,
+            methods: 3,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2DevToolsProtocolEventReceivedEventArgs2",
+            parent: "ICoreWebView2DevToolsProtocolEventReceivedEventArgs",
+            iid: IID_ICoreWebView2DevToolsProtocolEventReceivedEventArgs2,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1185")]
+pub use self::sdk_1_0_1185::*;
+
+#[cfg(feature = "sdk-1.0.1245")]
+mod sdk_1_0_1245 {
+    use super::*;
+
+    /// This interface is the continuation of the
+    /// ICoreWebView2CompositionController2 interface to manage drag and drop.
+    ///
+    /// The `dataObject` parameters are `IDataObject`, which the generator has no
+    /// declaration of, so they are declared as the `IUnknown` it extends.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller3>
+    #[com_interface("9570570e-4d76-4361-9ee1-f04d0dbdfb1e")]
+    pub trait ICoreWebView2CompositionController3: ICoreWebView2CompositionController2 {
+        /// This function corresponds to [IDropTarget::DragEnter](/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragenter).
+        ///
+        /// This function has a dependency on AllowExternalDrop property of
+        /// CoreWebView2Controller and return E_FAIL to callers to indicate this
+        /// operation is not allowed if AllowExternalDrop property is set to false.
+        ///
+        /// The hosting application must register as an IDropTarget and implement
+        /// and forward DragEnter calls to this function.
+        ///
+        /// point parameter must be modified to include the WebView's offset and be in
+        /// the WebView's client coordinates (Similar to how SendMouseInput works).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller3#dragenter>
+        #[doc(alias = "DragEnter")]
+        unsafe fn drag_enter(
+            &self,
+            /* in */ dataObject: *mut *mut IUnknownVTable,
+            /* in */ keyState: DWORD,
+            /* in */ point: POINT,
+            /* out, retval */ effect: *mut DWORD,
+        ) -> HRESULT;
+
+        /// This function corresponds to [IDropTarget::DragLeave](/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragleave).
+        ///
+        /// The hosting application must register as an IDropTarget and implement
+        /// and forward DragLeave calls to this function.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller3#dragleave>
+        #[doc(alias = "DragLeave")]
+        unsafe fn drag_leave(&self) -> HRESULT;
+
+        /// This function corresponds to [IDropTarget::DragOver](/windows/win32/api/oleidl/nf-oleidl-idroptarget-dragover).
+        ///
+        /// The hosting application must register as an IDropTarget and implement
+        /// and forward DragOver calls to this function.
+        ///
+        /// point parameter must be modified to include the WebView's offset and be in
+        /// the WebView's client coordinates (Similar to how SendMouseInput works).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller3#dragover>
+        #[doc(alias = "DragOver")]
+        unsafe fn drag_over(
+            &self,
+            /* in */ keyState: DWORD,
+            /* in */ point: POINT,
+            /* out, retval */ effect: *mut DWORD,
+        ) -> HRESULT;
+
+        /// This function corresponds to [IDropTarget::Drop](/windows/win32/api/oleidl/nf-oleidl-idroptarget-drop).
+        ///
+        /// The hosting application must register as an IDropTarget and implement
+        /// and forward Drop calls to this function.
+        ///
+        /// point parameter must be modified to include the WebView's offset and be in
+        /// the WebView's client coordinates (Similar to how SendMouseInput works).
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2compositioncontroller3#drop>
+        #[doc(alias = "Drop")]
+        unsafe fn drop(
+            &self,
+            /* in */ dataObject: *mut *mut IUnknownVTable,
+            /* in */ keyState: DWORD,
+            /* in */ point: POINT,
+            /* out, retval */ effect: *mut DWORD,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2CompositionController3`.
+    pub const IID_ICoreWebView2CompositionController3: GUID = GUID {
+        Data1: 0x9570570e,
+        Data2: 0x4d76,
+        Data3: 0x4361,
+        Data4: [0x9e, 0xe1, 0xf0, 0x4d, 0x0d, 0xbd, 0xfb, 0x1e],
+    };
+
+    /// This interface is used to manage profile options that created by
+    /// 'CreateCoreWebView2ControllerOptions'.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controlleroptions>
+    #[com_interface("12aae616-8ccb-44ec-bcb3-eb1831881635")]
+    pub trait ICoreWebView2ControllerOptions: IUnknown {
+        /// `ProfileName` property is to specify a profile name, which is only
+        /// allowed to contain the following ASCII characters. It has a maximum
+        /// length of 64 characters excluding the null-terminator. It is ASCII case
+        /// insensitive.
+        ///
+        /// * alphabet characters: a-z and A-Z
+        /// * digit characters: 0-9
+        /// * and '#', '@', '$', '(', ')', '+', '-', '_', '~', '.', ' ' (space).
+        ///
+        /// Note: the text must not end with a period '.' or ' ' (space). And,
+        /// although upper-case letters are allowed, they're treated just as
+        /// lower-case counterparts because the profile name will be mapped to the
+        /// real profile directory path on disk and Windows file system handles path
+        /// names in a case-insensitive way.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controlleroptions#get_profilename>
+        #[doc(alias = "get_ProfileName")]
+        unsafe fn get_profile_name(&self, /* out, retval */ value: *mut LPWSTR) -> HRESULT;
+
+        /// Sets the `ProfileName` property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controlleroptions#put_profilename>
+        #[doc(alias = "put_ProfileName")]
+        unsafe fn put_profile_name(&self, /* in */ value: LPCWSTR) -> HRESULT;
+
+        /// `IsInPrivateModeEnabled` property is to enable/disable InPrivate mode.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controlleroptions#get_isinprivatemodeenabled>
+        #[doc(alias = "get_IsInPrivateModeEnabled")]
+        unsafe fn get_is_in_private_mode_enabled(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Sets the `IsInPrivateModeEnabled` property.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controlleroptions#put_isinprivatemodeenabled>
+        #[doc(alias = "put_IsInPrivateModeEnabled")]
+        unsafe fn put_is_in_private_mode_enabled(&self, /* in */ value: BOOL) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2ControllerOptions`.
+    pub const IID_ICoreWebView2ControllerOptions: GUID = GUID {
+        Data1: 0x12aae616,
+        Data2: 0x8ccb,
+        Data3: 0x44ec,
+        Data4: [0xbc, 0xb3, 0xeb, 0x18, 0x31, 0x88, 0x16, 0x35],
+    };
+
+    /// This interface is used to create `ICoreWebView2ControllerOptions` object,
+    /// which can be passed as a parameter in
+    /// `CreateCoreWebView2ControllerWithOptions` and
+    /// `CreateCoreWebView2CompositionControllerWithOptions` function for multiple
+    /// profiles support.
+    /// The profile will be created on disk or opened when calling
+    /// `CreateCoreWebView2ControllerWithOptions` or
+    /// `CreateCoreWebView2CompositionControllerWithOptions` no matter InPrivate
+    /// mode is enabled or not, and it will be released in memory when the
+    /// corresponding controller is closed but still remain on disk.
+    /// If you create a WebView2Controller with {ProfileName="name",
+    /// InPrivate=false} and then later create another one with
+    /// {ProfileName="name", InPrivate=true}, these two controllers using the same
+    /// profile would be allowed to run at the same time.
+    /// As WebView2 is built on top of Edge browser, it follows Edge's behavior
+    /// pattern. To create an InPrivate WebView, we gets an off-the-record profile
+    /// (an InPrivate profile) from a regular profile, then create the WebView with
+    /// the off-the-record profile.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment10>
+    #[com_interface("ee0eb9df-6f12-46ce-b53f-3f47b9c928e0")]
+    pub trait ICoreWebView2Environment10: ICoreWebView2Environment9 {
+        /// Create a new ICoreWebView2ControllerOptions to be passed as a parameter of
+        /// CreateCoreWebView2ControllerWithOptions and
+        /// CreateCoreWebView2CompositionControllerWithOptions.
+        /// The 'options' is settable and in it the default value for profile name is
+        /// the empty string, and the default value for IsInPrivateModeEnabled is
+        /// false.
+        /// Also the profile name can be reused.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment10#createcorewebview2controlleroptions>
+        #[doc(alias = "CreateCoreWebView2ControllerOptions")]
+        unsafe fn create_core_web_view2_controller_options(
+            &self,
+            /* out, retval */ options: *mut *mut *mut ICoreWebView2ControllerOptionsVTable,
+        ) -> HRESULT;
+
+        /// Create a new WebView with options.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment10#createcorewebview2controllerwithoptions>
+        #[doc(alias = "CreateCoreWebView2ControllerWithOptions")]
+        unsafe fn create_core_web_view2_controller_with_options(
+            &self,
+            /* in */ parentWindow: HWND,
+            /* in */ options: *mut *mut ICoreWebView2ControllerOptionsVTable,
+            /* in */
+            handler: *mut *mut ICoreWebView2CreateCoreWebView2HostCompletedHandlerVTable,
+        ) -> HRESULT;
+
+        /// Create a new WebView in visual hosting mode with options.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment10#createcorewebview2compositioncontrollerwithoptions>
+        #[doc(alias = "CreateCoreWebView2CompositionControllerWithOptions")]
+        unsafe fn create_core_web_view2_composition_controller_with_options(
+            &self,
+            /* in */ parentWindow: HWND,
+            /* in */ options: *mut *mut ICoreWebView2ControllerOptionsVTable,
+            /* in */
+            handler: *mut *mut ICoreWebView2CreateCoreWebView2CompositionControllerCompletedHandlerVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment10`.
+    pub const IID_ICoreWebView2Environment10: GUID = GUID {
+        Data1: 0xee0eb9df,
+        Data2: 0x6f12,
+        Data3: 0x46ce,
+        Data4: [0xb5, 0x3f, 0x3f, 0x47, 0xb9, 0xc9, 0x28, 0xe0],
+    };
+
+    pub const SDK_1_0_1245_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2CompositionController3",
+            parent: "ICoreWebView2CompositionController2",
+            iid: IID_ICoreWebView2CompositionController3,
+            methods: 4,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2ControllerOptions",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2ControllerOptions,
+            methods: 4,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment10",
+            parent: "ICoreWebView2Environment9",
+            iid: IID_ICoreWebView2Environment10,
+            methods: 3,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1245")]
+pub use self::sdk_1_0_1245::*;
+
+#[cfg(feature = "sdk-1.0.1518")]
+mod sdk_1_0_1518 {
+    use super::*;
+
+    /// This is the ICoreWebView2Controller4 interface.
+    /// The ICoreWebView2Controller4 provides interface to enable/disable external
+    /// drop.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller4>
+    #[com_interface("97d418d5-a426-4e49-a151-e1a10f327d9e")]
+    pub trait ICoreWebView2Controller4: ICoreWebView2Controller3 {
+        /// Gets the `AllowExternalDrop` property which is used to configure the
+        /// capability that dragging objects from outside the bounds of webview2 and
+        /// dropping into webview2 is allowed or disallowed. The default value is
+        /// TRUE.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller4#get_allowexternaldrop>
+        #[doc(alias = "get_AllowExternalDrop")]
+        unsafe fn get_allow_external_drop(
+            &self,
+            /* out, retval */ value: *mut BOOL,
+        ) -> HRESULT;
+
+        /// Sets the `AllowExternalDrop` property which is used to configure the
+        /// capability that dragging objects from outside the bounds of webview2 and
+        /// dropping into webview2 is allowed or disallowed.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2controller4#put_allowexternaldrop>
+        #[doc(alias = "put_AllowExternalDrop")]
+        unsafe fn put_allow_external_drop(&self, /* in */ value: BOOL) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Controller4`.
+    pub const IID_ICoreWebView2Controller4: GUID = GUID {
+        Data1: 0x97d418d5,
+        Data2: 0xa426,
+        Data3: 0x4e49,
+        Data4: [0xa1, 0x51, 0xe1, 0xa1, 0x0f, 0x32, 0x7d, 0x9e],
+    };
+
+    /// A continuation of the ICoreWebView2Environment10 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment11>
+    #[com_interface("F0913DC6-A0EC-42EF-9805-91DFF3A2966A")]
+    pub trait ICoreWebView2Environment11: ICoreWebView2Environment10 {
+        /// `FailureReportFolderPath` returns the path of the folder where minidump
+        /// files are written. Whenever a WebView2 process crashes, a crash dump file
+        /// will be created in the crash dump folder. The crash dump format is
+        /// minidump files. Please see
+        /// [Minidump Files documentation](/windows/win32/debug/minidump-files) for
+        /// detailed information. Normally when a single child process fails, a
+        /// minidump will be generated and written to disk, then the
+        /// `ProcessFailed` event is raised. But for unexpected crashes, a minidump
+        /// file might not be generated at all, despite whether `ProcessFailed`
+        /// event is raised. If there are multiple process failures at once,
+        /// multiple minidump files could be generated. Thus `FailureReportFolderPath`
+        /// could contain old minidump files that are not associated with a specific
+        /// `ProcessFailed` event.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment11#get_failurereportfolderpath>
+        #[doc(alias = "get_FailureReportFolderPath")]
+        unsafe fn get_failure_report_folder_path(
+            &self,
+            /* out, retval */ value: *mut LPWSTR,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment11`.
+    pub const IID_ICoreWebView2Environment11: GUID = GUID {
+        Data1: 0xF0913DC6,
+        Data2: 0xA0EC,
+        Data3: 0x42EF,
+        Data4: [0x98, 0x05, 0x91, 0xDF, 0xF3, 0xA2, 0x96, 0x6A],
+    };
+
+    pub const SDK_1_0_1518_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2Controller4",
+            parent: "ICoreWebView2Controller3",
+            iid: IID_ICoreWebView2Controller4,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment11",
+            parent: "ICoreWebView2Environment10",
+            iid: IID_ICoreWebView2Environment11,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1518")]
+pub use self::sdk_1_0_1518::*;
+
+#[cfg(feature = "sdk-1.0.1661")]
+mod sdk_1_0_1661 {
+    use super::*;
+
+    /// The shared buffer object that is created by CreateSharedBuffer. Only
+    /// passed through here, its members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2sharedbuffer>
+    #[com_interface("b747a495-0c6f-449e-97b8-2f81e9d6ab43")]
+    pub trait ICoreWebView2SharedBuffer: IUnknown {}
+
+    /// IID of `ICoreWebView2SharedBuffer`.
+    pub const IID_ICoreWebView2SharedBuffer: GUID = GUID {
+        Data1: 0xb747a495,
+        Data2: 0x0c6f,
+        Data3: 0x449e,
+        Data4: [0x97, 0xb8, 0x2f, 0x81, 0xe9, 0xd6, 0xab, 0x43],
+    };
+
+    /// A continuation of the ICoreWebView2Environment11 interface for creating
+    /// shared buffer object.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment12>
+    #[com_interface("F503DB9B-739F-48DD-B151-FDFCF253F54E")]
+    pub trait ICoreWebView2Environment12: ICoreWebView2Environment11 {
+        /// Create a shared memory based buffer with the specified size in bytes.
+        /// The buffer can be shared with web contents in WebView by calling
+        /// `PostSharedBufferToScript` on `CoreWebView2` or `CoreWebView2Frame`
+        /// object.
+        /// Once shared, the same content of the buffer will be accessible from both
+        /// the app process and script in WebView. Modification to the content will
+        /// be visible to all parties that have access to the buffer.
+        /// The shared buffer is presented to the script as ArrayBuffer. All
+        /// JavaScript APIs that work for ArrayBuffer including Atomics APIs can be
+        /// used on it. There is currently a limitation that only size less than
+        /// 2GB is supported.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment12#createsharedbuffer>
+        #[doc(alias = "CreateSharedBuffer")]
+        unsafe fn create_shared_buffer(
+            &self,
+            /* in */ size: UINT64,
+            /* out, retval */ shared_buffer: *mut *mut *mut ICoreWebView2SharedBufferVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment12`.
+    pub const IID_ICoreWebView2Environment12: GUID = GUID {
+        Data1: 0xF503DB9B,
+        Data2: 0x739F,
+        Data3: 0x48DD,
+        Data4: [0xB1, 0x51, 0xFD, 0xFC, 0xF2, 0x53, 0xF5, 0x4E],
+    };
+
+    pub const SDK_1_0_1661_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2SharedBuffer",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2SharedBuffer,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment12",
+            parent: "ICoreWebView2Environment11",
+            iid: IID_ICoreWebView2Environment12,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.1661")]
+pub use self::sdk_1_0_1661::*;
+
+#[cfg(feature = "sdk-1.0.1774")]
+mod sdk_1_0_1774 {
+    use super::*;
+
+    /// Read-only collection of generic objects.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollectionview>
+    #[com_interface("0f36fd87-4f69-4415-98da-888f89fb9a33")]
+    pub trait ICoreWebView2ObjectCollectionView: IUnknown {
+        /// Gets the number of items in the collection.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollectionview#get_count>
+        #[doc(alias = "get_Count")]
+        unsafe fn get_count(&self, /* out, retval */ value: *mut UINT32) -> HRESULT;
+
+        /// Gets the object at the specified index. Cast the object to the native type
+        /// to access its specific properties.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollectionview#getvalueatindex>
+        #[doc(alias = "GetValueAtIndex")]
+        unsafe fn get_value_at_index(
+            &self,
+            /* in */ index: UINT32,
+            /* out, retval */ value: *mut *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2ObjectCollectionView`.
+    pub const IID_ICoreWebView2ObjectCollectionView: GUID = GUID {
+        Data1: 0x0f36fd87,
+        Data2: 0x4f69,
+        Data3: 0x4415,
+        Data4: [0x98, 0xda, 0x88, 0x8f, 0x89, 0xfb, 0x9a, 0x33],
+    };
+
+    pub const SDK_1_0_1774_INTERFACES: &[InterfaceInfo] = &[InterfaceInfo {
+        name: "ICoreWebView2ObjectCollectionView",
+        parent: "IUnknown",
+        iid: IID_ICoreWebView2ObjectCollectionView,
+        methods: 2,
+    }];
+}
+
+#[cfg(feature = "sdk-1.0.1774")]
+pub use self::sdk_1_0_1774::*;
+
+#[cfg(feature = "sdk-1.0.2210")]
+mod sdk_1_0_2210 {
+    use super::*;
+
+    /// Receives the result of the `GetProcessExtendedInfos` method. Only passed
+    /// through here, its members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2getprocessextendedinfoscompletedhandler>
+    #[com_interface("f45e55aa-3bc2-11ee-be56-0242ac120002")]
+    pub trait ICoreWebView2GetProcessExtendedInfosCompletedHandler: IUnknown {}
+
+    /// IID of `ICoreWebView2GetProcessExtendedInfosCompletedHandler`.
+    pub const IID_ICoreWebView2GetProcessExtendedInfosCompletedHandler: GUID = GUID {
+        Data1: 0xf45e55aa,
+        Data2: 0x3bc2,
+        Data3: 0x11ee,
+        Data4: [0xbe, 0x56, 0x02, 0x42, 0xac, 0x12, 0x00, 0x02],
+    };
+
+    /// A continuation of the ICoreWebView2Environment12 interface.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment13>
+    #[com_interface("af641f58-72b2-11ee-b962-0242ac120002")]
+    pub trait ICoreWebView2Environment13: ICoreWebView2Environment12 {
+        /// Gets a snapshot collection of `ProcessExtendedInfo`s corresponding to
+        /// all currently running processes associated with this
+        /// `CoreWebView2Environment` excludes crashpad process.
+        /// This provides the same list of `ProcessInfo`s as what's provided in
+        /// `GetProcessInfos`, but additionally provides a list of associated
+        /// `FrameInfo`s which are actively running (showing or hiding UI elements)
+        /// in the renderer process.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment13#getprocessextendedinfos>
+        #[doc(alias = "GetProcessExtendedInfos")]
+        unsafe fn get_process_extended_infos(
+            &self,
+            /* in */
+            handler: *mut *mut ICoreWebView2GetProcessExtendedInfosCompletedHandlerVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment13`.
+    pub const IID_ICoreWebView2Environment13: GUID = GUID {
+        Data1: 0xaf641f58,
+        Data2: 0x72b2,
+        Data3: 0x11ee,
+        Data4: [0xb9, 0x62, 0x02, 0x42, 0xac, 0x12, 0x00, 0x02],
+    };
+
+    pub const SDK_1_0_2210_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2GetProcessExtendedInfosCompletedHandler",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2GetProcessExtendedInfosCompletedHandler,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment13",
+            parent: "ICoreWebView2Environment12",
+            iid: IID_ICoreWebView2Environment13,
+            methods: 1,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.2210")]
+pub use self::sdk_1_0_2210::*;
+
+#[cfg(feature = "sdk-1.0.2739")]
+mod sdk_1_0_2739 {
+    use super::*;
+
+    /// Representation of a DOM FileSystemHandle object. Only passed through
+    /// here, its members are not declared.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2filesystemhandle>
+    #[com_interface("c65100ac-0de2-5551-a362-23d9bd1d0e1f")]
+    pub trait ICoreWebView2FileSystemHandle: IUnknown {}
+
+    /// IID of `ICoreWebView2FileSystemHandle`.
+    pub const IID_ICoreWebView2FileSystemHandle: GUID = GUID {
+        Data1: 0xc65100ac,
+        Data2: 0x0de2,
+        Data3: 0x5551,
+        Data4: [0xa3, 0x62, 0x23, 0xd9, 0xbd, 0x1d, 0x0e, 0x1f],
+    };
+
+    /// Represents a collection of generic objects. Objects can be added or
+    /// removed from the collection.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollection>
+    #[com_interface("5cfec11c-25bd-4e8d-9e1a-7acdaeeec047")]
+    pub trait ICoreWebView2ObjectCollection: ICoreWebView2ObjectCollectionView {
+        /// Removes the object at the specified index.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollection#removevalueatindex>
+        #[doc(alias = "RemoveValueAtIndex")]
+        unsafe fn remove_value_at_index(&self, /* in */ index: UINT32) -> HRESULT;
+
+        /// Inserts the object at the specified index.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2objectcollection#insertvalueatindex>
+        #[doc(alias = "InsertValueAtIndex")]
+        unsafe fn insert_value_at_index(
+            &self,
+            /* in */ index: UINT32,
+            /* in */ value: *mut *mut IUnknownVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2ObjectCollection`.
+    pub const IID_ICoreWebView2ObjectCollection: GUID = GUID {
+        Data1: 0x5cfec11c,
+        Data2: 0x25bd,
+        Data3: 0x4e8d,
+        Data4: [0x9e, 0x1a, 0x7a, 0xcd, 0xae, 0xee, 0xc0, 0x47],
+    };
+
+    /// This interface is an extension of the ICoreWebView2Environment13 that
+    /// supports creating FileSystemHandles and ObjectCollections.
+    ///
+    /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment14>
+    #[com_interface("a5e9fad9-c875-59da-9bd7-473aa5ca1cef")]
+    pub trait ICoreWebView2Environment14: ICoreWebView2Environment13 {
+        /// Create a `ICoreWebView2FileSystemHandle` object from a path that
+        /// represents a Web
+        /// [FileSystemFileHandle](https://developer.mozilla.org/docs/Web/API/FileSystemFileHandle).
+        /// The `path` is the path pointed by the file and must be a syntactically
+        /// correct fully qualified path, but it is not checked here whether it
+        /// currently points to a file. Any other state validation will be done when
+        /// this handle is accessed from web content and will cause DOM exceptions
+        /// if access operations fail.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment14#createwebfilesystemfilehandle>
+        #[doc(alias = "CreateWebFileSystemFileHandle")]
+        unsafe fn create_web_file_system_file_handle(
+            &self,
+            /* in */ path: LPCWSTR,
+            /* in */ permission: COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION,
+            /* out, retval */ value: *mut *mut *mut ICoreWebView2FileSystemHandleVTable,
+        ) -> HRESULT;
+
+        /// Create a `ICoreWebView2FileSystemHandle` object from a path that
+        /// represents a Web
+        /// [FileSystemDirectoryHandle](https://developer.mozilla.org/docs/Web/API/FileSystemDirectoryHandle).
+        /// The `path` is the path pointed by the directory and must be a
+        /// syntactically correct fully qualified path, but it is not checked here
+        /// whether it currently points to a directory.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment14#createwebfilesystemdirectoryhandle>
+        #[doc(alias = "CreateWebFileSystemDirectoryHandle")]
+        unsafe fn create_web_file_system_directory_handle(
+            &self,
+            /* in */ path: LPCWSTR,
+            /* in */ permission: COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION,
+            /* out, retval */ value: *mut *mut *mut ICoreWebView2FileSystemHandleVTable,
+        ) -> HRESULT;
+
+        /// Create an `ICoreWebView2ObjectCollection` object from an array of
+        /// `IUnknown` objects. The returned collection holds a reference on each
+        /// of the items, and items can be added to or removed from it afterwards.
+        ///
+        /// `items` is an array with `length` elements.
+        ///
+        /// See also: <https://learn.microsoft.com/en-us/microsoft-edge/webview2/reference/win32/icorewebview2environment14#createobjectcollection>
+        #[doc(alias = "CreateObjectCollection")]
+        unsafe fn create_object_collection(
+            &self,
+            /* in */ length: UINT32,
+            /* in, size_is(length) */ items: *mut *mut *mut IUnknownVTable,
+            /* out, retval */
+            objectCollection: *mut *mut *mut ICoreWebView2ObjectCollectionVTable,
+        ) -> HRESULT;
+    }
+
+    /// IID of `ICoreWebView2Environment14`.
+    pub const IID_ICoreWebView2Environment14: GUID = GUID {
+        Data1: 0xa5e9fad9,
+        Data2: 0xc875,
+        Data3: 0x59da,
+        Data4: [0x9b, 0xd7, 0x47, 0x3a, 0xa5, 0xca, 0x1c, 0xef],
+    };
+
+    /// Specifies the permission of a FileSystemHandle.
+    #[repr(u32)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION {
+        /// Read-only permission for FileSystemHandle.
+        COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION_READ_ONLY,
+        /// Read and write permissions for FileSystemHandle.
+        COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION_READ_WRITE,
+    }
+
+    impl COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION {
+        pub const fn as_u32(self) -> u32 {
+            self as u32
+        }
+    }
+
+    /// Fails with the value if it is not a known variant.
+    impl std::convert::TryFrom<u32> for COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0 => Ok(Self::COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION_READ_ONLY),
+                1 => Ok(Self::COREWEBVIEW2_FILE_SYSTEM_HANDLE_PERMISSION_READ_WRITE),
+                _ => Err(value),
+            }
+        }
+    }
+
+    pub const SDK_1_0_2739_INTERFACES: &[InterfaceInfo] = &[
+        InterfaceInfo {
+            name: "ICoreWebView2FileSystemHandle",
+            parent: "IUnknown",
+            iid: IID_ICoreWebView2FileSystemHandle,
+            methods: 0,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2ObjectCollection",
+            parent: "ICoreWebView2ObjectCollectionView",
+            iid: IID_ICoreWebView2ObjectCollection,
+            methods: 2,
+        },
+        InterfaceInfo {
+            name: "ICoreWebView2Environment14",
+            parent: "ICoreWebView2Environment13",
+            iid: IID_ICoreWebView2Environment14,
+            methods: 3,
+        },
+    ];
+}
+
+#[cfg(feature = "sdk-1.0.2739")]
+pub use self::sdk_1_0_2739::*;